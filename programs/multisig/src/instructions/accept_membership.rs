@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{error::MultisigError, group::Group, invite::MemberInvite, member::GroupMember};
+
+#[derive(Accounts)]
+pub struct AcceptMembershipInstructionAccounts<'info> {
+    #[account(
+        mut,
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Seeds bind it to the group and the invited key.
+    #[account(
+        mut,
+        seeds = [b"invite", group.key().as_ref(), new_member.key().as_ref()],
+        bump = member_invite.account_bump,
+        close = invite_payer
+    )]
+    pub member_invite: Account<'info, MemberInvite>,
+
+    #[account(
+        init,
+        space = 8 + GroupMember::INIT_SPACE,
+        payer = payer,
+        seeds = [b"member", group.key().as_ref(), new_member.key().as_ref()],
+        bump
+    )]
+    pub new_group_member: Account<'info, GroupMember>,
+
+    /// The invited key.
+    pub new_member: Signer<'info>,
+
+    /// CHECK: Must match the payer stored in the invite; receives the invite rent.
+    #[account(mut)]
+    pub invite_payer: UncheckedAccount<'info>,
+
+    /// Pays for the member account, so a member key that cannot hold lamports, such as another
+    /// group's PDA, can still accept.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<AcceptMembershipInstructionAccounts>) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require_keys_eq!(
+        ctx.accounts.invite_payer.key(),
+        ctx.accounts.member_invite.payer,
+        MultisigError::InvalidInvitePayer
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        ctx.accounts.member_invite.is_active(now),
+        MultisigError::InviteExpired
+    );
+
+    // The term of the membership may have ended while the invite was pending.
+    require!(
+        ctx.accounts
+            .member_invite
+            .expires_at
+            .is_none_or(|expires_at| now < expires_at),
+        MultisigError::MembershipExpired
+    );
+
+    Ok(())
+}
+
+/// Accepts a member invite, the invited key becomes a group member with the weight and
+/// permissions and term of the invite. The weight is checked against the current group config.
+/// Open proposals are marked stale, like any other membership change.
+pub fn accept_membership_handler(ctx: Context<AcceptMembershipInstructionAccounts>) -> Result<()> {
+    checks(&ctx)?;
+
+    let group = &mut ctx.accounts.group;
+    let invite = &ctx.accounts.member_invite;
+
+    ctx.accounts.new_group_member.set_inner(GroupMember {
+        expires_at: invite.expires_at,
+        ..GroupMember::new(
+            invite.user,
+            group.key(),
+            invite.permissions,
+            invite.weight,
+            group.take_member_index()?,
+            ctx.bumps.new_group_member,
+            group.max_member_weight,
+        )?
+    });
+
+    group.increment_member_count()?;
+    group.update_stale_proposal_index();
+
+    Ok(())
+}
@@ -3,8 +3,9 @@ use crate::state::{
     asset::Asset,
     group::Group,
     member::{AssetMember, GroupMember},
-    proposal::{ConfigChange, ConfigProposal, ProposalState, ProposalTarget},
+    proposal::{ConfigChange, ConfigProposal, ProposalState},
 };
+use crate::utils::close_open_proposal;
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -35,7 +36,6 @@ pub struct AddAssetMemberInstructionAccounts<'info> {
         mut,
         seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
         bump = proposal.account_bump,
-        close = proposer,
     )]
     pub proposal: Account<'info, ConfigProposal>,
 
@@ -44,8 +44,18 @@ pub struct AddAssetMemberInstructionAccounts<'info> {
     #[account(mut)]
     pub proposer: UncheckedAccount<'info>,
 
+    /// CHECK: The proposer's member account, its open proposal count is decremented
+    /// unless it was closed since the proposal was created.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_group_account: UncheckedAccount<'info>,
+
     // Corresponding group member must exist
     #[account(
+        mut,
         seeds = [b"member", group.key().as_ref(), args.new_member.as_ref()],
         bump = group_member.account_bump
     )]
@@ -81,7 +91,9 @@ fn checks(ctx: &Context<AddAssetMemberInstructionAccounts>) -> Result<()> {
 
     require_gte!(
         now,
-        ctx.accounts.proposal.get_valid_from_timestamp()?,
+        ctx.accounts
+            .proposal
+            .get_asset_valid_from_timestamp(&ctx.accounts.asset.asset_address)?,
         MultisigError::ProposalStillTimelocked
     );
 
@@ -92,7 +104,10 @@ fn checks(ctx: &Context<AddAssetMemberInstructionAccounts>) -> Result<()> {
     );
 
     require!(
-        ctx.accounts.proposal.state == ProposalState::Passed,
+        ctx.accounts
+            .proposal
+            .get_asset_state(&ctx.accounts.asset.asset_address)?
+            == ProposalState::Passed,
         MultisigError::ProposalNotPassed
     );
 
@@ -108,6 +123,7 @@ fn checks(ctx: &Context<AddAssetMemberInstructionAccounts>) -> Result<()> {
 /// Adds a pre-existing group member to govern an existing asset, storing their key and weight
 /// and permissions, as well as the group key and asset key for indexing.
 /// it must be triggered by an approved proposal and can then be called by anyone.
+/// A proposal targeting several assets is closed once the last of them is applied.
 pub fn add_asset_member_handler(
     ctx: Context<AddAssetMemberInstructionAccounts>,
     args: AddAssetMemberInstructionArgs,
@@ -119,30 +135,19 @@ pub fn add_asset_member_handler(
     let group = &mut ctx.accounts.group;
     let asset = &mut ctx.accounts.asset;
     let proposal = &ctx.accounts.proposal;
+    let asset_address = asset.asset_address;
 
     group.update_stale_proposal_index();
 
-    match &proposal.target {
-        ProposalTarget::Asset(asset_address) => {
-            require_keys_eq!(
-                *asset_address,
-                asset.asset_address,
-                MultisigError::InvalidAsset
-            );
-        }
-        ProposalTarget::Group => return Err(MultisigError::InvalidConfigChange.into()),
-    }
-
     match &proposal.config_change {
         ConfigChange::AddAssetMember {
             weight,
             member,
             permissions,
-            asset_address,
+            asset_address: change_asset,
         } => {
-            require_keys_eq!(
-                *asset_address,
-                asset.asset_address,
+            require!(
+                proposal.applies_to_asset(change_asset, &asset_address),
                 MultisigError::InvalidAsset
             );
             require_keys_eq!(*member, new_member, MultisigError::InvalidMember);
@@ -158,9 +163,26 @@ pub fn add_asset_member_handler(
             )?);
 
             asset.increment_member_count()?;
+            ctx.accounts.group_member.increment_asset_member_count()?;
         }
         _ => return Err(MultisigError::InvalidConfigChange.into()),
     }
 
+    let proposal = &mut ctx.accounts.proposal;
+    if proposal.mark_asset_executed(&asset_address, &mut ctx.accounts.group)? {
+        // The member is written back when this instruction exits, so a proposer adding
+        // themselves to the asset is updated through it.
+        if ctx.accounts.proposer_group_account.key() == ctx.accounts.group_member.key() {
+            ctx.accounts.group.close_open_proposal();
+            ctx.accounts.group_member.close_open_proposal();
+        } else {
+            close_open_proposal(
+                &mut ctx.accounts.group,
+                &ctx.accounts.proposer_group_account,
+            )?;
+        }
+        proposal.close(ctx.accounts.proposer.to_account_info())?;
+    }
+
     Ok(())
 }
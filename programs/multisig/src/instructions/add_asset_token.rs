@@ -89,18 +89,21 @@ pub struct AddAssetTokenInstructionAccounts<'info> {
     pub adder: Account<'info, GroupMember>,
 
     #[account(
+        mut,
         seeds = [b"member", group.key().as_ref(), args.member_key_1.as_ref()],
         bump = group_member_1.account_bump
     )]
     pub group_member_1: Box<Account<'info, GroupMember>>,
 
     #[account(
+        mut,
         seeds = [b"member", group.key().as_ref(), args.member_key_2.as_ref()],
         bump = group_member_2.account_bump
     )]
     pub group_member_2: Box<Account<'info, GroupMember>>,
 
     #[account(
+        mut,
         seeds = [b"member", group.key().as_ref(), args.member_key_3.as_ref()],
         bump = group_member_3.account_bump
     )]
@@ -275,5 +278,9 @@ pub fn add_asset_token_handler(
         )?);
     }
 
+    ctx.accounts.group_member_1.increment_asset_member_count()?;
+    ctx.accounts.group_member_2.increment_asset_member_count()?;
+    ctx.accounts.group_member_3.increment_asset_member_count()?;
+
     Ok(())
 }
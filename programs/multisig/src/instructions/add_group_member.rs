@@ -1,9 +1,10 @@
 use crate::state::error::*;
 use crate::state::{
     group::Group,
-    member::GroupMember,
+    invite::MemberInvite,
     proposal::{ConfigChange, ConfigProposal, ProposalState},
 };
+use crate::utils::close_open_proposal;
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -34,14 +35,24 @@ pub struct AddGroupMemberInstructionAccounts<'info> {
     #[account(mut)]
     pub proposer: UncheckedAccount<'info>,
 
+    /// CHECK: The proposer's member account, its open proposal count is decremented
+    /// unless it was closed since the proposal was created.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_group_account: UncheckedAccount<'info>,
+
+    /// The new member only joins once they accept the invite.
     #[account(
         init,
-        space = 8 + GroupMember::INIT_SPACE,
+        space = 8 + MemberInvite::INIT_SPACE,
         payer = payer,
-        seeds = [b"member", group.key().as_ref(), args.new_member.as_ref()],
+        seeds = [b"invite", group.key().as_ref(), args.new_member.as_ref()],
         bump
     )]
-    pub new_group_member: Account<'info, GroupMember>,
+    pub new_member_invite: Account<'info, MemberInvite>,
 
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -87,7 +98,8 @@ fn checks(ctx: &Context<AddGroupMemberInstructionAccounts>) -> Result<()> {
     Ok(())
 }
 
-/// Executes a passed AddGroupMember config proposal.
+/// Executes a passed AddGroupMember config proposal by inviting the new member,
+/// who joins the group by signing `accept_membership` before the invite expires.
 pub fn add_group_member_handler(
     ctx: Context<AddGroupMemberInstructionAccounts>,
     args: AddGroupMemberInstructionArgs,
@@ -106,22 +118,26 @@ pub fn add_group_member_handler(
             member,
             weight,
             permissions,
+            expires_at,
         } => {
             require_keys_eq!(*member, new_member, MultisigError::InvalidMember);
 
-            ctx.accounts.new_group_member.set_inner(GroupMember::new(
+            ctx.accounts.new_member_invite.set_inner(MemberInvite::new(
                 new_member,
                 group.key(),
                 *permissions,
                 *weight,
-                ctx.bumps.new_group_member,
-                group.max_member_weight,
+                *expires_at,
+                ctx.accounts.payer.key(),
+                Clock::get()?.unix_timestamp,
+                ctx.bumps.new_member_invite,
             )?);
-
-            group.increment_member_count()?;
         }
         _ => return Err(MultisigError::InvalidConfigChange.into()),
     }
 
-    Ok(())
+    close_open_proposal(
+        &mut ctx.accounts.group,
+        &ctx.accounts.proposer_group_account,
+    )
 }
@@ -89,6 +89,7 @@ pub fn add_member_in_reset_mode_handler(
         group.key(),
         args.permissions,
         args.weight,
+        group.take_member_index()?,
         ctx.bumps.new_member_account,
         group.max_member_weight,
     )?);
@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        error::MultisigError,
+        group::Group,
+        invite::MemberInvite,
+        member::GroupMember,
+        proposal::{ConfigChange, NormalProposal, ProposalAssetThresholdState, ProposalState},
+    },
+    utils::{close_account, create_pda_account},
+};
+
+#[derive(Accounts)]
+pub struct ApplyGroupConfigChangeInstructionAccounts<'info> {
+    /// Only this program can sign for the group, which it does for this instruction alone
+    /// while executing a normal proposal transaction.
+    #[account(
+        mut,
+        signer,
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Seeds bind proposal to group, `execute_proposal_transaction` checks that it
+    /// is the proposal being executed.
+    #[account(
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump,
+    )]
+    pub proposal: Account<'info, NormalProposal>,
+
+    /// CHECK: Member PDA the change applies to, verified against the config change in the handler.
+    #[account(mut)]
+    pub group_member: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Invite PDA of an added member, verified against the config change in the handler.
+    #[account(mut)]
+    pub member_invite: Option<UncheckedAccount<'info>>,
+
+    /// Pays for the invite of an added member.
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// CHECK: Receives the rent of a removed member; verified against group.rent_collector.
+    #[account(mut)]
+    pub rent_collector: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<ApplyGroupConfigChangeInstructionAccounts>) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    let proposal = &ctx.accounts.proposal;
+
+    require!(
+        proposal.state == ProposalState::Passed,
+        MultisigError::ProposalNotPassed
+    );
+
+    let group_approval = proposal
+        .group_approval
+        .as_ref()
+        .ok_or(MultisigError::GroupApprovalNotRequired)?;
+
+    // The group thresholds for this kind of change must have been met, the approval
+    // is checked again against the current group config.
+    require!(
+        group_approval.threshold_state == ProposalAssetThresholdState::UseThresholdReached
+            && group_approval.is_approved(&ctx.accounts.group)?,
+        MultisigError::GroupApprovalNotMet
+    );
+
+    Ok(())
+}
+
+/// Looks up the member account of the config change and checks its address.
+#[inline(always)]
+fn group_member_info<'info>(
+    ctx: &Context<ApplyGroupConfigChangeInstructionAccounts<'info>>,
+    member: &Pubkey,
+) -> Result<(AccountInfo<'info>, u8)> {
+    let group_member = ctx
+        .accounts
+        .group_member
+        .as_ref()
+        .ok_or(MultisigError::GroupMembershipNotProvided)?;
+
+    let (expected_member, bump) = Pubkey::find_program_address(
+        &[
+            b"member",
+            ctx.accounts.group.key().as_ref(),
+            member.as_ref(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        group_member.key(),
+        expected_member,
+        MultisigError::InvalidMember
+    );
+
+    Ok((group_member.to_account_info(), bump))
+}
+
+/// Looks up the invite account of the config change and checks its address.
+#[inline(always)]
+fn member_invite_info<'info>(
+    ctx: &Context<ApplyGroupConfigChangeInstructionAccounts<'info>>,
+    member: &Pubkey,
+) -> Result<(AccountInfo<'info>, u8)> {
+    let member_invite = ctx
+        .accounts
+        .member_invite
+        .as_ref()
+        .ok_or(MultisigError::GroupMembershipNotProvided)?;
+
+    let (expected_invite, bump) = Pubkey::find_program_address(
+        &[
+            b"invite",
+            ctx.accounts.group.key().as_ref(),
+            member.as_ref(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(
+        member_invite.key(),
+        expected_invite,
+        MultisigError::InvalidMember
+    );
+
+    Ok((member_invite.to_account_info(), bump))
+}
+
+/// Applies the group config change carried by a normal proposal.
+/// An added member is invited and joins the group once they sign `accept_membership`.
+/// This instruction can only be reached through `execute_proposal_transaction`.
+pub fn apply_group_config_change_handler(
+    ctx: Context<ApplyGroupConfigChangeInstructionAccounts>,
+) -> Result<()> {
+    checks(&ctx)?;
+
+    let group_key = ctx.accounts.group.key();
+    let config_change = ctx
+        .accounts
+        .proposal
+        .group_approval
+        .as_ref()
+        .ok_or(MultisigError::GroupApprovalNotRequired)?
+        .config_change
+        .clone();
+
+    match &config_change {
+        ConfigChange::AddGroupMember {
+            member,
+            weight,
+            permissions,
+            expires_at,
+        } => {
+            let (invite_info, bump) = member_invite_info(&ctx, member)?;
+            require_keys_eq!(
+                *invite_info.owner,
+                System::id(),
+                MultisigError::InvalidMember
+            );
+
+            let payer = ctx
+                .accounts
+                .payer
+                .as_ref()
+                .ok_or(MultisigError::PayerNotProvided)?;
+
+            let invite = MemberInvite::new(
+                *member,
+                group_key,
+                *permissions,
+                *weight,
+                *expires_at,
+                payer.key(),
+                Clock::get()?.unix_timestamp,
+                bump,
+            )?;
+
+            create_pda_account(
+                &payer.to_account_info(),
+                &invite_info,
+                &ctx.accounts.system_program.to_account_info(),
+                8 + MemberInvite::INIT_SPACE,
+                &[b"invite", group_key.as_ref(), member.as_ref(), &[bump]],
+            )?;
+            invite.try_serialize(&mut &mut invite_info.try_borrow_mut_data()?[..])?;
+        }
+        ConfigChange::RemoveGroupMember { member } => {
+            let (member_info, _) = group_member_info(&ctx, member)?;
+            require_keys_eq!(*member_info.owner, crate::ID, MultisigError::InvalidMember);
+
+            let rent_collector = ctx
+                .accounts
+                .rent_collector
+                .as_ref()
+                .ok_or(MultisigError::UnexpectedRentCollector)?;
+            require_keys_eq!(
+                rent_collector.key(),
+                ctx.accounts.group.rent_collector,
+                MultisigError::UnexpectedRentCollector
+            );
+
+            // Deserializing checks the discriminator, the address was checked above.
+            GroupMember::try_deserialize(&mut &member_info.try_borrow_data()?[..])?;
+
+            ctx.accounts.group.decrement_member_count()?;
+            close_account(&member_info, &rent_collector.to_account_info())?;
+        }
+        ConfigChange::ChangeGroupConfig { config_types } => {
+            ctx.accounts.group.apply_config_types(config_types)?
+        }
+        ConfigChange::RenewGroupMemberTerm { member, expires_at } => {
+            let (member_info, _) = group_member_info(&ctx, member)?;
+            require_keys_eq!(*member_info.owner, crate::ID, MultisigError::InvalidMember);
+
+            let mut group_member =
+                GroupMember::try_deserialize(&mut &member_info.try_borrow_data()?[..])?;
+            group_member.expires_at = *expires_at;
+            group_member.try_serialize(&mut &mut member_info.try_borrow_mut_data()?[..])?;
+        }
+        ConfigChange::ChangeGroupMemberPermissions {
+            member,
+            permissions,
+        } => {
+            let (member_info, _) = group_member_info(&ctx, member)?;
+            require_keys_eq!(*member_info.owner, crate::ID, MultisigError::InvalidMember);
+
+            let mut group_member =
+                GroupMember::try_deserialize(&mut &member_info.try_borrow_data()?[..])?;
+            group_member.set_permissions(*permissions)?;
+            group_member.try_serialize(&mut &mut member_info.try_borrow_mut_data()?[..])?;
+        }
+        _ => return Err(MultisigError::InvalidConfigChange.into()),
+    }
+
+    ctx.accounts.group.update_stale_proposal_index();
+
+    Ok(())
+}
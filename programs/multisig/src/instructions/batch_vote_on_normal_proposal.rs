@@ -0,0 +1,301 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        asset::Asset,
+        error::MultisigError,
+        group::Group,
+        member::AssetMember,
+        proposal::{NormalProposal, ProposalAssetThresholdState, ProposalState},
+        vote::{VoteChoice, VoteRecord},
+    },
+    utils::create_pda_account,
+    GroupMember,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AssetVote {
+    pub voting_asset_index: u8,
+    pub vote: VoteChoice,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchVoteOnNormalProposalInstructionArgs {
+    /// Sorted by `voting_asset_index` without duplicates.
+    pub votes: Vec<AssetVote>,
+}
+
+#[derive(Accounts)]
+pub struct BatchVoteOnNormalProposalInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Seeds checks bind it to the group.
+    #[account(
+        mut,
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump,
+    )]
+    pub proposal: Account<'info, NormalProposal>,
+
+    /// Seeds and ownership checks bind it to the proposal.
+    #[account(
+        seeds = [b"proposal-transaction", proposal.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub proposal_transaction: UncheckedAccount<'info>,
+
+    /// Seeds check binds it to the group and voter.
+    #[account(
+        seeds = [b"member", group.key().as_ref(), voter.key().as_ref()],
+        bump = group_member.account_bump
+    )]
+    pub group_member: Account<'info, GroupMember>,
+
+    pub voter: Signer<'info>,
+
+    /// Pays for new vote records, so a member key that cannot hold lamports, such as another
+    /// group's PDA, can still vote.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[inline(always)]
+fn checks(
+    ctx: &Context<BatchVoteOnNormalProposalInstructionAccounts>,
+    args: &BatchVoteOnNormalProposalInstructionArgs,
+) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require!(
+        ctx.accounts.proposal.state == ProposalState::Open,
+        MultisigError::ProposalNotOpen
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_index,
+        ctx.accounts.group.proposal_index_after_stale,
+        MultisigError::ProposalStale
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require_gt!(
+        ctx.accounts.proposal.proposal_deadline_timestamp,
+        now,
+        MultisigError::ProposalExpired
+    );
+
+    require!(
+        ctx.accounts.group_member.is_active(now),
+        MultisigError::MembershipExpired
+    );
+
+    require!(
+        ctx.accounts
+            .group_member
+            .can_vote_on_proposal_from(ctx.accounts.proposal.propose_timestamp),
+        MultisigError::KeyRotatedAfterProposal
+    );
+
+    require!(
+        ctx.accounts.proposal.compact_votes.is_none(),
+        MultisigError::CompactVotesRequired
+    );
+
+    require!(!args.votes.is_empty(), MultisigError::InvalidVoteOption);
+
+    // Strictly increasing indices rule out voting twice on an asset in one batch.
+    require!(
+        args.votes
+            .windows(2)
+            .all(|pair| pair[0].voting_asset_index < pair[1].voting_asset_index),
+        MultisigError::AssetsNotSortedOrDuplicate
+    );
+
+    require_gt!(
+        ctx.accounts.proposal.assets.len(),
+        usize::from(args.votes[args.votes.len() - 1].voting_asset_index),
+        MultisigError::InvalidAssetIndex
+    );
+
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        args.votes.len() * 3,
+        MultisigError::LengthMismatch
+    );
+
+    Ok(())
+}
+
+/// Vote on several assets of a normal proposal at once.
+/// Remaining accounts are `[asset, asset member, vote record]` for each vote, in order.
+/// Votes are recorded in the same per-asset vote records `vote_on_normal_proposal` uses.
+pub fn batch_vote_on_normal_proposal_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchVoteOnNormalProposalInstructionAccounts<'info>>,
+    args: BatchVoteOnNormalProposalInstructionArgs,
+) -> Result<()> {
+    checks(&ctx, &args)?;
+
+    let group = &ctx.accounts.group;
+    let group_key = group.key();
+    let proposal = &mut ctx.accounts.proposal;
+    let proposal_key = proposal.key();
+    let voter = ctx.accounts.voter.to_account_info();
+    let voter_key = voter.key();
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let now = Clock::get()?.unix_timestamp;
+
+    for (
+        AssetVote {
+            voting_asset_index,
+            vote,
+        },
+        accounts,
+    ) in args
+        .votes
+        .into_iter()
+        .zip(ctx.remaining_accounts.chunks_exact(3))
+    {
+        // A vote earlier in the batch may have passed or failed the proposal.
+        if proposal.state != ProposalState::Open {
+            break;
+        }
+
+        let (asset_info, asset_member_info, vote_record_info) =
+            (&accounts[0], &accounts[1], &accounts[2]);
+        let asset_index = usize::from(voting_asset_index);
+
+        let asset = Account::<Asset>::try_from(asset_info)?;
+        require_keys_eq!(
+            asset.key(),
+            Pubkey::create_program_address(
+                &[
+                    b"asset",
+                    group_key.as_ref(),
+                    asset.asset_address.as_ref(),
+                    &[asset.account_bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| MultisigError::InvalidAsset)?,
+            MultisigError::InvalidAsset
+        );
+        require_keys_eq!(
+            proposal.assets[asset_index].asset,
+            asset.asset_address,
+            MultisigError::InvalidAsset
+        );
+        require!(
+            proposal.assets[asset_index].threshold_state
+                == ProposalAssetThresholdState::NoThresholdReached,
+            MultisigError::StateAlreadyFinalized
+        );
+
+        let asset_member = Account::<AssetMember>::try_from(asset_member_info)?;
+        require!(
+            asset_member.user == voter_key
+                && asset_member.group == group_key
+                && asset_member.asset == asset.asset_address,
+            MultisigError::InvalidAssetMember
+        );
+        require!(
+            asset_member.is_active(now),
+            MultisigError::MembershipExpired
+        );
+
+        let weight = asset_member.weight.min(group.max_member_weight);
+        require_gt!(weight, 0, MultisigError::UnauthorizedVoter);
+
+        let index_seed = [voting_asset_index];
+        let (expected_vote_record, bump) = Pubkey::find_program_address(
+            &[
+                b"vote-record",
+                group_key.as_ref(),
+                proposal_key.as_ref(),
+                voter_key.as_ref(),
+                &index_seed,
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            vote_record_info.key(),
+            expected_vote_record,
+            MultisigError::InvalidAssetIndex
+        );
+
+        let previous_vote = if *vote_record_info.owner == crate::ID {
+            let mut vote_record =
+                VoteRecord::try_deserialize(&mut &vote_record_info.try_borrow_data()?[..])?;
+            let previous_vote = vote_record.vote_choice;
+
+            // The voter takes back a vote their delegate may have cast.
+            vote_record.vote_choice = vote;
+            vote_record.delegate = None;
+            vote_record.try_serialize(&mut &mut vote_record_info.try_borrow_mut_data()?[..])?;
+
+            Some(previous_vote)
+        } else {
+            create_pda_account(
+                &payer,
+                vote_record_info,
+                &system_program,
+                8 + VoteRecord::INIT_SPACE,
+                &[
+                    b"vote-record",
+                    group_key.as_ref(),
+                    proposal_key.as_ref(),
+                    voter_key.as_ref(),
+                    &index_seed,
+                    &[bump],
+                ],
+            )?;
+
+            VoteRecord::new(
+                voter_key,
+                proposal_key,
+                Some(voting_asset_index),
+                bump,
+                vote,
+            )
+            .try_serialize(&mut &mut vote_record_info.try_borrow_mut_data()?[..])?;
+
+            None
+        };
+
+        let proposal_asset = proposal
+            .get_asset_mut(asset_index)
+            .ok_or(MultisigError::InvalidAssetIndex)?;
+
+        match previous_vote {
+            // First vote
+            None => proposal_asset.increment_vote_count()?,
+            // Re-vote, undo previous
+            Some(previous_vote) if previous_vote != vote => match previous_vote {
+                VoteChoice::For => proposal_asset.sub_use_vote_weight(weight),
+                VoteChoice::Against => proposal_asset.sub_not_use_vote_weight(weight),
+            },
+            Some(_) => continue,
+        }
+
+        match vote {
+            VoteChoice::For => {
+                proposal_asset.add_use_vote_weight(weight);
+                proposal.check_and_mark_asset_passed(asset_index, &asset)?;
+            }
+            VoteChoice::Against => {
+                proposal_asset.add_not_use_vote_weight(weight);
+                proposal.check_and_mark_asset_failed(asset_index, &asset)?;
+            }
+        }
+    }
+
+    Ok(())
+}
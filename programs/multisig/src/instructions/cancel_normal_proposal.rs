@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    error::MultisigError, group::Group, member::GroupMember, proposal::NormalProposal,
+};
+
+#[derive(Accounts)]
+pub struct CancelNormalProposalInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump
+    )]
+    pub proposal: Account<'info, NormalProposal>,
+
+    /// Seeds bind membership to group + canceller.
+    #[account(
+        seeds = [b"member", group.key().as_ref(), canceller.key().as_ref()],
+        bump = canceller_group_account.account_bump
+    )]
+    pub canceller_group_account: Account<'info, GroupMember>,
+
+    pub canceller: Signer<'info>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<CancelNormalProposalInstructionAccounts>) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require!(
+        ctx.accounts.canceller_group_account.has_cancel(),
+        MultisigError::InsufficientPermissions
+    );
+
+    require!(
+        ctx.accounts
+            .canceller_group_account
+            .is_active(Clock::get()?.unix_timestamp),
+        MultisigError::MembershipExpired
+    );
+
+    Ok(())
+}
+
+/// Cancels a normal proposal that is open, or passed but not yet being executed.
+/// Requires Cancel permission. The proposal can then be closed right away.
+pub fn cancel_normal_proposal_handler(
+    ctx: Context<CancelNormalProposalInstructionAccounts>,
+) -> Result<()> {
+    checks(&ctx)?;
+
+    ctx.accounts.proposal.cancel()
+}
@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    asset::Asset,
+    error::MultisigError,
+    group::Group,
+    proposal::{ConfigChange, ConfigProposal, ProposalState},
+    schedule::Schedule,
+};
+use crate::utils::close_open_proposal;
+
+#[derive(Accounts)]
+pub struct CancelScheduleInstructionAccounts<'info> {
+    /// Mutable so the proposer's open proposal can be released.
+    #[account(
+        mut,
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        seeds = [b"asset", group.key().as_ref(), asset.asset_address.as_ref()],
+        bump = asset.account_bump
+    )]
+    pub asset: Account<'info, Asset>,
+
+    #[account(
+        mut,
+        seeds = [b"schedule", group.key().as_ref(), schedule.schedule_seed.as_ref()],
+        bump = schedule.account_bump,
+        has_one = group,
+        has_one = rent_payer,
+        close = rent_payer,
+    )]
+    pub schedule: Account<'info, Schedule>,
+
+    /// CHECK: Checked against the schedule; receives its rent.
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump,
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Must match the proposer stored in the proposal; receives closed-account rent.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// CHECK: The proposer's member account, its open proposal count is decremented
+    /// unless it was closed since the proposal was created.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_group_account: UncheckedAccount<'info>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<CancelScheduleInstructionAccounts>) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require_keys_eq!(
+        ctx.accounts.proposer.key(),
+        ctx.accounts.proposal.proposer,
+        MultisigError::InvalidProposer
+    );
+
+    require!(
+        ctx.accounts
+            .proposal
+            .get_asset_state(&ctx.accounts.asset.asset_address)?
+            == ProposalState::Passed,
+        MultisigError::ProposalNotPassed
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require_gte!(
+        now,
+        ctx.accounts
+            .proposal
+            .get_asset_valid_from_timestamp(&ctx.accounts.asset.asset_address)?,
+        MultisigError::ProposalStillTimelocked
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_deadline_timestamp,
+        now,
+        MultisigError::ProposalExpired
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_index,
+        ctx.accounts.group.proposal_index_after_stale,
+        MultisigError::ProposalStale
+    );
+
+    Ok(())
+}
+
+/// Executes a passed CancelSchedule config proposal, closing the schedule so none of its
+/// remaining runs are made. Schedules do not affect voting, so open proposals are not staled.
+pub fn cancel_schedule_handler(ctx: Context<CancelScheduleInstructionAccounts>) -> Result<()> {
+    checks(&ctx)?;
+
+    let asset_address = ctx.accounts.asset.asset_address;
+    let schedule = &ctx.accounts.schedule;
+    let proposal = &ctx.accounts.proposal;
+
+    match &proposal.config_change {
+        ConfigChange::CancelSchedule {
+            asset_address: change_asset,
+            schedule: change_schedule,
+        } => {
+            require!(
+                proposal.applies_to_asset(change_asset, &asset_address),
+                MultisigError::InvalidAsset
+            );
+            require_keys_eq!(
+                schedule.key(),
+                *change_schedule,
+                MultisigError::InvalidConfigChange
+            );
+            require_keys_eq!(schedule.asset, asset_address, MultisigError::InvalidAsset);
+        }
+        _ => return Err(MultisigError::InvalidConfigChange.into()),
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    if proposal.mark_asset_executed(&asset_address, &mut ctx.accounts.group)? {
+        close_open_proposal(
+            &mut ctx.accounts.group,
+            &ctx.accounts.proposer_group_account,
+        )?;
+        proposal.close(ctx.accounts.proposer.to_account_info())?;
+    }
+
+    Ok(())
+}
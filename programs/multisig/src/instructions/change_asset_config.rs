@@ -2,9 +2,9 @@ use crate::state::error::*;
 use crate::state::{
     asset::Asset,
     group::Group,
-    proposal::{ConfigChange, ConfigProposal, ConfigType, ProposalState},
+    proposal::{ConfigChange, ConfigProposal, ProposalState},
 };
-use crate::ProposalTarget;
+use crate::utils::close_open_proposal;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -27,13 +27,21 @@ pub struct ChangeAssetConfigInstructionAccounts<'info> {
         mut,
         seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
         bump = proposal.account_bump,
-        close = proposer,
     )]
     pub proposal: Account<'info, ConfigProposal>,
 
     /// CHECK: Must match the proposer stored in the proposal; receives closed-account rent.
     #[account(mut)]
     pub proposer: UncheckedAccount<'info>,
+
+    /// CHECK: The proposer's member account, its open proposal count is decremented
+    /// unless it was closed since the proposal was created.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_group_account: UncheckedAccount<'info>,
 }
 
 #[inline(always)]
@@ -48,7 +56,9 @@ fn checks(ctx: &Context<ChangeAssetConfigInstructionAccounts>) -> Result<()> {
 
     require_gte!(
         now,
-        ctx.accounts.proposal.get_valid_from_timestamp()?,
+        ctx.accounts
+            .proposal
+            .get_asset_valid_from_timestamp(&ctx.accounts.asset.asset_address)?,
         MultisigError::ProposalStillTimelocked
     );
 
@@ -59,7 +69,10 @@ fn checks(ctx: &Context<ChangeAssetConfigInstructionAccounts>) -> Result<()> {
     );
 
     require!(
-        ctx.accounts.proposal.state == ProposalState::Passed,
+        ctx.accounts
+            .proposal
+            .get_asset_state(&ctx.accounts.asset.asset_address)?
+            == ProposalState::Passed,
         MultisigError::ProposalNotPassed
     );
 
@@ -74,6 +87,7 @@ fn checks(ctx: &Context<ChangeAssetConfigInstructionAccounts>) -> Result<()> {
 
 /// Updates asset-wide configuration (e.g, timelock, thresholds, expiry),
 /// It must be triggered by an approved proposal and can then be called by anyone.
+/// A proposal targeting several assets is closed once the last of them is applied.
 pub fn change_asset_config_handler(
     ctx: Context<ChangeAssetConfigInstructionAccounts>,
 ) -> Result<()> {
@@ -82,42 +96,25 @@ pub fn change_asset_config_handler(
     let group = &mut ctx.accounts.group;
     let asset = &mut ctx.accounts.asset;
     let proposal = &ctx.accounts.proposal;
+    let asset_address = asset.asset_address;
 
     group.update_stale_proposal_index();
 
     match &proposal.config_change {
-        ConfigChange::ChangeAssetConfig { config_type } => {
-            let asset_key = match &proposal.target {
-                &ProposalTarget::Asset(asset_key) => asset_key,
-                ProposalTarget::Group => return Err(MultisigError::InvalidConfigChange.into()),
-            };
-
-            require_keys_eq!(asset_key, asset.asset_address, MultisigError::InvalidAsset);
-
-            match config_type {
-                ConfigType::AddMember(threshold) => asset.set_add_threshold(*threshold)?,
-                ConfigType::NotAddMember(threshold) => asset.set_not_add_threshold(*threshold)?,
-                ConfigType::RemoveMember(threshold) => asset.set_remove_threshold(*threshold)?,
-                ConfigType::NotRemoveMember(threshold) => {
-                    asset.set_not_remove_threshold(*threshold)?
-                }
-                ConfigType::Use(threshold) => asset.set_use_threshold(*threshold)?,
-                ConfigType::NotUse(threshold) => asset.set_not_use_threshold(*threshold)?,
-                ConfigType::ChangeConfig(threshold) => {
-                    asset.set_change_config_threshold(*threshold)?
-                }
-                ConfigType::NotChangeConfig(threshold) => {
-                    asset.set_not_change_config_threshold(*threshold)?
-                }
-                ConfigType::MinimumMemberCount(count) => asset.set_minimum_member_count(*count)?,
-                ConfigType::MinimumVoteCount(count) => asset.set_minimum_vote_count(*count)?,
-                ConfigType::MinimumTimelock(_) => {
-                    return Err(MultisigError::InvalidConfigChange.into())
-                }
-            }
+        ConfigChange::ChangeAssetConfig { config_types } => {
+            asset.apply_config_types(config_types)?;
         }
         _ => return Err(MultisigError::InvalidConfigChange.into()),
     }
 
+    let proposal = &mut ctx.accounts.proposal;
+    if proposal.mark_asset_executed(&asset_address, &mut ctx.accounts.group)? {
+        close_open_proposal(
+            &mut ctx.accounts.group,
+            &ctx.accounts.proposer_group_account,
+        )?;
+        proposal.close(ctx.accounts.proposer.to_account_info())?;
+    }
+
     Ok(())
 }
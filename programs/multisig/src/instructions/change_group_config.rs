@@ -1,8 +1,9 @@
 use crate::state::error::*;
 use crate::state::{
     group::Group,
-    proposal::{ConfigChange, ConfigProposal, ConfigType, ProposalState},
+    proposal::{ConfigChange, ConfigProposal, ProposalState},
 };
+use crate::utils::close_open_proposal;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -25,6 +26,15 @@ pub struct ChangeGroupConfigInstructionAccounts<'info> {
     /// CHECK: Must match the proposer stored in the proposal; receives closed-account rent.
     #[account(mut)]
     pub proposer: UncheckedAccount<'info>,
+
+    /// CHECK: The proposer's member account, its open proposal count is decremented
+    /// unless it was closed since the proposal was created.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_group_account: UncheckedAccount<'info>,
 }
 
 #[inline(always)]
@@ -77,22 +87,14 @@ pub fn change_group_config_handler(
     group.update_stale_proposal_index();
 
     match &proposal.config_change {
-        ConfigChange::ChangeGroupConfig { config_type } => match config_type {
-            ConfigType::AddMember(threshold) => group.set_add_threshold(*threshold)?,
-            ConfigType::NotAddMember(threshold) => group.set_not_add_threshold(*threshold)?,
-            ConfigType::RemoveMember(threshold) => group.set_remove_threshold(*threshold)?,
-            ConfigType::NotRemoveMember(threshold) => group.set_not_remove_threshold(*threshold)?,
-            ConfigType::ChangeConfig(threshold) => group.set_change_config_threshold(*threshold)?,
-            ConfigType::NotChangeConfig(threshold) => {
-                group.set_not_change_config_threshold(*threshold)?
-            }
-            ConfigType::MinimumMemberCount(count) => group.set_minimum_member_count(*count)?,
-            ConfigType::MinimumVoteCount(count) => group.set_minimum_vote_count(*count)?,
-            ConfigType::MinimumTimelock(timelock) => group.set_minimum_timelock(*timelock),
-            _ => return Err(MultisigError::UnexpectedConfigChange.into()),
-        },
+        ConfigChange::ChangeGroupConfig { config_types } => {
+            group.apply_config_types(config_types)?
+        }
         _ => return Err(MultisigError::InvalidConfigChange.into()),
     }
 
-    Ok(())
+    close_open_proposal(
+        &mut ctx.accounts.group,
+        &ctx.accounts.proposer_group_account,
+    )
 }
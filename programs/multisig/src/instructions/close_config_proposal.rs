@@ -5,6 +5,7 @@ use crate::{
         error::MultisigError,
         proposal::{ConfigProposal, ProposalState},
     },
+    utils::close_open_proposal,
     Group,
 };
 
@@ -30,7 +31,10 @@ fn validate_proposal_closable(
         ProposalState::Passed => {
             require!(is_stale || is_expired, MultisigError::ProposalStillActive);
         }
-        ProposalState::Expired | ProposalState::Failed | ProposalState::Executed => {}
+        ProposalState::Expired
+        | ProposalState::Failed
+        | ProposalState::Executed
+        | ProposalState::Cancelled => {}
     }
 
     Ok(())
@@ -39,6 +43,7 @@ fn validate_proposal_closable(
 #[derive(Accounts)]
 pub struct CloseProposalInstructionAccounts<'info> {
     #[account(
+        mut,
         seeds = [b"group", group.group_seed.as_ref()],
         bump = group.account_bump
     )]
@@ -56,12 +61,23 @@ pub struct CloseProposalInstructionAccounts<'info> {
     /// CHECK: Must match the proposer stored in the proposal; verified in checks(); receives rent.
     #[account(mut)]
     pub proposer: UncheckedAccount<'info>,
+
+    /// CHECK: The proposer's member account, its open proposal count is decremented
+    /// unless it was closed since the proposal was created.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_group_account: UncheckedAccount<'info>,
 }
 
 #[inline(always)]
 fn checks(ctx: &Context<CloseProposalInstructionAccounts>) -> Result<()> {
     let proposal = &ctx.accounts.proposal;
 
+    require!(!proposal.bonded, MultisigError::ProposalBondNotReleased);
+
     validate_proposal_closable(
         &ctx.accounts.group,
         ctx.accounts.proposer.key(),
@@ -75,5 +91,10 @@ fn checks(ctx: &Context<CloseProposalInstructionAccounts>) -> Result<()> {
 /// Close a config proposal that failed, expired, or became stale after passing.
 /// This instruction can be called by anyone.
 pub fn close_proposal_handler(ctx: Context<CloseProposalInstructionAccounts>) -> Result<()> {
-    checks(&ctx)
+    checks(&ctx)?;
+
+    close_open_proposal(
+        &mut ctx.accounts.group,
+        &ctx.accounts.proposer_group_account,
+    )
 }
@@ -46,7 +46,7 @@ fn checks(ctx: &Context<CloseEmergencyResetProposalAccounts>) -> Result<()> {
             require!(is_expired, MultisigError::ProposalStillActive);
         }
         ProposalState::Failed | ProposalState::Expired => {}
-        ProposalState::Executed => {}
+        ProposalState::Executed | ProposalState::Cancelled => {}
     }
 
     Ok(())
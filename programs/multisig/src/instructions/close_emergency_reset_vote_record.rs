@@ -50,7 +50,10 @@ fn checks(ctx: &Context<CloseEmergencyResetVoteRecordAccounts>) -> Result<()> {
             ProposalState::Passed => {
                 require!(is_expired, MultisigError::ProposalStillActive);
             }
-            ProposalState::Failed | ProposalState::Expired | ProposalState::Executed => {}
+            ProposalState::Failed
+            | ProposalState::Expired
+            | ProposalState::Executed
+            | ProposalState::Cancelled => {}
         }
     }
 
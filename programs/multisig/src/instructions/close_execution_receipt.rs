@@ -0,0 +1,75 @@
+use crate::{
+    state::{error::MultisigError, ExecutionReceipt},
+    Group, NormalProposal,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseExecutionReceiptInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// CHECK: The proposal the receipt was recorded for; may be closed so we load it as UncheckedAccount.
+    pub proposal: UncheckedAccount<'info>,
+
+    /// Seeds bind receipt to proposal - execution_receipt.proposal == proposal is guaranteed.
+    /// execution_receipt.group is checked against group in checks().
+    #[account(
+        mut,
+        seeds = [b"execution-receipt", proposal.key.as_ref()],
+        bump = execution_receipt.account_bump,
+        close = rent_collector,
+    )]
+    pub execution_receipt: Account<'info, ExecutionReceipt>,
+
+    /// CHECK: Rent collector; verified against group.rent_collector in checks().
+    #[account(mut)]
+    pub rent_collector: UncheckedAccount<'info>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<CloseExecutionReceiptInstructionAccounts>) -> Result<()> {
+    let group = &ctx.accounts.group;
+    let execution_receipt = &ctx.accounts.execution_receipt;
+
+    require_keys_eq!(
+        execution_receipt.group,
+        group.key(),
+        MultisigError::UnexpectedGroup
+    );
+
+    require_keys_eq!(
+        ctx.accounts.rent_collector.key(),
+        group.rent_collector,
+        MultisigError::UnexpectedRentCollector
+    );
+
+    let proposal = &ctx.accounts.proposal;
+
+    // The receipt is kept for as long as its proposal exists, unless the proposal
+    // at this address has since been replaced by a new one.
+    if !(proposal.data_is_empty() && proposal.owner == &System::id()) {
+        let proposal_account: NormalProposal =
+            NormalProposal::try_deserialize(&mut &proposal.data.borrow()[..])?;
+
+        require_neq!(
+            proposal_account.proposal_index,
+            execution_receipt.proposal_index,
+            MultisigError::ProposalStillActive
+        );
+    }
+
+    Ok(())
+}
+
+/// Close the execution receipt of a proposal that has been closed and refund the
+/// rent to the rent collector.
+/// This instruction can be called by anyone.
+pub fn close_execution_receipt_handler(
+    ctx: Context<CloseExecutionReceiptInstructionAccounts>,
+) -> Result<()> {
+    checks(&ctx)
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    asset::Asset,
+    error::MultisigError,
+    group::Group,
+    member::{AssetMember, GroupMember},
+};
+
+#[derive(Accounts)]
+pub struct CloseExpiredAssetMemberInstructionAccounts<'info> {
+    #[account(
+        mut,
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        mut,
+        seeds = [b"asset", group.key().as_ref(), asset.asset_address.as_ref()],
+        bump = asset.account_bump
+    )]
+    pub asset: Account<'info, Asset>,
+
+    /// Seeds bind it to the group and asset.
+    #[account(
+        mut,
+        seeds = [b"asset-member", group.key().as_ref(), asset.asset_address.as_ref(), asset_member.user.as_ref()],
+        bump = asset_member.account_bump,
+        close = rent_collector
+    )]
+    pub asset_member: Account<'info, AssetMember>,
+
+    /// CHECK: Seeds bind it to the group and member, it is only updated while it exists.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), asset_member.user.as_ref()],
+        bump
+    )]
+    pub group_member: UncheckedAccount<'info>,
+
+    /// CHECK: Validated against group.rent_collector in checks().
+    #[account(mut)]
+    pub rent_collector: UncheckedAccount<'info>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<CloseExpiredAssetMemberInstructionAccounts>) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require_keys_eq!(
+        ctx.accounts.rent_collector.key(),
+        ctx.accounts.group.rent_collector,
+        MultisigError::UnexpectedRentCollector
+    );
+
+    require!(
+        !ctx.accounts
+            .asset_member
+            .is_active(Clock::get()?.unix_timestamp),
+        MultisigError::MembershipNotExpired
+    );
+
+    Ok(())
+}
+
+/// Close an asset member whose term has ended and send the rent to the rent collector.
+/// Fails while closing them would take the asset below its minimum member or vote count.
+/// This instruction can be called by anyone.
+pub fn close_expired_asset_member_handler(
+    ctx: Context<CloseExpiredAssetMemberInstructionAccounts>,
+) -> Result<()> {
+    checks(&ctx)?;
+
+    ctx.accounts.asset.decrement_member_count()?;
+    ctx.accounts.group.update_stale_proposal_index();
+
+    let group_member_info = ctx.accounts.group_member.to_account_info();
+    if *group_member_info.owner == crate::ID {
+        let mut group_member =
+            GroupMember::try_deserialize(&mut &group_member_info.try_borrow_data()?[..])?;
+        group_member.decrement_asset_member_count();
+        group_member.try_serialize(&mut &mut group_member_info.try_borrow_mut_data()?[..])?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{error::MultisigError, group::Group, member::GroupMember};
+
+#[derive(Accounts)]
+pub struct CloseExpiredGroupMemberInstructionAccounts<'info> {
+    #[account(
+        mut,
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Seeds bind it to the group.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), group_member.user.as_ref()],
+        bump = group_member.account_bump,
+        close = rent_collector
+    )]
+    pub group_member: Account<'info, GroupMember>,
+
+    /// CHECK: Validated against group.rent_collector in checks().
+    #[account(mut)]
+    pub rent_collector: UncheckedAccount<'info>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<CloseExpiredGroupMemberInstructionAccounts>) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require_keys_eq!(
+        ctx.accounts.rent_collector.key(),
+        ctx.accounts.group.rent_collector,
+        MultisigError::UnexpectedRentCollector
+    );
+
+    require!(
+        !ctx.accounts
+            .group_member
+            .is_active(Clock::get()?.unix_timestamp),
+        MultisigError::MembershipNotExpired
+    );
+
+    Ok(())
+}
+
+/// Close a group member whose term has ended and send the rent to the rent collector.
+/// Fails while closing them would take the group below its minimum member or vote count,
+/// a renewal or a config change is needed then. Their asset members are left for
+/// `clean_up_asset_member_instruction`.
+/// This instruction can be called by anyone.
+pub fn close_expired_group_member_handler(
+    ctx: Context<CloseExpiredGroupMemberInstructionAccounts>,
+) -> Result<()> {
+    checks(&ctx)?;
+
+    let group = &mut ctx.accounts.group;
+    group.decrement_member_count()?;
+    group.update_stale_proposal_index();
+
+    Ok(())
+}
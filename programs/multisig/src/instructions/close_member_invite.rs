@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{error::MultisigError, group::Group, invite::MemberInvite};
+
+#[derive(Accounts)]
+pub struct CloseMemberInviteInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Seeds bind it to the group.
+    #[account(
+        mut,
+        seeds = [b"invite", group.key().as_ref(), member_invite.user.as_ref()],
+        bump = member_invite.account_bump,
+        close = invite_payer
+    )]
+    pub member_invite: Account<'info, MemberInvite>,
+
+    /// CHECK: Must match the payer stored in the invite; receives the invite rent.
+    #[account(mut)]
+    pub invite_payer: UncheckedAccount<'info>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<CloseMemberInviteInstructionAccounts>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.invite_payer.key(),
+        ctx.accounts.member_invite.payer,
+        MultisigError::InvalidInvitePayer
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        !ctx.accounts.member_invite.is_active(now),
+        MultisigError::InviteNotExpired
+    );
+
+    Ok(())
+}
+
+/// Closes an expired member invite and refunds its payer, anyone can call it.
+/// The member can be invited again afterwards.
+pub fn close_member_invite_handler(
+    ctx: Context<CloseMemberInviteInstructionAccounts>,
+) -> Result<()> {
+    checks(&ctx)?;
+    Ok(())
+}
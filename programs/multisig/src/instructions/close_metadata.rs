@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{error::MultisigError, metadata::Metadata};
+
+#[derive(Accounts)]
+pub struct CloseMetadataInstructionAccounts<'info> {
+    #[account(
+        mut,
+        seeds = [b"metadata", subject.key().as_ref()],
+        bump = metadata.account_bump,
+        close = metadata_payer
+    )]
+    pub metadata: Account<'info, Metadata>,
+
+    /// CHECK: The account the metadata describes, seeds bind it to the metadata.
+    pub subject: UncheckedAccount<'info>,
+
+    /// CHECK: Must match the payer stored in the metadata; receives the metadata rent.
+    #[account(mut)]
+    pub metadata_payer: UncheckedAccount<'info>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<CloseMetadataInstructionAccounts>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.metadata_payer.key(),
+        ctx.accounts.metadata.payer,
+        MultisigError::InvalidMetadataPayer
+    );
+
+    // Closed accounts are reassigned to the system program with no lamports.
+    require!(
+        *ctx.accounts.subject.owner != crate::ID || ctx.accounts.subject.lamports() == 0,
+        MultisigError::MetadataSubjectStillExists
+    );
+
+    Ok(())
+}
+
+/// Close the metadata of a group member, asset or proposal whose account has been closed,
+/// refunding the rent to whoever paid for it. Can be called by anyone.
+pub fn close_metadata_handler(ctx: Context<CloseMetadataInstructionAccounts>) -> Result<()> {
+    checks(&ctx)
+}
@@ -5,6 +5,7 @@ use crate::{
         error::MultisigError,
         proposal::{NormalProposal, ProposalState},
     },
+    utils::close_open_proposal,
     Group,
 };
 
@@ -16,6 +17,7 @@ fn validate_proposal_closable(
     proposal_state: ProposalState,
     proposal_deadline_timestamp: i64,
     proposal_index: u64,
+    execution_in_progress: bool,
 ) -> Result<()> {
     require_keys_eq!(proposer, proposal_proposer, MultisigError::InvalidProposer);
 
@@ -28,9 +30,16 @@ fn validate_proposal_closable(
             require!(is_expired || is_stale, MultisigError::ProposalStillActive)
         }
         ProposalState::Passed => {
-            require!(is_stale || is_expired, MultisigError::ProposalStillActive);
+            // Once execution has started the deadline no longer applies.
+            require!(
+                is_stale || (is_expired && !execution_in_progress),
+                MultisigError::ProposalStillActive
+            );
         }
-        ProposalState::Expired | ProposalState::Failed | ProposalState::Executed => {}
+        ProposalState::Expired
+        | ProposalState::Failed
+        | ProposalState::Executed
+        | ProposalState::Cancelled => {}
     }
 
     Ok(())
@@ -39,6 +48,7 @@ fn validate_proposal_closable(
 #[derive(Accounts)]
 pub struct CloseNormalProposalInstructionAccounts<'info> {
     #[account(
+        mut,
         seeds = [b"group", group.group_seed.as_ref()],
         bump = group.account_bump
     )]
@@ -56,12 +66,23 @@ pub struct CloseNormalProposalInstructionAccounts<'info> {
     /// CHECK: Must match the proposer stored in the proposal; verified in checks(); receives rent.
     #[account(mut)]
     pub proposer: UncheckedAccount<'info>,
+
+    /// CHECK: The proposer's member account, its open proposal count is decremented
+    /// unless it was closed since the proposal was created.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_group_account: UncheckedAccount<'info>,
 }
 
 #[inline(always)]
 fn checks(ctx: &Context<CloseNormalProposalInstructionAccounts>) -> Result<()> {
     let proposal = &ctx.accounts.proposal;
 
+    require!(!proposal.bonded, MultisigError::ProposalBondNotReleased);
+
     validate_proposal_closable(
         &ctx.accounts.group,
         ctx.accounts.proposer.key(),
@@ -69,6 +90,7 @@ fn checks(ctx: &Context<CloseNormalProposalInstructionAccounts>) -> Result<()> {
         proposal.state,
         proposal.proposal_deadline_timestamp,
         proposal.proposal_index,
+        proposal.is_execution_in_progress(),
     )
 }
 
@@ -77,5 +99,15 @@ fn checks(ctx: &Context<CloseNormalProposalInstructionAccounts>) -> Result<()> {
 pub fn close_normal_proposal_handler(
     ctx: Context<CloseNormalProposalInstructionAccounts>,
 ) -> Result<()> {
-    checks(&ctx)
+    checks(&ctx)?;
+
+    // An executed proposal stopped counting as open when it was executed.
+    if ctx.accounts.proposal.state != ProposalState::Executed {
+        close_open_proposal(
+            &mut ctx.accounts.group,
+            &ctx.accounts.proposer_group_account,
+        )?;
+    }
+
+    Ok(())
 }
@@ -22,7 +22,10 @@ fn ensure_proposal_allows_vote_record_close(
         ProposalState::Passed => {
             require!(is_stale || is_expired, MultisigError::ProposalStillActive);
         }
-        ProposalState::Expired | ProposalState::Failed | ProposalState::Executed => {}
+        ProposalState::Expired
+        | ProposalState::Failed
+        | ProposalState::Executed
+        | ProposalState::Cancelled => {}
     }
 
     Ok(())
@@ -20,7 +20,7 @@ pub struct CloseProposalTransactionInstructionAccounts<'info> {
     /// UncheckedAccount (no seed chain from transaction -> group).
     #[account(
         mut,
-        seeds = [b"proposal-transaction", proposal.key.as_ref()],
+        seeds = [b"proposal-transaction", proposal.key.as_ref(), proposal_transaction.option_index.as_slice()],
         bump = proposal_transaction.account_bump,
         close = rent_collector,
     )]
@@ -66,9 +66,21 @@ fn checks(ctx: &Context<CloseProposalTransactionInstructionAccounts>) -> Result<
                 require!(is_expired || is_stale, MultisigError::ProposalStillActive)
             }
             ProposalState::Passed => {
-                require!(is_stale || is_expired, MultisigError::ProposalStillActive);
+                // Only the winning option of a multi-choice proposal can be executed.
+                let is_losing_option =
+                    proposal_account.winning_option != proposal_transaction.option_index;
+                // Once execution has started the deadline no longer applies.
+                require!(
+                    is_stale
+                        || is_losing_option
+                        || (is_expired && !proposal_account.is_execution_in_progress()),
+                    MultisigError::ProposalStillActive
+                );
             }
-            ProposalState::Expired | ProposalState::Failed | ProposalState::Executed => {} // Ok
+            ProposalState::Expired
+            | ProposalState::Failed
+            | ProposalState::Executed
+            | ProposalState::Cancelled => {} // Ok
         }
     }
 
@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        asset::Asset,
+        error::MultisigError,
+        group::Group,
+        member::AssetMember,
+        proposal::{ConfigProposal, ProposalState, ProposalTarget},
+        vote::{CompactVote, VoteChoice},
+    },
+    GroupMember,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompactVoteOnConfigProposalInstructionArgs {
+    pub vote: VoteChoice,
+}
+
+#[derive(Accounts)]
+pub struct CompactVoteOnConfigProposalInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    #[account(
+        seeds = [b"asset", group.key().as_ref(), asset.asset_address.as_ref()],
+        bump = asset.account_bump
+    )]
+    pub asset: Option<Account<'info, Asset>>,
+
+    /// Seeds check binds it to the group and voter, its index keys the vote.
+    #[account(
+        seeds = [b"member", group.key().as_ref(), voter.key().as_ref()],
+        bump = group_member.account_bump
+    )]
+    pub group_member: Account<'info, GroupMember>,
+
+    /// Checked against the voter and asset in the handler.
+    pub asset_member: Option<Account<'info, AssetMember>>,
+
+    pub voter: Signer<'info>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<CompactVoteOnConfigProposalInstructionAccounts>) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require!(
+        ctx.accounts.proposal.state == ProposalState::Open,
+        MultisigError::ProposalNotOpen
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_index,
+        ctx.accounts.group.proposal_index_after_stale,
+        MultisigError::ProposalStale
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require_gt!(
+        ctx.accounts.proposal.proposal_deadline_timestamp,
+        now,
+        MultisigError::ProposalExpired
+    );
+
+    require!(
+        ctx.accounts.group_member.is_active(now)
+            && ctx
+                .accounts
+                .asset_member
+                .as_ref()
+                .is_none_or(|asset_member| asset_member.is_active(now)),
+        MultisigError::MembershipExpired
+    );
+
+    require!(
+        ctx.accounts.proposal.compact_votes.is_some(),
+        MultisigError::CompactVotesNotEnabled
+    );
+
+    Ok(())
+}
+
+/// Vote on a config proposal that keeps its votes in the proposal account,
+/// no vote record is created so there is nothing to close afterwards.
+pub fn compact_vote_on_config_proposal_handler(
+    ctx: Context<CompactVoteOnConfigProposalInstructionAccounts>,
+    args: CompactVoteOnConfigProposalInstructionArgs,
+) -> Result<()> {
+    checks(&ctx)?;
+
+    let CompactVoteOnConfigProposalInstructionArgs { vote } = args;
+
+    let group = &ctx.accounts.group;
+    let voter = &ctx.accounts.voter;
+    let target = ctx.accounts.proposal.target.clone();
+
+    let weight = match target {
+        ProposalTarget::Assets => return Err(MultisigError::PerAssetVoteRequired.into()),
+        ProposalTarget::Group => ctx.accounts.group_member.weight,
+        ProposalTarget::Asset(target_asset) => {
+            let asset = ctx
+                .accounts
+                .asset
+                .as_ref()
+                .ok_or(MultisigError::AssetNotProvided)?;
+            let asset_member = ctx
+                .accounts
+                .asset_member
+                .as_ref()
+                .ok_or(MultisigError::AssetMemberNotProvided)?;
+
+            require_keys_eq!(
+                target_asset,
+                asset.asset_address,
+                MultisigError::UnexpectedAsset
+            );
+            require_keys_eq!(asset_member.user, voter.key(), MultisigError::InvalidMember);
+            require_keys_eq!(
+                asset_member.group,
+                group.key(),
+                MultisigError::UnexpectedGroup
+            );
+            require_keys_eq!(
+                asset_member.asset,
+                asset.asset_address,
+                MultisigError::InvalidAssetMember
+            );
+
+            asset_member.weight
+        }
+    }
+    .min(group.max_member_weight);
+    require_gt!(weight, 0, MultisigError::UnauthorizedVoter);
+
+    let member_index = ctx.accounts.group_member.member_index;
+    let proposal = &mut ctx.accounts.proposal;
+
+    let compact_votes = proposal
+        .compact_votes
+        .as_mut()
+        .ok_or(MultisigError::CompactVotesNotEnabled)?;
+    // Config proposals don't use asset_index
+    let previous_vote = CompactVote::record(compact_votes, member_index, None, vote);
+
+    match previous_vote {
+        None => proposal.increment_vote_count()?,
+        Some(previous_vote) if previous_vote != vote => match previous_vote {
+            VoteChoice::For => proposal.sub_weight_for(weight),
+            VoteChoice::Against => proposal.sub_weight_against(weight),
+        },
+        Some(_) => return Ok(()),
+    }
+
+    let (maybe_group, maybe_asset) = match target {
+        ProposalTarget::Assets => return Err(MultisigError::PerAssetVoteRequired.into()),
+        ProposalTarget::Group => (Some(group), None),
+        ProposalTarget::Asset(_) => (None, ctx.accounts.asset.as_ref()),
+    };
+
+    match vote {
+        VoteChoice::For => {
+            proposal.add_weight_for(weight);
+            proposal.check_and_mark_passed(maybe_group, maybe_asset)?;
+        }
+        VoteChoice::Against => {
+            proposal.add_weight_against(weight);
+            proposal.check_and_mark_failed(maybe_group, maybe_asset)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        asset::Asset,
+        error::MultisigError,
+        group::Group,
+        member::AssetMember,
+        proposal::{NormalProposal, ProposalAssetThresholdState, ProposalState},
+        vote::{CompactVote, VoteChoice},
+    },
+    GroupMember,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CompactVoteOnNormalProposalInstructionArgs {
+    pub voting_asset_index: u8,
+    pub vote: VoteChoice,
+}
+
+#[derive(Accounts)]
+pub struct CompactVoteOnNormalProposalInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Seeds checks bind it to the group.
+    #[account(
+        mut,
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump,
+    )]
+    pub proposal: Account<'info, NormalProposal>,
+
+    /// Seeds and ownership checks bind it to the proposal.
+    #[account(
+        seeds = [b"proposal-transaction", proposal.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub proposal_transaction: UncheckedAccount<'info>,
+
+    /// Seeds check binds it to the asset and group.
+    #[account(
+        seeds = [b"asset", group.key().as_ref(), asset.asset_address.as_ref()],
+        bump = asset.account_bump
+    )]
+    pub asset: Account<'info, Asset>,
+
+    /// Seeds check binds it to the group and voter, its index keys the vote.
+    #[account(
+        seeds = [b"member", group.key().as_ref(), voter.key().as_ref()],
+        bump = group_member.account_bump
+    )]
+    pub group_member: Account<'info, GroupMember>,
+
+    /// Seeds check binds it to the voter, group and asset.
+    #[account(
+        seeds = [b"asset-member", group.key().as_ref(), asset.asset_address.as_ref(), voter.key().as_ref()],
+        bump = asset_member.account_bump
+    )]
+    pub asset_member: Account<'info, AssetMember>,
+
+    pub voter: Signer<'info>,
+}
+
+#[inline(always)]
+fn checks(
+    ctx: &Context<CompactVoteOnNormalProposalInstructionAccounts>,
+    args: &CompactVoteOnNormalProposalInstructionArgs,
+) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require!(
+        ctx.accounts.proposal.state == ProposalState::Open,
+        MultisigError::ProposalNotOpen
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_index,
+        ctx.accounts.group.proposal_index_after_stale,
+        MultisigError::ProposalStale
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require_gt!(
+        ctx.accounts.proposal.proposal_deadline_timestamp,
+        now,
+        MultisigError::ProposalExpired
+    );
+
+    require!(
+        ctx.accounts.group_member.is_active(now) && ctx.accounts.asset_member.is_active(now),
+        MultisigError::MembershipExpired
+    );
+
+    require!(
+        ctx.accounts.proposal.compact_votes.is_some(),
+        MultisigError::CompactVotesNotEnabled
+    );
+
+    require_gt!(
+        ctx.accounts.proposal.assets.len(),
+        usize::from(args.voting_asset_index),
+        MultisigError::InvalidAssetIndex
+    );
+
+    require_keys_eq!(
+        ctx.accounts.proposal.assets[usize::from(args.voting_asset_index)].asset,
+        ctx.accounts.asset.asset_address,
+        MultisigError::InvalidAsset
+    );
+
+    require!(
+        ctx.accounts.proposal.assets[usize::from(args.voting_asset_index)].threshold_state
+            == ProposalAssetThresholdState::NoThresholdReached,
+        MultisigError::StateAlreadyFinalized
+    );
+
+    Ok(())
+}
+
+/// Vote on an asset of a normal proposal that keeps its votes in the proposal account,
+/// no vote record is created so there is nothing to close afterwards.
+pub fn compact_vote_on_normal_proposal_handler(
+    ctx: Context<CompactVoteOnNormalProposalInstructionAccounts>,
+    args: CompactVoteOnNormalProposalInstructionArgs,
+) -> Result<()> {
+    checks(&ctx, &args)?;
+
+    let CompactVoteOnNormalProposalInstructionArgs {
+        voting_asset_index,
+        vote,
+    } = args;
+
+    let weight = ctx
+        .accounts
+        .asset_member
+        .weight
+        .min(ctx.accounts.group.max_member_weight);
+    require_gt!(weight, 0, MultisigError::UnauthorizedVoter);
+
+    let member_index = ctx.accounts.group_member.member_index;
+    let proposal = &mut ctx.accounts.proposal;
+    let asset = &ctx.accounts.asset;
+    let asset_index = usize::from(voting_asset_index);
+
+    let compact_votes = proposal
+        .compact_votes
+        .as_mut()
+        .ok_or(MultisigError::CompactVotesNotEnabled)?;
+    let previous_vote =
+        CompactVote::record(compact_votes, member_index, Some(voting_asset_index), vote);
+
+    let proposal_asset = proposal
+        .get_asset_mut(asset_index)
+        .ok_or(MultisigError::InvalidAssetIndex)?;
+
+    match previous_vote {
+        // First vote
+        None => proposal_asset.increment_vote_count()?,
+        // Re-vote, undo previous
+        Some(previous_vote) if previous_vote != vote => match previous_vote {
+            VoteChoice::For => proposal_asset.sub_use_vote_weight(weight),
+            VoteChoice::Against => proposal_asset.sub_not_use_vote_weight(weight),
+        },
+        Some(_) => return Ok(()),
+    }
+
+    match vote {
+        VoteChoice::For => {
+            proposal_asset.add_use_vote_weight(weight);
+            proposal.check_and_mark_asset_passed(asset_index, asset)?;
+        }
+        VoteChoice::Against => {
+            proposal_asset.add_not_use_vote_weight(weight);
+            proposal.check_and_mark_asset_failed(asset_index, asset)?;
+        }
+    }
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
-use crate::{state::*, utils::FractionalThreshold};
+use crate::state::*;
+use crate::utils::{deposit_proposal_bond, BondDepositAccounts};
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct CreateConfigProposalInstructionArgs {
@@ -7,12 +9,16 @@ pub struct CreateConfigProposalInstructionArgs {
     pub timelock_offset: u32,
     pub proposal_deadline_timestamp: i64,
     pub config_change: ConfigChange,
+    /// Keep votes in the proposal instead of vote records, for groups of
+    /// up to `MAX_COMPACT_VOTES` members.
+    pub compact_votes: bool,
+    /// Title, URI and hash of the proposal text, can be changed until voting starts.
+    pub description: Option<ProposalDescription>,
 }
 
 #[derive(Accounts)]
 #[instruction(args: CreateConfigProposalInstructionArgs)]
 pub struct CreateConfigProposalInstructionAccounts<'info> {
-    #[account(mut)]
     pub proposer: Signer<'info>,
 
     #[account(
@@ -30,6 +36,7 @@ pub struct CreateConfigProposalInstructionAccounts<'info> {
     pub asset: Option<Account<'info, Asset>>,
 
     #[account(
+        mut,
         seeds = [b"member", group.key().as_ref(), proposer.key().as_ref()],
         bump = proposer_group_account.account_bump
     )]
@@ -37,14 +44,52 @@ pub struct CreateConfigProposalInstructionAccounts<'info> {
 
     #[account(
         init,
-        payer = proposer,
-        space = 8 + ConfigProposal::INIT_SPACE,
+        payer = payer,
+        space = 8 + ConfigProposal::get_size(
+            args.compact_votes.then_some(group.member_count as usize)
+        ),
         seeds = [b"proposal", group.key().as_ref(), args.proposal_seed.as_ref()],
         bump,
     )]
     pub proposal: Account<'info, ConfigProposal>,
 
+    /// Pays for the proposal, so a member key that cannot hold lamports, such as another
+    /// group's PDA, can still propose.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     pub system_program: Program<'info, System>,
+
+    /// Escrow of the group's proposal bond, required exactly when the group sets one.
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProposalBond::INIT_SPACE,
+        seeds = [b"proposal-bond", proposal.key().as_ref()],
+        bump
+    )]
+    pub bond: Option<Account<'info, ProposalBond>>,
+
+    /// Mint of a bond paid in a token.
+    pub bond_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// Token account the payer pays a token bond from.
+    #[account(mut)]
+    pub payer_bond_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Holds a token bond until it is released, owned by the bond account.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"bond-escrow", proposal.key().as_ref()],
+        bump,
+        token::mint = bond_mint,
+        token::authority = bond,
+        token::token_program = token_program,
+    )]
+    pub bond_escrow: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
 }
 
 #[inline(always)]
@@ -64,7 +109,7 @@ fn validate_member_params(
 }
 
 #[inline(always)]
-fn validate_group_config_change(
+pub(crate) fn validate_group_config_change(
     group: &Account<Group>,
     config_change: &ConfigChange,
 ) -> Result<()> {
@@ -72,125 +117,58 @@ fn validate_group_config_change(
         ConfigChange::AddGroupMember {
             weight,
             permissions,
+            expires_at,
             ..
-        } => validate_member_params(group, *weight, permissions),
+        } => {
+            validate_member_params(group, *weight, permissions)?;
+            validate_term(*expires_at)
+        }
         ConfigChange::RemoveGroupMember { .. } => Ok(()),
-        ConfigChange::ChangeGroupConfig { config_type } => {
-            validate_group_config_type(group, config_type)
+        ConfigChange::RenewGroupMemberTerm { expires_at, .. } => validate_term(*expires_at),
+        ConfigChange::ChangeGroupMemberPermissions { permissions, .. } => permissions.is_valid(),
+        ConfigChange::SetGroupMetadata { content } => content.validate(),
+        ConfigChange::ChangeGroupConfig { config_types } => {
+            validate_config_types_len(config_types)?;
+            // Validated on a copy, the same way the batch is applied when executed.
+            group.with_config_types(config_types)?;
+            Ok(())
         }
         _ => Err(MultisigError::InvalidConfigChange.into()),
     }
 }
 
+/// A renewed term has to end in the future.
 #[inline(always)]
-fn validate_group_config_type(group: &Account<Group>, config_type: &ConfigType) -> Result<()> {
-    match config_type {
-        ConfigType::AddMember(threshold) => {
-            FractionalThreshold::validate_non_overlapping_pair(*threshold, group.not_add_threshold)
-        }
-        ConfigType::NotAddMember(threshold) => {
-            FractionalThreshold::validate_non_overlapping_pair(group.add_threshold, *threshold)
-        }
-        ConfigType::RemoveMember(threshold) => FractionalThreshold::validate_non_overlapping_pair(
-            *threshold,
-            group.not_remove_threshold,
-        ),
-        ConfigType::NotRemoveMember(threshold) => {
-            FractionalThreshold::validate_non_overlapping_pair(group.remove_threshold, *threshold)
-        }
-        ConfigType::ChangeConfig(threshold) => FractionalThreshold::validate_non_overlapping_pair(
-            *threshold,
-            group.not_change_config_threshold,
-        ),
-        ConfigType::NotChangeConfig(threshold) => {
-            FractionalThreshold::validate_non_overlapping_pair(
-                group.change_config_threshold,
-                *threshold,
-            )
-        }
-        ConfigType::MinimumMemberCount(count) => {
-            require_gt!(*count, 0, MultisigError::InvalidMemberCount);
-            require_gte!(
-                group.member_count,
-                *count,
-                MultisigError::InvalidMemberCount
-            );
-            Ok(())
-        }
-        ConfigType::MinimumVoteCount(count) => {
-            require_gt!(*count, 0, MultisigError::InvalidMemberCount);
-            require_gte!(
-                group.member_count,
-                *count,
-                MultisigError::InvalidMemberCount
-            );
-            Ok(())
-        }
-        ConfigType::MinimumTimelock(_) => Ok(()), // any u32 is valid
-        ConfigType::Use(_) | ConfigType::NotUse(_) => {
-            Err(MultisigError::UnexpectedConfigChange.into())
-        }
+fn validate_term(expires_at: Option<i64>) -> Result<()> {
+    if let Some(expires_at) = expires_at {
+        require_gt!(
+            expires_at,
+            Clock::get()?.unix_timestamp,
+            MultisigError::MembershipExpired
+        );
     }
+    Ok(())
 }
 
+/// A batch must change something and fit in the proposal account.
 #[inline(always)]
-fn validate_asset_config_type(asset: &Account<Asset>, config_type: &ConfigType) -> Result<()> {
-    match config_type {
-        ConfigType::AddMember(threshold) => {
-            FractionalThreshold::validate_non_overlapping_pair(*threshold, asset.not_add_threshold)
-        }
-        ConfigType::NotAddMember(threshold) => {
-            FractionalThreshold::validate_non_overlapping_pair(asset.add_threshold, *threshold)
-        }
-        ConfigType::RemoveMember(threshold) => FractionalThreshold::validate_non_overlapping_pair(
-            *threshold,
-            asset.not_remove_threshold,
-        ),
-        ConfigType::NotRemoveMember(threshold) => {
-            FractionalThreshold::validate_non_overlapping_pair(asset.remove_threshold, *threshold)
-        }
-        ConfigType::Use(threshold) => {
-            FractionalThreshold::validate_non_overlapping_pair(*threshold, asset.not_use_threshold)
-        }
-        ConfigType::NotUse(threshold) => {
-            FractionalThreshold::validate_non_overlapping_pair(asset.use_threshold, *threshold)
-        }
-        ConfigType::ChangeConfig(threshold) => FractionalThreshold::validate_non_overlapping_pair(
-            *threshold,
-            asset.not_change_config_threshold,
-        ),
-        ConfigType::NotChangeConfig(threshold) => {
-            FractionalThreshold::validate_non_overlapping_pair(
-                asset.change_config_threshold,
-                *threshold,
-            )
-        }
-        ConfigType::MinimumMemberCount(count) => {
-            require_gt!(*count, 0, MultisigError::InvalidMemberCount);
-            require_gte!(
-                asset.member_count,
-                *count,
-                MultisigError::InvalidMemberCount
-            );
-            Ok(())
-        }
-        ConfigType::MinimumVoteCount(count) => {
-            require_gt!(*count, 1, MultisigError::InvalidThreshold);
-            require_gte!(
-                asset.member_count,
-                *count,
-                MultisigError::InvalidMemberCount
-            );
-            Ok(())
-        }
-        ConfigType::MinimumTimelock(_) => Err(MultisigError::UnexpectedConfigChange.into()),
-    }
+fn validate_config_types_len(config_types: &[ConfigType]) -> Result<()> {
+    require!(!config_types.is_empty(), MultisigError::InvalidConfigChange);
+    require_gte!(
+        MAX_CONFIG_CHANGES,
+        config_types.len(),
+        MultisigError::TooManyConfigChanges
+    );
+    Ok(())
 }
 
+/// `change_asset` is the asset address the change must name, the default key for
+/// proposals targeting several assets.
 #[inline(always)]
 fn validate_asset_config_change(
     group: &Account<Group>,
-    asset: &Account<Asset>,
+    asset: &Asset,
+    change_asset: Pubkey,
     config_change: &ConfigChange,
 ) -> Result<()> {
     match config_change {
@@ -200,38 +178,102 @@ fn validate_asset_config_change(
             permissions,
             ..
         } => {
-            require_keys_eq!(
-                *asset_address,
-                asset.asset_address,
-                MultisigError::InvalidAsset
-            );
+            require_keys_eq!(*asset_address, change_asset, MultisigError::InvalidAsset);
             validate_member_params(group, *weight, permissions)?;
         }
         ConfigChange::RemoveAssetMember { asset_address, .. } => {
-            require_keys_eq!(
-                *asset_address,
-                asset.asset_address,
-                MultisigError::InvalidAsset
-            );
+            require_keys_eq!(*asset_address, change_asset, MultisigError::InvalidAsset);
+        }
+        ConfigChange::ChangeAssetConfig { config_types } => {
+            validate_config_types_len(config_types)?;
+            asset.with_config_types(config_types)?;
         }
-        ConfigChange::ChangeAssetConfig { config_type } => {
-            validate_asset_config_type(asset, config_type)?;
+        ConfigChange::RenewAssetMemberTerm {
+            asset_address,
+            expires_at,
+            ..
+        } => {
+            require_keys_eq!(*asset_address, change_asset, MultisigError::InvalidAsset);
+            validate_term(*expires_at)?;
+        }
+        ConfigChange::ChangeAssetMemberPermissions {
+            asset_address,
+            permissions,
+            ..
+        } => {
+            require_keys_eq!(*asset_address, change_asset, MultisigError::InvalidAsset);
+            permissions.is_valid()?;
+        }
+        ConfigChange::SetAssetMetadata {
+            asset_address,
+            content,
+        } => {
+            require_keys_eq!(*asset_address, change_asset, MultisigError::InvalidAsset);
+            content.validate()?;
+        }
+        ConfigChange::CancelSchedule { asset_address, .. } => {
+            require_keys_eq!(*asset_address, change_asset, MultisigError::InvalidAsset);
         }
         _ => return Err(MultisigError::InvalidConfigChange.into()),
     }
     Ok(())
 }
 
+/// Checks the timelock of a config change against `required`, the floor given by
+/// `config_change_timelock` of the group or asset it changes.
+pub fn validate_config_change_timelock(timelock_offset: u32, required: u32) -> Result<()> {
+    require_gte!(
+        timelock_offset,
+        required,
+        MultisigError::TimelockBelowMinimum
+    );
+    Ok(())
+}
+
+/// Loads asset accounts of `group` passed as remaining accounts, such as the other
+/// assets of a config proposal targeting several assets.
+pub fn load_assets(group: &Pubkey, remaining_accounts: &[AccountInfo]) -> Result<Vec<Asset>> {
+    remaining_accounts
+        .iter()
+        .map(|asset_info| {
+            require_keys_eq!(*asset_info.owner, crate::ID, MultisigError::InvalidAsset);
+            let asset = Asset::try_deserialize(&mut &asset_info.try_borrow_data()?[..])
+                .map_err(|_| MultisigError::InvalidAsset)?;
+
+            let expected_asset = Pubkey::create_program_address(
+                &[
+                    b"asset",
+                    group.as_ref(),
+                    asset.asset_address.as_ref(),
+                    &[asset.account_bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| MultisigError::InvalidAsset)?;
+            require_keys_eq!(
+                expected_asset,
+                asset_info.key(),
+                MultisigError::InvalidAsset
+            );
+
+            Ok(asset)
+        })
+        .collect()
+}
+
 #[inline(always)]
 fn checks(
     ctx: &Context<CreateConfigProposalInstructionAccounts>,
     args: &CreateConfigProposalInstructionArgs,
+    additional_assets: &[Asset],
 ) -> Result<()> {
     require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
 
+    let now = Clock::get()?.unix_timestamp;
+
     require_gt!(
         args.proposal_deadline_timestamp,
-        Clock::get()?.unix_timestamp,
+        now,
         MultisigError::ProposalExpired
     );
 
@@ -241,6 +283,16 @@ fn checks(
         MultisigError::TimelockBelowMinimum
     );
 
+    ctx.accounts
+        .group
+        .validate_proposal_deadline(now, args.proposal_deadline_timestamp)?;
+
+    // An asset change must also respect the limits of every asset it targets.
+    let assets = ctx.accounts.asset.iter().map(|asset| &**asset);
+    for asset in assets.chain(additional_assets) {
+        asset.validate_proposal_deadline(now, args.proposal_deadline_timestamp)?;
+    }
+
     if args.config_change.is_asset_change() {
         let asset = ctx
             .accounts
@@ -248,53 +300,116 @@ fn checks(
             .as_ref()
             .ok_or(MultisigError::AssetNotProvided)?;
 
-        validate_asset_config_change(&ctx.accounts.group, asset, &args.config_change)?;
+        if additional_assets.is_empty() {
+            validate_asset_config_change(
+                &ctx.accounts.group,
+                asset,
+                asset.asset_address,
+                &args.config_change,
+            )?;
+            validate_config_change_timelock(
+                args.timelock_offset,
+                asset.config_change_timelock(&args.config_change)?,
+            )?;
+        } else {
+            require_gte!(
+                constants::MAX_CONFIG_PROPOSAL_ASSETS,
+                additional_assets.len() + 1,
+                MultisigError::TooManyAssets
+            );
+            require!(!args.compact_votes, MultisigError::PerAssetVoteRequired);
+
+            let assets = std::iter::once(&**asset).chain(additional_assets);
+            let mut previous: Option<Pubkey> = None;
+            for asset in assets {
+                require!(
+                    previous.is_none_or(|previous| previous < asset.asset_address),
+                    MultisigError::AssetsNotSortedOrDuplicate
+                );
+                previous = Some(asset.asset_address);
+
+                validate_asset_config_change(
+                    &ctx.accounts.group,
+                    asset,
+                    Pubkey::default(),
+                    &args.config_change,
+                )?;
+                validate_config_change_timelock(
+                    args.timelock_offset,
+                    asset.config_change_timelock(&args.config_change)?,
+                )?;
+            }
+        }
     } else {
+        require!(
+            ctx.remaining_accounts.is_empty(),
+            MultisigError::InvalidConfigChange
+        );
         validate_group_config_change(&ctx.accounts.group, &args.config_change)?;
+        validate_config_change_timelock(
+            args.timelock_offset,
+            ctx.accounts
+                .group
+                .config_change_timelock(&args.config_change)?,
+        )?;
+    }
+
+    if let Some(description) = args.description.as_ref() {
+        description.validate()?;
+    }
+
+    // Every group member votes at most once, asset members are group members too.
+    if args.compact_votes {
+        require_gte!(
+            constants::MAX_COMPACT_VOTES,
+            ctx.accounts.group.member_count as usize,
+            MultisigError::TooManyCompactVotes
+        );
     }
 
     Ok(())
 }
 
-/// Creates a config proposal. Requires Propose permission.
+/// Creates a config proposal. Requires Propose config permission.
+/// An asset change can target several assets, passed in ascending order of address as
+/// `asset` and then as remaining accounts. Each asset votes on and applies it separately.
+/// The payer also pays the group's proposal bond, if it sets one.
 pub fn create_config_proposal_handler(
     ctx: Context<CreateConfigProposalInstructionAccounts>,
     args: CreateConfigProposalInstructionArgs,
 ) -> Result<()> {
-    checks(&ctx, &args)?;
+    let additional_assets = load_assets(&ctx.accounts.group.key(), ctx.remaining_accounts)?;
+    checks(&ctx, &args, &additional_assets)?;
 
     let CreateConfigProposalInstructionArgs {
         proposal_seed,
         timelock_offset,
         config_change,
         proposal_deadline_timestamp,
+        compact_votes,
+        description,
     } = args;
 
     let proposer_key = ctx.accounts.proposer.key();
     let group = &mut ctx.accounts.group;
-    let proposer_member = &ctx.accounts.proposer_group_account;
+    let proposer_member = &mut ctx.accounts.proposer_group_account;
     let proposal = &mut ctx.accounts.proposal;
 
     require!(
-        proposer_member.has_propose(),
+        proposer_member.has_propose_config(),
         MultisigError::InsufficientPermissions
     );
 
-    if config_change.is_group_change() {
-        let new_proposal = ConfigProposal::new(
-            proposer_key,
-            proposal_seed,
-            group.key(),
-            ctx.bumps.proposal,
-            group.get_and_increment_proposal_index()?,
-            timelock_offset,
-            proposal_deadline_timestamp,
-            ProposalTarget::Group,
-            config_change,
-        )?;
+    require!(
+        proposer_member.is_active(Clock::get()?.unix_timestamp),
+        MultisigError::MembershipExpired
+    );
+
+    group.open_proposal(proposer_member)?;
+    let proposal_index = group.get_and_increment_proposal_index()?;
 
-        proposal.set_inner(new_proposal);
-        return Ok(());
+    let (target, assets) = if config_change.is_group_change() {
+        (ProposalTarget::Group, Vec::new())
     } else {
         let asset = ctx
             .accounts
@@ -302,18 +417,48 @@ pub fn create_config_proposal_handler(
             .as_ref()
             .ok_or(MultisigError::AssetNotProvided)?;
 
-        proposal.set_inner(ConfigProposal::new(
-            proposer_key,
-            proposal_seed,
-            group.key(),
-            ctx.bumps.proposal,
-            group.get_and_increment_proposal_index()?,
-            timelock_offset,
-            proposal_deadline_timestamp,
-            ProposalTarget::Asset(asset.asset_address),
-            config_change,
-        )?);
+        if additional_assets.is_empty() {
+            (ProposalTarget::Asset(asset.asset_address), Vec::new())
+        } else {
+            let assets = std::iter::once(asset.asset_address)
+                .chain(additional_assets.iter().map(|asset| asset.asset_address))
+                .map(ConfigProposalAsset::new)
+                .collect();
+            (ProposalTarget::Assets, assets)
+        }
     };
 
+    proposal.set_inner(ConfigProposal::new(
+        proposer_key,
+        proposal_seed,
+        group.key(),
+        ctx.bumps.proposal,
+        proposal_index,
+        timelock_offset,
+        proposal_deadline_timestamp,
+        target,
+        assets,
+        config_change,
+        compact_votes,
+        description,
+    )?);
+
+    proposal.bonded = deposit_proposal_bond(
+        group.proposal_bond.as_ref(),
+        BondDepositAccounts {
+            bond: ctx.accounts.bond.as_mut(),
+            bond_bump: ctx.bumps.bond,
+            bond_mint: ctx.accounts.bond_mint.as_deref(),
+            payer_bond_token_account: ctx.accounts.payer_bond_token_account.as_deref(),
+            bond_escrow: ctx.accounts.bond_escrow.as_deref(),
+            token_program: ctx.accounts.token_program.as_ref(),
+        },
+        group.key(),
+        proposal.key(),
+        proposal_index,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+
     Ok(())
 }
@@ -53,10 +53,17 @@ fn checks(
     require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
 
     require!(
-        ctx.accounts.proposer_group_account.has_propose(),
+        ctx.accounts.proposer_group_account.has_emergency_reset(),
         MultisigError::InsufficientPermissions
     );
 
+    require!(
+        ctx.accounts
+            .proposer_group_account
+            .is_active(Clock::get()?.unix_timestamp),
+        MultisigError::MembershipExpired
+    );
+
     require_gt!(
         args.proposal_deadline_timestamp,
         Clock::get()?.unix_timestamp,
@@ -165,18 +165,20 @@ pub fn create_group_handler(
         &ctx.accounts.member_5,
     ];
 
-    for ((((account, member), weight), permissions), bump) in member_accounts
+    for (member_index, ((((account, member), weight), permissions), bump)) in member_accounts
         .into_iter()
         .zip(members.into_iter())
         .zip(member_weights.into_iter())
         .zip(member_permissions.into_iter())
         .zip(member_account_bumps.into_iter())
+        .enumerate()
     {
         account.set_inner(GroupMember::new(
             member.key(),
             group.key(),
             permissions,
             weight,
+            member_index as u32,
             bump,
             max_member_weight,
         )?);
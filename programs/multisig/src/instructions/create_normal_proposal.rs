@@ -1,5 +1,13 @@
+use crate::instructions::create_config_proposal::{
+    load_assets, validate_config_change_timelock, validate_group_config_change,
+};
 use crate::state::*;
+use crate::utils::{deposit_proposal_bond, BondDepositAccounts};
 use anchor_lang::{prelude::*, solana_program::hash::HASH_BYTES as HASH_BYTES_LENGTH};
+use anchor_spl::{
+    token::ID as TOKEN_PROGRAM_ID,
+    token_interface::{spl_token_2022, Mint, TokenAccount, TokenInterface},
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct CreateNormalProposalInstructionArgs {
@@ -11,6 +19,27 @@ pub struct CreateNormalProposalInstructionArgs {
     pub proposal_deadline_timestamp: i64,
     /// Hashes of each instruction in the proposal transaction, in order.
     pub instruction_hashes: Vec<[u8; HASH_BYTES_LENGTH]>,
+    /// Allow the transaction to be executed in order across several calls,
+    /// rather than all at once.
+    pub sequential_execution: bool,
+    /// Maximum decrease allowed for the balances of the proposal's assets
+    /// once the transaction has been executed.
+    pub balance_guards: Vec<BalanceGuard>,
+    /// Conditions checked before the transaction is executed, voted on along
+    /// with the instruction hashes.
+    pub preconditions: Vec<ExecutionPrecondition>,
+    /// Group config change applied by the transaction through `apply_group_config_change`,
+    /// it must also be approved by the group before the proposal can pass.
+    pub group_config_change: Option<ConfigChange>,
+    /// Keep asset votes in the proposal instead of vote records, space is reserved
+    /// for every group member to vote on every asset.
+    pub compact_votes: bool,
+    /// Title, URI and hash of the proposal text, can be changed until voting starts.
+    pub description: Option<ProposalDescription>,
+    /// Instruction hashes of each candidate transaction of a multi-choice proposal,
+    /// members vote for one of them with `vote_on_proposal_option`. Leave
+    /// `instruction_hashes` empty when set.
+    pub options: Vec<Vec<[u8; HASH_BYTES_LENGTH]>>,
 }
 
 #[derive(Accounts)]
@@ -23,10 +52,10 @@ pub struct CreateNormalProposalInstructionAccounts<'info> {
     )]
     pub group: Account<'info, Group>,
 
-    #[account(mut)]
     pub proposer: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [b"member", group.key().as_ref(), proposer.key.as_ref()],
         bump = proposer_group_account.account_bump
     )]
@@ -34,26 +63,89 @@ pub struct CreateNormalProposalInstructionAccounts<'info> {
 
     #[account(
         init,
-        payer = proposer,
-        space = 8 + NormalProposal::get_size(args.asset_keys.len(), args.instruction_hashes.len()),
+        payer = payer,
+        space = 8 + NormalProposal::get_size(
+            args.asset_keys.len(),
+            args.instruction_hashes.len(),
+            args.balance_guards.len(),
+            &args.preconditions,
+            args.group_config_change.is_some(),
+            args.compact_votes
+                .then_some(group.member_count as usize * args.asset_keys.len()),
+            args.options.iter().map(Vec::len)
+        ),
         seeds = [b"proposal", group.key().as_ref(), args.proposal_seed.as_ref()],
         bump,
     )]
     pub proposal: Account<'info, NormalProposal>,
 
+    /// Pays for the proposal, so a member key that cannot hold lamports, such as another
+    /// group's PDA, can still propose.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     pub system_program: Program<'info, System>,
+
+    /// Escrow of the group's proposal bond, required exactly when the group sets one.
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProposalBond::INIT_SPACE,
+        seeds = [b"proposal-bond", proposal.key().as_ref()],
+        bump
+    )]
+    pub bond: Option<Account<'info, ProposalBond>>,
+
+    /// Mint of a bond paid in a token.
+    pub bond_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// Token account the payer pays a token bond from.
+    #[account(mut)]
+    pub payer_bond_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Holds a token bond until it is released, owned by the bond account.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"bond-escrow", proposal.key().as_ref()],
+        bump,
+        token::mint = bond_mint,
+        token::authority = bond,
+        token::token_program = token_program,
+    )]
+    pub bond_escrow: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+/// Whether a governed asset is a token account, the only kind whose amount can be guarded.
+fn is_token_account(asset_info: &AccountInfo) -> Result<bool> {
+    if asset_info.owner != &TOKEN_PROGRAM_ID && asset_info.owner != &spl_token_2022::ID {
+        return Ok(false);
+    }
+
+    Ok(TokenAccount::try_deserialize(&mut &asset_info.try_borrow_data()?[..]).is_ok())
 }
 
 #[inline(always)]
 fn checks(
     ctx: &Context<CreateNormalProposalInstructionAccounts>,
     args: &CreateNormalProposalInstructionArgs,
+    assets: &[Asset],
+    guarded_token_infos: &[AccountInfo],
 ) -> Result<()> {
     require!(
         ctx.accounts.proposer_group_account.has_propose(),
         MultisigError::InsufficientPermissions
     );
 
+    require!(
+        ctx.accounts
+            .proposer_group_account
+            .is_active(Clock::get()?.unix_timestamp),
+        MultisigError::MembershipExpired
+    );
+
     require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
 
     require!(!args.asset_keys.is_empty(), MultisigError::AssetNotProvided);
@@ -90,9 +182,11 @@ fn checks(
         );
     }
 
+    let now = Clock::get()?.unix_timestamp;
+
     require_gt!(
         args.proposal_deadline_timestamp,
-        Clock::get()?.unix_timestamp,
+        now,
         MultisigError::ProposalExpired
     );
 
@@ -102,20 +196,156 @@ fn checks(
         MultisigError::TimelockBelowMinimum
     );
 
-    require!(
-        !args.instruction_hashes.is_empty(),
-        MultisigError::EmptyInstructions
+    ctx.accounts
+        .group
+        .validate_proposal_deadline(now, args.proposal_deadline_timestamp)?;
+
+    // The strictest limits of the group and of every asset the proposal uses apply.
+    require_eq!(
+        assets.len(),
+        args.asset_keys.len(),
+        MultisigError::AssetNotProvided
     );
 
+    for (asset, asset_key) in assets.iter().zip(args.asset_keys.iter()) {
+        require_keys_eq!(asset.asset_address, *asset_key, MultisigError::InvalidAsset);
+        require_gte!(
+            args.timelock_offset,
+            asset.minimum_timelock,
+            MultisigError::TimelockBelowMinimum
+        );
+        asset.validate_proposal_deadline(now, args.proposal_deadline_timestamp)?;
+    }
+
+    // The receipt of an atomic proposal grows by all of its instructions in one call.
+    let max_instructions = if args.sequential_execution {
+        usize::from(u8::MAX)
+    } else {
+        constants::MAX_INSTRUCTIONS_PER_EXECUTION
+    };
+
+    if args.options.is_empty() {
+        require!(
+            !args.instruction_hashes.is_empty(),
+            MultisigError::EmptyInstructions
+        );
+    } else {
+        // Options are voted on with a single asset vote, without a group approval.
+        require!(
+            args.instruction_hashes.is_empty()
+                && (2..=constants::MAX_PROPOSAL_OPTIONS).contains(&args.options.len())
+                && args.asset_keys.len() == 1
+                && args.group_config_change.is_none()
+                && !args.compact_votes,
+            MultisigError::InvalidProposalOptions
+        );
+
+        for option in args.options.iter() {
+            require!(!option.is_empty(), MultisigError::InvalidProposalOptions);
+            require_gte!(
+                max_instructions,
+                option.len(),
+                MultisigError::TooManyInstructions
+            );
+        }
+    }
+
+    require_gte!(
+        max_instructions,
+        args.instruction_hashes.len(),
+        MultisigError::TooManyInstructions
+    );
+
+    // Each asset can have at most one guard of each kind. The governed account of each
+    // token amount guard follows the asset accounts, in guard order.
+    let mut guarded_tokens = guarded_token_infos.iter();
+    for (i, guard) in args.balance_guards.iter().enumerate() {
+        require!(
+            args.asset_keys.binary_search(&guard.asset).is_ok(),
+            MultisigError::InvalidBalanceGuard
+        );
+
+        require!(
+            !args.balance_guards[..i]
+                .iter()
+                .any(|other| other.asset == guard.asset && other.kind == guard.kind),
+            MultisigError::InvalidBalanceGuard
+        );
+
+        if guard.kind == BalanceGuardKind::TokenAmount {
+            let token_info = guarded_tokens
+                .next()
+                .ok_or(MultisigError::BalanceGuardAccountNotProvided)?;
+            require_keys_eq!(
+                token_info.key(),
+                guard.asset,
+                MultisigError::InvalidBalanceGuard
+            );
+            require!(
+                is_token_account(token_info)?,
+                MultisigError::InvalidBalanceGuard
+            );
+        }
+    }
+
+    require_gte!(
+        constants::MAX_EXECUTION_PRECONDITIONS,
+        args.preconditions.len(),
+        MultisigError::InvalidPrecondition
+    );
+
+    for precondition in args.preconditions.iter() {
+        if let ExecutionPrecondition::AccountData { bytes, .. } = precondition {
+            require!(
+                !bytes.is_empty() && bytes.len() <= constants::MAX_PRECONDITION_DATA_LEN,
+                MultisigError::InvalidPrecondition
+            );
+        }
+    }
+
+    if let Some(config_change) = args.group_config_change.as_ref() {
+        // Metadata accounts are only created by `set_group_metadata`, not by
+        // `apply_group_config_change`.
+        require!(
+            !matches!(config_change, ConfigChange::SetGroupMetadata { .. }),
+            MultisigError::InvalidConfigChange
+        );
+        validate_group_config_change(&ctx.accounts.group, config_change)?;
+        validate_config_change_timelock(
+            args.timelock_offset,
+            ctx.accounts.group.config_change_timelock(config_change)?,
+        )?;
+    }
+
+    if let Some(description) = args.description.as_ref() {
+        description.validate()?;
+    }
+
+    if args.compact_votes {
+        require_gte!(
+            constants::MAX_COMPACT_VOTES,
+            ctx.accounts.group.member_count as usize * args.asset_keys.len(),
+            MultisigError::TooManyCompactVotes
+        );
+    }
+
     Ok(())
 }
 
 /// Creates a normal proposal. Requires Propose permission.
+/// The asset account of each of `asset_keys` is passed in the same order as remaining accounts,
+/// followed by the governed token account of each token amount balance guard.
+/// The payer also pays the group's proposal bond, if it sets one.
+/// With `options`, members vote between several candidate transactions instead of one.
 pub fn create_normal_proposal_handler(
     ctx: Context<CreateNormalProposalInstructionAccounts>,
     args: CreateNormalProposalInstructionArgs,
 ) -> Result<()> {
-    checks(&ctx, &args)?;
+    let (asset_infos, guarded_token_infos) = ctx
+        .remaining_accounts
+        .split_at(args.asset_keys.len().min(ctx.remaining_accounts.len()));
+    let assets = load_assets(&ctx.accounts.group.key(), asset_infos)?;
+    checks(&ctx, &args, &assets, guarded_token_infos)?;
 
     let CreateNormalProposalInstructionArgs {
         proposal_seed,
@@ -125,6 +355,13 @@ pub fn create_normal_proposal_handler(
         timelock_offset,
         instruction_hashes,
         proposal_deadline_timestamp,
+        sequential_execution,
+        balance_guards,
+        preconditions,
+        group_config_change,
+        compact_votes,
+        description,
+        options,
     } = args;
 
     let proposal_assets: Vec<ProposalAsset> = asset_keys
@@ -139,6 +376,8 @@ pub fn create_normal_proposal_handler(
     let proposal = &mut ctx.accounts.proposal;
     let proposer = &mut ctx.accounts.proposer;
     let group = &mut ctx.accounts.group;
+    group.open_proposal(&mut ctx.accounts.proposer_group_account)?;
+    let proposal_index = group.get_and_increment_proposal_index()?;
 
     proposal.set_inner(NormalProposal::new(
         *proposer.key,
@@ -146,11 +385,35 @@ pub fn create_normal_proposal_handler(
         group.key(),
         proposal_assets,
         ctx.bumps.proposal,
-        group.get_and_increment_proposal_index()?,
+        proposal_index,
         proposal_deadline_timestamp,
         instruction_hashes,
         timelock_offset,
+        sequential_execution,
+        balance_guards,
+        preconditions,
+        group_config_change,
+        compact_votes,
+        description,
+        options,
     )?);
 
+    proposal.bonded = deposit_proposal_bond(
+        group.proposal_bond.as_ref(),
+        BondDepositAccounts {
+            bond: ctx.accounts.bond.as_mut(),
+            bond_bump: ctx.bumps.bond,
+            bond_mint: ctx.accounts.bond_mint.as_deref(),
+            payer_bond_token_account: ctx.accounts.payer_bond_token_account.as_deref(),
+            bond_escrow: ctx.accounts.bond_escrow.as_deref(),
+            token_program: ctx.accounts.token_program.as_ref(),
+        },
+        group.key(),
+        proposal.key(),
+        proposal_index,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )?;
+
     Ok(())
 }
@@ -65,50 +65,72 @@ pub struct CreateProposalTransactionInstructionAccounts<'info> {
 fn checks(
     ctx: &Context<CreateProposalTransactionInstructionAccounts>,
     args: &CreateProposalTransactionInstructionArgs,
-) -> Result<Vec<SerializableInstruction>> {
+) -> Result<(Vec<SerializableInstruction>, Vec<bool>)> {
     require!(
-        ctx.accounts.proposal.state == ProposalState::Open,
-        MultisigError::ProposalNotOpen
+        !ctx.accounts.proposal.is_multi_choice(),
+        MultisigError::OptionTransactionRequired
     );
 
-    require_keys_eq!(
-        ctx.accounts.proposal.group,
-        ctx.accounts.group.key(),
-        MultisigError::UnexpectedGroup
+    validate_proposal_transaction(
+        &ctx.accounts.group,
+        &ctx.accounts.proposal,
+        ctx.remaining_accounts,
+        &args.raw_instructions,
+        &ctx.accounts.proposal.instruction_hashes,
+    )
+}
+
+/// Checks that the proposal can still get a transaction and that `raw_instructions` match
+/// `instruction_hashes`, with each proposal asset at its declared index. The asset account of
+/// each proposal asset is passed in `asset_infos`, in order. Returns the deserialized
+/// instructions and the execute permission setting of each asset.
+pub fn validate_proposal_transaction(
+    group: &Account<Group>,
+    proposal: &Account<NormalProposal>,
+    asset_infos: &[AccountInfo],
+    raw_instructions: &[Vec<u8>],
+    instruction_hashes: &[[u8; hash::HASH_BYTES]],
+) -> Result<(Vec<SerializableInstruction>, Vec<bool>)> {
+    require!(
+        proposal.state == ProposalState::Open,
+        MultisigError::ProposalNotOpen
     );
 
+    require_keys_eq!(proposal.group, group.key(), MultisigError::UnexpectedGroup);
+
     require_gte!(
-        ctx.accounts.proposal.proposal_index,
-        ctx.accounts.group.proposal_index_after_stale,
+        proposal.proposal_index,
+        group.proposal_index_after_stale,
         MultisigError::ProposalStale
     );
 
     require_gt!(
-        ctx.accounts.proposal.proposal_deadline_timestamp,
+        proposal.proposal_deadline_timestamp,
         Clock::get()?.unix_timestamp,
         MultisigError::ProposalExpired
     );
 
     require!(
-        !args.raw_instructions.is_empty(),
+        !raw_instructions.is_empty(),
         MultisigError::EmptyInstructions
     );
 
     require_eq!(
-        args.raw_instructions.len(),
-        ctx.accounts.proposal.instruction_hashes.len(),
+        raw_instructions.len(),
+        instruction_hashes.len(),
         MultisigError::LengthMismatch
     );
 
-    let proposal_assets = &ctx.accounts.proposal.assets;
+    let proposal_assets = &proposal.assets;
     require_eq!(
-        ctx.remaining_accounts.len(),
+        asset_infos.len(),
         proposal_assets.len(),
         MultisigError::LengthMismatch
     );
 
-    let group_key = ctx.accounts.group.key();
-    for (asset_info, proposal_asset) in ctx.remaining_accounts.iter().zip(proposal_assets.iter()) {
+    let group_key = group.key();
+    let mut asset_execute_permissions = Vec::with_capacity(proposal_assets.len());
+    for (asset_info, proposal_asset) in asset_infos.iter().zip(proposal_assets.iter()) {
         require_keys_eq!(*asset_info.owner, crate::ID, MultisigError::InvalidAsset);
         let asset_data = asset_info.try_borrow_data()?;
         let asset_account = Asset::try_deserialize(&mut &asset_data[..])
@@ -147,16 +169,15 @@ fn checks(
             expected_authority_bump,
             MultisigError::InvalidAsset
         );
+
+        // Any later change to the asset config stales the proposal, so the snapshot stays accurate.
+        asset_execute_permissions.push(asset_account.require_execute_permission);
     }
 
-    let mut serializable_instructions = Vec::with_capacity(args.raw_instructions.len());
+    let mut serializable_instructions = Vec::with_capacity(raw_instructions.len());
 
     // Hash check - each raw instruction must match its stored hash to prevent mistakes
-    for (raw, expected_hash) in args
-        .raw_instructions
-        .iter()
-        .zip(ctx.accounts.proposal.instruction_hashes.iter())
-    {
+    for (raw, expected_hash) in raw_instructions.iter().zip(instruction_hashes.iter()) {
         let actual_hash = hash::hash(raw).to_bytes();
         require!(
             actual_hash == *expected_hash,
@@ -186,7 +207,7 @@ fn checks(
         );
     }
 
-    Ok(serializable_instructions)
+    Ok((serializable_instructions, asset_execute_permissions))
 }
 
 /// Create a transaction associated with a particular proposal.
@@ -195,11 +216,28 @@ pub fn create_proposal_transaction_handler(
     ctx: Context<CreateProposalTransactionInstructionAccounts>,
     args: CreateProposalTransactionInstructionArgs,
 ) -> Result<()> {
-    let serializable_instructions = checks(&ctx, &args)?;
+    let (serializable_instructions, asset_execute_permissions) = checks(&ctx, &args)?;
+
+    let transaction = build_proposal_transaction(
+        &ctx.accounts.proposal,
+        serializable_instructions,
+        asset_execute_permissions,
+        ctx.bumps.proposal_transaction,
+    );
 
-    let proposal = &ctx.accounts.proposal;
-    let proposal_tx = &mut ctx.accounts.proposal_transaction;
+    ctx.accounts.proposal_transaction.set_inner(transaction);
+
+    Ok(())
+}
 
+/// Builds the transaction of a proposal from instructions checked by
+/// [`validate_proposal_transaction`].
+pub fn build_proposal_transaction(
+    proposal: &Account<NormalProposal>,
+    serializable_instructions: Vec<SerializableInstruction>,
+    asset_execute_permissions: Vec<bool>,
+    account_bump: u8,
+) -> ProposalTransaction {
     let proposal_assets = &proposal.assets;
 
     let asset_indices: Vec<AssetIndex> = proposal_assets
@@ -212,17 +250,14 @@ pub fn create_proposal_transaction_handler(
     let asset_authority_bumps: Vec<[u8; 1]> =
         proposal_assets.iter().map(|a| [a.authority_bump]).collect();
 
-    let transaction = ProposalTransaction::new(
+    ProposalTransaction::new(
         proposal.key(),
         proposal.group,
         proposal.proposal_index,
         asset_indices,
         asset_authority_bumps,
+        asset_execute_permissions,
         serializable_instructions,
-        ctx.bumps.proposal_transaction,
-    );
-
-    proposal_tx.set_inner(transaction);
-
-    Ok(())
+        account_bump,
+    )
 }
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::instructions::create_proposal_instruction::{
+    build_proposal_transaction, validate_proposal_transaction,
+};
+use crate::state::{
+    error::MultisigError, Group, NormalProposal, ProposalTransaction, SerializableInstruction,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateProposalOptionTransactionInstructionArgs {
+    pub option_index: u8,
+    /// Serialized bytes for each instruction of the option, in order.
+    pub raw_instructions: Vec<Vec<u8>>,
+}
+
+impl CreateProposalOptionTransactionInstructionArgs {
+    /// Total serialized size of all raw instructions, including the outer Vec length prefix.
+    pub fn instructions_total_size(&self) -> usize {
+        4 + self.raw_instructions.iter().map(Vec::len).sum::<usize>()
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(args: CreateProposalOptionTransactionInstructionArgs)]
+pub struct CreateProposalOptionTransactionInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump,
+    )]
+    pub proposal: Account<'info, NormalProposal>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProposalTransaction::get_size(proposal.assets.len(), args.instructions_total_size()),
+        seeds = [b"proposal-transaction", proposal.key().as_ref(), &[args.option_index]],
+        bump
+    )]
+    pub proposal_transaction: Account<'info, ProposalTransaction>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[inline(always)]
+fn checks(
+    ctx: &Context<CreateProposalOptionTransactionInstructionAccounts>,
+    args: &CreateProposalOptionTransactionInstructionArgs,
+) -> Result<(Vec<SerializableInstruction>, Vec<bool>)> {
+    let option = ctx
+        .accounts
+        .proposal
+        .options
+        .get(usize::from(args.option_index))
+        .ok_or(MultisigError::InvalidOptionIndex)?;
+
+    validate_proposal_transaction(
+        &ctx.accounts.group,
+        &ctx.accounts.proposal,
+        ctx.remaining_accounts,
+        &args.raw_instructions,
+        &option.instruction_hashes,
+    )
+}
+
+/// Create the transaction of one option of a multi-choice proposal.
+/// This instruction can be called by anyone.
+pub fn create_proposal_option_transaction_handler(
+    ctx: Context<CreateProposalOptionTransactionInstructionAccounts>,
+    args: CreateProposalOptionTransactionInstructionArgs,
+) -> Result<()> {
+    let (serializable_instructions, asset_execute_permissions) = checks(&ctx, &args)?;
+
+    let transaction = build_proposal_transaction(
+        &ctx.accounts.proposal,
+        serializable_instructions,
+        asset_execute_permissions,
+        ctx.bumps.proposal_transaction,
+    );
+
+    ctx.accounts
+        .proposal_transaction
+        .set_inner(ProposalTransaction {
+            option_index: Some(args.option_index),
+            ..transaction
+        });
+
+    Ok(())
+}
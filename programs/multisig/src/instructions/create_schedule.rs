@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    asset::Asset,
+    group::Group,
+    schedule::{Schedule, ScheduledTransfer},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateScheduleInstructionArgs {
+    pub schedule_seed: Pubkey,
+    pub transfer: ScheduledTransfer,
+    /// Seconds between two runs.
+    pub interval: u32,
+    pub total_runs: u32,
+    /// The first run is due from this timestamp.
+    pub start_timestamp: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: CreateScheduleInstructionArgs)]
+pub struct CreateScheduleInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// CHECK: The governed asset, listed so a proposal can point its asset index at it.
+    pub asset_address: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"asset", group.key().as_ref(), asset_address.key().as_ref()],
+        bump = asset.account_bump
+    )]
+    pub asset: Account<'info, Asset>,
+
+    /// The asset authority only signs through `execute_proposal_transaction`, so a
+    /// schedule can only be created by a passed normal proposal that uses the asset.
+    #[account(
+        seeds = [b"authority", group.key().as_ref(), asset_address.key().as_ref()],
+        bump = asset.authority_bump
+    )]
+    pub asset_authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Schedule::INIT_SPACE,
+        seeds = [b"schedule", group.key().as_ref(), args.schedule_seed.as_ref()],
+        bump
+    )]
+    pub schedule: Account<'info, Schedule>,
+
+    /// Pays for the schedule and gets its rent back once it is closed, usually the
+    /// asset authority itself.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a schedule making `total_runs` transfers from the asset, one every `interval`
+/// seconds from `start_timestamp`, run by `run_schedule`.
+pub fn create_schedule_handler(
+    ctx: Context<CreateScheduleInstructionAccounts>,
+    args: CreateScheduleInstructionArgs,
+) -> Result<()> {
+    let schedule = Schedule::new(
+        ctx.accounts.group.key(),
+        ctx.accounts.asset.asset_address,
+        args.schedule_seed,
+        args.transfer,
+        args.interval,
+        args.total_runs,
+        args.start_timestamp,
+        ctx.accounts.payer.key(),
+        ctx.bumps.schedule,
+        ctx.accounts.asset.authority_bump,
+    )?;
+    ctx.accounts.schedule.set_inner(schedule);
+
+    Ok(())
+}
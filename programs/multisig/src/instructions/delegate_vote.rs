@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    delegation::Delegation,
+    error::MultisigError,
+    group::Group,
+    member::{AssetMember, GroupMember},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DelegateVoteInstructionArgs {
+    pub delegate: Pubkey,
+    /// Delegate the weight held as a member of this asset instead of the group member weight.
+    pub asset: Option<Pubkey>,
+    pub expiry_timestamp: Option<i64>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: DelegateVoteInstructionArgs)]
+pub struct DelegateVoteInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Seeds check binds it to the group and delegator.
+    #[account(
+        seeds = [b"member", group.key().as_ref(), delegator.key().as_ref()],
+        bump = delegator_group_member.account_bump
+    )]
+    pub delegator_group_member: Account<'info, GroupMember>,
+
+    /// Seeds check binds it to the group and delegate, who must be a member too.
+    #[account(
+        seeds = [b"member", group.key().as_ref(), args.delegate.as_ref()],
+        bump = delegate_group_member.account_bump
+    )]
+    pub delegate_group_member: Account<'info, GroupMember>,
+
+    /// Required when delegating asset weight, checked against `args.asset` in the handler.
+    #[account(
+        seeds = [b"asset-member", group.key().as_ref(), delegator_asset_member.asset.as_ref(), delegator.key().as_ref()],
+        bump = delegator_asset_member.account_bump
+    )]
+    pub delegator_asset_member: Option<Account<'info, AssetMember>>,
+
+    /// The delegate needs their own asset membership to vote on the asset.
+    #[account(
+        seeds = [b"asset-member", group.key().as_ref(), delegate_asset_member.asset.as_ref(), args.delegate.as_ref()],
+        bump = delegate_asset_member.account_bump
+    )]
+    pub delegate_asset_member: Option<Account<'info, AssetMember>>,
+
+    /// One delegation per delegator and scope, the scope is the asset or the group.
+    #[account(
+        init,
+        payer = delegator,
+        space = 8 + Delegation::INIT_SPACE,
+        seeds = [b"delegation", group.key().as_ref(), delegator.key().as_ref(), args.asset.unwrap_or(group.key()).as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[inline(always)]
+fn checks(
+    ctx: &Context<DelegateVoteInstructionAccounts>,
+    args: &DelegateVoteInstructionArgs,
+) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require_keys_neq!(
+        args.delegate,
+        ctx.accounts.delegator.key(),
+        MultisigError::InvalidDelegation
+    );
+
+    if let Some(expiry_timestamp) = args.expiry_timestamp {
+        require_gt!(
+            expiry_timestamp,
+            Clock::get()?.unix_timestamp,
+            MultisigError::DelegationExpired
+        );
+    }
+
+    if let Some(asset) = args.asset {
+        let delegator_asset_member = ctx
+            .accounts
+            .delegator_asset_member
+            .as_ref()
+            .ok_or(MultisigError::AssetMemberNotProvided)?;
+        let delegate_asset_member = ctx
+            .accounts
+            .delegate_asset_member
+            .as_ref()
+            .ok_or(MultisigError::AssetMemberNotProvided)?;
+
+        require_keys_eq!(
+            delegator_asset_member.asset,
+            asset,
+            MultisigError::InvalidAssetMember
+        );
+        require_keys_eq!(
+            delegate_asset_member.asset,
+            asset,
+            MultisigError::InvalidAssetMember
+        );
+    }
+
+    Ok(())
+}
+
+/// Lets another group member vote with the delegator's group or asset weight,
+/// until the delegator revokes it or it expires.
+pub fn delegate_vote_handler(
+    ctx: Context<DelegateVoteInstructionAccounts>,
+    args: DelegateVoteInstructionArgs,
+) -> Result<()> {
+    checks(&ctx, &args)?;
+
+    let DelegateVoteInstructionArgs {
+        delegate,
+        asset,
+        expiry_timestamp,
+    } = args;
+
+    ctx.accounts.delegation.set_inner(Delegation::new(
+        ctx.accounts.group.key(),
+        ctx.accounts.delegator.key(),
+        delegate,
+        asset,
+        expiry_timestamp,
+        ctx.bumps.delegation,
+    ));
+
+    Ok(())
+}
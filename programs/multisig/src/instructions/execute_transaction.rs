@@ -1,8 +1,27 @@
-use crate::state::{error::MultisigError, ProposalTransaction};
+use crate::state::{
+    constants, error::MultisigError, Asset, AssetMember, BalanceGuardKind, ExecutedInstruction,
+    ExecutionPrecondition, ExecutionReceipt, GroupMember, InstructionReturnData,
+    ProposalTransaction,
+};
+use crate::utils::{close_open_proposal, create_pda_account, resize_account};
 use crate::{Group, NormalProposal};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::compute_units::sol_remaining_compute_units;
 use anchor_lang::solana_program::instruction::Instruction;
-use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::program::{get_return_data, invoke_signed, set_return_data};
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use anchor_spl::{
+    token::ID as TOKEN_PROGRAM_ID,
+    token_interface::{spl_token_2022, TokenAccount},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ExecuteProposalTransactionInstructionArgs {
+    /// Number of instructions to run in this call, starting from the proposal's cursor.
+    /// Proposals without sequential execution must run every instruction at once.
+    pub instruction_count: u8,
+}
 
 #[derive(Accounts)]
 pub struct ExecuteProposalTransactionInstructionAccounts<'info> {
@@ -15,10 +34,11 @@ pub struct ExecuteProposalTransactionInstructionAccounts<'info> {
     pub proposal: Account<'info, NormalProposal>,
 
     /// Seeds bind transaction to proposal - proposal_transaction.proposal == proposal is guaranteed.
+    /// For a multi-choice proposal, they also bind it to the winning option.
+    /// Closed to the rent collector once the last instruction has run.
     #[account(
         mut,
-        close = rent_collector,
-        seeds = [b"proposal-transaction", proposal.key().as_ref()],
+        seeds = [b"proposal-transaction", proposal.key().as_ref(), proposal.winning_option.as_slice()],
         bump = proposal_transaction.account_bump,
     )]
     pub proposal_transaction: Account<'info, ProposalTransaction>,
@@ -33,10 +53,56 @@ pub struct ExecuteProposalTransactionInstructionAccounts<'info> {
     /// CHECK: Rent collector; verified against group.rent_collector in checks().
     #[account(mut)]
     pub rent_collector: UncheckedAccount<'info>,
+
+    /// CHECK: Created on the first execution call and deserialized in the handler,
+    /// it grows as instructions are executed.
+    #[account(
+        mut,
+        seeds = [b"execution-receipt", proposal.key().as_ref()],
+        bump
+    )]
+    pub execution_receipt: UncheckedAccount<'info>,
+
+    /// Pays for the execution receipt and receives the executor tip.
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// Required when the group requires the Execute permission.
+    #[account(
+        seeds = [b"member", group.key().as_ref(), executor.key().as_ref()],
+        bump = executor_group_member.account_bump
+    )]
+    pub executor_group_member: Option<Account<'info, GroupMember>>,
+
+    /// Asset paying the executor tip, it must be one of the proposal's assets.
+    /// The tip is skipped when this or the vault is omitted.
+    #[account(
+        seeds = [b"asset", group.key().as_ref(), executor_tip_asset.asset_address.as_ref()],
+        bump = executor_tip_asset.account_bump
+    )]
+    pub executor_tip_asset: Option<Account<'info, Asset>>,
+
+    /// CHECK: Authority of the executor tip asset, verified before the tip is paid.
+    #[account(mut)]
+    pub executor_tip_vault: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: The proposer's member account, its open proposal count is decremented once the
+    /// proposal is executed unless it was closed since the proposal was created.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), proposal.proposer.as_ref()],
+        bump
+    )]
+    pub proposer_group_account: UncheckedAccount<'info>,
 }
 
 #[inline(always)]
-fn checks(ctx: &Context<ExecuteProposalTransactionInstructionAccounts>) -> Result<()> {
+fn checks(
+    ctx: &Context<ExecuteProposalTransactionInstructionAccounts>,
+    args: &ExecuteProposalTransactionInstructionArgs,
+) -> Result<()> {
     require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
 
     require_keys_eq!(
@@ -62,11 +128,14 @@ fn checks(ctx: &Context<ExecuteProposalTransactionInstructionAccounts>) -> Resul
         MultisigError::ProposalStillTimelocked
     );
 
-    require_gte!(
-        ctx.accounts.proposal.proposal_deadline_timestamp,
-        now,
-        MultisigError::ProposalExpired
-    );
+    // A proposal that has started executing may be finished after its deadline.
+    if !ctx.accounts.proposal.is_execution_in_progress() {
+        require_gte!(
+            ctx.accounts.proposal.proposal_deadline_timestamp,
+            now,
+            MultisigError::ProposalExpired
+        );
+    }
 
     require_gte!(
         ctx.accounts.proposal.proposal_index,
@@ -74,22 +143,413 @@ fn checks(ctx: &Context<ExecuteProposalTransactionInstructionAccounts>) -> Resul
         MultisigError::ProposalStale
     );
 
+    let instruction_total = ctx.accounts.proposal_transaction.instructions.len();
+    let instructions_left =
+        instruction_total.saturating_sub(usize::from(ctx.accounts.proposal.next_instruction_index));
+    let instruction_count = usize::from(args.instruction_count);
+
+    require_gt!(instruction_count, 0, MultisigError::InvalidInstructionCount);
+
+    require_gte!(
+        constants::MAX_INSTRUCTIONS_PER_EXECUTION,
+        instruction_count,
+        MultisigError::InvalidInstructionCount
+    );
+
+    if ctx.accounts.group.require_execute_permission {
+        let executor_group_member = ctx
+            .accounts
+            .executor_group_member
+            .as_ref()
+            .ok_or(MultisigError::InsufficientPermissions)?;
+
+        require!(
+            executor_group_member.has_execute(),
+            MultisigError::InsufficientPermissions
+        );
+    }
+
+    if ctx.accounts.proposal.sequential_execution {
+        require_gte!(
+            instructions_left,
+            instruction_count,
+            MultisigError::InvalidInstructionCount
+        );
+    } else {
+        require_eq!(
+            instruction_count,
+            instruction_total,
+            MultisigError::InvalidInstructionCount
+        );
+    }
+
+    Ok(())
+}
+
+/// Reads the token amount held by a guarded token account.
+fn read_token_amount(token: &AccountInfo) -> Result<u64> {
+    // A token account can only be closed once it is empty.
+    if token.data_is_empty() {
+        return Ok(0);
+    }
+
+    require!(
+        token.owner == &TOKEN_PROGRAM_ID || token.owner == &spl_token_2022::ID,
+        MultisigError::InvalidBalanceGuard
+    );
+
+    let token_account = TokenAccount::try_deserialize(&mut &token.try_borrow_data()?[..])
+        .map_err(|_| MultisigError::InvalidBalanceGuard)?;
+
+    Ok(token_account.amount)
+}
+
+/// Reads the balance watched by each of the proposal's balance guards, in order.
+/// The watched accounts are looked up among the accounts used by the instructions.
+fn read_guarded_balances(
+    proposal: &NormalProposal,
+    instruction_accounts: &[AccountInfo],
+) -> Result<Vec<u64>> {
+    proposal
+        .balance_guards
+        .iter()
+        .map(|guard| {
+            let proposal_asset = proposal
+                .assets
+                .iter()
+                .find(|proposal_asset| proposal_asset.asset == guard.asset)
+                .ok_or(MultisigError::InvalidBalanceGuard)?;
+
+            let watched_key = match guard.kind {
+                BalanceGuardKind::TokenAmount => guard.asset,
+                BalanceGuardKind::Lamports => Pubkey::create_program_address(
+                    &[
+                        b"authority",
+                        proposal.group.as_ref(),
+                        guard.asset.as_ref(),
+                        &[proposal_asset.authority_bump],
+                    ],
+                    &crate::ID,
+                )
+                .map_err(|_| MultisigError::InvalidBalanceGuard)?,
+            };
+
+            let watched = instruction_accounts
+                .iter()
+                .find(|account| account.key == &watched_key)
+                .ok_or(MultisigError::BalanceGuardAccountNotProvided)?;
+
+            match guard.kind {
+                BalanceGuardKind::TokenAmount => read_token_amount(watched),
+                BalanceGuardKind::Lamports => Ok(watched.lamports()),
+            }
+        })
+        .collect()
+}
+
+/// Checks that every precondition of the proposal holds.
+/// The accounts read are looked up among the accounts used by the instructions.
+fn check_preconditions(
+    proposal: &NormalProposal,
+    instruction_accounts: &[AccountInfo],
+) -> Result<()> {
+    for precondition in proposal.preconditions.iter() {
+        let account = precondition
+            .account()
+            .map(|key| {
+                instruction_accounts
+                    .iter()
+                    .find(|account| account.key == key)
+                    .ok_or(MultisigError::PreconditionAccountNotProvided)
+            })
+            .transpose()?;
+
+        let holds = match (precondition, account) {
+            (ExecutionPrecondition::AccountData { offset, bytes, .. }, Some(account)) => {
+                let offset = *offset as usize;
+                account
+                    .try_borrow_data()?
+                    .get(offset..offset.saturating_add(bytes.len()))
+                    .is_some_and(|data| data == bytes.as_slice())
+            }
+            // An account that is not a token account holds no tokens to compare.
+            (ExecutionPrecondition::MinimumTokenBalance { amount, .. }, Some(account)) => {
+                read_token_amount(account).is_ok_and(|balance| balance >= *amount)
+            }
+            (ExecutionPrecondition::MinimumSlot { slot }, _) => Clock::get()?.slot >= *slot,
+            _ => false,
+        };
+
+        require!(holds, MultisigError::PreconditionNotMet);
+    }
+
+    Ok(())
+}
+
+/// Returns the position of the group account in the instructions a group can invoke as a
+/// member of another group, voting and proposing there through its own proposals.
+/// Signed vote submissions are left out, a PDA cannot sign the off-chain votes they carry.
+fn nested_member_group_position(data: &[u8]) -> Option<usize> {
+    use crate::instruction as ix;
+
+    if data.starts_with(ix::AcceptMembership::DISCRIMINATOR)
+        || data.starts_with(ix::CreateNormalProposal::DISCRIMINATOR)
+        || data.starts_with(ix::VoteOnNormalProposal::DISCRIMINATOR)
+        || data.starts_with(ix::BatchVoteOnNormalProposal::DISCRIMINATOR)
+        || data.starts_with(ix::VoteOnNormalProposalGroupApproval::DISCRIMINATOR)
+        || data.starts_with(ix::VoteOnConfigProposal::DISCRIMINATOR)
+        || data.starts_with(ix::VoteOnMultiAssetConfigProposal::DISCRIMINATOR)
+        || data.starts_with(ix::CompactVoteOnNormalProposal::DISCRIMINATOR)
+        || data.starts_with(ix::CompactVoteOnConfigProposal::DISCRIMINATOR)
+        || data.starts_with(ix::VoteOnProposalOption::DISCRIMINATOR)
+    {
+        Some(0)
+    } else if data.starts_with(ix::CreateConfigProposal::DISCRIMINATOR) {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+/// Checks the instructions that invoke this program, returning whether one of them applies
+/// the group config change. Such instructions are signed by the group, so they may only apply
+/// the proposal's own group config change, once, in a proposal that is executed atomically,
+/// or act as a member of another group.
+fn check_self_invocations(
+    proposal: &Account<NormalProposal>,
+    proposal_transaction: &ProposalTransaction,
+) -> Result<bool> {
+    let mut applies_config_change = false;
+
+    for instruction in proposal_transaction
+        .instructions
+        .iter()
+        .filter(|instruction| instruction.program_id == crate::ID)
+    {
+        // The group PDA or an asset authority signs as a member of another group, it may
+        // not target its own group where the signature would bypass the member checks.
+        if let Some(position) = nested_member_group_position(&instruction.data) {
+            require!(
+                instruction
+                    .accounts
+                    .get(position)
+                    .is_some_and(|meta| meta.key != proposal.group),
+                MultisigError::SelfInvocationNotAllowed
+            );
+            continue;
+        }
+
+        // Creating a schedule is signed by an asset authority of the proposal's group, it does
+        // not change the group config and can run alongside any other instruction.
+        if instruction
+            .data
+            .starts_with(crate::instruction::CreateSchedule::DISCRIMINATOR)
+        {
+            require!(
+                instruction
+                    .accounts
+                    .first()
+                    .is_some_and(|meta| meta.key == proposal.group),
+                MultisigError::SelfInvocationNotAllowed
+            );
+            continue;
+        }
+
+        // Applying the change stales the proposal, so nothing may run in a later call.
+        require!(
+            !applies_config_change
+                && !proposal.sequential_execution
+                && proposal.group_approval.is_some(),
+            MultisigError::SelfInvocationNotAllowed
+        );
+
+        // Otherwise only `apply_group_config_change` can be invoked, in particular this rules
+        // out re-entering `execute_proposal_transaction`.
+        require!(
+            instruction
+                .data
+                .starts_with(crate::instruction::ApplyGroupConfigChange::DISCRIMINATOR),
+            MultisigError::SelfInvocationNotAllowed
+        );
+
+        require!(
+            instruction
+                .accounts
+                .first()
+                .is_some_and(|meta| meta.key == proposal.group)
+                && instruction
+                    .accounts
+                    .get(1)
+                    .is_some_and(|meta| meta.key == proposal.key()),
+            MultisigError::SelfInvocationNotAllowed
+        );
+
+        applies_config_change = true;
+    }
+
+    Ok(applies_config_change)
+}
+
+/// Loads the execution receipt for this proposal, creating it on the first call.
+fn load_or_create_execution_receipt(
+    ctx: &Context<ExecuteProposalTransactionInstructionAccounts>,
+    instruction_accounts: &[AccountInfo],
+) -> Result<ExecutionReceipt> {
+    let receipt_info = ctx.accounts.execution_receipt.to_account_info();
+    let proposal = &ctx.accounts.proposal;
+    let proposal_key = proposal.key();
+
+    if receipt_info.owner == &System::id() {
+        let bump = ctx.bumps.execution_receipt;
+        let receipt = ExecutionReceipt::new(
+            proposal_key,
+            proposal.group,
+            proposal.proposal_index,
+            u8::try_from(ctx.accounts.proposal_transaction.instructions.len())
+                .map_err(|_| MultisigError::TooManyInstructions)?,
+            read_guarded_balances(proposal, instruction_accounts)?,
+            bump,
+        );
+
+        create_pda_account(
+            &ctx.accounts.executor.to_account_info(),
+            &receipt_info,
+            &ctx.accounts.system_program.to_account_info(),
+            8 + receipt.get_size(),
+            &[b"execution-receipt", proposal_key.as_ref(), &[bump]],
+        )?;
+
+        return Ok(receipt);
+    }
+
+    require_keys_eq!(
+        *receipt_info.owner,
+        crate::ID,
+        MultisigError::StaleExecutionReceipt
+    );
+
+    let receipt = ExecutionReceipt::try_deserialize(&mut &receipt_info.try_borrow_data()?[..])?;
+
+    // A receipt left behind by an earlier proposal with the same seed must be closed first.
+    require_eq!(
+        receipt.proposal_index,
+        proposal.proposal_index,
+        MultisigError::StaleExecutionReceipt
+    );
+
+    Ok(receipt)
+}
+
+/// Checks the executor's asset member accounts, one for every asset that requires
+/// the Execute permission, in the same order as the proposal's assets.
+fn check_executor_asset_permissions(
+    executor: &Pubkey,
+    group: &Pubkey,
+    required_assets: &[&Pubkey],
+    executor_asset_members: &[AccountInfo],
+) -> Result<()> {
+    for (asset, member_info) in required_assets.iter().zip(executor_asset_members) {
+        require_keys_eq!(
+            *member_info.owner,
+            crate::ID,
+            MultisigError::InsufficientPermissions
+        );
+
+        let member = AssetMember::try_deserialize(&mut &member_info.try_borrow_data()?[..])
+            .map_err(|_| MultisigError::InsufficientPermissions)?;
+
+        require!(
+            member.user == *executor && member.group == *group && member.asset == **asset,
+            MultisigError::InsufficientPermissions
+        );
+
+        require!(member.has_execute(), MultisigError::InsufficientPermissions);
+    }
+
     Ok(())
 }
 
-/// Executes the transaction attached to a passed normal proposal.
+/// Pays the executor tip configured by one of the proposal's assets from its authority.
+/// The tip is skipped, rather than failing the execution, if the asset or vault is not
+/// provided or cannot pay it without dropping below the rent exempt minimum.
+fn pay_executor_tip(ctx: &Context<ExecuteProposalTransactionInstructionAccounts>) -> Result<()> {
+    let (Some(asset), Some(vault)) = (
+        ctx.accounts.executor_tip_asset.as_ref(),
+        ctx.accounts.executor_tip_vault.as_ref(),
+    ) else {
+        return Ok(());
+    };
+
+    if asset.executor_tip_lamports == 0 {
+        return Ok(());
+    }
+
+    // Only an asset whose committee voted on the proposal pays for its execution.
+    require!(
+        ctx.accounts
+            .proposal
+            .assets
+            .iter()
+            .any(|proposal_asset| proposal_asset.asset == asset.asset_address),
+        MultisigError::InvalidAsset
+    );
+
+    let group_key = ctx.accounts.group.key();
+    let vault_bump = [asset.authority_bump];
+    let vault_seeds: [&[u8]; 4] = [
+        b"authority",
+        group_key.as_ref(),
+        asset.asset_address.as_ref(),
+        &vault_bump,
+    ];
+    let expected_vault = Pubkey::create_program_address(&vault_seeds, &crate::ID)
+        .map_err(|_| MultisigError::InvalidAsset)?;
+    require_keys_eq!(vault.key(), expected_vault, MultisigError::InvalidAsset);
+
+    let required_balance = Rent::get()?
+        .minimum_balance(vault.data_len())
+        .checked_add(asset.executor_tip_lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    if vault.lamports() < required_balance {
+        return Ok(());
+    }
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: vault.to_account_info(),
+                to: ctx.accounts.executor.to_account_info(),
+            },
+            &[&vault_seeds],
+        ),
+        asset.executor_tip_lamports,
+    )
+}
+
+/// Executes the transaction attached to a passed normal proposal, recording each
+/// executed instruction in the execution receipt.
+/// The proposal's preconditions are checked on every call before any instruction runs,
+/// and the call fails if a balance guard is exceeded once the instructions have run.
 pub fn execute_proposal_transaction_handler(
     ctx: Context<ExecuteProposalTransactionInstructionAccounts>,
+    args: ExecuteProposalTransactionInstructionArgs,
 ) -> Result<()> {
-    checks(&ctx)?;
+    checks(&ctx, &args)?;
 
     let proposal = &ctx.accounts.proposal;
     let proposal_transaction = &ctx.accounts.proposal_transaction;
     let group_key = proposal.group;
+    let group_seed = ctx.accounts.group.group_seed;
+    let group_bump = [ctx.accounts.group.account_bump];
+
+    let applies_config_change = check_self_invocations(proposal, proposal_transaction)?;
 
     // Locate each asset key by following (instruction_index, account_index) into the stored instruction list.
     let mut signer_seeds: Vec<[&[u8]; 4]> =
         Vec::with_capacity(proposal_transaction.asset_indices.len());
+    let mut execute_permission_assets: Vec<&Pubkey> = Vec::new();
 
     for (position, asset_index) in proposal_transaction.asset_indices.iter().enumerate() {
         let ix = proposal_transaction
@@ -106,6 +566,15 @@ pub fn execute_proposal_transaction_handler(
             .get(position)
             .ok_or(MultisigError::InvalidAssetIndex)?;
 
+        if proposal_transaction
+            .asset_execute_permissions
+            .get(position)
+            .copied()
+            .unwrap_or(false)
+        {
+            execute_permission_assets.push(asset_key);
+        }
+
         signer_seeds.push([
             b"authority",
             group_key.as_ref(),
@@ -116,12 +585,121 @@ pub fn execute_proposal_transaction_handler(
 
     let signer_slices: Vec<&[&[u8]]> = signer_seeds.iter().map(|s| s.as_slice()).collect();
 
-    for serializable in &proposal_transaction.instructions {
+    // The group only signs the instructions that invoke this program.
+    let group_signer_seeds: [&[u8]; 3] = [b"group", group_seed.as_ref(), &group_bump];
+    let mut self_signer_slices = signer_slices.clone();
+    self_signer_slices.push(group_signer_seeds.as_slice());
+
+    // The executor's asset member accounts prefix the accounts used by the instructions.
+    require_gte!(
+        ctx.remaining_accounts.len(),
+        execute_permission_assets.len(),
+        MultisigError::LengthMismatch
+    );
+    let (executor_asset_members, instruction_accounts) = ctx
+        .remaining_accounts
+        .split_at(execute_permission_assets.len());
+
+    check_executor_asset_permissions(
+        &ctx.accounts.executor.key(),
+        &group_key,
+        &execute_permission_assets,
+        executor_asset_members,
+    )?;
+
+    check_preconditions(proposal, instruction_accounts)?;
+
+    // Balance guards are measured from before the first instruction of the proposal ran.
+    let mut receipt = load_or_create_execution_receipt(&ctx, instruction_accounts)?;
+
+    let start = usize::from(proposal.next_instruction_index);
+    let end = start + usize::from(args.instruction_count);
+    let executor = ctx.accounts.executor.key();
+    let slot = Clock::get()?.slot;
+
+    for (index, serializable) in proposal_transaction
+        .instructions
+        .iter()
+        .enumerate()
+        .take(end)
+        .skip(start)
+    {
         let instruction: Instruction = serializable.into_instruction();
-        invoke_signed(&instruction, ctx.remaining_accounts, &signer_slices)?;
+
+        // Return data is shared by the whole transaction, clear it so an instruction
+        // that returns nothing is not credited with an earlier instruction's data.
+        set_return_data(&[]);
+
+        let compute_units_before = sol_remaining_compute_units();
+        let signers = if serializable.program_id == crate::ID {
+            &self_signer_slices
+        } else {
+            &signer_slices
+        };
+        invoke_signed(&instruction, instruction_accounts, signers)?;
+        let compute_units = compute_units_before.saturating_sub(sol_remaining_compute_units());
+
+        receipt.record(ExecutedInstruction {
+            // Cannot truncate, the receipt was created with a u8 instruction count.
+            instruction_index: index as u8,
+            executor,
+            slot,
+            compute_units,
+            return_data: get_return_data()
+                .map(|(program_id, data)| InstructionReturnData::new(program_id, &data)),
+        });
+    }
+
+    // The group may have been changed by the invoked instruction, reload it so the
+    // stale copy is not written back when this instruction exits.
+    if applies_config_change {
+        ctx.accounts.group.reload()?;
+    }
+
+    // Paid before the balance guards are checked, so a guard on the tip asset counts it.
+    if receipt.is_complete() {
+        pay_executor_tip(&ctx)?;
+    }
+
+    let balances = read_guarded_balances(proposal, instruction_accounts)?;
+    for ((guard, baseline), balance) in proposal
+        .balance_guards
+        .iter()
+        .zip(&receipt.balance_baselines)
+        .zip(balances)
+    {
+        require!(
+            guard.allows(*baseline, balance),
+            MultisigError::BalanceGuardViolated
+        );
+    }
+
+    let receipt_info = ctx.accounts.execution_receipt.to_account_info();
+    let receipt_size = 8 + receipt.get_size();
+    if receipt_size > receipt_info.data_len() {
+        resize_account(
+            &ctx.accounts.executor.to_account_info(),
+            &receipt_info,
+            &ctx.accounts.system_program.to_account_info(),
+            receipt_size,
+        )?;
     }
+    receipt.try_serialize(&mut &mut receipt_info.try_borrow_mut_data()?[..])?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    // Cannot truncate, end is bounded by the receipt's u8 instruction count.
+    proposal.next_instruction_index = end as u8;
 
-    ctx.accounts.proposal.mark_executed()?;
+    if receipt.is_complete() {
+        proposal.mark_executed()?;
+        close_open_proposal(
+            &mut ctx.accounts.group,
+            &ctx.accounts.proposer_group_account,
+        )?;
+        ctx.accounts
+            .proposal_transaction
+            .close(ctx.accounts.rent_collector.to_account_info())?;
+    }
 
     Ok(())
 }
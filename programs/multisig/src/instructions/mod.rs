@@ -46,9 +46,54 @@ pub use remove_asset_member::*;
 pub mod vote_on_normal_proposal;
 pub use vote_on_normal_proposal::*;
 
+pub mod batch_vote_on_normal_proposal;
+pub use batch_vote_on_normal_proposal::*;
+
+pub mod vote_on_normal_proposal_group_approval;
+pub use vote_on_normal_proposal_group_approval::*;
+
 pub mod vote_on_config_proposal;
 pub use vote_on_config_proposal::*;
 
+pub mod compact_vote_on_normal_proposal;
+pub use compact_vote_on_normal_proposal::*;
+
+pub mod compact_vote_on_config_proposal;
+pub use compact_vote_on_config_proposal::*;
+
+pub mod submit_signed_votes_on_normal_proposal;
+pub use submit_signed_votes_on_normal_proposal::*;
+
+pub mod submit_signed_votes_on_config_proposal;
+pub use submit_signed_votes_on_config_proposal::*;
+
+pub mod delegate_vote;
+pub use delegate_vote::*;
+
+pub mod revoke_delegation;
+pub use revoke_delegation::*;
+
+pub mod rotate_member_key;
+pub use rotate_member_key::*;
+
+pub mod accept_membership;
+pub use accept_membership::*;
+
+pub mod close_member_invite;
+pub use close_member_invite::*;
+
+pub mod renew_group_member_term;
+pub use renew_group_member_term::*;
+
+pub mod renew_asset_member_term;
+pub use renew_asset_member_term::*;
+
+pub mod close_expired_group_member;
+pub use close_expired_group_member::*;
+
+pub mod close_expired_asset_member;
+pub use close_expired_asset_member::*;
+
 pub mod create_proposal_instruction;
 pub use create_proposal_instruction::*;
 
@@ -58,6 +103,12 @@ pub use close_proposal_transaction_instruction::*;
 pub mod execute_transaction;
 pub use execute_transaction::*;
 
+pub mod apply_group_config_change;
+pub use apply_group_config_change::*;
+
+pub mod close_execution_receipt;
+pub use close_execution_receipt::*;
+
 pub mod close_asset_member;
 pub use close_asset_member::*;
 
@@ -84,3 +135,57 @@ pub use remove_member_in_reset_mode::*;
 
 pub mod exit_pause_mode;
 pub use exit_pause_mode::*;
+
+pub mod change_group_member_permissions;
+pub use change_group_member_permissions::*;
+
+pub mod change_asset_member_permissions;
+pub use change_asset_member_permissions::*;
+
+pub mod cancel_normal_proposal;
+pub use cancel_normal_proposal::*;
+
+pub mod cancel_config_proposal;
+pub use cancel_config_proposal::*;
+
+pub mod set_group_metadata;
+pub use set_group_metadata::*;
+
+pub mod set_asset_metadata;
+pub use set_asset_metadata::*;
+
+pub mod set_member_metadata;
+pub use set_member_metadata::*;
+
+pub mod set_proposal_metadata;
+pub use set_proposal_metadata::*;
+
+pub mod close_metadata;
+pub use close_metadata::*;
+
+pub mod update_normal_proposal_description;
+pub use update_normal_proposal_description::*;
+
+pub mod update_config_proposal_description;
+pub use update_config_proposal_description::*;
+
+pub mod vote_on_multi_asset_config_proposal;
+pub use vote_on_multi_asset_config_proposal::*;
+
+pub mod release_proposal_bond;
+pub use release_proposal_bond::*;
+
+pub mod create_schedule;
+pub use create_schedule::*;
+
+pub mod run_schedule;
+pub use run_schedule::*;
+
+pub mod cancel_schedule;
+pub use cancel_schedule::*;
+
+pub mod create_proposal_option_transaction;
+pub use create_proposal_option_transaction::*;
+
+pub mod vote_on_proposal_option;
+pub use vote_on_proposal_option::*;
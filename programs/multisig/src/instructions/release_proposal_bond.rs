@@ -0,0 +1,227 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    state::{
+        bond::ProposalBond,
+        error::MultisigError,
+        group::Group,
+        proposal::{ConfigProposal, NormalProposal, ProposalState},
+    },
+    utils::{release_token_bond, BondReleaseTokenAccounts},
+};
+
+#[derive(Accounts)]
+pub struct ReleaseProposalBondInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// CHECK: The bonded proposal, deserialized in the handler. It is already closed when it
+    /// was applied by a config change instruction.
+    #[account(mut)]
+    pub proposal: UncheckedAccount<'info>,
+
+    /// Seeds bind the bond to the proposal.
+    #[account(
+        mut,
+        seeds = [b"proposal-bond", proposal.key().as_ref()],
+        bump = bond.account_bump,
+        has_one = group,
+        has_one = depositor,
+        close = depositor,
+    )]
+    pub bond: Account<'info, ProposalBond>,
+
+    /// CHECK: Checked against the bond; receives a refunded bond and the escrow rent.
+    #[account(mut)]
+    pub depositor: UncheckedAccount<'info>,
+
+    /// CHECK: Rent collector; verified against group.rent_collector in checks(). Receives a
+    /// forfeited lamport bond.
+    #[account(mut)]
+    pub rent_collector: UncheckedAccount<'info>,
+
+    pub bond_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        seeds = [b"bond-escrow", proposal.key().as_ref()],
+        bump,
+        token::mint = bond_mint,
+        token::authority = bond,
+    )]
+    pub bond_escrow: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Receives a token bond, the depositor's token account on a refund and the rent
+    /// collector's on a forfeit.
+    #[account(mut, token::mint = bond_mint)]
+    pub bond_recipient_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<ReleaseProposalBondInstructionAccounts>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.group.rent_collector,
+        ctx.accounts.rent_collector.key(),
+        MultisigError::UnexpectedRentCollector
+    );
+
+    Ok(())
+}
+
+/// Whether a proposal that is still open or passed can no longer be executed.
+#[inline(always)]
+fn is_settled(
+    group: &Group,
+    state: ProposalState,
+    proposal_deadline_timestamp: i64,
+    proposal_index: u64,
+    execution_in_progress: bool,
+) -> Result<bool> {
+    let now = Clock::get()?.unix_timestamp;
+    let is_stale = group.proposal_index_after_stale > proposal_index;
+    let is_expired = now >= proposal_deadline_timestamp;
+
+    Ok(match state {
+        ProposalState::Open => is_expired || is_stale,
+        // Once execution has started the deadline no longer applies.
+        ProposalState::Passed => is_stale || (is_expired && !execution_in_progress),
+        ProposalState::Expired
+        | ProposalState::Failed
+        | ProposalState::Executed
+        | ProposalState::Cancelled => true,
+    })
+}
+
+/// Reads the bonded proposal, clears its `bonded` flag and returns whether the bond is
+/// forfeited. A proposal that is gone, or replaced by a later proposal with the same seed,
+/// was applied by a config change instruction and gets its bond back.
+fn settle_bonded_proposal(
+    group: &Group,
+    proposal_info: &AccountInfo,
+    bond: &ProposalBond,
+) -> Result<bool> {
+    if proposal_info.data_is_empty() || proposal_info.owner != &crate::ID {
+        return Ok(false);
+    }
+
+    let mut data = proposal_info.try_borrow_mut_data()?;
+
+    if data.starts_with(NormalProposal::DISCRIMINATOR) {
+        let mut proposal = NormalProposal::try_deserialize(&mut &data[..])?;
+        if proposal.proposal_index != bond.proposal_index {
+            return Ok(false);
+        }
+        require!(
+            is_settled(
+                group,
+                proposal.state,
+                proposal.proposal_deadline_timestamp,
+                proposal.proposal_index,
+                proposal.is_execution_in_progress(),
+            )?,
+            MultisigError::ProposalStillActive
+        );
+
+        let forfeited = bond.is_forfeited_by_normal_proposal(&proposal)?;
+        proposal.bonded = false;
+        proposal.try_serialize(&mut &mut data[..])?;
+        Ok(forfeited)
+    } else if data.starts_with(ConfigProposal::DISCRIMINATOR) {
+        let mut proposal = ConfigProposal::try_deserialize(&mut &data[..])?;
+        // A multi asset proposal takes a new index once an applied asset stales the group,
+        // so the bond is matched on the index the proposal was created with.
+        if proposal.created_proposal_index != bond.proposal_index {
+            return Ok(false);
+        }
+        require!(
+            is_settled(
+                group,
+                proposal.state,
+                proposal.proposal_deadline_timestamp,
+                proposal.proposal_index,
+                false,
+            )?,
+            MultisigError::ProposalStillActive
+        );
+
+        let forfeited = bond.is_forfeited_by_config_proposal(&proposal)?;
+        proposal.bonded = false;
+        proposal.try_serialize(&mut &mut data[..])?;
+        Ok(forfeited)
+    } else {
+        Err(MultisigError::InvalidProposalBond.into())
+    }
+}
+
+/// Releases the bond of a settled proposal, so the proposal can then be closed.
+/// The bond goes back to its depositor when the proposal was executed, failed normally or
+/// expired, and to the rent collector when it was cancelled or rejected by at least the
+/// bond's forfeit threshold of the votes. The escrow rent always goes back to the depositor.
+/// This instruction can be called by anyone.
+pub fn release_proposal_bond_handler(
+    ctx: Context<ReleaseProposalBondInstructionAccounts>,
+) -> Result<()> {
+    checks(&ctx)?;
+
+    let forfeited = settle_bonded_proposal(
+        &ctx.accounts.group,
+        &ctx.accounts.proposal.to_account_info(),
+        &ctx.accounts.bond,
+    )?;
+
+    let bond = &ctx.accounts.bond;
+    let depositor = ctx.accounts.depositor.to_account_info();
+    let rent_collector = ctx.accounts.rent_collector.to_account_info();
+
+    match bond.mint {
+        None => {
+            // The rest of the lamports go to the depositor when the bond is closed.
+            if forfeited {
+                let bond_info = bond.to_account_info();
+                bond_info.sub_lamports(bond.amount)?;
+                rent_collector.add_lamports(bond.amount)?;
+            }
+        }
+        Some(mint) => {
+            let (Some(bond_mint), Some(bond_escrow), Some(recipient), Some(token_program)) = (
+                ctx.accounts.bond_mint.as_deref(),
+                ctx.accounts.bond_escrow.as_deref(),
+                ctx.accounts.bond_recipient_token_account.as_deref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return Err(MultisigError::ProposalBondNotProvided.into());
+            };
+            require_keys_eq!(bond_mint.key(), mint, MultisigError::InvalidProposalBond);
+
+            let expected_owner = if forfeited {
+                rent_collector.key()
+            } else {
+                depositor.key()
+            };
+            require_keys_eq!(
+                recipient.owner,
+                expected_owner,
+                MultisigError::InvalidProposalBond
+            );
+
+            release_token_bond(
+                bond,
+                BondReleaseTokenAccounts {
+                    bond_mint,
+                    bond_escrow,
+                    recipient,
+                    token_program,
+                },
+                &depositor,
+            )?;
+        }
+    }
+
+    Ok(())
+}
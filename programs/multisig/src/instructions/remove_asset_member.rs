@@ -3,10 +3,11 @@ use anchor_lang::prelude::*;
 use crate::state::{
     asset::Asset,
     group::Group,
-    member::AssetMember,
+    member::{AssetMember, GroupMember},
     proposal::{ConfigChange, ConfigProposal, ProposalState},
     MultisigError,
 };
+use crate::utils::close_open_proposal;
 
 #[derive(Accounts)]
 pub struct RemoveAssetMemberInstructionAccounts<'info> {
@@ -34,11 +35,18 @@ pub struct RemoveAssetMemberInstructionAccounts<'info> {
     )]
     pub asset_member_account: Account<'info, AssetMember>,
 
+    /// CHECK: Seeds bind it to the group and member, it is only updated while it exists.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), asset_member_account.user.as_ref()],
+        bump
+    )]
+    pub group_member: UncheckedAccount<'info>,
+
     #[account(
         mut,
         seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
-        bump = proposal.account_bump,
-        close = proposer
+        bump = proposal.account_bump
     )]
     pub proposal: Account<'info, ConfigProposal>,
 
@@ -50,6 +58,15 @@ pub struct RemoveAssetMemberInstructionAccounts<'info> {
     #[account(mut)]
     pub proposer: UncheckedAccount<'info>,
 
+    /// CHECK: The proposer's member account, its open proposal count is decremented
+    /// unless it was closed since the proposal was created.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_group_account: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -71,7 +88,10 @@ fn checks(ctx: &Context<RemoveAssetMemberInstructionAccounts>) -> Result<()> {
 
     // Validate proposal
     require!(
-        ctx.accounts.proposal.state == ProposalState::Passed,
+        ctx.accounts
+            .proposal
+            .get_asset_state(&ctx.accounts.asset.asset_address)?
+            == ProposalState::Passed,
         MultisigError::ProposalNotPassed
     );
 
@@ -79,7 +99,9 @@ fn checks(ctx: &Context<RemoveAssetMemberInstructionAccounts>) -> Result<()> {
 
     require_gte!(
         now,
-        ctx.accounts.proposal.get_valid_from_timestamp()?,
+        ctx.accounts
+            .proposal
+            .get_asset_valid_from_timestamp(&ctx.accounts.asset.asset_address)?,
         MultisigError::ProposalStillTimelocked
     );
 
@@ -101,7 +123,8 @@ fn checks(ctx: &Context<RemoveAssetMemberInstructionAccounts>) -> Result<()> {
 /// Removes an existing asset member once a proposal to remove them has passed,
 /// closes their AssetMember account and sends the rent to the rent_collector.
 /// It is not checked that they have a corresponding group account since one(AssetMember) could
-/// exist without the other(GroupMember).
+/// exist without the other(GroupMember), when it does exist its asset member count is updated.
+/// A proposal targeting several assets is closed once the last of them is applied.
 pub fn remove_asset_member_handler(
     ctx: Context<RemoveAssetMemberInstructionAccounts>,
 ) -> Result<()> {
@@ -111,21 +134,21 @@ pub fn remove_asset_member_handler(
     let group = &mut ctx.accounts.group;
     let proposal = &ctx.accounts.proposal;
     let asset_member = &ctx.accounts.asset_member_account;
+    let asset_address = asset.asset_address;
 
     match &proposal.config_change {
         ConfigChange::RemoveAssetMember {
             member: target_member,
-            asset_address,
+            asset_address: change_asset,
         } => {
-            require_keys_eq!(
-                *asset_address,
-                asset.asset_address,
+            require!(
+                proposal.applies_to_asset(change_asset, &asset_address),
                 MultisigError::InvalidAsset
             );
 
             require_keys_eq!(
                 asset_member.asset,
-                *asset_address,
+                asset_address,
                 MultisigError::InvalidAsset
             );
 
@@ -137,9 +160,27 @@ pub fn remove_asset_member_handler(
 
             asset.decrement_member_count()?;
             group.update_stale_proposal_index();
+
+            let group_member_info = ctx.accounts.group_member.to_account_info();
+            if *group_member_info.owner == crate::ID {
+                let mut group_member =
+                    GroupMember::try_deserialize(&mut &group_member_info.try_borrow_data()?[..])?;
+                group_member.decrement_asset_member_count();
+                group_member
+                    .try_serialize(&mut &mut group_member_info.try_borrow_mut_data()?[..])?;
+            }
         }
         _ => return Err(MultisigError::InvalidConfigChange.into()),
     }
 
+    let proposal = &mut ctx.accounts.proposal;
+    if proposal.mark_asset_executed(&asset_address, &mut ctx.accounts.group)? {
+        close_open_proposal(
+            &mut ctx.accounts.group,
+            &ctx.accounts.proposer_group_account,
+        )?;
+        proposal.close(ctx.accounts.proposer.to_account_info())?;
+    }
+
     Ok(())
 }
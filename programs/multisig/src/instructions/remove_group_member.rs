@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 
+use crate::utils::close_open_proposal;
 use crate::{
     state::{
         group::Group,
@@ -42,6 +43,15 @@ pub struct RemoveGroupMemberInstructionAccounts<'info> {
     #[account(mut)]
     pub proposer: UncheckedAccount<'info>,
 
+    /// CHECK: The proposer's member account, its open proposal count is decremented
+    /// unless it was closed since the proposal was created.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_group_account: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -114,5 +124,8 @@ pub fn remove_group_member_handler(
         _ => return Err(MultisigError::InvalidConfigChange.into()),
     }
 
-    Ok(())
+    close_open_proposal(
+        &mut ctx.accounts.group,
+        &ctx.accounts.proposer_group_account,
+    )
 }
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    asset::Asset,
+    error::MultisigError,
+    group::Group,
+    member::AssetMember,
+    proposal::{ConfigChange, ConfigProposal, ProposalState},
+};
+use crate::utils::close_open_proposal;
+
+#[derive(Accounts)]
+pub struct RenewAssetMemberTermInstructionAccounts<'info> {
+    #[account(
+        mut,
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        seeds = [b"asset", group.key().as_ref(), asset.asset_address.as_ref()],
+        bump = asset.account_bump
+    )]
+    pub asset: Account<'info, Asset>,
+
+    /// Seeds bind it to the group and asset, the user is checked against the config change.
+    #[account(
+        mut,
+        seeds = [b"asset-member", group.key().as_ref(), asset.asset_address.as_ref(), asset_member.user.as_ref()],
+        bump = asset_member.account_bump
+    )]
+    pub asset_member: Account<'info, AssetMember>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump,
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Must match the proposer stored in the proposal; receives closed-account rent.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// CHECK: The proposer's member account, its open proposal count is decremented
+    /// unless it was closed since the proposal was created.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_group_account: UncheckedAccount<'info>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<RenewAssetMemberTermInstructionAccounts>) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require_keys_eq!(
+        ctx.accounts.proposer.key(),
+        ctx.accounts.proposal.proposer,
+        MultisigError::InvalidProposer
+    );
+
+    require!(
+        ctx.accounts
+            .proposal
+            .get_asset_state(&ctx.accounts.asset.asset_address)?
+            == ProposalState::Passed,
+        MultisigError::ProposalNotPassed
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require_gte!(
+        now,
+        ctx.accounts
+            .proposal
+            .get_asset_valid_from_timestamp(&ctx.accounts.asset.asset_address)?,
+        MultisigError::ProposalStillTimelocked
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_deadline_timestamp,
+        now,
+        MultisigError::ProposalExpired
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_index,
+        ctx.accounts.group.proposal_index_after_stale,
+        MultisigError::ProposalStale
+    );
+
+    Ok(())
+}
+
+/// Executes a passed RenewAssetMemberTerm config proposal, setting the end of the asset
+/// member's term.
+/// A proposal targeting several assets is closed once the last of them is applied.
+pub fn renew_asset_member_term_handler(
+    ctx: Context<RenewAssetMemberTermInstructionAccounts>,
+) -> Result<()> {
+    checks(&ctx)?;
+
+    let group = &mut ctx.accounts.group;
+    let asset_member = &mut ctx.accounts.asset_member;
+    let proposal = &ctx.accounts.proposal;
+    let asset_address = ctx.accounts.asset.asset_address;
+
+    match &proposal.config_change {
+        ConfigChange::RenewAssetMemberTerm {
+            member,
+            asset_address: change_asset,
+            expires_at,
+        } => {
+            require!(
+                proposal.applies_to_asset(change_asset, &asset_address),
+                MultisigError::InvalidAsset
+            );
+            require_keys_eq!(*member, asset_member.user, MultisigError::InvalidMember);
+
+            asset_member.expires_at = *expires_at;
+            group.update_stale_proposal_index();
+        }
+        _ => return Err(MultisigError::InvalidConfigChange.into()),
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    if proposal.mark_asset_executed(&asset_address, &mut ctx.accounts.group)? {
+        close_open_proposal(
+            &mut ctx.accounts.group,
+            &ctx.accounts.proposer_group_account,
+        )?;
+        proposal.close(ctx.accounts.proposer.to_account_info())?;
+    }
+
+    Ok(())
+}
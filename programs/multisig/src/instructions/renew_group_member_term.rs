@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    error::MultisigError,
+    group::Group,
+    member::GroupMember,
+    proposal::{ConfigChange, ConfigProposal, ProposalState},
+};
+use crate::utils::close_open_proposal;
+
+#[derive(Accounts)]
+pub struct RenewGroupMemberTermInstructionAccounts<'info> {
+    #[account(
+        mut,
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Seeds bind it to the group, the user is checked against the config change.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), group_member.user.as_ref()],
+        bump = group_member.account_bump
+    )]
+    pub group_member: Account<'info, GroupMember>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump,
+        close = proposer,
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Must match the proposer stored in the proposal; receives closed-account rent.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// CHECK: The proposer's member account, its open proposal count is decremented
+    /// unless it was closed since the proposal was created.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_group_account: UncheckedAccount<'info>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<RenewGroupMemberTermInstructionAccounts>) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require_keys_eq!(
+        ctx.accounts.proposer.key(),
+        ctx.accounts.proposal.proposer,
+        MultisigError::InvalidProposer
+    );
+
+    require!(
+        ctx.accounts.proposal.state == ProposalState::Passed,
+        MultisigError::ProposalNotPassed
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require_gte!(
+        now,
+        ctx.accounts.proposal.get_valid_from_timestamp()?,
+        MultisigError::ProposalStillTimelocked
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_deadline_timestamp,
+        now,
+        MultisigError::ProposalExpired
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_index,
+        ctx.accounts.group.proposal_index_after_stale,
+        MultisigError::ProposalStale
+    );
+
+    Ok(())
+}
+
+/// Executes a passed RenewGroupMemberTerm config proposal, setting the end of the member's term.
+/// A member whose term already ended can be renewed as long as they were not closed yet.
+pub fn renew_group_member_term_handler(
+    ctx: Context<RenewGroupMemberTermInstructionAccounts>,
+) -> Result<()> {
+    checks(&ctx)?;
+
+    let group = &mut ctx.accounts.group;
+    let group_member = &mut ctx.accounts.group_member;
+
+    match &ctx.accounts.proposal.config_change {
+        ConfigChange::RenewGroupMemberTerm { member, expires_at } => {
+            require_keys_eq!(*member, group_member.user, MultisigError::InvalidMember);
+
+            group_member.expires_at = *expires_at;
+            group.update_stale_proposal_index();
+        }
+        _ => return Err(MultisigError::InvalidConfigChange.into()),
+    }
+
+    // The member is written back when this instruction exits, so a proposer changing
+    // their own membership is updated through it.
+    if ctx.accounts.proposer_group_account.key() == ctx.accounts.group_member.key() {
+        ctx.accounts.group.close_open_proposal();
+        ctx.accounts.group_member.close_open_proposal();
+        return Ok(());
+    }
+
+    close_open_proposal(
+        &mut ctx.accounts.group,
+        &ctx.accounts.proposer_group_account,
+    )
+}
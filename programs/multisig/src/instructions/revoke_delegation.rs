@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{delegation::Delegation, group::Group};
+
+#[derive(Accounts)]
+pub struct RevokeDelegationInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Seeds bind it to the group and delegator.
+    #[account(
+        mut,
+        seeds = [b"delegation", group.key().as_ref(), delegator.key().as_ref(), delegation.asset.unwrap_or(group.key()).as_ref()],
+        bump = delegation.account_bump,
+        close = delegator
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+}
+
+/// Revokes a delegation, votes already cast by the delegate are kept
+/// but the delegator can still change them.
+pub fn revoke_delegation_handler(_ctx: Context<RevokeDelegationInstructionAccounts>) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{error::MultisigError, group::Group, member::AssetMember},
+    utils::create_pda_account,
+    GroupMember,
+};
+
+#[derive(Accounts)]
+pub struct RotateMemberKeyInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), old_key.key().as_ref()],
+        bump = old_group_member.account_bump,
+        close = old_key
+    )]
+    pub old_group_member: Account<'info, GroupMember>,
+
+    #[account(
+        init,
+        payer = new_key,
+        space = 8 + GroupMember::INIT_SPACE,
+        seeds = [b"member", group.key().as_ref(), new_key.key().as_ref()],
+        bump
+    )]
+    pub new_group_member: Account<'info, GroupMember>,
+
+    #[account(mut)]
+    pub old_key: Signer<'info>,
+
+    /// Pays for the new member accounts, the old ones are refunded to `old_key`.
+    #[account(mut)]
+    pub new_key: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<RotateMemberKeyInstructionAccounts>) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    // Every asset membership has to move, none can be left behind on the old key.
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        ctx.accounts.old_group_member.asset_member_count as usize * 2,
+        MultisigError::LengthMismatch
+    );
+
+    Ok(())
+}
+
+/// Moves a member to a new key, signed by both keys. The group member and every asset member
+/// account of the old key are closed and recreated at the new key's seeds with the same weight,
+/// permissions and member index. Remaining accounts are `[old asset member, new asset member]`
+/// for each asset membership.
+/// Vote records are keyed by the voter, so the new key cannot vote on proposals created
+/// before the rotation, the old key may already have.
+pub fn rotate_member_key_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RotateMemberKeyInstructionAccounts<'info>>,
+) -> Result<()> {
+    checks(&ctx)?;
+
+    let group_key = ctx.accounts.group.key();
+    let old_key = ctx.accounts.old_key.key();
+    let new_key = ctx.accounts.new_key.key();
+    let old_key_info = ctx.accounts.old_key.to_account_info();
+    let new_key_info = ctx.accounts.new_key.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let now = Clock::get()?.unix_timestamp;
+
+    for accounts in ctx.remaining_accounts.chunks_exact(2) {
+        let (old_asset_member_info, new_asset_member_info) = (&accounts[0], &accounts[1]);
+
+        let old_asset_member = Account::<AssetMember>::try_from(old_asset_member_info)?;
+        require!(
+            old_asset_member.user == old_key && old_asset_member.group == group_key,
+            MultisigError::InvalidAssetMember
+        );
+        require_keys_eq!(
+            old_asset_member_info.key(),
+            Pubkey::create_program_address(
+                &[
+                    b"asset-member",
+                    group_key.as_ref(),
+                    old_asset_member.asset.as_ref(),
+                    old_key.as_ref(),
+                    &[old_asset_member.account_bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| MultisigError::InvalidAssetMember)?,
+            MultisigError::InvalidAssetMember
+        );
+
+        let (expected_new_asset_member, bump) = Pubkey::find_program_address(
+            &[
+                b"asset-member",
+                group_key.as_ref(),
+                old_asset_member.asset.as_ref(),
+                new_key.as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            new_asset_member_info.key(),
+            expected_new_asset_member,
+            MultisigError::InvalidAssetMember
+        );
+
+        create_pda_account(
+            &new_key_info,
+            new_asset_member_info,
+            &system_program,
+            8 + AssetMember::INIT_SPACE,
+            &[
+                b"asset-member",
+                group_key.as_ref(),
+                old_asset_member.asset.as_ref(),
+                new_key.as_ref(),
+                &[bump],
+            ],
+        )?;
+        old_asset_member
+            .rotated(new_key, bump, now)
+            .try_serialize(&mut &mut new_asset_member_info.try_borrow_mut_data()?[..])?;
+
+        old_asset_member.close(old_key_info.clone())?;
+    }
+
+    let new_group_member =
+        ctx.accounts
+            .old_group_member
+            .rotated(new_key, ctx.bumps.new_group_member, now);
+    ctx.accounts.new_group_member.set_inner(new_group_member);
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::state::{
+    error::MultisigError,
+    group::Group,
+    schedule::{Schedule, ScheduledTransfer},
+};
+
+#[derive(Accounts)]
+pub struct RunScheduleInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        mut,
+        seeds = [b"schedule", group.key().as_ref(), schedule.schedule_seed.as_ref()],
+        bump = schedule.account_bump,
+        has_one = group,
+        has_one = rent_payer,
+    )]
+    pub schedule: Account<'info, Schedule>,
+
+    /// CHECK: The asset authority, signs the transfer and pays lamport transfers.
+    #[account(
+        mut,
+        seeds = [b"authority", group.key().as_ref(), schedule.asset.as_ref()],
+        bump = schedule.authority_bump
+    )]
+    pub asset_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Checked against the destination of the scheduled transfer in checks().
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// The asset token account, only for token transfers.
+    #[account(mut, address = schedule.asset)]
+    pub asset_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+
+    /// CHECK: Checked against the schedule; receives its rent after the last run.
+    #[account(mut)]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<RunScheduleInstructionAccounts>) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ctx.accounts.schedule.is_due(now),
+        MultisigError::ScheduleNotDue
+    );
+
+    require_keys_eq!(
+        ctx.accounts.destination.key(),
+        ctx.accounts.schedule.transfer.destination(),
+        MultisigError::InvalidSchedule
+    );
+
+    Ok(())
+}
+
+/// Makes the next due transfer of a schedule, signed by the asset authority.
+/// Can be called by anyone, the schedule is closed after its last run.
+pub fn run_schedule_handler(ctx: Context<RunScheduleInstructionAccounts>) -> Result<()> {
+    checks(&ctx)?;
+
+    let group_key = ctx.accounts.group.key();
+    let schedule = &ctx.accounts.schedule;
+    let signer_seeds: &[&[u8]] = &[
+        b"authority",
+        group_key.as_ref(),
+        schedule.asset.as_ref(),
+        &[schedule.authority_bump],
+    ];
+
+    match schedule.transfer {
+        ScheduledTransfer::Lamports { amount, .. } => {
+            transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.asset_authority.to_account_info(),
+                        to: ctx.accounts.destination.to_account_info(),
+                    },
+                    &[signer_seeds],
+                ),
+                amount,
+            )?;
+        }
+        ScheduledTransfer::Token { amount, .. } => {
+            let (Some(asset_token_account), Some(mint), Some(token_program)) = (
+                ctx.accounts.asset_token_account.as_ref(),
+                ctx.accounts.mint.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return Err(MultisigError::InvalidSchedule.into());
+            };
+
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: asset_token_account.to_account_info(),
+                        mint: mint.to_account_info(),
+                        to: ctx.accounts.destination.to_account_info(),
+                        authority: ctx.accounts.asset_authority.to_account_info(),
+                    },
+                    &[signer_seeds],
+                ),
+                amount,
+                mint.decimals,
+            )?;
+        }
+    }
+
+    let schedule = &mut ctx.accounts.schedule;
+    if schedule.record_run()? {
+        schedule.close(ctx.accounts.rent_payer.to_account_info())?;
+    }
+
+    Ok(())
+}
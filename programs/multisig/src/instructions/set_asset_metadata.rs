@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    asset::Asset,
+    error::MultisigError,
+    group::Group,
+    metadata::Metadata,
+    proposal::{ConfigChange, ConfigProposal, ProposalState},
+};
+use crate::utils::close_open_proposal;
+
+#[derive(Accounts)]
+pub struct SetAssetMetadataInstructionAccounts<'info> {
+    /// Mutable so a proposal targeting several assets can be given a new index.
+    #[account(
+        mut,
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        seeds = [b"asset", group.key().as_ref(), asset.asset_address.as_ref()],
+        bump = asset.account_bump
+    )]
+    pub asset: Account<'info, Asset>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Metadata::INIT_SPACE,
+        seeds = [b"metadata", asset.key().as_ref()],
+        bump
+    )]
+    pub metadata: Account<'info, Metadata>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump,
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Must match the proposer stored in the proposal; receives closed-account rent.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// CHECK: The proposer's member account, its open proposal count is decremented
+    /// unless it was closed since the proposal was created.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_group_account: UncheckedAccount<'info>,
+
+    /// Pays for the metadata account when it is created, does not need to be a member.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<SetAssetMetadataInstructionAccounts>) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require_keys_eq!(
+        ctx.accounts.proposer.key(),
+        ctx.accounts.proposal.proposer,
+        MultisigError::InvalidProposer
+    );
+
+    require!(
+        ctx.accounts
+            .proposal
+            .get_asset_state(&ctx.accounts.asset.asset_address)?
+            == ProposalState::Passed,
+        MultisigError::ProposalNotPassed
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require_gte!(
+        now,
+        ctx.accounts
+            .proposal
+            .get_asset_valid_from_timestamp(&ctx.accounts.asset.asset_address)?,
+        MultisigError::ProposalStillTimelocked
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_deadline_timestamp,
+        now,
+        MultisigError::ProposalExpired
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_index,
+        ctx.accounts.group.proposal_index_after_stale,
+        MultisigError::ProposalStale
+    );
+
+    Ok(())
+}
+
+/// Executes a passed SetAssetMetadata config proposal, creating or replacing the asset's
+/// metadata. Metadata does not affect voting, so open proposals are not staled.
+/// A proposal targeting several assets is closed once the last of them is applied.
+pub fn set_asset_metadata_handler(ctx: Context<SetAssetMetadataInstructionAccounts>) -> Result<()> {
+    checks(&ctx)?;
+
+    let group_key = ctx.accounts.group.key();
+    let asset_key = ctx.accounts.asset.key();
+    let payer = ctx.accounts.payer.key();
+    let bump = ctx.bumps.metadata;
+    let asset_address = ctx.accounts.asset.asset_address;
+    let proposal = &ctx.accounts.proposal;
+
+    match &proposal.config_change {
+        ConfigChange::SetAssetMetadata {
+            asset_address: change_asset,
+            content,
+        } => {
+            require!(
+                proposal.applies_to_asset(change_asset, &asset_address),
+                MultisigError::InvalidAsset
+            );
+            ctx.accounts
+                .metadata
+                .set(asset_key, group_key, content.clone(), payer, bump)?;
+        }
+        _ => return Err(MultisigError::InvalidConfigChange.into()),
+    }
+
+    let proposal = &mut ctx.accounts.proposal;
+    if proposal.mark_asset_executed(&asset_address, &mut ctx.accounts.group)? {
+        close_open_proposal(
+            &mut ctx.accounts.group,
+            &ctx.accounts.proposer_group_account,
+        )?;
+        proposal.close(ctx.accounts.proposer.to_account_info())?;
+    }
+
+    Ok(())
+}
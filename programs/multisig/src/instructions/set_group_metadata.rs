@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    error::MultisigError,
+    group::Group,
+    metadata::Metadata,
+    proposal::{ConfigChange, ConfigProposal, ProposalState},
+};
+use crate::utils::close_open_proposal;
+
+#[derive(Accounts)]
+pub struct SetGroupMetadataInstructionAccounts<'info> {
+    #[account(
+        mut,
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + Metadata::INIT_SPACE,
+        seeds = [b"metadata", group.key().as_ref()],
+        bump
+    )]
+    pub metadata: Account<'info, Metadata>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump,
+        close = proposer,
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// CHECK: Must match the proposer stored in the proposal; receives closed-account rent.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// CHECK: The proposer's member account, its open proposal count is decremented
+    /// unless it was closed since the proposal was created.
+    #[account(
+        mut,
+        seeds = [b"member", group.key().as_ref(), proposer.key().as_ref()],
+        bump
+    )]
+    pub proposer_group_account: UncheckedAccount<'info>,
+
+    /// Pays for the metadata account when it is created, does not need to be a member.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<SetGroupMetadataInstructionAccounts>) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require_keys_eq!(
+        ctx.accounts.proposer.key(),
+        ctx.accounts.proposal.proposer,
+        MultisigError::InvalidProposer
+    );
+
+    require!(
+        ctx.accounts.proposal.state == ProposalState::Passed,
+        MultisigError::ProposalNotPassed
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require_gte!(
+        now,
+        ctx.accounts.proposal.get_valid_from_timestamp()?,
+        MultisigError::ProposalStillTimelocked
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_deadline_timestamp,
+        now,
+        MultisigError::ProposalExpired
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_index,
+        ctx.accounts.group.proposal_index_after_stale,
+        MultisigError::ProposalStale
+    );
+
+    Ok(())
+}
+
+/// Executes a passed SetGroupMetadata config proposal, creating or replacing the group's
+/// metadata. Metadata does not affect voting, so open proposals are not staled.
+pub fn set_group_metadata_handler(ctx: Context<SetGroupMetadataInstructionAccounts>) -> Result<()> {
+    checks(&ctx)?;
+
+    let group_key = ctx.accounts.group.key();
+    let payer = ctx.accounts.payer.key();
+    let bump = ctx.bumps.metadata;
+
+    match &ctx.accounts.proposal.config_change {
+        ConfigChange::SetGroupMetadata { content } => {
+            ctx.accounts
+                .metadata
+                .set(group_key, group_key, content.clone(), payer, bump)?;
+        }
+        _ => return Err(MultisigError::InvalidConfigChange.into()),
+    }
+
+    close_open_proposal(
+        &mut ctx.accounts.group,
+        &ctx.accounts.proposer_group_account,
+    )
+}
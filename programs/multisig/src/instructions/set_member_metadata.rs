@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    error::MultisigError,
+    group::Group,
+    member::GroupMember,
+    metadata::{Metadata, MetadataContent},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetMemberMetadataInstructionArgs {
+    pub content: MetadataContent,
+}
+
+#[derive(Accounts)]
+pub struct SetMemberMetadataInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Seeds bind membership to group + member.
+    #[account(
+        seeds = [b"member", group.key().as_ref(), member.key().as_ref()],
+        bump = group_member.account_bump
+    )]
+    pub group_member: Account<'info, GroupMember>,
+
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = 8 + Metadata::INIT_SPACE,
+        seeds = [b"metadata", group_member.key().as_ref()],
+        bump
+    )]
+    pub metadata: Account<'info, Metadata>,
+
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates or replaces the signing member's own metadata, paid for by the member.
+pub fn set_member_metadata_handler(
+    ctx: Context<SetMemberMetadataInstructionAccounts>,
+    args: SetMemberMetadataInstructionArgs,
+) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    let group_member_key = ctx.accounts.group_member.key();
+    let group_key = ctx.accounts.group.key();
+    let member = ctx.accounts.member.key();
+    let bump = ctx.bumps.metadata;
+
+    ctx.accounts
+        .metadata
+        .set(group_member_key, group_key, args.content, member, bump)
+}
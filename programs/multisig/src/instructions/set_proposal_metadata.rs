@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    error::MultisigError,
+    group::Group,
+    metadata::{Metadata, MetadataContent},
+    proposal::{ConfigProposal, NormalProposal},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetProposalMetadataInstructionArgs {
+    pub content: MetadataContent,
+}
+
+#[derive(Accounts)]
+pub struct SetProposalMetadataInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// CHECK: A normal or config proposal of the group, checked in the handler.
+    #[account(owner = crate::ID)]
+    pub proposal: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = proposer,
+        space = 8 + Metadata::INIT_SPACE,
+        seeds = [b"metadata", proposal.key().as_ref()],
+        bump
+    )]
+    pub metadata: Account<'info, Metadata>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The proposer, seed and bump of a normal or config proposal, and whether it has votes.
+#[inline(always)]
+fn proposal_keys(proposal_info: &AccountInfo) -> Result<(Pubkey, Pubkey, u8, bool)> {
+    let data = proposal_info.try_borrow_data()?;
+
+    if let Ok(proposal) = NormalProposal::try_deserialize(&mut &data[..]) {
+        return Ok((
+            proposal.proposer,
+            proposal.proposal_seed,
+            proposal.account_bump,
+            proposal.has_votes(),
+        ));
+    }
+
+    let proposal = ConfigProposal::try_deserialize(&mut &data[..])?;
+    Ok((
+        proposal.proposer,
+        proposal.proposal_seed,
+        proposal.account_bump,
+        proposal.has_votes(),
+    ))
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<SetProposalMetadataInstructionAccounts>) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    let (proposer, proposal_seed, bump, has_votes) = proposal_keys(&ctx.accounts.proposal)?;
+
+    require_keys_eq!(
+        ctx.accounts.proposal.key(),
+        Pubkey::create_program_address(
+            &[
+                b"proposal",
+                ctx.accounts.group.key().as_ref(),
+                proposal_seed.as_ref(),
+                &[bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| MultisigError::UnexpectedProposal)?,
+        MultisigError::UnexpectedProposal
+    );
+
+    require_keys_eq!(
+        ctx.accounts.proposer.key(),
+        proposer,
+        MultisigError::InvalidProposer
+    );
+
+    require!(!has_votes, MultisigError::ProposalMetadataLocked);
+
+    Ok(())
+}
+
+/// Creates or replaces the metadata of a normal or config proposal, only its proposer can
+/// set it, until the first vote is cast.
+pub fn set_proposal_metadata_handler(
+    ctx: Context<SetProposalMetadataInstructionAccounts>,
+    args: SetProposalMetadataInstructionArgs,
+) -> Result<()> {
+    checks(&ctx)?;
+
+    let proposal_key = ctx.accounts.proposal.key();
+    let group_key = ctx.accounts.group.key();
+    let proposer = ctx.accounts.proposer.key();
+    let bump = ctx.bumps.metadata;
+
+    ctx.accounts
+        .metadata
+        .set(proposal_key, group_key, args.content, proposer, bump)
+}
@@ -0,0 +1,268 @@
+use anchor_lang::prelude::*;
+
+#[allow(deprecated)]
+use anchor_lang::solana_program::sysvar::instructions::ID as INSTRUCTIONS_SYSVAR_ID;
+
+use crate::{
+    state::{
+        asset::Asset,
+        error::MultisigError,
+        group::Group,
+        member::{AssetMember, GroupMember},
+        proposal::{ConfigProposal, ProposalState, ProposalTarget},
+        vote::{SignedVoteMessage, VoteChoice, VoteRecord},
+    },
+    utils::{create_pda_account, load_ed25519_signatures},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SubmitSignedVotesOnConfigProposalInstructionArgs {
+    /// One vote per signature of the preceding Ed25519 instruction, in the same order.
+    pub votes: Vec<VoteChoice>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitSignedVotesOnConfigProposalInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// Required when the proposal targets an asset.
+    #[account(
+        seeds = [b"asset", group.key().as_ref(), asset.asset_address.as_ref()],
+        bump = asset.account_bump
+    )]
+    pub asset: Option<Account<'info, Asset>>,
+
+    /// Address check binds it to the instructions sysvar.
+    #[account(address = INSTRUCTIONS_SYSVAR_ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Pays for the vote records, does not need to be a member.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[inline(always)]
+fn checks(
+    ctx: &Context<SubmitSignedVotesOnConfigProposalInstructionAccounts>,
+    args: &SubmitSignedVotesOnConfigProposalInstructionArgs,
+) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require!(
+        ctx.accounts.proposal.state == ProposalState::Open,
+        MultisigError::ProposalNotOpen
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_index,
+        ctx.accounts.group.proposal_index_after_stale,
+        MultisigError::ProposalStale
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require_gt!(
+        ctx.accounts.proposal.proposal_deadline_timestamp,
+        now,
+        MultisigError::ProposalExpired
+    );
+
+    require!(
+        ctx.accounts.proposal.compact_votes.is_none(),
+        MultisigError::CompactVotesRequired
+    );
+
+    if let ProposalTarget::Asset(target_asset) = ctx.accounts.proposal.target {
+        let asset = ctx
+            .accounts
+            .asset
+            .as_ref()
+            .ok_or(MultisigError::AssetNotProvided)?;
+        require_keys_eq!(
+            target_asset,
+            asset.asset_address,
+            MultisigError::UnexpectedAsset
+        );
+    }
+
+    require!(!args.votes.is_empty(), MultisigError::InvalidVoteOption);
+
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        args.votes.len() * 2,
+        MultisigError::LengthMismatch
+    );
+
+    Ok(())
+}
+
+/// Apply votes members signed off-chain, verified by the Ed25519 instruction placed right
+/// before this one. Each member signs a [`SignedVoteMessage`] whose nonce is the proposal's
+/// `proposal_index`. Remaining accounts are `[member, vote record]` for each vote, in order,
+/// the member is the voter's `AssetMember` of the target asset or their `GroupMember` for
+/// group proposals.
+/// A signed vote is only counted as the member's first vote, the existing vote record
+/// rejects replays and can only be changed by the member voting themselves.
+pub fn submit_signed_votes_on_config_proposal_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, SubmitSignedVotesOnConfigProposalInstructionAccounts<'info>>,
+    args: SubmitSignedVotesOnConfigProposalInstructionArgs,
+) -> Result<()> {
+    checks(&ctx, &args)?;
+
+    let signatures = load_ed25519_signatures(&ctx.accounts.instructions_sysvar)?;
+    require_eq!(
+        signatures.len(),
+        args.votes.len(),
+        MultisigError::InvalidSignedVote
+    );
+
+    let group = &ctx.accounts.group;
+    let group_key = group.key();
+    let proposal = &mut ctx.accounts.proposal;
+    let proposal_key = proposal.key();
+    let target = proposal.target.clone();
+    let propose_timestamp = proposal.propose_timestamp;
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let now = Clock::get()?.unix_timestamp;
+
+    let (maybe_group, maybe_asset) = match target {
+        ProposalTarget::Assets => return Err(MultisigError::PerAssetVoteRequired.into()),
+        ProposalTarget::Group => (Some(group), None),
+        ProposalTarget::Asset(_) => (None, ctx.accounts.asset.as_ref()),
+    };
+
+    for ((vote, (voter, message)), accounts) in args
+        .votes
+        .into_iter()
+        .zip(signatures)
+        .zip(ctx.remaining_accounts.chunks_exact(2))
+    {
+        // A vote earlier in the batch may have passed or failed the proposal.
+        if proposal.state != ProposalState::Open {
+            break;
+        }
+
+        let expected_message = SignedVoteMessage {
+            group: group_key,
+            proposal: proposal_key,
+            asset_index: None, // config proposals don't use asset_index
+            vote_choice: vote,
+            nonce: proposal.proposal_index,
+        }
+        .to_bytes()?;
+        require!(
+            message == expected_message,
+            MultisigError::InvalidSignedVote
+        );
+
+        let (member_info, vote_record_info) = (&accounts[0], &accounts[1]);
+
+        require_keys_eq!(*member_info.owner, crate::ID, MultisigError::InvalidMember);
+        let member_weight = match target {
+            ProposalTarget::Assets => return Err(MultisigError::PerAssetVoteRequired.into()),
+            ProposalTarget::Group => {
+                let member =
+                    GroupMember::try_deserialize(&mut &member_info.try_borrow_data()?[..])?;
+                require!(
+                    member.user == voter && member.group == group_key,
+                    MultisigError::InvalidMember
+                );
+                require!(member.is_active(now), MultisigError::MembershipExpired);
+                require!(
+                    member.can_vote_on_proposal_from(propose_timestamp),
+                    MultisigError::KeyRotatedAfterProposal
+                );
+                member.weight
+            }
+            ProposalTarget::Asset(target_asset) => {
+                let member =
+                    AssetMember::try_deserialize(&mut &member_info.try_borrow_data()?[..])?;
+                require!(
+                    member.user == voter
+                        && member.group == group_key
+                        && member.asset == target_asset,
+                    MultisigError::InvalidAssetMember
+                );
+                require!(member.is_active(now), MultisigError::MembershipExpired);
+                require!(
+                    member.can_vote_on_proposal_from(propose_timestamp),
+                    MultisigError::KeyRotatedAfterProposal
+                );
+                member.weight
+            }
+        };
+        let weight = member_weight.min(group.max_member_weight);
+        require_gt!(weight, 0, MultisigError::UnauthorizedVoter);
+
+        let (expected_vote_record, bump) = Pubkey::find_program_address(
+            &[
+                b"vote-record",
+                group_key.as_ref(),
+                proposal_key.as_ref(),
+                voter.as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            vote_record_info.key(),
+            expected_vote_record,
+            MultisigError::InvalidSignedVote
+        );
+        require_keys_neq!(
+            *vote_record_info.owner,
+            crate::ID,
+            MultisigError::VoteAlreadyRecorded
+        );
+
+        create_pda_account(
+            &payer,
+            vote_record_info,
+            &system_program,
+            8 + VoteRecord::INIT_SPACE,
+            &[
+                b"vote-record",
+                group_key.as_ref(),
+                proposal_key.as_ref(),
+                voter.as_ref(),
+                &[bump],
+            ],
+        )?;
+        VoteRecord::new(
+            voter,
+            proposal_key,
+            None, // config proposals don't use asset_index
+            bump,
+            vote,
+        )
+        .try_serialize(&mut &mut vote_record_info.try_borrow_mut_data()?[..])?;
+
+        proposal.increment_vote_count()?;
+
+        match vote {
+            VoteChoice::For => {
+                proposal.add_weight_for(weight);
+                proposal.check_and_mark_passed(maybe_group, maybe_asset)?;
+            }
+            VoteChoice::Against => {
+                proposal.add_weight_against(weight);
+                proposal.check_and_mark_failed(maybe_group, maybe_asset)?;
+            }
+        }
+    }
+
+    Ok(())
+}
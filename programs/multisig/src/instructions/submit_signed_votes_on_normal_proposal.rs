@@ -0,0 +1,286 @@
+use anchor_lang::prelude::*;
+
+#[allow(deprecated)]
+use anchor_lang::solana_program::sysvar::instructions::ID as INSTRUCTIONS_SYSVAR_ID;
+
+use crate::{
+    instructions::AssetVote,
+    state::{
+        asset::Asset,
+        error::MultisigError,
+        group::Group,
+        member::AssetMember,
+        proposal::{NormalProposal, ProposalAssetThresholdState, ProposalState},
+        vote::{SignedVoteMessage, VoteChoice, VoteRecord},
+    },
+    utils::{create_pda_account, load_ed25519_signatures},
+    GroupMember,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SubmitSignedVotesOnNormalProposalInstructionArgs {
+    /// One vote per signature of the preceding Ed25519 instruction, in the same order.
+    pub votes: Vec<AssetVote>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitSignedVotesOnNormalProposalInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Seeds checks bind it to the group.
+    #[account(
+        mut,
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump,
+    )]
+    pub proposal: Account<'info, NormalProposal>,
+
+    /// Seeds and ownership checks bind it to the proposal.
+    #[account(
+        seeds = [b"proposal-transaction", proposal.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub proposal_transaction: UncheckedAccount<'info>,
+
+    /// Address check binds it to the instructions sysvar.
+    #[account(address = INSTRUCTIONS_SYSVAR_ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Pays for the vote records, does not need to be a member.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[inline(always)]
+fn checks(
+    ctx: &Context<SubmitSignedVotesOnNormalProposalInstructionAccounts>,
+    args: &SubmitSignedVotesOnNormalProposalInstructionArgs,
+) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require!(
+        ctx.accounts.proposal.state == ProposalState::Open,
+        MultisigError::ProposalNotOpen
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_index,
+        ctx.accounts.group.proposal_index_after_stale,
+        MultisigError::ProposalStale
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require_gt!(
+        ctx.accounts.proposal.proposal_deadline_timestamp,
+        now,
+        MultisigError::ProposalExpired
+    );
+
+    require!(
+        ctx.accounts.proposal.compact_votes.is_none(),
+        MultisigError::CompactVotesRequired
+    );
+
+    require!(!args.votes.is_empty(), MultisigError::InvalidVoteOption);
+
+    require!(
+        args.votes.iter().all(|asset_vote| {
+            usize::from(asset_vote.voting_asset_index) < ctx.accounts.proposal.assets.len()
+        }),
+        MultisigError::InvalidAssetIndex
+    );
+
+    require_eq!(
+        ctx.remaining_accounts.len(),
+        args.votes.len() * 4,
+        MultisigError::LengthMismatch
+    );
+
+    Ok(())
+}
+
+/// Apply votes members signed off-chain, verified by the Ed25519 instruction placed right
+/// before this one. Each member signs a [`SignedVoteMessage`] whose nonce is the proposal's
+/// `proposal_index`. Remaining accounts are `[group member, asset, asset member, vote record]`
+/// for each vote, in order.
+/// A signed vote is only counted as the member's first vote on the asset, the existing vote
+/// record rejects replays and can only be changed by the member voting themselves.
+pub fn submit_signed_votes_on_normal_proposal_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SubmitSignedVotesOnNormalProposalInstructionAccounts<'info>>,
+    args: SubmitSignedVotesOnNormalProposalInstructionArgs,
+) -> Result<()> {
+    checks(&ctx, &args)?;
+
+    let signatures = load_ed25519_signatures(&ctx.accounts.instructions_sysvar)?;
+    require_eq!(
+        signatures.len(),
+        args.votes.len(),
+        MultisigError::InvalidSignedVote
+    );
+
+    let group = &ctx.accounts.group;
+    let group_key = group.key();
+    let proposal = &mut ctx.accounts.proposal;
+    let proposal_key = proposal.key();
+    let payer = ctx.accounts.payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let now = Clock::get()?.unix_timestamp;
+
+    for (
+        (
+            AssetVote {
+                voting_asset_index,
+                vote,
+            },
+            (voter, message),
+        ),
+        accounts,
+    ) in args
+        .votes
+        .into_iter()
+        .zip(signatures)
+        .zip(ctx.remaining_accounts.chunks_exact(4))
+    {
+        // A vote earlier in the batch may have passed or failed the proposal.
+        if proposal.state != ProposalState::Open {
+            break;
+        }
+
+        let expected_message = SignedVoteMessage {
+            group: group_key,
+            proposal: proposal_key,
+            asset_index: Some(voting_asset_index),
+            vote_choice: vote,
+            nonce: proposal.proposal_index,
+        }
+        .to_bytes()?;
+        require!(
+            message == expected_message,
+            MultisigError::InvalidSignedVote
+        );
+
+        let (group_member_info, asset_info, asset_member_info, vote_record_info) =
+            (&accounts[0], &accounts[1], &accounts[2], &accounts[3]);
+        let asset_index = usize::from(voting_asset_index);
+
+        let group_member = Account::<GroupMember>::try_from(group_member_info)?;
+        require!(
+            group_member.user == voter && group_member.group == group_key,
+            MultisigError::InvalidMember
+        );
+        require!(
+            group_member.is_active(now),
+            MultisigError::MembershipExpired
+        );
+        require!(
+            group_member.can_vote_on_proposal_from(proposal.propose_timestamp),
+            MultisigError::KeyRotatedAfterProposal
+        );
+
+        let asset = Account::<Asset>::try_from(asset_info)?;
+        require_keys_eq!(
+            asset.key(),
+            Pubkey::create_program_address(
+                &[
+                    b"asset",
+                    group_key.as_ref(),
+                    asset.asset_address.as_ref(),
+                    &[asset.account_bump],
+                ],
+                &crate::ID,
+            )
+            .map_err(|_| MultisigError::InvalidAsset)?,
+            MultisigError::InvalidAsset
+        );
+        require_keys_eq!(
+            proposal.assets[asset_index].asset,
+            asset.asset_address,
+            MultisigError::InvalidAsset
+        );
+        require!(
+            proposal.assets[asset_index].threshold_state
+                == ProposalAssetThresholdState::NoThresholdReached,
+            MultisigError::StateAlreadyFinalized
+        );
+
+        let asset_member = Account::<AssetMember>::try_from(asset_member_info)?;
+        require!(
+            asset_member.user == voter
+                && asset_member.group == group_key
+                && asset_member.asset == asset.asset_address,
+            MultisigError::InvalidAssetMember
+        );
+        require!(
+            asset_member.is_active(now),
+            MultisigError::MembershipExpired
+        );
+
+        let weight = asset_member.weight.min(group.max_member_weight);
+        require_gt!(weight, 0, MultisigError::UnauthorizedVoter);
+
+        let index_seed = [voting_asset_index];
+        let (expected_vote_record, bump) = Pubkey::find_program_address(
+            &[
+                b"vote-record",
+                group_key.as_ref(),
+                proposal_key.as_ref(),
+                voter.as_ref(),
+                &index_seed,
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            vote_record_info.key(),
+            expected_vote_record,
+            MultisigError::InvalidAssetIndex
+        );
+        require_keys_neq!(
+            *vote_record_info.owner,
+            crate::ID,
+            MultisigError::VoteAlreadyRecorded
+        );
+
+        create_pda_account(
+            &payer,
+            vote_record_info,
+            &system_program,
+            8 + VoteRecord::INIT_SPACE,
+            &[
+                b"vote-record",
+                group_key.as_ref(),
+                proposal_key.as_ref(),
+                voter.as_ref(),
+                &index_seed,
+                &[bump],
+            ],
+        )?;
+        VoteRecord::new(voter, proposal_key, Some(voting_asset_index), bump, vote)
+            .try_serialize(&mut &mut vote_record_info.try_borrow_mut_data()?[..])?;
+
+        let proposal_asset = proposal
+            .get_asset_mut(asset_index)
+            .ok_or(MultisigError::InvalidAssetIndex)?;
+        proposal_asset.increment_vote_count()?;
+
+        match vote {
+            VoteChoice::For => {
+                proposal_asset.add_use_vote_weight(weight);
+                proposal.check_and_mark_asset_passed(asset_index, &asset)?;
+            }
+            VoteChoice::Against => {
+                proposal_asset.add_not_use_vote_weight(weight);
+                proposal.check_and_mark_asset_failed(asset_index, &asset)?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    error::MultisigError,
+    group::Group,
+    proposal::{ConfigProposal, ProposalDescription, ProposalState},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateConfigProposalDescriptionInstructionArgs {
+    pub description: Option<ProposalDescription>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfigProposalDescriptionInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    #[account(
+        mut,
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    pub proposer: Signer<'info>,
+}
+
+#[inline(always)]
+fn checks(
+    ctx: &Context<UpdateConfigProposalDescriptionInstructionAccounts>,
+    args: &UpdateConfigProposalDescriptionInstructionArgs,
+) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require_keys_eq!(
+        ctx.accounts.proposer.key(),
+        ctx.accounts.proposal.proposer,
+        MultisigError::InvalidProposer
+    );
+
+    require!(
+        ctx.accounts.proposal.state == ProposalState::Open,
+        MultisigError::ProposalNotOpen
+    );
+
+    require!(
+        !ctx.accounts.proposal.has_votes(),
+        MultisigError::ProposalDescriptionLocked
+    );
+
+    if let Some(description) = args.description.as_ref() {
+        description.validate()?;
+    }
+
+    Ok(())
+}
+
+/// Replaces the description of a config proposal, signed by its proposer.
+/// The description is fixed once the first vote is cast.
+pub fn update_config_proposal_description_handler(
+    ctx: Context<UpdateConfigProposalDescriptionInstructionAccounts>,
+    args: UpdateConfigProposalDescriptionInstructionArgs,
+) -> Result<()> {
+    checks(&ctx, &args)?;
+
+    ctx.accounts.proposal.description = args.description;
+
+    Ok(())
+}
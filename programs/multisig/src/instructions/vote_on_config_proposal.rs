@@ -9,6 +9,7 @@ use crate::{
         proposal::{ConfigProposal, ProposalState, ProposalTarget},
         vote::{VoteChoice, VoteRecord},
     },
+    utils::{cast_delegated_votes, DelegatedVote},
     GroupMember,
 };
 
@@ -53,16 +54,20 @@ pub struct VoteOnConfigProposalInstructionAccounts<'info> {
 
     #[account(
         init_if_needed,
-        payer = voter,
+        payer = payer,
         space = 8 + VoteRecord::INIT_SPACE,
         seeds = [b"vote-record", group.key().as_ref(), proposal.key().as_ref(), voter.key().as_ref()],
         bump
     )]
     pub vote_record: Account<'info, VoteRecord>,
 
-    #[account(mut)]
     pub voter: Signer<'info>,
 
+    /// Pays for the vote record, so a member key that cannot hold lamports, such as another
+    /// group's PDA, can still vote.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -89,60 +94,49 @@ fn checks(ctx: &Context<VoteOnConfigProposalInstructionAccounts>) -> Result<()>
         MultisigError::ProposalExpired
     );
 
+    require!(
+        ctx.accounts.group_member.is_active(now)
+            && ctx
+                .accounts
+                .asset_member
+                .as_ref()
+                .is_none_or(|asset_member| asset_member.is_active(now)),
+        MultisigError::MembershipExpired
+    );
+
+    require!(
+        ctx.accounts
+            .group_member
+            .can_vote_on_proposal_from(ctx.accounts.proposal.propose_timestamp),
+        MultisigError::KeyRotatedAfterProposal
+    );
+
+    require!(
+        ctx.accounts.proposal.compact_votes.is_none(),
+        MultisigError::CompactVotesRequired
+    );
+
     Ok(())
 }
 
 /// Vote on a proposal that changes the configuration of a group or asset if passed.
-pub fn vote_on_config_proposal_handler(
-    ctx: Context<VoteOnConfigProposalInstructionAccounts>,
+/// Delegates can also vote for the members who delegated their weight on the proposal's
+/// target, see [`cast_delegated_votes`].
+pub fn vote_on_config_proposal_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, VoteOnConfigProposalInstructionAccounts<'info>>,
     args: VoteOnConfigProposalInstructionArgs,
 ) -> Result<()> {
     checks(&ctx)?;
 
     let VoteOnConfigProposalInstructionArgs { vote } = args;
 
-    let proposal = &mut ctx.accounts.proposal;
     let group = &ctx.accounts.group;
     let voter = &ctx.accounts.voter;
-    let group_member = &ctx.accounts.group_member;
-    let vote_record = &mut ctx.accounts.vote_record;
+    let target = ctx.accounts.proposal.target.clone();
 
-    // `is_initialized` checks whether `voter != Pubkey::default()`, which is always true once set
-    // since the voter is a signer and can never be the default key.
-    let is_first_vote = !vote_record.is_initialized();
-    if !is_first_vote && vote_record.vote_choice == vote {
-        return Ok(());
-    }
-
-    if is_first_vote {
-        proposal.increment_vote_count()?;
-    }
-
-    let target = proposal.target.clone();
-
-    match target {
-        ProposalTarget::Group => {
-            let weight = group_member.weight.min(group.max_member_weight);
-            require_gt!(weight, 0, MultisigError::UnauthorizedVoter);
-
-            if !is_first_vote {
-                match vote_record.vote_choice {
-                    VoteChoice::For => proposal.sub_weight_for(weight),
-                    VoteChoice::Against => proposal.sub_weight_against(weight),
-                }
-            }
-
-            match vote {
-                VoteChoice::For => {
-                    proposal.add_weight_for(weight);
-                    proposal.check_and_mark_passed(Some(group), None)?;
-                }
-                VoteChoice::Against => {
-                    proposal.add_weight_against(weight);
-                    proposal.check_and_mark_failed(Some(group), None)?;
-                }
-            }
-        }
+    let weight = match target {
+        ProposalTarget::Assets => return Err(MultisigError::PerAssetVoteRequired.into()),
+        ProposalTarget::Group => ctx.accounts.group_member.weight,
         ProposalTarget::Asset(target_asset) => {
             let asset = ctx
                 .accounts
@@ -172,30 +166,79 @@ pub fn vote_on_config_proposal_handler(
                 MultisigError::InvalidAssetMember
             );
 
-            let weight = asset_member.weight.min(group.max_member_weight);
-            require_gt!(weight, 0, MultisigError::UnauthorizedVoter);
-
-            if !is_first_vote {
-                match vote_record.vote_choice {
-                    VoteChoice::For => proposal.sub_weight_for(weight),
-                    VoteChoice::Against => proposal.sub_weight_against(weight),
-                }
-            }
-
-            match vote {
-                VoteChoice::For => {
-                    proposal.add_weight_for(weight);
-                    proposal.check_and_mark_passed(None, Some(asset))?;
-                }
-                VoteChoice::Against => {
-                    proposal.add_weight_against(weight);
-                    proposal.check_and_mark_failed(None, Some(asset))?;
-                }
-            }
+            asset_member.weight
         }
     }
+    .min(group.max_member_weight);
+    require_gt!(weight, 0, MultisigError::UnauthorizedVoter);
+
+    let delegated_votes = cast_delegated_votes(
+        ctx.remaining_accounts,
+        group,
+        ctx.accounts.proposal.key(),
+        ctx.accounts.proposal.propose_timestamp,
+        match target {
+            ProposalTarget::Assets => return Err(MultisigError::PerAssetVoteRequired.into()),
+            ProposalTarget::Group => None,
+            ProposalTarget::Asset(target_asset) => Some(target_asset),
+        },
+        None, // config proposals don't use asset_index
+        &voter.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        vote,
+        None,
+    )?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    let vote_record = &mut ctx.accounts.vote_record;
+
+    // `is_initialized` checks whether `voter != Pubkey::default()`, which is always true once set
+    // since the voter is a signer and can never be the default key.
+    let previous_vote = vote_record
+        .is_initialized()
+        .then_some(vote_record.vote_choice);
+
+    let votes = std::iter::once(DelegatedVote {
+        weight,
+        previous_vote,
+        previous_option_index: None,
+    })
+    .chain(delegated_votes);
+
+    for DelegatedVote {
+        weight,
+        previous_vote,
+        ..
+    } in votes
+    {
+        match previous_vote {
+            None => proposal.increment_vote_count()?,
+            Some(previous_vote) if previous_vote != vote => match previous_vote {
+                VoteChoice::For => proposal.sub_weight_for(weight),
+                VoteChoice::Against => proposal.sub_weight_against(weight),
+            },
+            Some(_) => continue,
+        }
+
+        match vote {
+            VoteChoice::For => proposal.add_weight_for(weight),
+            VoteChoice::Against => proposal.add_weight_against(weight),
+        }
+    }
+
+    let (maybe_group, maybe_asset) = match target {
+        ProposalTarget::Assets => return Err(MultisigError::PerAssetVoteRequired.into()),
+        ProposalTarget::Group => (Some(group), None),
+        ProposalTarget::Asset(_) => (None, ctx.accounts.asset.as_ref()),
+    };
+
+    match vote {
+        VoteChoice::For => proposal.check_and_mark_passed(maybe_group, maybe_asset)?,
+        VoteChoice::Against => proposal.check_and_mark_failed(maybe_group, maybe_asset)?,
+    };
 
-    if is_first_vote {
+    if previous_vote.is_none() {
         vote_record.set_inner(VoteRecord::new(
             voter.key(),
             proposal.key(),
@@ -204,7 +247,9 @@ pub fn vote_on_config_proposal_handler(
             vote,
         ));
     } else {
+        // The voter takes back a vote their delegate may have cast.
         vote_record.vote_choice = vote;
+        vote_record.delegate = None;
     }
 
     Ok(())
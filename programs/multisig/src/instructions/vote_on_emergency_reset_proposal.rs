@@ -68,6 +68,17 @@ fn checks(ctx: &Context<VoteOnEmergencyResetAccounts>) -> Result<()> {
         now,
         MultisigError::ProposalExpired
     );
+    require!(
+        ctx.accounts.group_member.is_active(now),
+        MultisigError::MembershipExpired
+    );
+
+    require!(
+        ctx.accounts
+            .group_member
+            .can_vote_on_proposal_from(ctx.accounts.proposal.propose_timestamp),
+        MultisigError::KeyRotatedAfterProposal
+    );
 
     // No stale-index check, emergency resets survive config changes.
 
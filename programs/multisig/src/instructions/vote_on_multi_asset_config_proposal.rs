@@ -0,0 +1,245 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        asset::Asset,
+        error::MultisigError,
+        group::Group,
+        member::AssetMember,
+        proposal::{ConfigProposal, ProposalState, ProposalTarget},
+        vote::{VoteChoice, VoteRecord},
+    },
+    utils::{cast_delegated_votes, DelegatedVote},
+    GroupMember,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VoteOnMultiAssetConfigProposalInstructionArgs {
+    pub voting_asset_index: u8,
+    pub vote: VoteChoice,
+}
+
+#[derive(Accounts)]
+#[instruction(args: VoteOnMultiAssetConfigProposalInstructionArgs)]
+pub struct VoteOnMultiAssetConfigProposalInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Seeds checks bind it to the group.
+    #[account(
+        mut,
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump,
+    )]
+    pub proposal: Account<'info, ConfigProposal>,
+
+    /// Seeds check binds it to the asset and group.
+    #[account(
+        seeds = [b"asset", group.key().as_ref(), asset.asset_address.as_ref()],
+        bump = asset.account_bump
+    )]
+    pub asset: Account<'info, Asset>,
+
+    /// Seeds check binds it to the group and voter.
+    #[account(
+        seeds = [b"member", group.key().as_ref(), voter.key().as_ref()],
+        bump = group_member.account_bump
+    )]
+    pub group_member: Account<'info, GroupMember>,
+
+    /// Seeds check binds it to the voter, group and asset.
+    #[account(
+        seeds = [b"asset-member", group.key().as_ref(), asset.asset_address.as_ref(), voter.key().as_ref()],
+        bump = asset_member.account_bump
+    )]
+    pub asset_member: Account<'info, AssetMember>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoteRecord::INIT_SPACE,
+        seeds = [b"vote-record", group.key().as_ref(), proposal.key().as_ref(), voter.key().as_ref(), &[args.voting_asset_index]],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    pub voter: Signer<'info>,
+
+    /// Pays for the vote record, so a member key that cannot hold lamports, such as another
+    /// group's PDA, can still vote.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[inline(always)]
+fn checks(
+    ctx: &Context<VoteOnMultiAssetConfigProposalInstructionAccounts>,
+    args: &VoteOnMultiAssetConfigProposalInstructionArgs,
+) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require!(
+        ctx.accounts.proposal.target == ProposalTarget::Assets,
+        MultisigError::InvalidConfigChange
+    );
+
+    require!(
+        ctx.accounts.proposal.state == ProposalState::Open,
+        MultisigError::ProposalNotOpen
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_index,
+        ctx.accounts.group.proposal_index_after_stale,
+        MultisigError::ProposalStale
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require_gt!(
+        ctx.accounts.proposal.proposal_deadline_timestamp,
+        now,
+        MultisigError::ProposalExpired
+    );
+
+    require!(
+        ctx.accounts.group_member.is_active(now) && ctx.accounts.asset_member.is_active(now),
+        MultisigError::MembershipExpired
+    );
+
+    require!(
+        ctx.accounts
+            .group_member
+            .can_vote_on_proposal_from(ctx.accounts.proposal.propose_timestamp),
+        MultisigError::KeyRotatedAfterProposal
+    );
+
+    let proposal_asset = ctx
+        .accounts
+        .proposal
+        .assets
+        .get(usize::from(args.voting_asset_index))
+        .ok_or(MultisigError::InvalidAssetIndex)?;
+
+    require_keys_eq!(
+        proposal_asset.asset,
+        ctx.accounts.asset.asset_address,
+        MultisigError::InvalidAsset
+    );
+
+    require!(
+        proposal_asset.state == ProposalState::Open,
+        MultisigError::StateAlreadyFinalized
+    );
+
+    Ok(())
+}
+
+/// Vote on one asset of a config proposal targeting several assets, against that asset's
+/// thresholds. Each asset applies the change once its own members pass it.
+/// Delegates can also vote for the members who delegated their weight on this asset,
+/// see [`cast_delegated_votes`].
+pub fn vote_on_multi_asset_config_proposal_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, VoteOnMultiAssetConfigProposalInstructionAccounts<'info>>,
+    args: VoteOnMultiAssetConfigProposalInstructionArgs,
+) -> Result<()> {
+    checks(&ctx, &args)?;
+
+    let VoteOnMultiAssetConfigProposalInstructionArgs {
+        voting_asset_index,
+        vote,
+    } = args;
+
+    let group = &ctx.accounts.group;
+    let weight = ctx
+        .accounts
+        .asset_member
+        .weight
+        .min(group.max_member_weight);
+    require_gt!(weight, 0, MultisigError::UnauthorizedVoter);
+
+    let delegated_votes = cast_delegated_votes(
+        ctx.remaining_accounts,
+        group,
+        ctx.accounts.proposal.key(),
+        ctx.accounts.proposal.propose_timestamp,
+        Some(ctx.accounts.asset.asset_address),
+        Some(voting_asset_index),
+        &ctx.accounts.voter.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        vote,
+        None,
+    )?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    let asset = &ctx.accounts.asset;
+    let voter = &ctx.accounts.voter;
+    let vote_record = &mut ctx.accounts.vote_record;
+
+    let asset_index = usize::from(voting_asset_index);
+
+    // `is_initialized` checks whether `voter != Pubkey::default()`, which is always true once set
+    // since the voter is a signer and can never be the default key.
+    let previous_vote = vote_record
+        .is_initialized()
+        .then_some(vote_record.vote_choice);
+
+    let votes = std::iter::once(DelegatedVote {
+        weight,
+        previous_vote,
+        previous_option_index: None,
+    })
+    .chain(delegated_votes);
+
+    for DelegatedVote {
+        weight,
+        previous_vote,
+        ..
+    } in votes
+    {
+        let proposal_asset = proposal
+            .get_asset_mut(asset_index)
+            .ok_or(MultisigError::InvalidAssetIndex)?;
+
+        match previous_vote {
+            None => proposal_asset.increment_vote_count()?,
+            Some(previous_vote) if previous_vote != vote => match previous_vote {
+                VoteChoice::For => proposal_asset.sub_weight_for(weight),
+                VoteChoice::Against => proposal_asset.sub_weight_against(weight),
+            },
+            Some(_) => continue,
+        }
+
+        match vote {
+            VoteChoice::For => proposal_asset.add_weight_for(weight),
+            VoteChoice::Against => proposal_asset.add_weight_against(weight),
+        }
+    }
+
+    match vote {
+        VoteChoice::For => proposal.check_and_mark_asset_passed(asset_index, asset)?,
+        VoteChoice::Against => proposal.check_and_mark_asset_failed(asset_index, asset)?,
+    };
+
+    if previous_vote.is_none() {
+        vote_record.set_inner(VoteRecord::new(
+            voter.key(),
+            proposal.key(),
+            Some(voting_asset_index),
+            ctx.bumps.vote_record,
+            vote,
+        ));
+    } else {
+        // The voter takes back a vote their delegate may have cast.
+        vote_record.vote_choice = vote;
+        vote_record.delegate = None;
+    }
+
+    Ok(())
+}
@@ -9,6 +9,7 @@ use crate::{
         proposal::{NormalProposal, ProposalAssetThresholdState, ProposalState},
         vote::{VoteChoice, VoteRecord},
     },
+    utils::{cast_delegated_votes, DelegatedVote},
     GroupMember,
 };
 
@@ -70,16 +71,20 @@ pub struct VoteOnNormalProposalInstructionAccounts<'info> {
 
     #[account(
         init_if_needed,
-        payer = voter,
+        payer = payer,
         space = 8 + VoteRecord::INIT_SPACE,
         seeds = [b"vote-record", group.key().as_ref(), proposal.key().as_ref(), voter.key().as_ref(), &[args.voting_asset_index]],
         bump
     )]
     pub vote_record: Account<'info, VoteRecord>,
 
-    #[account(mut)]
     pub voter: Signer<'info>,
 
+    /// Pays for the vote record, so a member key that cannot hold lamports, such as another
+    /// group's PDA, can still vote.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -109,6 +114,23 @@ fn checks(
         MultisigError::ProposalExpired
     );
 
+    require!(
+        ctx.accounts.group_member.is_active(now) && ctx.accounts.asset_member.is_active(now),
+        MultisigError::MembershipExpired
+    );
+
+    require!(
+        ctx.accounts
+            .group_member
+            .can_vote_on_proposal_from(ctx.accounts.proposal.propose_timestamp),
+        MultisigError::KeyRotatedAfterProposal
+    );
+
+    require!(
+        ctx.accounts.proposal.compact_votes.is_none(),
+        MultisigError::CompactVotesRequired
+    );
+
     require_gt!(
         ctx.accounts.proposal.assets.len(),
         usize::from(args.voting_asset_index),
@@ -132,9 +154,10 @@ fn checks(
 
 /// Vote on a proposal that would execute a transaction and uses assets
 /// controlled by the multisig if passed.
-/// This instruction can be called by any group member.
-pub fn vote_on_normal_proposal_handler(
-    ctx: Context<VoteOnNormalProposalInstructionAccounts>,
+/// This instruction can be called by any group member. Delegates can also vote for
+/// the members who delegated their weight on this asset, see [`cast_delegated_votes`].
+pub fn vote_on_normal_proposal_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, VoteOnNormalProposalInstructionAccounts<'info>>,
     args: VoteOnNormalProposalInstructionArgs,
 ) -> Result<()> {
     checks(&ctx, &args)?;
@@ -144,44 +167,81 @@ pub fn vote_on_normal_proposal_handler(
         vote,
     } = args;
 
+    let group = &ctx.accounts.group;
+    let weight = ctx
+        .accounts
+        .asset_member
+        .weight
+        .min(group.max_member_weight);
+    require_gt!(weight, 0, MultisigError::UnauthorizedVoter);
+
+    let delegated_votes = cast_delegated_votes(
+        ctx.remaining_accounts,
+        group,
+        ctx.accounts.proposal.key(),
+        ctx.accounts.proposal.propose_timestamp,
+        Some(ctx.accounts.asset.asset_address),
+        Some(voting_asset_index),
+        &ctx.accounts.voter.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        vote,
+        None,
+    )?;
+
     let proposal = &mut ctx.accounts.proposal;
     let asset = &ctx.accounts.asset;
     let voter = &ctx.accounts.voter;
-    let asset_member = &ctx.accounts.asset_member;
     let vote_record = &mut ctx.accounts.vote_record;
 
-    let group = &ctx.accounts.group;
-    let weight = asset_member.weight.min(group.max_member_weight);
-    require_gt!(weight, 0, MultisigError::UnauthorizedVoter);
-
     let asset_index = usize::from(voting_asset_index);
 
     // `is_initialized` checks whether `voter != Pubkey::default()`, which is always true once set
     // since the voter is a signer and can never be the default key.
-    if !vote_record.is_initialized() {
-        // First vote
-        proposal
+    let previous_vote = vote_record
+        .is_initialized()
+        .then_some(vote_record.vote_choice);
+
+    let votes = std::iter::once(DelegatedVote {
+        weight,
+        previous_vote,
+        previous_option_index: None,
+    })
+    .chain(delegated_votes);
+
+    for DelegatedVote {
+        weight,
+        previous_vote,
+        ..
+    } in votes
+    {
+        let proposal_asset = proposal
             .get_asset_mut(asset_index)
-            .ok_or(MultisigError::InvalidAssetIndex)?
-            .increment_vote_count()?;
+            .ok_or(MultisigError::InvalidAssetIndex)?;
+
+        match previous_vote {
+            // First vote
+            None => proposal_asset.increment_vote_count()?,
+            // Re-vote, undo previous
+            Some(previous_vote) if previous_vote != vote => match previous_vote {
+                VoteChoice::For => proposal_asset.sub_use_vote_weight(weight),
+                VoteChoice::Against => proposal_asset.sub_not_use_vote_weight(weight),
+            },
+            Some(_) => continue,
+        }
 
         match vote {
-            VoteChoice::For => {
-                proposal
-                    .get_asset_mut(asset_index)
-                    .ok_or(MultisigError::InvalidAssetIndex)?
-                    .add_use_vote_weight(weight);
-                proposal.check_and_mark_asset_passed(asset_index, asset)?;
-            }
-            VoteChoice::Against => {
-                proposal
-                    .get_asset_mut(asset_index)
-                    .ok_or(MultisigError::InvalidAssetIndex)?
-                    .add_not_use_vote_weight(weight);
-                proposal.check_and_mark_asset_failed(asset_index, asset)?;
-            }
+            VoteChoice::For => proposal_asset.add_use_vote_weight(weight),
+            VoteChoice::Against => proposal_asset.add_not_use_vote_weight(weight),
         }
+    }
+
+    match vote {
+        VoteChoice::For => proposal.check_and_mark_asset_passed(asset_index, asset)?,
+        VoteChoice::Against => proposal.check_and_mark_asset_failed(asset_index, asset)?,
+    };
 
+    if previous_vote.is_none() {
         vote_record.set_inner(VoteRecord::new(
             voter.key(),
             proposal.key(),
@@ -190,45 +250,9 @@ pub fn vote_on_normal_proposal_handler(
             vote,
         ));
     } else {
-        // Re-vote
-
-        if vote_record.vote_choice != vote {
-            // Undo previous
-            match vote_record.vote_choice {
-                VoteChoice::For => {
-                    proposal
-                        .get_asset_mut(asset_index)
-                        .ok_or(MultisigError::InvalidAssetIndex)?
-                        .sub_use_vote_weight(weight);
-                }
-                VoteChoice::Against => {
-                    proposal
-                        .get_asset_mut(asset_index)
-                        .ok_or(MultisigError::InvalidAssetIndex)?
-                        .sub_not_use_vote_weight(weight);
-                }
-            }
-
-            // Apply new
-            match vote {
-                VoteChoice::For => {
-                    proposal
-                        .get_asset_mut(asset_index)
-                        .ok_or(MultisigError::InvalidAssetIndex)?
-                        .add_use_vote_weight(weight);
-                    proposal.check_and_mark_asset_passed(asset_index, asset)?;
-                }
-                VoteChoice::Against => {
-                    proposal
-                        .get_asset_mut(asset_index)
-                        .ok_or(MultisigError::InvalidAssetIndex)?
-                        .add_not_use_vote_weight(weight);
-                    proposal.check_and_mark_asset_failed(asset_index, asset)?;
-                }
-            }
-
-            vote_record.vote_choice = vote;
-        }
+        // The voter takes back a vote their delegate may have cast.
+        vote_record.vote_choice = vote;
+        vote_record.delegate = None;
     }
 
     Ok(())
@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        error::MultisigError,
+        group::Group,
+        proposal::{NormalProposal, ProposalAssetThresholdState, ProposalState},
+        vote::{VoteChoice, VoteRecord},
+    },
+    GroupMember,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VoteOnNormalProposalGroupApprovalInstructionArgs {
+    pub vote: VoteChoice,
+}
+
+#[derive(Accounts)]
+#[instruction(args: VoteOnNormalProposalGroupApprovalInstructionArgs)]
+pub struct VoteOnNormalProposalGroupApprovalInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Seeds checks bind it to the group.
+    #[account(
+        mut,
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump,
+    )]
+    pub proposal: Account<'info, NormalProposal>,
+
+    /// Seeds check binds it to the group and voter.
+    #[account(
+        seeds = [b"member", group.key().as_ref(), voter.key().as_ref()],
+        bump = group_member.account_bump
+    )]
+    pub group_member: Account<'info, GroupMember>,
+
+    /// Group approval votes have no asset index, so they are closed with `close_config_vote_record`.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoteRecord::INIT_SPACE,
+        seeds = [b"vote-record", group.key().as_ref(), proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    pub voter: Signer<'info>,
+
+    /// Pays for the vote record, so a member key that cannot hold lamports, such as another
+    /// group's PDA, can still vote.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[inline(always)]
+fn checks(ctx: &Context<VoteOnNormalProposalGroupApprovalInstructionAccounts>) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    require!(
+        ctx.accounts.proposal.state == ProposalState::Open,
+        MultisigError::ProposalNotOpen
+    );
+
+    require_gte!(
+        ctx.accounts.proposal.proposal_index,
+        ctx.accounts.group.proposal_index_after_stale,
+        MultisigError::ProposalStale
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require_gt!(
+        ctx.accounts.proposal.proposal_deadline_timestamp,
+        now,
+        MultisigError::ProposalExpired
+    );
+
+    require!(
+        ctx.accounts.group_member.is_active(now),
+        MultisigError::MembershipExpired
+    );
+
+    require!(
+        ctx.accounts
+            .group_member
+            .can_vote_on_proposal_from(ctx.accounts.proposal.propose_timestamp),
+        MultisigError::KeyRotatedAfterProposal
+    );
+
+    let group_approval = ctx
+        .accounts
+        .proposal
+        .group_approval
+        .as_ref()
+        .ok_or(MultisigError::GroupApprovalNotRequired)?;
+
+    require!(
+        group_approval.threshold_state == ProposalAssetThresholdState::NoThresholdReached,
+        MultisigError::StateAlreadyFinalized
+    );
+
+    Ok(())
+}
+
+/// Vote, as a group member, on the config change carried by a normal proposal.
+/// The proposal can only pass once both its assets and the group have approved it.
+pub fn vote_on_normal_proposal_group_approval_handler(
+    ctx: Context<VoteOnNormalProposalGroupApprovalInstructionAccounts>,
+    args: VoteOnNormalProposalGroupApprovalInstructionArgs,
+) -> Result<()> {
+    checks(&ctx)?;
+
+    let VoteOnNormalProposalGroupApprovalInstructionArgs { vote } = args;
+
+    let proposal = &mut ctx.accounts.proposal;
+    let group = &ctx.accounts.group;
+    let voter = &ctx.accounts.voter;
+    let vote_record = &mut ctx.accounts.vote_record;
+
+    let weight = ctx
+        .accounts
+        .group_member
+        .weight
+        .min(group.max_member_weight);
+    require_gt!(weight, 0, MultisigError::UnauthorizedVoter);
+
+    // `is_initialized` checks whether `voter != Pubkey::default()`, which is always true once set
+    // since the voter is a signer and can never be the default key.
+    let is_first_vote = !vote_record.is_initialized();
+    if !is_first_vote && vote_record.vote_choice == vote {
+        return Ok(());
+    }
+
+    let group_approval = proposal
+        .group_approval
+        .as_mut()
+        .ok_or(MultisigError::GroupApprovalNotRequired)?;
+
+    if is_first_vote {
+        group_approval.increment_vote_count()?;
+    } else {
+        match vote_record.vote_choice {
+            VoteChoice::For => group_approval.sub_weight_for(weight),
+            VoteChoice::Against => group_approval.sub_weight_against(weight),
+        }
+    }
+
+    match vote {
+        VoteChoice::For => {
+            group_approval.add_weight_for(weight);
+            proposal.check_and_mark_group_approval_passed(group)?;
+        }
+        VoteChoice::Against => {
+            group_approval.add_weight_against(weight);
+            proposal.check_and_mark_group_approval_failed(group)?;
+        }
+    }
+
+    if is_first_vote {
+        vote_record.set_inner(VoteRecord::new(
+            voter.key(),
+            proposal.key(),
+            None, // group approval votes don't use asset_index
+            ctx.bumps.vote_record,
+            vote,
+        ));
+    } else {
+        vote_record.vote_choice = vote;
+    }
+
+    Ok(())
+}
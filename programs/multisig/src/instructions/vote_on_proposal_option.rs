@@ -0,0 +1,271 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        asset::Asset,
+        error::MultisigError,
+        group::Group,
+        member::AssetMember,
+        proposal::{NormalProposal, ProposalAssetThresholdState, ProposalState},
+        vote::{VoteChoice, VoteRecord},
+    },
+    utils::{cast_delegated_votes, DelegatedVote},
+    GroupMember,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VoteOnProposalOptionInstructionArgs {
+    /// The option voted for, `None` votes against every option.
+    pub option_index: Option<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(args: VoteOnProposalOptionInstructionArgs)]
+pub struct VoteOnProposalOptionInstructionAccounts<'info> {
+    #[account(
+        seeds = [b"group", group.group_seed.as_ref()],
+        bump = group.account_bump
+    )]
+    pub group: Account<'info, Group>,
+
+    /// Seeds checks bind it to the group.
+    #[account(
+        mut,
+        seeds = [b"proposal", group.key().as_ref(), proposal.proposal_seed.as_ref()],
+        bump = proposal.account_bump,
+    )]
+    pub proposal: Account<'info, NormalProposal>,
+
+    /// The transaction of the option voted for, required to vote for an option.
+    /// Seeds and ownership checks bind it to the proposal and option.
+    #[account(
+        seeds = [b"proposal-transaction", proposal.key().as_ref(), args.option_index.as_slice()],
+        bump,
+        owner = crate::ID
+    )]
+    pub proposal_transaction: Option<UncheckedAccount<'info>>,
+
+    /// Seeds check binds it to the asset and group.
+    #[account(
+        seeds = [b"asset", group.key().as_ref(), asset.asset_address.as_ref()],
+        bump = asset.account_bump
+    )]
+    pub asset: Account<'info, Asset>,
+
+    /// Seeds check binds it to the group and voter.
+    #[account(
+        seeds = [b"member", group.key().as_ref(), voter.key().as_ref()],
+        bump = group_member.account_bump
+    )]
+    pub group_member: Account<'info, GroupMember>,
+
+    /// Seeds check binds it to the voter, group and asset.
+    #[account(
+        seeds = [b"asset-member", group.key().as_ref(), asset.asset_address.as_ref(), voter.key().as_ref()],
+        bump = asset_member.account_bump
+    )]
+    pub asset_member: Account<'info, AssetMember>,
+
+    /// Multi-choice proposals have a single asset, so the vote is recorded against its index.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + VoteRecord::INIT_SPACE,
+        seeds = [b"vote-record", group.key().as_ref(), proposal.key().as_ref(), voter.key().as_ref(), &[0]],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    pub voter: Signer<'info>,
+
+    /// Pays for the vote record, so a member key that cannot hold lamports, such as another
+    /// group's PDA, can still vote.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[inline(always)]
+fn checks(
+    ctx: &Context<VoteOnProposalOptionInstructionAccounts>,
+    args: &VoteOnProposalOptionInstructionArgs,
+) -> Result<()> {
+    require!(!ctx.accounts.group.paused, MultisigError::GroupPaused);
+
+    let proposal = &ctx.accounts.proposal;
+
+    require!(
+        proposal.state == ProposalState::Open,
+        MultisigError::ProposalNotOpen
+    );
+
+    require_gte!(
+        proposal.proposal_index,
+        ctx.accounts.group.proposal_index_after_stale,
+        MultisigError::ProposalStale
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require_gt!(
+        proposal.proposal_deadline_timestamp,
+        now,
+        MultisigError::ProposalExpired
+    );
+
+    require!(
+        ctx.accounts.group_member.is_active(now) && ctx.accounts.asset_member.is_active(now),
+        MultisigError::MembershipExpired
+    );
+
+    require!(
+        ctx.accounts
+            .group_member
+            .can_vote_on_proposal_from(proposal.propose_timestamp),
+        MultisigError::KeyRotatedAfterProposal
+    );
+
+    require!(
+        proposal.is_multi_choice(),
+        MultisigError::InvalidOptionIndex
+    );
+
+    if let Some(option_index) = args.option_index {
+        require_gt!(
+            proposal.options.len(),
+            usize::from(option_index),
+            MultisigError::InvalidOptionIndex
+        );
+
+        require!(
+            ctx.accounts.proposal_transaction.is_some(),
+            MultisigError::OptionTransactionRequired
+        );
+    }
+
+    require_keys_eq!(
+        proposal.assets[0].asset,
+        ctx.accounts.asset.asset_address,
+        MultisigError::InvalidAsset
+    );
+
+    require!(
+        proposal.assets[0].threshold_state == ProposalAssetThresholdState::NoThresholdReached,
+        MultisigError::StateAlreadyFinalized
+    );
+
+    Ok(())
+}
+
+/// Vote for one option of a multi-choice proposal, or against all of them.
+/// The option with the most votes passes once its weight meets the asset's use threshold.
+/// This instruction can be called by any group member. Delegates can also vote for
+/// the members who delegated their weight on this asset, see [`cast_delegated_votes`].
+pub fn vote_on_proposal_option_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, VoteOnProposalOptionInstructionAccounts<'info>>,
+    args: VoteOnProposalOptionInstructionArgs,
+) -> Result<()> {
+    checks(&ctx, &args)?;
+
+    let VoteOnProposalOptionInstructionArgs { option_index } = args;
+    let vote = match option_index {
+        Some(_) => VoteChoice::For,
+        None => VoteChoice::Against,
+    };
+
+    let group = &ctx.accounts.group;
+    let weight = ctx
+        .accounts
+        .asset_member
+        .weight
+        .min(group.max_member_weight);
+    require_gt!(weight, 0, MultisigError::UnauthorizedVoter);
+
+    let delegated_votes = cast_delegated_votes(
+        ctx.remaining_accounts,
+        group,
+        ctx.accounts.proposal.key(),
+        ctx.accounts.proposal.propose_timestamp,
+        Some(ctx.accounts.asset.asset_address),
+        Some(0),
+        &ctx.accounts.voter.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        vote,
+        option_index,
+    )?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    let asset = &ctx.accounts.asset;
+    let voter = &ctx.accounts.voter;
+    let vote_record = &mut ctx.accounts.vote_record;
+
+    // `is_initialized` checks whether `voter != Pubkey::default()`, which is always true once set
+    // since the voter is a signer and can never be the default key.
+    let is_initialized = vote_record.is_initialized();
+
+    let votes = std::iter::once(DelegatedVote {
+        weight,
+        previous_vote: is_initialized.then_some(vote_record.vote_choice),
+        previous_option_index: vote_record.option_index,
+    })
+    .chain(delegated_votes);
+
+    for DelegatedVote {
+        weight,
+        previous_vote,
+        previous_option_index,
+    } in votes
+    {
+        match previous_vote {
+            // First vote
+            None => proposal.assets[0].increment_vote_count()?,
+            Some(_) if previous_option_index == option_index => continue,
+            // Re-vote, undo previous
+            Some(VoteChoice::For) => {
+                proposal.assets[0].sub_use_vote_weight(weight);
+                if let Some(option) = previous_option_index
+                    .and_then(|index| proposal.options.get_mut(usize::from(index)))
+                {
+                    option.sub_vote_weight(weight);
+                }
+            }
+            Some(VoteChoice::Against) => proposal.assets[0].sub_not_use_vote_weight(weight),
+        }
+
+        match option_index {
+            Some(index) => {
+                proposal.assets[0].add_use_vote_weight(weight);
+                proposal.options[usize::from(index)].add_vote_weight(weight);
+            }
+            None => proposal.assets[0].add_not_use_vote_weight(weight),
+        }
+    }
+
+    // Moving weight between options or against all of them can change the leading option.
+    proposal.check_and_mark_option_passed(asset)?;
+    if vote == VoteChoice::Against {
+        proposal.check_and_mark_asset_failed(0, asset)?;
+    }
+
+    if !is_initialized {
+        vote_record.set_inner(VoteRecord {
+            option_index,
+            ..VoteRecord::new(
+                voter.key(),
+                proposal.key(),
+                Some(0),
+                ctx.bumps.vote_record,
+                vote,
+            )
+        });
+    } else {
+        // The voter takes back a vote their delegate may have cast.
+        vote_record.vote_choice = vote;
+        vote_record.option_index = option_index;
+        vote_record.delegate = None;
+    }
+
+    Ok(())
+}
@@ -31,7 +31,7 @@ pub mod multisig {
         add_asset_token_handler(ctx, args)
     }
 
-    /// Adds a group member to a group, storing their key and weight
+    /// Invites a group member to a group, storing their key and weight
     ///  and permissions, as well as the group key for indexing.
     pub fn add_group_member(
         ctx: Context<AddGroupMemberInstructionAccounts>,
@@ -96,11 +96,29 @@ pub mod multisig {
         create_config_proposal_handler(ctx, args)
     }
 
-    /// Execute a transaction associated with a particular proposal
+    /// Execute a transaction associated with a particular proposal, or the next
+    /// instructions of it when the proposal allows sequential execution
     pub fn execute_proposal_transaction(
         ctx: Context<ExecuteProposalTransactionInstructionAccounts>,
+        args: ExecuteProposalTransactionInstructionArgs,
     ) -> Result<()> {
-        execute_proposal_transaction_handler(ctx)
+        execute_proposal_transaction_handler(ctx, args)
+    }
+
+    /// Close the execution receipt of a proposal once the proposal itself is closed,
+    /// the rent is sent to the rent_collector.
+    pub fn close_execution_receipt(
+        ctx: Context<CloseExecutionReceiptInstructionAccounts>,
+    ) -> Result<()> {
+        close_execution_receipt_handler(ctx)
+    }
+
+    /// Applies the group config change carried by a normal proposal, it can only be
+    /// invoked by `execute_proposal_transaction`, which signs for the group.
+    pub fn apply_group_config_change(
+        ctx: Context<ApplyGroupConfigChangeInstructionAccounts>,
+    ) -> Result<()> {
+        apply_group_config_change_handler(ctx)
     }
 
     /// Removes an existing group member once a proposal to remove them has passed,
@@ -119,21 +137,224 @@ pub mod multisig {
 
     /// Vote on a proposal that would execute a transaction and uses assets
     /// controlled by the multisig if passed.
-    pub fn vote_on_normal_proposal(
-        ctx: Context<VoteOnNormalProposalInstructionAccounts>,
+    pub fn vote_on_normal_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, VoteOnNormalProposalInstructionAccounts<'info>>,
         args: VoteOnNormalProposalInstructionArgs,
     ) -> Result<()> {
         vote_on_normal_proposal_handler(ctx, args)
     }
 
+    /// Vote on several assets of a normal proposal in one instruction.
+    pub fn batch_vote_on_normal_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchVoteOnNormalProposalInstructionAccounts<'info>>,
+        args: BatchVoteOnNormalProposalInstructionArgs,
+    ) -> Result<()> {
+        batch_vote_on_normal_proposal_handler(ctx, args)
+    }
+
+    /// Vote as a group member on the config change carried by a normal proposal.
+    pub fn vote_on_normal_proposal_group_approval(
+        ctx: Context<VoteOnNormalProposalGroupApprovalInstructionAccounts>,
+        args: VoteOnNormalProposalGroupApprovalInstructionArgs,
+    ) -> Result<()> {
+        vote_on_normal_proposal_group_approval_handler(ctx, args)
+    }
+
     /// Vote on a proposal that changes the configuration of a group or asset if passed.
-    pub fn vote_on_config_proposal(
-        ctx: Context<VoteOnConfigProposalInstructionAccounts>,
+    pub fn vote_on_config_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, VoteOnConfigProposalInstructionAccounts<'info>>,
         args: VoteOnConfigProposalInstructionArgs,
     ) -> Result<()> {
         vote_on_config_proposal_handler(ctx, args)
     }
 
+    /// Vote on an asset of a normal proposal that stores its votes compactly.
+    pub fn compact_vote_on_normal_proposal(
+        ctx: Context<CompactVoteOnNormalProposalInstructionAccounts>,
+        args: CompactVoteOnNormalProposalInstructionArgs,
+    ) -> Result<()> {
+        compact_vote_on_normal_proposal_handler(ctx, args)
+    }
+
+    /// Vote on a config proposal that stores its votes compactly.
+    pub fn compact_vote_on_config_proposal(
+        ctx: Context<CompactVoteOnConfigProposalInstructionAccounts>,
+        args: CompactVoteOnConfigProposalInstructionArgs,
+    ) -> Result<()> {
+        compact_vote_on_config_proposal_handler(ctx, args)
+    }
+
+    /// Apply votes on a normal proposal that members signed off-chain, checked by the
+    /// Ed25519 instruction placed right before this one.
+    pub fn submit_signed_votes_on_normal_proposal<'info>(
+        ctx: Context<
+            '_,
+            '_,
+            'info,
+            'info,
+            SubmitSignedVotesOnNormalProposalInstructionAccounts<'info>,
+        >,
+        args: SubmitSignedVotesOnNormalProposalInstructionArgs,
+    ) -> Result<()> {
+        submit_signed_votes_on_normal_proposal_handler(ctx, args)
+    }
+
+    /// Apply votes on a config proposal that members signed off-chain, checked by the
+    /// Ed25519 instruction placed right before this one.
+    pub fn submit_signed_votes_on_config_proposal<'info>(
+        ctx: Context<
+            '_,
+            '_,
+            '_,
+            'info,
+            SubmitSignedVotesOnConfigProposalInstructionAccounts<'info>,
+        >,
+        args: SubmitSignedVotesOnConfigProposalInstructionArgs,
+    ) -> Result<()> {
+        submit_signed_votes_on_config_proposal_handler(ctx, args)
+    }
+
+    /// Let another group member vote with the delegator's group or asset weight.
+    pub fn delegate_vote(
+        ctx: Context<DelegateVoteInstructionAccounts>,
+        args: DelegateVoteInstructionArgs,
+    ) -> Result<()> {
+        delegate_vote_handler(ctx, args)
+    }
+
+    /// Revoke a delegation, can be called by the delegator at any time.
+    pub fn revoke_delegation(ctx: Context<RevokeDelegationInstructionAccounts>) -> Result<()> {
+        revoke_delegation_handler(ctx)
+    }
+
+    /// Move a member and all of their asset memberships to a new key, signed by both keys.
+    pub fn rotate_member_key<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RotateMemberKeyInstructionAccounts<'info>>,
+    ) -> Result<()> {
+        rotate_member_key_handler(ctx)
+    }
+
+    /// Join a group as the invited key of a passed AddGroupMember change.
+    pub fn accept_membership(ctx: Context<AcceptMembershipInstructionAccounts>) -> Result<()> {
+        accept_membership_handler(ctx)
+    }
+
+    /// Close an expired member invite, refunding the rent to its payer.
+    pub fn close_member_invite(ctx: Context<CloseMemberInviteInstructionAccounts>) -> Result<()> {
+        close_member_invite_handler(ctx)
+    }
+
+    /// Set the end of a group member's term once a RenewGroupMemberTerm proposal passed.
+    pub fn renew_group_member_term(
+        ctx: Context<RenewGroupMemberTermInstructionAccounts>,
+    ) -> Result<()> {
+        renew_group_member_term_handler(ctx)
+    }
+
+    /// Set the end of an asset member's term once a RenewAssetMemberTerm proposal passed.
+    pub fn renew_asset_member_term(
+        ctx: Context<RenewAssetMemberTermInstructionAccounts>,
+    ) -> Result<()> {
+        renew_asset_member_term_handler(ctx)
+    }
+
+    /// Close a group member whose term has ended, can be called by anyone.
+    pub fn close_expired_group_member(
+        ctx: Context<CloseExpiredGroupMemberInstructionAccounts>,
+    ) -> Result<()> {
+        close_expired_group_member_handler(ctx)
+    }
+
+    /// Close an asset member whose term has ended, can be called by anyone.
+    pub fn close_expired_asset_member(
+        ctx: Context<CloseExpiredAssetMemberInstructionAccounts>,
+    ) -> Result<()> {
+        close_expired_asset_member_handler(ctx)
+    }
+
+    /// Replace a group member's permissions once a ChangeGroupMemberPermissions proposal passed.
+    pub fn change_group_member_permissions(
+        ctx: Context<ChangeGroupMemberPermissionsInstructionAccounts>,
+    ) -> Result<()> {
+        change_group_member_permissions_handler(ctx)
+    }
+
+    /// Replace an asset member's permissions once a ChangeAssetMemberPermissions proposal passed.
+    pub fn change_asset_member_permissions(
+        ctx: Context<ChangeAssetMemberPermissionsInstructionAccounts>,
+    ) -> Result<()> {
+        change_asset_member_permissions_handler(ctx)
+    }
+
+    /// Cancel a normal proposal before it is executed, requires Cancel permission.
+    pub fn cancel_normal_proposal(
+        ctx: Context<CancelNormalProposalInstructionAccounts>,
+    ) -> Result<()> {
+        cancel_normal_proposal_handler(ctx)
+    }
+
+    /// Cancel a config proposal before it is executed, requires Cancel permission.
+    pub fn cancel_config_proposal(
+        ctx: Context<CancelConfigProposalInstructionAccounts>,
+    ) -> Result<()> {
+        cancel_config_proposal_handler(ctx)
+    }
+
+    /// Create or replace the group's metadata once a SetGroupMetadata proposal passed.
+    pub fn set_group_metadata(ctx: Context<SetGroupMetadataInstructionAccounts>) -> Result<()> {
+        set_group_metadata_handler(ctx)
+    }
+
+    /// Create or replace an asset's metadata once a SetAssetMetadata proposal passed.
+    pub fn set_asset_metadata(ctx: Context<SetAssetMetadataInstructionAccounts>) -> Result<()> {
+        set_asset_metadata_handler(ctx)
+    }
+
+    /// Create or replace the signing member's own metadata.
+    pub fn set_member_metadata(
+        ctx: Context<SetMemberMetadataInstructionAccounts>,
+        args: SetMemberMetadataInstructionArgs,
+    ) -> Result<()> {
+        set_member_metadata_handler(ctx, args)
+    }
+
+    /// Create or replace a proposal's metadata, signed by its proposer.
+    pub fn set_proposal_metadata(
+        ctx: Context<SetProposalMetadataInstructionAccounts>,
+        args: SetProposalMetadataInstructionArgs,
+    ) -> Result<()> {
+        set_proposal_metadata_handler(ctx, args)
+    }
+
+    /// Close the metadata of an account that no longer exists, can be called by anyone.
+    pub fn close_metadata(ctx: Context<CloseMetadataInstructionAccounts>) -> Result<()> {
+        close_metadata_handler(ctx)
+    }
+
+    /// Replace a normal proposal's description, only until the first vote is cast.
+    pub fn update_normal_proposal_description(
+        ctx: Context<UpdateNormalProposalDescriptionInstructionAccounts>,
+        args: UpdateNormalProposalDescriptionInstructionArgs,
+    ) -> Result<()> {
+        update_normal_proposal_description_handler(ctx, args)
+    }
+
+    /// Replace a config proposal's description, only until the first vote is cast.
+    pub fn update_config_proposal_description(
+        ctx: Context<UpdateConfigProposalDescriptionInstructionAccounts>,
+        args: UpdateConfigProposalDescriptionInstructionArgs,
+    ) -> Result<()> {
+        update_config_proposal_description_handler(ctx, args)
+    }
+
+    /// Vote on one asset of a config proposal targeting several assets.
+    pub fn vote_on_multi_asset_config_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, VoteOnMultiAssetConfigProposalInstructionAccounts<'info>>,
+        args: VoteOnMultiAssetConfigProposalInstructionArgs,
+    ) -> Result<()> {
+        vote_on_multi_asset_config_proposal_handler(ctx, args)
+    }
+
     /// Close a proposal transaction that though was finalized after the proposal was passed
     /// and active(no config had changed), execution was delayed till after a config changed
     /// and refund the rent to the proposal
@@ -243,4 +464,47 @@ pub mod multisig {
     ) -> Result<()> {
         exit_pause_mode_handler(ctx, args)
     }
+
+    /// Release the bond of a settled proposal, refunding it to its depositor or sending it
+    /// to the rent collector when the proposal was cancelled or overwhelmingly rejected.
+    pub fn release_proposal_bond(
+        ctx: Context<ReleaseProposalBondInstructionAccounts>,
+    ) -> Result<()> {
+        release_proposal_bond_handler(ctx)
+    }
+
+    /// Create a schedule of recurring transfers from an asset, signed by the asset authority
+    /// so only a passed normal proposal can create it.
+    pub fn create_schedule(
+        ctx: Context<CreateScheduleInstructionAccounts>,
+        args: CreateScheduleInstructionArgs,
+    ) -> Result<()> {
+        create_schedule_handler(ctx, args)
+    }
+
+    /// Make the next due transfer of a schedule, can be called by anyone.
+    pub fn run_schedule(ctx: Context<RunScheduleInstructionAccounts>) -> Result<()> {
+        run_schedule_handler(ctx)
+    }
+
+    /// Close a schedule once a CancelSchedule proposal passed.
+    pub fn cancel_schedule(ctx: Context<CancelScheduleInstructionAccounts>) -> Result<()> {
+        cancel_schedule_handler(ctx)
+    }
+
+    /// Create the transaction of one option of a multi-choice proposal.
+    pub fn create_proposal_option_transaction(
+        ctx: Context<CreateProposalOptionTransactionInstructionAccounts>,
+        args: CreateProposalOptionTransactionInstructionArgs,
+    ) -> Result<()> {
+        create_proposal_option_transaction_handler(ctx, args)
+    }
+
+    /// Vote for one option of a multi-choice proposal, or against all of them.
+    pub fn vote_on_proposal_option<'info>(
+        ctx: Context<'_, '_, '_, 'info, VoteOnProposalOptionInstructionAccounts<'info>>,
+        args: VoteOnProposalOptionInstructionArgs,
+    ) -> Result<()> {
+        vote_on_proposal_option_handler(ctx, args)
+    }
 }
@@ -1,6 +1,13 @@
 use anchor_lang::prelude::*;
 
-use crate::{utils::FractionalThreshold, MultisigError};
+use crate::{
+    state::{
+        constants::MAX_EXECUTOR_TIP_LAMPORTS,
+        proposal::{ConfigChange, ConfigChangeTimelocks, ConfigType},
+    },
+    utils::FractionalThreshold,
+    MultisigError,
+};
 
 /// Stores information required to govern an asset
 #[account]
@@ -23,6 +30,19 @@ pub struct Asset {
     /// Constraints
     pub minimum_member_count: u32,
     pub minimum_vote_count: u32,
+    /// Least timelock of the proposals that use or change this asset.
+    pub minimum_timelock: u32,
+    /// Longest time in seconds those proposals can stay open for votes, zero for no limit.
+    pub maximum_proposal_duration: u32,
+    pub config_change_timelocks: ConfigChangeTimelocks,
+
+    /// Only members of this asset with the Execute permission may execute
+    /// normal proposals that use it.
+    pub require_execute_permission: bool,
+
+    /// Lamports paid from the asset authority to the executor that completes a proposal
+    /// using this asset, zero for no tip.
+    pub executor_tip_lamports: u64,
 
     /// PDA bumps
     pub account_bump: u8,
@@ -63,18 +83,7 @@ impl Asset {
         account_bump: u8,
         authority_bump: u8,
     ) -> Result<Self> {
-        Self::validate_minimum_vote_count(initial_member_count, minimum_vote_count)?;
-        Self::validate_minimum_member_count(initial_member_count, minimum_member_count)?;
-
-        FractionalThreshold::validate_non_overlapping_pair(use_threshold, not_use_threshold)?;
-        FractionalThreshold::validate_non_overlapping_pair(add_threshold, not_add_threshold)?;
-        FractionalThreshold::validate_non_overlapping_pair(remove_threshold, not_remove_threshold)?;
-        FractionalThreshold::validate_non_overlapping_pair(
-            change_config_threshold,
-            not_change_config_threshold,
-        )?;
-
-        Ok(Self {
+        let asset = Self {
             asset_address,
             use_threshold,
             not_use_threshold,
@@ -87,66 +96,187 @@ impl Asset {
             member_count: initial_member_count,
             minimum_member_count,
             minimum_vote_count,
+            minimum_timelock: 0,
+            maximum_proposal_duration: 0,
+            config_change_timelocks: ConfigChangeTimelocks::default(),
+            require_execute_permission: false,
+            executor_tip_lamports: 0,
             account_bump,
             authority_bump,
-        })
+        };
+        asset.validate_config()?;
+
+        Ok(asset)
     }
 
-    pub fn set_use_threshold(&mut self, threshold: FractionalThreshold) -> Result<()> {
-        FractionalThreshold::validate_non_overlapping_pair(threshold, self.not_use_threshold)?;
-        self.use_threshold = threshold;
+    /// Checks the fields that constrain each other, setters don't so that related
+    /// fields can be changed together before the result is validated.
+    pub fn validate_config(&self) -> Result<()> {
+        Self::validate_minimum_vote_count(self.member_count, self.minimum_vote_count)?;
+        Self::validate_minimum_member_count(self.member_count, self.minimum_member_count)?;
+
+        require_gte!(
+            MAX_EXECUTOR_TIP_LAMPORTS,
+            self.executor_tip_lamports,
+            MultisigError::ExecutorTipTooLarge
+        );
+
+        FractionalThreshold::validate_non_overlapping_pair(
+            self.use_threshold,
+            self.not_use_threshold,
+        )?;
+        FractionalThreshold::validate_non_overlapping_pair(
+            self.add_threshold,
+            self.not_add_threshold,
+        )?;
+        FractionalThreshold::validate_non_overlapping_pair(
+            self.remove_threshold,
+            self.not_remove_threshold,
+        )?;
+        FractionalThreshold::validate_non_overlapping_pair(
+            self.change_config_threshold,
+            self.not_change_config_threshold,
+        )
+    }
+
+    /// Returns the asset with a batch of config changes applied in order,
+    /// the resulting config is validated as a whole.
+    pub fn with_config_types(&self, config_types: &[ConfigType]) -> Result<Self> {
+        let mut asset = self.clone();
+
+        for config_type in config_types {
+            match config_type {
+                ConfigType::AddMember(threshold) => asset.set_add_threshold(*threshold),
+                ConfigType::NotAddMember(threshold) => asset.set_not_add_threshold(*threshold),
+                ConfigType::RemoveMember(threshold) => asset.set_remove_threshold(*threshold),
+                ConfigType::NotRemoveMember(threshold) => {
+                    asset.set_not_remove_threshold(*threshold)
+                }
+                ConfigType::Use(threshold) => asset.set_use_threshold(*threshold),
+                ConfigType::NotUse(threshold) => asset.set_not_use_threshold(*threshold),
+                ConfigType::ChangeConfig(threshold) => {
+                    asset.set_change_config_threshold(*threshold)
+                }
+                ConfigType::NotChangeConfig(threshold) => {
+                    asset.set_not_change_config_threshold(*threshold)
+                }
+                ConfigType::MinimumMemberCount(count) => asset.set_minimum_member_count(*count),
+                ConfigType::MinimumVoteCount(count) => asset.set_minimum_vote_count(*count),
+                ConfigType::MinimumTimelock(timelock) => asset.set_minimum_timelock(*timelock),
+                ConfigType::MaximumProposalDuration(duration) => {
+                    asset.set_maximum_proposal_duration(*duration)
+                }
+                ConfigType::ConfigChangeTimelocks(timelocks) => {
+                    asset.set_config_change_timelocks(*timelocks)
+                }
+                ConfigType::RequireExecutePermission(require) => {
+                    asset.set_require_execute_permission(*require)
+                }
+                ConfigType::AddMemberThresholds { pass, fail } => {
+                    asset.set_add_threshold(*pass);
+                    asset.set_not_add_threshold(*fail);
+                }
+                ConfigType::RemoveMemberThresholds { pass, fail } => {
+                    asset.set_remove_threshold(*pass);
+                    asset.set_not_remove_threshold(*fail);
+                }
+                ConfigType::UseThresholds { pass, fail } => {
+                    asset.set_use_threshold(*pass);
+                    asset.set_not_use_threshold(*fail);
+                }
+                ConfigType::ChangeConfigThresholds { pass, fail } => {
+                    asset.set_change_config_threshold(*pass);
+                    asset.set_not_change_config_threshold(*fail);
+                }
+                ConfigType::ExecutorTip(lamports) => asset.set_executor_tip(*lamports),
+                ConfigType::ProposalBond(_)
+                | ConfigType::MaxOpenProposals(_)
+                | ConfigType::MaxOpenProposalsPerMember(_) => {
+                    return Err(MultisigError::UnexpectedConfigChange.into())
+                }
+            }
+        }
+
+        asset.validate_config()?;
+
+        Ok(asset)
+    }
+
+    /// Applies a batch of config changes, nothing is changed if the batch is rejected.
+    pub fn apply_config_types(&mut self, config_types: &[ConfigType]) -> Result<()> {
+        *self = self.with_config_types(config_types)?;
         Ok(())
     }
 
-    pub fn set_not_use_threshold(&mut self, threshold: FractionalThreshold) -> Result<()> {
-        FractionalThreshold::validate_non_overlapping_pair(self.use_threshold, threshold)?;
+    #[inline(always)]
+    fn threshold_pairs(&self) -> [(FractionalThreshold, FractionalThreshold); 4] {
+        [
+            (self.use_threshold, self.not_use_threshold),
+            (self.add_threshold, self.not_add_threshold),
+            (self.remove_threshold, self.not_remove_threshold),
+            (
+                self.change_config_threshold,
+                self.not_change_config_threshold,
+            ),
+        ]
+    }
+
+    /// Least timelock of a proposal making `config_change` to the asset, the strictest of
+    /// `minimum_timelock` and the timelock of each kind of change it makes.
+    pub fn config_change_timelock(&self, config_change: &ConfigChange) -> Result<u32> {
+        let timelocks = &self.config_change_timelocks;
+        let change_timelock = match config_change {
+            ConfigChange::AddAssetMember { .. } => timelocks.add_member,
+            ConfigChange::RemoveAssetMember { .. } => timelocks.remove_member,
+            ConfigChange::ChangeAssetConfig { config_types } => {
+                let proposed = self.with_config_types(config_types)?;
+                timelocks
+                    .for_thresholds(&self.threshold_pairs(), &proposed.threshold_pairs())
+                    .max(timelocks.for_timelocks(&proposed.config_change_timelocks))
+            }
+            _ => 0,
+        };
+        Ok(self.minimum_timelock.max(change_timelock))
+    }
+
+    #[inline(always)]
+    pub fn set_use_threshold(&mut self, threshold: FractionalThreshold) {
+        self.use_threshold = threshold;
+    }
+
+    #[inline(always)]
+    pub fn set_not_use_threshold(&mut self, threshold: FractionalThreshold) {
         self.not_use_threshold = threshold;
-        Ok(())
     }
 
-    pub fn set_add_threshold(&mut self, threshold: FractionalThreshold) -> Result<()> {
-        FractionalThreshold::validate_non_overlapping_pair(threshold, self.not_add_threshold)?;
+    #[inline(always)]
+    pub fn set_add_threshold(&mut self, threshold: FractionalThreshold) {
         self.add_threshold = threshold;
-        Ok(())
     }
 
-    pub fn set_not_add_threshold(&mut self, threshold: FractionalThreshold) -> Result<()> {
-        FractionalThreshold::validate_non_overlapping_pair(self.add_threshold, threshold)?;
+    #[inline(always)]
+    pub fn set_not_add_threshold(&mut self, threshold: FractionalThreshold) {
         self.not_add_threshold = threshold;
-        Ok(())
     }
 
-    pub fn set_remove_threshold(&mut self, threshold: FractionalThreshold) -> Result<()> {
-        FractionalThreshold::validate_non_overlapping_pair(threshold, self.not_remove_threshold)?;
+    #[inline(always)]
+    pub fn set_remove_threshold(&mut self, threshold: FractionalThreshold) {
         self.remove_threshold = threshold;
-        Ok(())
     }
 
-    pub fn set_not_remove_threshold(&mut self, threshold: FractionalThreshold) -> Result<()> {
-        FractionalThreshold::validate_non_overlapping_pair(self.remove_threshold, threshold)?;
+    #[inline(always)]
+    pub fn set_not_remove_threshold(&mut self, threshold: FractionalThreshold) {
         self.not_remove_threshold = threshold;
-        Ok(())
     }
 
-    pub fn set_change_config_threshold(&mut self, threshold: FractionalThreshold) -> Result<()> {
-        FractionalThreshold::validate_non_overlapping_pair(
-            threshold,
-            self.not_change_config_threshold,
-        )?;
+    #[inline(always)]
+    pub fn set_change_config_threshold(&mut self, threshold: FractionalThreshold) {
         self.change_config_threshold = threshold;
-        Ok(())
     }
 
-    pub fn set_not_change_config_threshold(
-        &mut self,
-        threshold: FractionalThreshold,
-    ) -> Result<()> {
-        FractionalThreshold::validate_non_overlapping_pair(
-            self.change_config_threshold,
-            threshold,
-        )?;
+    #[inline(always)]
+    pub fn set_not_change_config_threshold(&mut self, threshold: FractionalThreshold) {
         self.not_change_config_threshold = threshold;
-        Ok(())
     }
 
     pub fn increment_member_count(&mut self) -> Result<()> {
@@ -170,16 +300,50 @@ impl Asset {
     }
 
     #[inline(always)]
-    pub fn set_minimum_vote_count(&mut self, count: u32) -> Result<()> {
-        Self::validate_minimum_vote_count(self.member_count, count)?;
+    pub fn set_minimum_vote_count(&mut self, count: u32) {
         self.minimum_vote_count = count;
-        Ok(())
     }
 
     #[inline(always)]
-    pub fn set_minimum_member_count(&mut self, count: u32) -> Result<()> {
-        Self::validate_minimum_member_count(self.member_count, count)?;
+    pub fn set_minimum_member_count(&mut self, count: u32) {
         self.minimum_member_count = count;
+    }
+
+    #[inline(always)]
+    pub fn set_minimum_timelock(&mut self, timelock: u32) {
+        self.minimum_timelock = timelock;
+    }
+
+    #[inline(always)]
+    pub fn set_maximum_proposal_duration(&mut self, duration: u32) {
+        self.maximum_proposal_duration = duration;
+    }
+
+    #[inline(always)]
+    pub fn set_config_change_timelocks(&mut self, timelocks: ConfigChangeTimelocks) {
+        self.config_change_timelocks = timelocks;
+    }
+
+    #[inline(always)]
+    pub fn set_require_execute_permission(&mut self, require: bool) {
+        self.require_execute_permission = require;
+    }
+
+    /// A tip of zero lamports disables it.
+    #[inline(always)]
+    pub fn set_executor_tip(&mut self, lamports: u64) {
+        self.executor_tip_lamports = lamports;
+    }
+
+    /// Rejects a proposal deadline further than `maximum_proposal_duration` from `now`.
+    pub fn validate_proposal_deadline(&self, now: i64, deadline: i64) -> Result<()> {
+        if self.maximum_proposal_duration > 0 {
+            require_gte!(
+                now.saturating_add(i64::from(self.maximum_proposal_duration)),
+                deadline,
+                MultisigError::ProposalDurationTooLong
+            );
+        }
         Ok(())
     }
 }
@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        error::MultisigError,
+        proposal::{ConfigProposal, NormalProposal, ProposalState},
+    },
+    utils::FractionalThreshold,
+};
+
+/// Bond a group asks of whoever pays for a new proposal, held until the proposal is settled.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub struct ProposalBondConfig {
+    /// Mint of the bond, usually one of the group's assets. Paid in lamports when not set.
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    /// Share of the weight voted against a failed proposal at which its bond is forfeited.
+    pub forfeit_threshold: FractionalThreshold,
+}
+
+impl ProposalBondConfig {
+    pub fn validate(&self) -> Result<()> {
+        require_gt!(self.amount, 0, MultisigError::InvalidProposalBond);
+        self.forfeit_threshold.is_valid()
+    }
+}
+
+/// Escrow of a proposal's bond. Lamport bonds are held on top of its rent, token bonds in
+/// the escrow token account it owns.
+#[account]
+#[derive(InitSpace)]
+pub struct ProposalBond {
+    pub group: Pubkey,
+    pub proposal: Pubkey,
+    /// Index the bonded proposal was created with, tells it apart from a later proposal
+    /// reusing its seed.
+    pub proposal_index: u64,
+    /// Paid the bond and the escrow rent, and gets both back unless the bond is forfeited.
+    pub depositor: Pubkey,
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub forfeit_threshold: FractionalThreshold,
+    pub account_bump: u8,
+}
+
+impl ProposalBond {
+    #[inline(always)]
+    pub fn new(
+        group: Pubkey,
+        proposal: Pubkey,
+        proposal_index: u64,
+        depositor: Pubkey,
+        config: &ProposalBondConfig,
+        account_bump: u8,
+    ) -> Self {
+        Self {
+            group,
+            proposal,
+            proposal_index,
+            depositor,
+            mint: config.mint,
+            amount: config.amount,
+            forfeit_threshold: config.forfeit_threshold,
+            account_bump,
+        }
+    }
+
+    /// Whether `against_weight` is at least `forfeit_threshold` of the weight that voted.
+    #[inline(always)]
+    fn is_overwhelming_rejection(&self, for_weight: u64, against_weight: u64) -> Result<bool> {
+        let total_weight = for_weight.saturating_add(against_weight);
+        if total_weight == 0 {
+            return Ok(false);
+        }
+        self.forfeit_threshold
+            .less_than_or_equal(against_weight, total_weight)
+    }
+
+    /// Whether a settled normal proposal forfeits the bond: it was cancelled, or it failed
+    /// and the members of one of its assets, or the group, rejected it overwhelmingly.
+    pub fn is_forfeited_by_normal_proposal(&self, proposal: &NormalProposal) -> Result<bool> {
+        match proposal.state {
+            ProposalState::Cancelled => Ok(true),
+            ProposalState::Failed => {
+                for asset in proposal.assets.iter() {
+                    if self.is_overwhelming_rejection(
+                        asset.use_vote_weight,
+                        asset.not_use_vote_weight,
+                    )? {
+                        return Ok(true);
+                    }
+                }
+                match proposal.group_approval.as_ref() {
+                    Some(group_approval) => self.is_overwhelming_rejection(
+                        group_approval.for_weight,
+                        group_approval.against_weight,
+                    ),
+                    None => Ok(false),
+                }
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Whether a settled config proposal forfeits the bond: it was cancelled, or it failed
+    /// and was rejected overwhelmingly, by one of its assets if it targets several.
+    pub fn is_forfeited_by_config_proposal(&self, proposal: &ConfigProposal) -> Result<bool> {
+        match proposal.state {
+            ProposalState::Cancelled => Ok(true),
+            ProposalState::Failed => {
+                if self.is_overwhelming_rejection(proposal.for_weight, proposal.against_weight)? {
+                    return Ok(true);
+                }
+                for asset in proposal.assets.iter() {
+                    if self.is_overwhelming_rejection(asset.for_weight, asset.against_weight)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            _ => Ok(false),
+        }
+    }
+}
@@ -1 +1,26 @@
 pub const MAX_ASSET_USE: usize = 10;
+pub const MAX_CONFIG_CHANGES: usize = 8;
+pub const MAX_EXECUTION_PRECONDITIONS: usize = 8;
+pub const MAX_PRECONDITION_DATA_LEN: usize = 64;
+pub const MAX_COMPACT_VOTES: usize = 64;
+/// Maximum number of instructions run by one `execute_proposal_transaction` call, so the
+/// execution receipt never grows by more than the 10240 bytes an account can be resized by
+/// in one instruction. Atomic proposals are limited to this many instructions.
+pub const MAX_INSTRUCTIONS_PER_EXECUTION: usize = 64;
+/// Maximum number of return data bytes kept in an execution receipt entry, longer return
+/// data is truncated so the entries of one call still fit in a single account resize.
+pub const MAX_RETURN_DATA_LEN: usize = 64;
+/// Largest executor tip an asset can pay for one proposal execution.
+pub const MAX_EXECUTOR_TIP_LAMPORTS: u64 = 10_000_000;
+/// Maximum number of options a multi-choice proposal can offer.
+pub const MAX_PROPOSAL_OPTIONS: usize = 8;
+/// Maximum number of assets a single config proposal can target.
+pub const MAX_CONFIG_PROPOSAL_ASSETS: usize = 20;
+/// Seconds an invited member has to accept their membership.
+pub const MEMBER_INVITE_DURATION: i64 = 7 * 24 * 60 * 60;
+/// Maximum length in bytes of a metadata name.
+pub const MAX_NAME_LEN: usize = 32;
+/// Maximum length in bytes of a metadata URI.
+pub const MAX_URI_LEN: usize = 64;
+/// Maximum length in bytes of a proposal title.
+pub const MAX_TITLE_LEN: usize = 64;
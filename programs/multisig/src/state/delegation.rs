@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+/// Lets `delegate` vote with the weight of `delegator` until revoked or expired.
+/// Seeds are `[b"delegation", group, delegator, scope]`, where the scope is the asset
+/// for asset weight and the group itself for group weight.
+#[account]
+#[derive(InitSpace)]
+pub struct Delegation {
+    pub group: Pubkey,
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    /// Asset whose member weight is delegated, `None` for the group member weight.
+    pub asset: Option<Pubkey>,
+    /// The delegation can no longer be used from this timestamp on, `None` if it never expires.
+    pub expiry_timestamp: Option<i64>,
+    pub account_bump: u8,
+}
+
+impl Delegation {
+    #[inline(always)]
+    pub fn new(
+        group: Pubkey,
+        delegator: Pubkey,
+        delegate: Pubkey,
+        asset: Option<Pubkey>,
+        expiry_timestamp: Option<i64>,
+        account_bump: u8,
+    ) -> Self {
+        Self {
+            group,
+            delegator,
+            delegate,
+            asset,
+            expiry_timestamp,
+            account_bump,
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_active(&self, now: i64) -> bool {
+        match self.expiry_timestamp {
+            Some(expiry) => now < expiry,
+            None => true,
+        }
+    }
+}
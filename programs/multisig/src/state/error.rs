@@ -140,6 +140,98 @@ pub enum MultisigError {
     InvalidTrustedMember,
     #[msg("Trusted members must be three distinct keys")]
     TrustedMembersNotUnique,
+    #[msg("Instruction count is invalid for this proposal's execution mode")]
+    InvalidInstructionCount,
+    #[msg("Execution receipt belongs to a previous proposal at this address")]
+    StaleExecutionReceipt,
+    #[msg("Too many instructions")]
+    TooManyInstructions,
+    #[msg("Balance guards must target distinct proposal assets")]
+    InvalidBalanceGuard,
+    #[msg("An account watched by a balance guard was not provided")]
+    BalanceGuardAccountNotProvided,
+    #[msg("A governed asset lost more than its balance guard allows")]
+    BalanceGuardViolated,
+    #[msg("Preconditions are empty, too large or too many")]
+    InvalidPrecondition,
+    #[msg("An account read by a precondition was not provided")]
+    PreconditionAccountNotProvided,
+    #[msg("A precondition of the proposal does not hold")]
+    PreconditionNotMet,
+    #[msg("The proposal does not carry a config change that needs group approval")]
+    GroupApprovalNotRequired,
+    #[msg("The group has not approved the proposal's config change")]
+    GroupApprovalNotMet,
+    #[msg("The proposal transaction invokes this program in a way that is not allowed")]
+    SelfInvocationNotAllowed,
+    #[msg("A payer was not provided")]
+    PayerNotProvided,
+    #[msg("Too many config changes in one proposal")]
+    TooManyConfigChanges,
+    #[msg("The delegation does not belong to this voter, group or scope")]
+    InvalidDelegation,
+    #[msg("The delegation has expired")]
+    DelegationExpired,
+    #[msg("The delegator has already voted on this proposal")]
+    DelegatorAlreadyVoted,
+    #[msg("The proposal does not store compact votes")]
+    CompactVotesNotEnabled,
+    #[msg("The proposal stores compact votes, vote records cannot be used")]
+    CompactVotesRequired,
+    #[msg("Too many possible votes for the proposal to store them compactly")]
+    TooManyCompactVotes,
+    #[msg("The signed votes do not match the Ed25519 signatures checked before this instruction")]
+    InvalidSignedVote,
+    #[msg("The member has already voted, signed votes cannot change a vote")]
+    VoteAlreadyRecorded,
+    #[msg("The member invite has expired")]
+    InviteExpired,
+    #[msg("The member invite has not expired yet")]
+    InviteNotExpired,
+    #[msg("The account is not the payer of the member invite")]
+    InvalidInvitePayer,
+    #[msg("The member's term has ended")]
+    MembershipExpired,
+    #[msg("The member's term has not ended yet")]
+    MembershipNotExpired,
+    #[msg("The proposal can no longer be cancelled")]
+    ProposalNotCancellable,
+    #[msg("The account described by the metadata still exists")]
+    MetadataSubjectStillExists,
+    #[msg("The account is not the payer of the metadata")]
+    InvalidMetadataPayer,
+    #[msg("The proposal title provided is greater than 64.")]
+    TitleTooLong,
+    #[msg("The proposal description can no longer be changed once voting has started")]
+    ProposalDescriptionLocked,
+    #[msg("Votes on a config proposal targeting several assets are cast per asset")]
+    PerAssetVoteRequired,
+    #[msg("Proposal deadline is further away than the maximum proposal duration")]
+    ProposalDurationTooLong,
+    #[msg("Invalid proposal bond")]
+    InvalidProposalBond,
+    #[msg("The group requires a proposal bond but its accounts were not provided")]
+    ProposalBondNotProvided,
+    #[msg("The proposal bond must be released before the proposal is closed")]
+    ProposalBondNotReleased,
+    #[msg("Too many proposals are open in the group or for the proposer")]
+    TooManyOpenProposals,
+    #[msg("A schedule needs a non-zero interval, run count and amount")]
+    InvalidSchedule,
+    #[msg("The next run of the schedule is not due yet")]
+    ScheduleNotDue,
+    #[msg("A multi-choice proposal needs 2 to 8 non-empty options on a single asset, without instruction hashes, a group config change or compact votes")]
+    InvalidProposalOptions,
+    #[msg("The option index does not match an option of the proposal")]
+    InvalidOptionIndex,
+    #[msg("Each option of a multi-choice proposal has its own transaction")]
+    OptionTransactionRequired,
+    #[msg("The executor tip is above the maximum")]
+    ExecutorTipTooLarge,
+    #[msg("The member's key was rotated after the proposal was created")]
+    KeyRotatedAfterProposal,
+    #[msg("The proposal metadata can no longer be changed once voting has started")]
+    ProposalMetadataLocked,
 }
 
 /// Implement Into<ProgramError> for MultisigError
@@ -0,0 +1,150 @@
+use anchor_lang::{prelude::*, solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE};
+
+use crate::state::constants::{MAX_ASSET_USE, MAX_INSTRUCTIONS_PER_EXECUTION, MAX_RETURN_DATA_LEN};
+
+/// Records how the transaction attached to a normal proposal was executed.
+/// The account grows by one entry for every instruction that is run.
+#[account]
+pub struct ExecutionReceipt {
+    pub proposal: Pubkey,
+    pub group: Pubkey,
+    pub proposal_index: u64,
+    /// Total number of instructions in the proposal transaction.
+    pub instruction_count: u8,
+    /// Balances watched by the proposal's balance guards, taken before the first
+    /// instruction ran, in the same order as the guards.
+    pub balance_baselines: Vec<u64>,
+    /// One entry per executed instruction, in execution order.
+    pub executed_instructions: Vec<ExecutedInstruction>,
+    pub account_bump: u8,
+}
+
+impl ExecutionReceipt {
+    #[inline(always)]
+    pub fn new(
+        proposal: Pubkey,
+        group: Pubkey,
+        proposal_index: u64,
+        instruction_count: u8,
+        balance_baselines: Vec<u64>,
+        account_bump: u8,
+    ) -> Self {
+        Self {
+            proposal,
+            group,
+            proposal_index,
+            instruction_count,
+            balance_baselines,
+            executed_instructions: Vec::new(),
+            account_bump,
+        }
+    }
+
+    #[inline(always)]
+    pub fn record(&mut self, executed_instruction: ExecutedInstruction) {
+        self.executed_instructions.push(executed_instruction);
+    }
+
+    #[inline(always)]
+    pub fn is_complete(&self) -> bool {
+        self.executed_instructions.len() == usize::from(self.instruction_count)
+    }
+
+    /// Serialized size of the receipt as it currently stands.
+    pub fn get_size(&self) -> usize {
+        32 + // proposal (Pubkey)
+        32 + // group (Pubkey)
+        8 +  // proposal_index (u64)
+        1 +  // instruction_count (u8)
+        (4 + 8 * self.balance_baselines.len()) + // balance_baselines (Vec<u64>)
+        4 + self
+            .executed_instructions
+            .iter()
+            .map(ExecutedInstruction::get_size)
+            .sum::<usize>() + // executed_instructions (Vec<ExecutedInstruction>)
+        1 // account_bump (u8)
+    }
+}
+
+/// Stores the outcome of a single instruction run by `execute_proposal_transaction`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ExecutedInstruction {
+    pub instruction_index: u8,
+    pub executor: Pubkey,
+    pub slot: u64,
+    /// Compute units consumed by the invocation, including the CPI overhead.
+    pub compute_units: u64,
+    /// Data returned through `set_return_data` by the invoked program, if any.
+    pub return_data: Option<InstructionReturnData>,
+}
+
+impl ExecutedInstruction {
+    #[inline(always)]
+    pub fn get_size(&self) -> usize {
+        1 +  // instruction_index (u8)
+        32 + // executor (Pubkey)
+        8 +  // slot (u64)
+        8 +  // compute_units (u64)
+        1 + self
+            .return_data
+            .as_ref()
+            .map_or(0, InstructionReturnData::get_size) // return_data (Option<InstructionReturnData>)
+    }
+
+    /// Largest serialized size of an entry, used to bound how much a receipt grows per call.
+    pub const MAX_SIZE: usize = 1 + 32 + 8 + 8 + 1 + InstructionReturnData::MAX_SIZE;
+}
+
+// The entries of one execution call, plus the rest of a new receipt with a guard of each
+// kind on every asset, must fit in a single account resize.
+const _: () = assert!(
+    8 + 32
+        + 32
+        + 8
+        + 1
+        + 4
+        + 8 * 2 * MAX_ASSET_USE
+        + 4
+        + 1
+        + MAX_INSTRUCTIONS_PER_EXECUTION * ExecutedInstruction::MAX_SIZE
+        <= MAX_PERMITTED_DATA_INCREASE
+);
+
+/// Return data is kept up to `MAX_RETURN_DATA_LEN` bytes, `data_len` is the length of the
+/// full data so a truncated entry can be told apart.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InstructionReturnData {
+    pub program_id: Pubkey,
+    pub data_len: u16,
+    pub data: Vec<u8>,
+}
+
+impl InstructionReturnData {
+    /// Largest serialized size, when the data is at least `MAX_RETURN_DATA_LEN` bytes.
+    pub const MAX_SIZE: usize = 32 + // program_id (Pubkey)
+        2 + // data_len (u16)
+        4 + MAX_RETURN_DATA_LEN; // data (Vec<u8>)
+
+    #[inline(always)]
+    pub fn new(program_id: Pubkey, data: &[u8]) -> Self {
+        Self {
+            program_id,
+            // Cannot truncate, return data is at most `MAX_RETURN_DATA` (1024) bytes.
+            data_len: data.len() as u16,
+            data: data[..data.len().min(MAX_RETURN_DATA_LEN)].to_vec(),
+        }
+    }
+
+    #[inline(always)]
+    pub fn get_size(&self) -> usize {
+        32 + // program_id (Pubkey)
+        2 + // data_len (u16)
+        4 + self.data.len() // data (Vec<u8>)
+    }
+
+    /// Whether the data was longer than what the receipt keeps.
+    #[inline(always)]
+    pub fn is_truncated(&self) -> bool {
+        usize::from(self.data_len) > self.data.len()
+    }
+}
@@ -1,4 +1,12 @@
-use crate::{utils::FractionalThreshold, MultisigError};
+use crate::{
+    state::{
+        bond::ProposalBondConfig,
+        member::GroupMember,
+        proposal::{ConfigChange, ConfigChangeTimelocks, ConfigType},
+    },
+    utils::FractionalThreshold,
+    MultisigError,
+};
 use anchor_lang::prelude::*;
 
 /// Stores information required to govern a group
@@ -27,8 +35,24 @@ pub struct Group {
     pub minimum_vote_count: u32,
     pub max_member_weight: u32,
     pub member_count: u32,
+    /// Index given to the next group member, indices are never reused.
+    pub next_member_index: u32,
     pub minimum_timelock: u32,
-
+    /// Longest time in seconds a proposal can stay open for votes, zero for no limit.
+    pub maximum_proposal_duration: u32,
+    pub config_change_timelocks: ConfigChangeTimelocks,
+    /// Bond escrowed when a proposal is created, refunded once it is settled unless it was
+    /// cancelled or rejected by `forfeit_threshold` of the votes.
+    pub proposal_bond: Option<ProposalBondConfig>,
+    /// Most proposals open at once in the group, zero for no limit.
+    pub max_open_proposals: u32,
+    /// Most proposals each member can have open at once, zero for no limit.
+    pub max_open_proposals_per_member: u32,
+    /// Proposals created and not yet executed or closed.
+    pub open_proposal_count: u32,
+
+    /// Only group members with the Execute permission may execute normal proposals.
+    pub require_execute_permission: bool,
     pub paused: bool,
     pub account_bump: u8,
 }
@@ -65,18 +89,8 @@ impl Group {
         member_count: u32,
         account_bump: u8,
     ) -> Result<Self> {
-        // Threshold checks
-        FractionalThreshold::validate_non_overlapping_pair(add_threshold, not_add_threshold)?;
-        FractionalThreshold::validate_non_overlapping_pair(remove_threshold, not_remove_threshold)?;
-        FractionalThreshold::validate_non_overlapping_pair(
-            change_config_threshold,
-            not_change_config_threshold,
-        )?;
-
         require_gt!(member_count, 0, MultisigError::InvalidMemberCount);
         require_gt!(max_member_weight, 0, MultisigError::InvalidMemberWeight);
-        Self::validate_minimum_vote_count(member_count, minimum_vote_count)?;
-        Self::validate_minimum_member_count(member_count, minimum_member_count)?;
 
         let group = Self {
             next_proposal_index: 0,
@@ -91,62 +105,81 @@ impl Group {
             minimum_vote_count,
             max_member_weight,
             member_count,
+            // The initial members take the indices before `member_count`.
+            next_member_index: member_count,
             minimum_timelock,
+            maximum_proposal_duration: 0,
+            config_change_timelocks: ConfigChangeTimelocks::default(),
+            proposal_bond: None,
+            max_open_proposals: 0,
+            max_open_proposals_per_member: 0,
+            open_proposal_count: 0,
             group_seed,
             rent_collector,
             reset_trusted_1: Pubkey::default(),
             reset_trusted_2: Pubkey::default(),
             reset_trusted_3: Pubkey::default(),
+            require_execute_permission: false,
             paused: false,
             account_bump,
         };
+        group.validate_config()?;
 
         Ok(group)
     }
 
-    pub fn set_add_threshold(&mut self, threshold: FractionalThreshold) -> Result<()> {
-        FractionalThreshold::validate_non_overlapping_pair(threshold, self.not_add_threshold)?;
+    /// Checks the fields that constrain each other, setters don't so that related
+    /// fields can be changed together before the result is validated.
+    pub fn validate_config(&self) -> Result<()> {
+        FractionalThreshold::validate_non_overlapping_pair(
+            self.add_threshold,
+            self.not_add_threshold,
+        )?;
+        FractionalThreshold::validate_non_overlapping_pair(
+            self.remove_threshold,
+            self.not_remove_threshold,
+        )?;
+        FractionalThreshold::validate_non_overlapping_pair(
+            self.change_config_threshold,
+            self.not_change_config_threshold,
+        )?;
+
+        if let Some(proposal_bond) = self.proposal_bond.as_ref() {
+            proposal_bond.validate()?;
+        }
+
+        Self::validate_minimum_vote_count(self.member_count, self.minimum_vote_count)?;
+        Self::validate_minimum_member_count(self.member_count, self.minimum_member_count)
+    }
+
+    #[inline(always)]
+    pub fn set_add_threshold(&mut self, threshold: FractionalThreshold) {
         self.add_threshold = threshold;
-        Ok(())
     }
 
-    pub fn set_not_add_threshold(&mut self, threshold: FractionalThreshold) -> Result<()> {
-        FractionalThreshold::validate_non_overlapping_pair(self.add_threshold, threshold)?;
+    #[inline(always)]
+    pub fn set_not_add_threshold(&mut self, threshold: FractionalThreshold) {
         self.not_add_threshold = threshold;
-        Ok(())
     }
 
-    pub fn set_remove_threshold(&mut self, threshold: FractionalThreshold) -> Result<()> {
-        FractionalThreshold::validate_non_overlapping_pair(threshold, self.not_remove_threshold)?;
+    #[inline(always)]
+    pub fn set_remove_threshold(&mut self, threshold: FractionalThreshold) {
         self.remove_threshold = threshold;
-        Ok(())
     }
 
-    pub fn set_not_remove_threshold(&mut self, threshold: FractionalThreshold) -> Result<()> {
-        FractionalThreshold::validate_non_overlapping_pair(self.remove_threshold, threshold)?;
+    #[inline(always)]
+    pub fn set_not_remove_threshold(&mut self, threshold: FractionalThreshold) {
         self.not_remove_threshold = threshold;
-        Ok(())
     }
 
-    pub fn set_change_config_threshold(&mut self, threshold: FractionalThreshold) -> Result<()> {
-        FractionalThreshold::validate_non_overlapping_pair(
-            threshold,
-            self.not_change_config_threshold,
-        )?;
+    #[inline(always)]
+    pub fn set_change_config_threshold(&mut self, threshold: FractionalThreshold) {
         self.change_config_threshold = threshold;
-        Ok(())
     }
 
-    pub fn set_not_change_config_threshold(
-        &mut self,
-        threshold: FractionalThreshold,
-    ) -> Result<()> {
-        FractionalThreshold::validate_non_overlapping_pair(
-            self.change_config_threshold,
-            threshold,
-        )?;
+    #[inline(always)]
+    pub fn set_not_change_config_threshold(&mut self, threshold: FractionalThreshold) {
         self.not_change_config_threshold = threshold;
-        Ok(())
     }
 
     #[inline(always)]
@@ -158,6 +191,16 @@ impl Group {
         Ok(())
     }
 
+    /// Hands out the index of a new group member.
+    #[inline(always)]
+    pub fn take_member_index(&mut self) -> Result<u32> {
+        let member_index = self.next_member_index;
+        self.next_member_index = member_index
+            .checked_add(1)
+            .ok_or(MultisigError::TooManyMembers)?;
+        Ok(member_index)
+    }
+
     pub fn decrement_member_count(&mut self) -> Result<()> {
         let new_count = self.member_count.saturating_sub(1);
 
@@ -171,17 +214,13 @@ impl Group {
     }
 
     #[inline(always)]
-    pub fn set_minimum_vote_count(&mut self, count: u32) -> Result<()> {
-        Self::validate_minimum_vote_count(self.member_count, count)?;
+    pub fn set_minimum_vote_count(&mut self, count: u32) {
         self.minimum_vote_count = count;
-        Ok(())
     }
 
     #[inline(always)]
-    pub fn set_minimum_member_count(&mut self, count: u32) -> Result<()> {
-        Self::validate_minimum_member_count(self.member_count, count)?;
+    pub fn set_minimum_member_count(&mut self, count: u32) {
         self.minimum_member_count = count;
-        Ok(())
     }
 
     #[inline(always)]
@@ -189,6 +228,181 @@ impl Group {
         self.minimum_timelock = timelock;
     }
 
+    #[inline(always)]
+    pub fn set_maximum_proposal_duration(&mut self, duration: u32) {
+        self.maximum_proposal_duration = duration;
+    }
+
+    #[inline(always)]
+    pub fn set_config_change_timelocks(&mut self, timelocks: ConfigChangeTimelocks) {
+        self.config_change_timelocks = timelocks;
+    }
+
+    #[inline(always)]
+    pub fn set_proposal_bond(&mut self, proposal_bond: Option<ProposalBondConfig>) {
+        self.proposal_bond = proposal_bond;
+    }
+
+    #[inline(always)]
+    pub fn set_max_open_proposals(&mut self, count: u32) {
+        self.max_open_proposals = count;
+    }
+
+    #[inline(always)]
+    pub fn set_max_open_proposals_per_member(&mut self, count: u32) {
+        self.max_open_proposals_per_member = count;
+    }
+
+    /// Counts a new proposal of `proposer` as open, failing if the group or the proposer
+    /// already reached their limit.
+    pub fn open_proposal(&mut self, proposer: &mut GroupMember) -> Result<()> {
+        if self.max_open_proposals > 0 {
+            require_gt!(
+                self.max_open_proposals,
+                self.open_proposal_count,
+                MultisigError::TooManyOpenProposals
+            );
+        }
+        if self.max_open_proposals_per_member > 0 {
+            require_gt!(
+                self.max_open_proposals_per_member,
+                proposer.open_proposal_count,
+                MultisigError::TooManyOpenProposals
+            );
+        }
+
+        self.open_proposal_count = self
+            .open_proposal_count
+            .checked_add(1)
+            .ok_or(MultisigError::TooManyOpenProposals)?;
+        proposer.open_proposal_count = proposer
+            .open_proposal_count
+            .checked_add(1)
+            .ok_or(MultisigError::TooManyOpenProposals)?;
+        Ok(())
+    }
+
+    /// Stops counting a proposal as open once it was executed or closed.
+    #[inline(always)]
+    pub fn close_open_proposal(&mut self) {
+        self.open_proposal_count = self.open_proposal_count.saturating_sub(1);
+    }
+
+    #[inline(always)]
+    pub fn set_require_execute_permission(&mut self, require: bool) {
+        self.require_execute_permission = require;
+    }
+
+    /// Rejects a proposal deadline further than `maximum_proposal_duration` from `now`.
+    pub fn validate_proposal_deadline(&self, now: i64, deadline: i64) -> Result<()> {
+        if self.maximum_proposal_duration > 0 {
+            require_gte!(
+                now.saturating_add(i64::from(self.maximum_proposal_duration)),
+                deadline,
+                MultisigError::ProposalDurationTooLong
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns the group with a batch of config changes applied in order,
+    /// the resulting config is validated as a whole.
+    pub fn with_config_types(&self, config_types: &[ConfigType]) -> Result<Self> {
+        let mut group = self.clone();
+
+        for config_type in config_types {
+            match config_type {
+                ConfigType::AddMember(threshold) => group.set_add_threshold(*threshold),
+                ConfigType::NotAddMember(threshold) => group.set_not_add_threshold(*threshold),
+                ConfigType::RemoveMember(threshold) => group.set_remove_threshold(*threshold),
+                ConfigType::NotRemoveMember(threshold) => {
+                    group.set_not_remove_threshold(*threshold)
+                }
+                ConfigType::ChangeConfig(threshold) => {
+                    group.set_change_config_threshold(*threshold)
+                }
+                ConfigType::NotChangeConfig(threshold) => {
+                    group.set_not_change_config_threshold(*threshold)
+                }
+                ConfigType::MinimumMemberCount(count) => group.set_minimum_member_count(*count),
+                ConfigType::MinimumVoteCount(count) => group.set_minimum_vote_count(*count),
+                ConfigType::MinimumTimelock(timelock) => group.set_minimum_timelock(*timelock),
+                ConfigType::MaximumProposalDuration(duration) => {
+                    group.set_maximum_proposal_duration(*duration)
+                }
+                ConfigType::ConfigChangeTimelocks(timelocks) => {
+                    group.set_config_change_timelocks(*timelocks)
+                }
+                ConfigType::RequireExecutePermission(require) => {
+                    group.set_require_execute_permission(*require)
+                }
+                ConfigType::ProposalBond(proposal_bond) => group.set_proposal_bond(*proposal_bond),
+                ConfigType::MaxOpenProposals(count) => group.set_max_open_proposals(*count),
+                ConfigType::MaxOpenProposalsPerMember(count) => {
+                    group.set_max_open_proposals_per_member(*count)
+                }
+                ConfigType::AddMemberThresholds { pass, fail } => {
+                    group.set_add_threshold(*pass);
+                    group.set_not_add_threshold(*fail);
+                }
+                ConfigType::RemoveMemberThresholds { pass, fail } => {
+                    group.set_remove_threshold(*pass);
+                    group.set_not_remove_threshold(*fail);
+                }
+                ConfigType::ChangeConfigThresholds { pass, fail } => {
+                    group.set_change_config_threshold(*pass);
+                    group.set_not_change_config_threshold(*fail);
+                }
+                ConfigType::Use(_)
+                | ConfigType::NotUse(_)
+                | ConfigType::UseThresholds { .. }
+                | ConfigType::ExecutorTip(_) => {
+                    return Err(MultisigError::UnexpectedConfigChange.into())
+                }
+            }
+        }
+
+        group.validate_config()?;
+
+        Ok(group)
+    }
+
+    /// Applies a batch of config changes, nothing is changed if the batch is rejected.
+    pub fn apply_config_types(&mut self, config_types: &[ConfigType]) -> Result<()> {
+        *self = self.with_config_types(config_types)?;
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn threshold_pairs(&self) -> [(FractionalThreshold, FractionalThreshold); 3] {
+        [
+            (self.add_threshold, self.not_add_threshold),
+            (self.remove_threshold, self.not_remove_threshold),
+            (
+                self.change_config_threshold,
+                self.not_change_config_threshold,
+            ),
+        ]
+    }
+
+    /// Least timelock of a proposal making `config_change` to the group, the strictest of
+    /// `minimum_timelock` and the timelock of each kind of change it makes.
+    pub fn config_change_timelock(&self, config_change: &ConfigChange) -> Result<u32> {
+        let timelocks = &self.config_change_timelocks;
+        let change_timelock = match config_change {
+            ConfigChange::AddGroupMember { .. } => timelocks.add_member,
+            ConfigChange::RemoveGroupMember { .. } => timelocks.remove_member,
+            ConfigChange::ChangeGroupConfig { config_types } => {
+                let proposed = self.with_config_types(config_types)?;
+                timelocks
+                    .for_thresholds(&self.threshold_pairs(), &proposed.threshold_pairs())
+                    .max(timelocks.for_timelocks(&proposed.config_change_timelocks))
+            }
+            _ => 0,
+        };
+        Ok(self.minimum_timelock.max(change_timelock))
+    }
+
     /// Decrement member count without enforcing minimum thresholds.
     #[inline(always)]
     pub fn force_decrement_member_count(&mut self) {
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{Permissions, MEMBER_INVITE_DURATION};
+
+/// Pending membership created by a passed AddGroupMember change, the invited key
+/// becomes a group member once it signs `accept_membership`.
+/// Seeds are `[b"invite", group, user]`.
+#[account]
+#[derive(InitSpace)]
+pub struct MemberInvite {
+    pub user: Pubkey,
+    pub group: Pubkey,
+    pub weight: u32,
+    pub permissions: Permissions,
+    /// End of the term of the membership, `None` for a permanent one.
+    pub expires_at: Option<i64>,
+    /// Paid for the invite and receives its rent back when it is accepted or closed.
+    pub payer: Pubkey,
+    /// The invite can no longer be accepted from this timestamp on.
+    pub expiry_timestamp: i64,
+    pub account_bump: u8,
+}
+
+impl MemberInvite {
+    #[allow(clippy::too_many_arguments)]
+    #[inline(always)]
+    pub fn new(
+        user: Pubkey,
+        group: Pubkey,
+        permissions: Permissions,
+        weight: u32,
+        expires_at: Option<i64>,
+        payer: Pubkey,
+        now: i64,
+        account_bump: u8,
+    ) -> Result<Self> {
+        permissions.is_valid()?;
+
+        let expiry_timestamp = now
+            .checked_add(MEMBER_INVITE_DURATION)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        Ok(Self {
+            user,
+            group,
+            weight,
+            permissions,
+            expires_at,
+            payer,
+            expiry_timestamp,
+            account_bump,
+        })
+    }
+
+    #[inline(always)]
+    pub fn is_active(&self, now: i64) -> bool {
+        now < self.expiry_timestamp
+    }
+}
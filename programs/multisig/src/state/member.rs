@@ -12,6 +12,11 @@ pub struct AssetMember {
     pub weight: u32,
     pub permissions: Permissions,
     pub account_bump: u8,
+    /// End of the member's term, they can no longer vote from this timestamp on.
+    /// `None` for members without a term.
+    pub expires_at: Option<i64>,
+    /// When the membership last moved to a new key, `None` if it never did.
+    pub key_rotated_at: Option<i64>,
 }
 
 /// Stores information about a group member
@@ -23,6 +28,32 @@ pub struct GroupMember {
     pub weight: u32,
     pub permissions: Permissions,
     pub account_bump: u8,
+    /// Stable index of the member within the group, used to key compact votes.
+    pub member_index: u32,
+    /// Number of `AssetMember` accounts held by the member, all of them move on key rotation.
+    pub asset_member_count: u32,
+    /// End of the member's term, they can no longer vote or propose from this timestamp on.
+    /// `None` for members without a term.
+    pub expires_at: Option<i64>,
+    /// Proposals created by the member that are not yet executed or closed.
+    pub open_proposal_count: u32,
+    /// When the membership last moved to a new key, `None` if it never did.
+    pub key_rotated_at: Option<i64>,
+}
+
+/// Whether a membership ending at `expires_at` is still running at `now`.
+#[inline(always)]
+fn term_is_active(expires_at: Option<i64>, now: i64) -> bool {
+    match expires_at {
+        Some(expires_at) => now < expires_at,
+        None => true,
+    }
+}
+
+/// Whether a membership rotated at `key_rotated_at` got its key at or after `timestamp`.
+#[inline(always)]
+fn key_rotated_since(key_rotated_at: Option<i64>, timestamp: i64) -> bool {
+    key_rotated_at.is_some_and(|key_rotated_at| key_rotated_at >= timestamp)
 }
 
 impl AssetMember {
@@ -53,9 +84,34 @@ impl AssetMember {
             permissions,
             weight,
             account_bump,
+            expires_at: None,
+            key_rotated_at: None,
         })
     }
 
+    /// The same membership held by `user` from `now`, stored at the PDA with `account_bump`.
+    #[inline(always)]
+    pub fn rotated(&self, user: Pubkey, account_bump: u8, now: i64) -> Self {
+        Self {
+            user,
+            account_bump,
+            key_rotated_at: Some(now),
+            ..self.clone()
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_active(&self, now: i64) -> bool {
+        term_is_active(self.expires_at, now)
+    }
+
+    /// Vote records are keyed by the voter, so a key rotated in after a proposal was created
+    /// cannot vote on it, the old key may already have.
+    #[inline(always)]
+    pub fn can_vote_on_proposal_from(&self, propose_timestamp: i64) -> bool {
+        !key_rotated_since(self.key_rotated_at, propose_timestamp)
+    }
+
     #[inline(always)]
     pub fn set_weight(&mut self, weight: u32, max_weight: u32) -> Result<()> {
         Self::validate_weight(weight, max_weight)?;
@@ -82,9 +138,46 @@ impl AssetMember {
     pub fn set_add_asset(&mut self, enable: bool) {
         self.permissions.set_add_asset(enable);
     }
+
+    #[inline(always)]
+    pub fn has_execute(&self) -> bool {
+        self.permissions.has_execute()
+    }
+
+    #[inline(always)]
+    pub fn set_execute(&mut self, enable: bool) {
+        self.permissions.set_execute(enable);
+    }
+
+    #[inline(always)]
+    pub fn set_permissions(&mut self, permissions: Permissions) -> Result<()> {
+        permissions.is_valid()?;
+        self.permissions = permissions;
+        Ok(())
+    }
 }
 
 impl GroupMember {
+    #[inline(always)]
+    pub fn increment_asset_member_count(&mut self) -> Result<()> {
+        self.asset_member_count = self
+            .asset_member_count
+            .checked_add(1)
+            .ok_or(MultisigError::TooManyMembers)?;
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn decrement_asset_member_count(&mut self) {
+        self.asset_member_count = self.asset_member_count.saturating_sub(1);
+    }
+
+    /// Stops counting one of the member's proposals as open.
+    #[inline(always)]
+    pub fn close_open_proposal(&mut self) {
+        self.open_proposal_count = self.open_proposal_count.saturating_sub(1);
+    }
+
     #[inline(always)]
     fn validate_weight(weight: u32, max_weight: u32) -> Result<()> {
         require_gt!(weight, 0, MultisigError::InvalidMemberWeight);
@@ -98,6 +191,7 @@ impl GroupMember {
         group: Pubkey,
         permissions: Permissions,
         weight: u32,
+        member_index: u32,
         account_bump: u8,
         max_weight: u32,
     ) -> Result<Self> {
@@ -110,9 +204,41 @@ impl GroupMember {
             permissions,
             weight,
             account_bump,
+            member_index,
+            asset_member_count: 0,
+            expires_at: None,
+            open_proposal_count: 0,
+            key_rotated_at: None,
         })
     }
 
+    /// The same membership held by `user` from `now`, stored at the PDA with `account_bump`.
+    /// The member index is kept, so compact votes cast with the old key still count.
+    /// Proposals opened with the old key are released against the closed old account,
+    /// so they no longer count toward the member's open proposals.
+    #[inline(always)]
+    pub fn rotated(&self, user: Pubkey, account_bump: u8, now: i64) -> Self {
+        Self {
+            user,
+            account_bump,
+            open_proposal_count: 0,
+            key_rotated_at: Some(now),
+            ..self.clone()
+        }
+    }
+
+    #[inline(always)]
+    pub fn is_active(&self, now: i64) -> bool {
+        term_is_active(self.expires_at, now)
+    }
+
+    /// Vote records are keyed by the voter, so a key rotated in after a proposal was created
+    /// cannot vote on it, the old key may already have.
+    #[inline(always)]
+    pub fn can_vote_on_proposal_from(&self, propose_timestamp: i64) -> bool {
+        !key_rotated_since(self.key_rotated_at, propose_timestamp)
+    }
+
     #[inline(always)]
     pub fn set_weight(&mut self, weight: u32, max_weight: u32) -> Result<()> {
         Self::validate_weight(weight, max_weight)?;
@@ -139,14 +265,50 @@ impl GroupMember {
     pub fn set_add_asset(&mut self, enable: bool) {
         self.permissions.set_add_asset(enable);
     }
+
+    #[inline(always)]
+    pub fn has_execute(&self) -> bool {
+        self.permissions.has_execute()
+    }
+
+    #[inline(always)]
+    pub fn set_execute(&mut self, enable: bool) {
+        self.permissions.set_execute(enable);
+    }
+
+    #[inline(always)]
+    pub fn has_propose_config(&self) -> bool {
+        self.permissions.has_propose_config()
+    }
+
+    #[inline(always)]
+    pub fn has_cancel(&self) -> bool {
+        self.permissions.has_cancel()
+    }
+
+    #[inline(always)]
+    pub fn has_emergency_reset(&self) -> bool {
+        self.permissions.has_emergency_reset()
+    }
+
+    #[inline(always)]
+    pub fn set_permissions(&mut self, permissions: Permissions) -> Result<()> {
+        permissions.is_valid()?;
+        self.permissions = permissions;
+        Ok(())
+    }
 }
 
 // Stores permissions with a bit flag
-/// 1 << ? - Vote - Anyone with a weight > 0 can vote.
-/// 1 << ? - Execute - If the proposal passed then anyone can execute the transaction,
-/// but whatever rent they pay would not be returned to them but to the rent collector.
-/// 1 << 0 - Propose
+/// Anyone with a weight > 0 can vote.
+/// 1 << 0 - Propose - Create normal proposals
 /// 1 << 1 - Add asset
+/// 1 << 2 - Execute - Only checked when the group or an asset used by the proposal
+/// requires it, otherwise anyone can execute a passed proposal.
+/// 1 << 3 - Propose config - Create config proposals
+/// 1 << 4 - Cancel - Cancel open or passed proposals before they are executed
+/// 1 << 5 - Emergency reset - Create emergency reset proposals
+/// 1 << 6 - Manage spending limits - Reserved for spending limit instructions
 #[derive(AnchorDeserialize, AnchorSerialize, InitSpace, Clone, Copy)]
 pub struct Permissions {
     permissions: u8,
@@ -168,55 +330,121 @@ impl Permissions {
     pub fn from_unchecked(value: u8) -> Self {
         Permissions { permissions: value }
     }
+}
 
-    pub fn from_flags(propose: bool, add_asset: bool) -> Permissions {
-        let mut flag = 0b00000000u8;
+impl Permissions {
+    const VALID_STATE_MASK: u8 = 0b10000000;
 
-        if propose {
-            flag |= 0b00000001u8;
-        }
+    const PROPOSE: u8 = 1 << 0;
+    const ADD_ASSET: u8 = 1 << 1;
+    const EXECUTE: u8 = 1 << 2;
+    const PROPOSE_CONFIG: u8 = 1 << 3;
+    const CANCEL: u8 = 1 << 4;
+    const EMERGENCY_RESET: u8 = 1 << 5;
+    const MANAGE_SPENDING_LIMITS: u8 = 1 << 6;
 
-        if add_asset {
-            flag |= 0b00000010u8;
-        }
+    /// Start building a set of permissions, every flag is unset.
+    #[inline(always)]
+    pub fn builder() -> PermissionsBuilder {
+        PermissionsBuilder::default()
+    }
 
-        Permissions { permissions: flag }
+    #[inline(always)]
+    fn has(&self, flag: u8) -> bool {
+        (self.permissions & flag) != 0
     }
-}
 
-impl Permissions {
-    const VALID_STATE_MASK: u8 = 0b11111100;
+    #[inline]
+    fn set(&mut self, flag: u8, enable: bool) {
+        if enable {
+            self.permissions |= flag;
+        } else {
+            self.permissions &= !flag;
+        }
+    }
 
     /// Check if "Propose" permission is set
     #[inline(always)]
     pub fn has_propose(&self) -> bool {
-        (self.permissions & (1 << 0)) != 0
+        self.has(Self::PROPOSE)
     }
 
     /// Set or unset "Propose" permission
     #[inline]
     pub fn set_propose(&mut self, enable: bool) {
-        if enable {
-            self.permissions |= 1 << 0;
-        } else {
-            self.permissions &= !(1 << 0);
-        }
+        self.set(Self::PROPOSE, enable);
     }
 
     /// Check if "Add asset" permission is set
     #[inline(always)]
     pub fn has_add_asset(&self) -> bool {
-        (self.permissions & (1 << 1)) != 0
+        self.has(Self::ADD_ASSET)
     }
 
     /// Set or unset "Add asset" permission
     #[inline]
     pub fn set_add_asset(&mut self, enable: bool) {
-        if enable {
-            self.permissions |= 1 << 1;
-        } else {
-            self.permissions &= !(1 << 1);
-        }
+        self.set(Self::ADD_ASSET, enable);
+    }
+
+    /// Check if "Execute" permission is set
+    #[inline(always)]
+    pub fn has_execute(&self) -> bool {
+        self.has(Self::EXECUTE)
+    }
+
+    /// Set or unset "Execute" permission
+    #[inline]
+    pub fn set_execute(&mut self, enable: bool) {
+        self.set(Self::EXECUTE, enable);
+    }
+
+    /// Check if "Propose config" permission is set
+    #[inline(always)]
+    pub fn has_propose_config(&self) -> bool {
+        self.has(Self::PROPOSE_CONFIG)
+    }
+
+    /// Set or unset "Propose config" permission
+    #[inline]
+    pub fn set_propose_config(&mut self, enable: bool) {
+        self.set(Self::PROPOSE_CONFIG, enable);
+    }
+
+    /// Check if "Cancel" permission is set
+    #[inline(always)]
+    pub fn has_cancel(&self) -> bool {
+        self.has(Self::CANCEL)
+    }
+
+    /// Set or unset "Cancel" permission
+    #[inline]
+    pub fn set_cancel(&mut self, enable: bool) {
+        self.set(Self::CANCEL, enable);
+    }
+
+    /// Check if "Emergency reset" permission is set
+    #[inline(always)]
+    pub fn has_emergency_reset(&self) -> bool {
+        self.has(Self::EMERGENCY_RESET)
+    }
+
+    /// Set or unset "Emergency reset" permission
+    #[inline]
+    pub fn set_emergency_reset(&mut self, enable: bool) {
+        self.set(Self::EMERGENCY_RESET, enable);
+    }
+
+    /// Check if "Manage spending limits" permission is set
+    #[inline(always)]
+    pub fn has_manage_spending_limits(&self) -> bool {
+        self.has(Self::MANAGE_SPENDING_LIMITS)
+    }
+
+    /// Set or unset "Manage spending limits" permission
+    #[inline]
+    pub fn set_manage_spending_limits(&mut self, enable: bool) {
+        self.set(Self::MANAGE_SPENDING_LIMITS, enable);
     }
 
     #[inline]
@@ -228,3 +456,57 @@ impl Permissions {
         Ok(())
     }
 }
+
+/// Builds a [`Permissions`] value flag by flag, e.g.
+/// `Permissions::builder().propose(true).execute(true).build()`.
+#[derive(Default, Clone, Copy)]
+pub struct PermissionsBuilder {
+    permissions: u8,
+}
+
+impl PermissionsBuilder {
+    #[inline]
+    fn with(mut self, flag: u8, enable: bool) -> Self {
+        if enable {
+            self.permissions |= flag;
+        } else {
+            self.permissions &= !flag;
+        }
+        self
+    }
+
+    pub fn propose(self, enable: bool) -> Self {
+        self.with(Permissions::PROPOSE, enable)
+    }
+
+    pub fn add_asset(self, enable: bool) -> Self {
+        self.with(Permissions::ADD_ASSET, enable)
+    }
+
+    pub fn execute(self, enable: bool) -> Self {
+        self.with(Permissions::EXECUTE, enable)
+    }
+
+    pub fn propose_config(self, enable: bool) -> Self {
+        self.with(Permissions::PROPOSE_CONFIG, enable)
+    }
+
+    pub fn cancel(self, enable: bool) -> Self {
+        self.with(Permissions::CANCEL, enable)
+    }
+
+    pub fn emergency_reset(self, enable: bool) -> Self {
+        self.with(Permissions::EMERGENCY_RESET, enable)
+    }
+
+    pub fn manage_spending_limits(self, enable: bool) -> Self {
+        self.with(Permissions::MANAGE_SPENDING_LIMITS, enable)
+    }
+
+    /// The builder only sets known flags, so the result is always valid.
+    pub fn build(self) -> Permissions {
+        Permissions {
+            permissions: self.permissions,
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{MultisigError, MAX_NAME_LEN, MAX_URI_LEN};
+
+/// Display information shown by clients for a group, group member, asset or proposal.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Eq)]
+pub struct MetadataContent {
+    #[max_len(MAX_NAME_LEN)]
+    pub name: String,
+    #[max_len(MAX_URI_LEN)]
+    pub uri: String,
+    /// Hash of a longer description stored off-chain, e.g. behind `uri`.
+    pub description_hash: [u8; 32],
+}
+
+impl MetadataContent {
+    #[inline(always)]
+    pub fn validate(&self) -> Result<()> {
+        require_gte!(MAX_NAME_LEN, self.name.len(), MultisigError::NameTooLong);
+        require_gte!(MAX_URI_LEN, self.uri.len(), MultisigError::UriTooLong);
+        Ok(())
+    }
+}
+
+/// Optional metadata of the account at `subject`, a group, group member, asset or proposal.
+/// Seeds are `[b"metadata", subject]`.
+#[account]
+#[derive(InitSpace)]
+pub struct Metadata {
+    pub subject: Pubkey,
+    pub group: Pubkey,
+    pub content: MetadataContent,
+    /// Paid for the account and receives its rent back when it is closed.
+    pub payer: Pubkey,
+    pub account_bump: u8,
+}
+
+impl Metadata {
+    /// Replaces the content, the first call also records who the metadata belongs to.
+    /// `init_if_needed` leaves a new account zeroed, so an unset subject marks it as new.
+    #[inline(always)]
+    pub fn set(
+        &mut self,
+        subject: Pubkey,
+        group: Pubkey,
+        content: MetadataContent,
+        payer: Pubkey,
+        account_bump: u8,
+    ) -> Result<()> {
+        content.validate()?;
+
+        if self.subject == Pubkey::default() {
+            self.subject = subject;
+            self.group = group;
+            self.payer = payer;
+            self.account_bump = account_bump;
+        }
+        self.content = content;
+
+        Ok(())
+    }
+}
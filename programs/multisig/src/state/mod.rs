@@ -10,11 +10,29 @@ pub use asset::*;
 pub mod vote;
 pub use vote::*;
 
+pub mod execution_receipt;
+pub use execution_receipt::*;
+
+pub mod delegation;
+pub use delegation::*;
+
 pub mod member;
 pub use member::*;
 
+pub mod invite;
+pub use invite::*;
+
+pub mod metadata;
+pub use metadata::*;
+
+pub mod bond;
+pub use bond::*;
+
 pub mod constants;
 pub use constants::*;
 
 pub mod error;
 pub use error::*;
+
+pub mod schedule;
+pub use schedule::*;
@@ -1,7 +1,14 @@
-use std::ops::AddAssign;
+use std::{cmp::Ordering, ops::AddAssign};
 
 use crate::{
-    state::{error::MultisigError, group::Group, Asset},
+    state::{
+        constants::{MAX_CONFIG_CHANGES, MAX_CONFIG_PROPOSAL_ASSETS, MAX_TITLE_LEN, MAX_URI_LEN},
+        error::MultisigError,
+        group::Group,
+        metadata::MetadataContent,
+        vote::CompactVote,
+        Asset, ProposalBondConfig,
+    },
     utils::FractionalThreshold,
     Permissions,
 };
@@ -13,6 +20,14 @@ pub struct NormalProposal {
     pub assets: Vec<ProposalAsset>,
     /// Hashes of each instruction in the proposal transaction, in order.
     pub instruction_hashes: Vec<[u8; HASH_BYTES_LENGTH]>,
+    /// Limits on how much the governed assets may lose while the transaction is executed.
+    pub balance_guards: Vec<BalanceGuard>,
+    /// Conditions that must hold every time the transaction is executed.
+    pub preconditions: Vec<ExecutionPrecondition>,
+    /// Group vote on the config change applied by the transaction, if it carries one.
+    pub group_approval: Option<GroupApproval>,
+    /// Asset votes kept in the proposal instead of vote records, if it was created with them.
+    pub compact_votes: Option<Vec<CompactVote>>,
     pub propose_timestamp: i64,
     pub proposal_deadline_timestamp: i64,
     pub proposal_passed_timestamp: Option<i64>,
@@ -22,8 +37,21 @@ pub struct NormalProposal {
     pub proposer: Pubkey,
     pub proposal_seed: Pubkey,
     pub passed_assets_count: u8,
+    /// When set, the transaction may be executed in order across several calls.
+    pub sequential_execution: bool,
+    /// Index of the next instruction to execute in the proposal transaction.
+    pub next_instruction_index: u8,
     pub state: ProposalState,
     pub account_bump: u8,
+    /// Human-readable context committed by the proposer, fixed once voting starts.
+    pub description: Option<ProposalDescription>,
+    /// Set while a bond escrowed for the proposal has not been released.
+    pub bonded: bool,
+    /// Candidate transactions of a multi-choice proposal, members vote for one of them
+    /// instead of `instruction_hashes`. Empty for other proposals.
+    pub options: Vec<ProposalOption>,
+    /// The option that won the vote, its transaction is the only one that can be executed.
+    pub winning_option: Option<u8>,
 }
 
 impl NormalProposal {
@@ -39,6 +67,13 @@ impl NormalProposal {
         proposal_deadline_timestamp: i64,
         instruction_hashes: Vec<[u8; HASH_BYTES_LENGTH]>,
         timelock_offset: u32,
+        sequential_execution: bool,
+        balance_guards: Vec<BalanceGuard>,
+        preconditions: Vec<ExecutionPrecondition>,
+        group_config_change: Option<ConfigChange>,
+        compact_votes: bool,
+        description: Option<ProposalDescription>,
+        options: Vec<Vec<[u8; HASH_BYTES_LENGTH]>>,
     ) -> Result<Self> {
         let clock = Clock::get()?;
         let now = clock.unix_timestamp;
@@ -48,6 +83,7 @@ impl NormalProposal {
             proposal_seed,
             group,
             assets,
+            compact_votes: compact_votes.then(Vec::new),
             passed_assets_count: 0,
             propose_timestamp: now,
             timelock_offset,
@@ -57,9 +93,23 @@ impl NormalProposal {
             proposal_deadline_timestamp,
             proposal_index,
             instruction_hashes,
+            balance_guards,
+            preconditions,
+            group_approval: group_config_change.map(GroupApproval::new),
+            sequential_execution,
+            next_instruction_index: 0,
+            description,
+            bonded: false,
+            options: options.into_iter().map(ProposalOption::new).collect(),
+            winning_option: None,
         })
     }
 
+    #[inline(always)]
+    pub fn is_multi_choice(&self) -> bool {
+        !self.options.is_empty()
+    }
+
     #[inline(always)]
     pub fn get_asset_mut(&mut self, index: usize) -> Option<&mut ProposalAsset> {
         self.assets.get_mut(index)
@@ -79,6 +129,29 @@ impl NormalProposal {
         self.passed_assets_count as usize == self.assets.len()
     }
 
+    /// Whether any member has voted on an asset or on the group approval.
+    #[inline(always)]
+    pub fn has_votes(&self) -> bool {
+        self.assets.iter().any(|asset| asset.vote_count > 0)
+            || self
+                .group_approval
+                .as_ref()
+                .is_some_and(|group_approval| group_approval.vote_count > 0)
+            || self
+                .compact_votes
+                .as_ref()
+                .is_some_and(|compact_votes| !compact_votes.is_empty())
+    }
+
+    /// Whether every asset, and the group if its approval is required, has voted for use.
+    #[inline(always)]
+    pub fn is_ready_to_pass(&self) -> bool {
+        self.has_all_assets_passed()
+            && self.group_approval.as_ref().is_none_or(|group_approval| {
+                group_approval.threshold_state == ProposalAssetThresholdState::UseThresholdReached
+            })
+    }
+
     #[inline(always)]
     pub fn set_proposal_passed_timestamp(&mut self, timestamp: i64) {
         self.proposal_passed_timestamp = Some(timestamp);
@@ -103,10 +176,17 @@ impl NormalProposal {
             ProposalState::Passed
             | ProposalState::Failed
             | ProposalState::Expired
-            | ProposalState::Executed => Err(error!(MultisigError::InvalidStateTransition)),
+            | ProposalState::Executed
+            | ProposalState::Cancelled => Err(error!(MultisigError::InvalidStateTransition)),
         }
     }
 
+    /// Whether some, but not all, of the proposal transaction has been executed.
+    #[inline(always)]
+    pub fn is_execution_in_progress(&self) -> bool {
+        self.state == ProposalState::Passed && self.next_instruction_index > 0
+    }
+
     #[inline(always)]
     pub fn mark_executed(&mut self) -> Result<()> {
         match self.state {
@@ -118,12 +198,43 @@ impl NormalProposal {
         }
     }
 
+    /// Open proposals, and passed proposals whose execution has not started, can be cancelled.
+    #[inline(always)]
+    pub fn cancel(&mut self) -> Result<()> {
+        match self.state {
+            ProposalState::Open => {}
+            ProposalState::Passed => require!(
+                self.next_instruction_index == 0,
+                MultisigError::ProposalNotCancellable
+            ),
+            _ => return Err(MultisigError::ProposalNotCancellable.into()),
+        }
+        self.state = ProposalState::Cancelled;
+        Ok(())
+    }
+
     #[inline(always)]
-    pub fn get_size(asset_count: usize, instruction_hash_count: usize) -> usize {
+    pub fn get_size(
+        asset_count: usize,
+        instruction_hash_count: usize,
+        balance_guard_count: usize,
+        preconditions: &[ExecutionPrecondition],
+        has_group_approval: bool,
+        compact_vote_capacity: Option<usize>,
+        option_hash_counts: impl IntoIterator<Item = usize>,
+    ) -> usize {
         // assets: Vec<ProposalAsset>
         4 + size_of::<ProposalAsset>() * asset_count
         // instruction_hashes: Vec<[u8; HASH_BYTES_LENGTH]>
         + 4 + HASH_BYTES_LENGTH * instruction_hash_count
+        // balance_guards: Vec<BalanceGuard>
+        + 4 + BalanceGuard::INIT_SPACE * balance_guard_count
+        // preconditions: Vec<ExecutionPrecondition>
+        + 4 + preconditions.iter().map(ExecutionPrecondition::get_size).sum::<usize>()
+        // group_approval: Option<GroupApproval>
+        + 1 + if has_group_approval { GroupApproval::INIT_SPACE } else { 0 }
+        // compact_votes: Option<Vec<CompactVote>>
+        + 1 + compact_vote_capacity.map_or(0, |capacity| 4 + CompactVote::INIT_SPACE * capacity)
         // propose_timestamp: i64
         + size_of::<i64>()
         // proposal_deadline_timestamp: i64
@@ -142,10 +253,22 @@ impl NormalProposal {
         + size_of::<Pubkey>()
         // passed_assets_count: u8
         + size_of::<u8>()
+        // sequential_execution: bool
+        + size_of::<bool>()
+        // next_instruction_index: u8
+        + size_of::<u8>()
         // state: ProposalState
         + size_of::<ProposalState>()
         // account_bump: u8
         + size_of::<u8>()
+        // description: Option<ProposalDescription>, reserved so it can be set until voting starts
+        + 1 + ProposalDescription::INIT_SPACE
+        // bonded: bool
+        + size_of::<bool>()
+        // options: Vec<ProposalOption>
+        + 4 + option_hash_counts.into_iter().map(ProposalOption::get_size).sum::<usize>()
+        // winning_option: Option<u8>
+        + size_of::<Option<u8>>()
     }
 
     /// Check if an asset has enough support to be marked as passed
@@ -185,7 +308,7 @@ impl NormalProposal {
         self.increment_passed_assets_count()?;
 
         // The proposal has been passed since the owners of the asset have voted for use
-        if self.has_all_assets_passed() {
+        if self.is_ready_to_pass() {
             self.state = ProposalState::Passed;
             self.set_proposal_passed_timestamp(Clock::get()?.unix_timestamp);
         }
@@ -194,6 +317,51 @@ impl NormalProposal {
         Ok(true)
     }
 
+    /// Check if the leading option of a multi-choice proposal has a plurality that meets the
+    /// use threshold of its asset, and pass the proposal with it if so. A tie for the lead
+    /// passes no option.
+    pub fn check_and_mark_option_passed(
+        &mut self,
+        governed_asset: &Account<Asset>,
+    ) -> Result<bool> {
+        let asset = &mut self.assets[0];
+
+        // We can only change the state of an asset if it is yet to reach any threshold
+        if asset.threshold_state != ProposalAssetThresholdState::NoThresholdReached {
+            return Ok(false);
+        }
+
+        // If the vote count is yet to meet the quorom then the vote cannot pass
+        if asset.vote_count.lt(&governed_asset.minimum_vote_count) {
+            return Ok(false);
+        }
+
+        let Some((leading_index, leading_weight)) = leading_option(&self.options) else {
+            return Ok(false);
+        };
+
+        // Overflow not possible: u32::MAX * u32::MAX < u64::MAX
+        let total_votes_weight = asset.use_vote_weight + asset.not_use_vote_weight;
+        let passes_threshold = governed_asset
+            .use_threshold
+            .less_than_or_equal(leading_weight, total_votes_weight)?;
+
+        if !passes_threshold {
+            return Ok(false);
+        }
+
+        asset.set_threshold_state(ProposalAssetThresholdState::UseThresholdReached)?;
+        self.increment_passed_assets_count()?;
+        self.winning_option = Some(leading_index);
+
+        if self.is_ready_to_pass() {
+            self.state = ProposalState::Passed;
+            self.set_proposal_passed_timestamp(Clock::get()?.unix_timestamp);
+        }
+
+        Ok(true)
+    }
+
     /// Check if an asset has enough opposition to be marked as failed
     /// and fail the proposal if possible
     pub fn check_and_mark_asset_failed(
@@ -234,6 +402,54 @@ impl NormalProposal {
         // This asset just now failed
         Ok(true)
     }
+
+    /// Check if the group has approved the proposal's config change
+    /// and pass the proposal if possible
+    pub fn check_and_mark_group_approval_passed(&mut self, group: &Group) -> Result<bool> {
+        let group_approval = self
+            .group_approval
+            .as_mut()
+            .ok_or(MultisigError::GroupApprovalNotRequired)?;
+
+        if group_approval.threshold_state != ProposalAssetThresholdState::NoThresholdReached {
+            return Ok(false);
+        }
+
+        if !group_approval.is_approved(group)? {
+            return Ok(false);
+        }
+
+        group_approval.threshold_state = ProposalAssetThresholdState::UseThresholdReached;
+
+        if self.is_ready_to_pass() {
+            self.state = ProposalState::Passed;
+            self.set_proposal_passed_timestamp(Clock::get()?.unix_timestamp);
+        }
+
+        Ok(true)
+    }
+
+    /// Check if the group has rejected the proposal's config change
+    /// and fail the proposal if possible
+    pub fn check_and_mark_group_approval_failed(&mut self, group: &Group) -> Result<bool> {
+        let group_approval = self
+            .group_approval
+            .as_mut()
+            .ok_or(MultisigError::GroupApprovalNotRequired)?;
+
+        if group_approval.threshold_state != ProposalAssetThresholdState::NoThresholdReached {
+            return Ok(false);
+        }
+
+        if !group_approval.is_rejected(group)? {
+            return Ok(false);
+        }
+
+        group_approval.threshold_state = ProposalAssetThresholdState::NotUseThresholdReached;
+        self.state = ProposalState::Failed;
+
+        Ok(true)
+    }
 }
 
 #[account]
@@ -255,9 +471,23 @@ pub struct ConfigProposal {
 
     pub target: ProposalTarget,
     pub config_change: ConfigChange,
+    /// Votes kept in the proposal instead of vote records, if it was created with them.
+    /// Their space is only reserved by `get_size` when they are enabled.
+    #[max_len(0)]
+    pub compact_votes: Option<Vec<CompactVote>>,
 
     pub state: ProposalState,
     pub account_bump: u8,
+    /// Human-readable context committed by the proposer, fixed once voting starts.
+    pub description: Option<ProposalDescription>,
+    /// Per-asset tallies when the proposal targets several assets, empty otherwise.
+    #[max_len(MAX_CONFIG_PROPOSAL_ASSETS)]
+    pub assets: Vec<ConfigProposalAsset>,
+    /// Set while a bond escrowed for the proposal has not been released.
+    pub bonded: bool,
+    /// Index the proposal was created with, kept when it takes a new `proposal_index` after
+    /// going stale, so its bond still refers to it.
+    pub created_proposal_index: u64,
 }
 
 impl ConfigProposal {
@@ -272,7 +502,10 @@ impl ConfigProposal {
         timelock_offset: u32,
         proposal_deadline_timestamp: i64,
         target: ProposalTarget,
+        assets: Vec<ConfigProposalAsset>,
         config_change: ConfigChange,
+        compact_votes: bool,
+        description: Option<ProposalDescription>,
     ) -> Result<Self> {
         let clock = Clock::get()?;
         let now = clock.unix_timestamp;
@@ -281,6 +514,7 @@ impl ConfigProposal {
             proposer,
             proposal_seed,
             group,
+            compact_votes: compact_votes.then(Vec::new),
             for_weight: 0,
             against_weight: 0,
             vote_count: 0,
@@ -293,14 +527,130 @@ impl ConfigProposal {
             state: ProposalState::Open,
             account_bump,
             proposal_index,
+            description,
+            assets,
+            bonded: false,
+            created_proposal_index: proposal_index,
         })
     }
 
+    /// Space of a config proposal with room for `compact_vote_capacity` compact votes,
+    /// `None` when they are not enabled.
+    #[inline(always)]
+    pub fn get_size(compact_vote_capacity: Option<usize>) -> usize {
+        Self::INIT_SPACE
+            + compact_vote_capacity.map_or(0, |capacity| CompactVote::INIT_SPACE * capacity)
+    }
+
     #[inline(always)]
     pub fn set_proposal_passed_timestamp(&mut self, timestamp: i64) {
         self.proposal_passed_timestamp = Some(timestamp);
     }
 
+    #[inline(always)]
+    pub fn get_asset_mut(&mut self, index: usize) -> Option<&mut ConfigProposalAsset> {
+        self.assets.get_mut(index)
+    }
+
+    /// The state of the change for `asset_address`, the proposal's own state unless it
+    /// is an open proposal targeting several assets.
+    pub fn get_asset_state(&self, asset_address: &Pubkey) -> Result<ProposalState> {
+        match &self.target {
+            ProposalTarget::Group => Err(MultisigError::InvalidConfigChange.into()),
+            ProposalTarget::Asset(target_asset) => {
+                require_keys_eq!(*target_asset, *asset_address, MultisigError::InvalidAsset);
+                Ok(self.state)
+            }
+            // A cancelled proposal is not applied to any of its assets.
+            ProposalTarget::Assets if self.state != ProposalState::Open => Ok(self.state),
+            ProposalTarget::Assets => Ok(self.find_asset(asset_address)?.state),
+        }
+    }
+
+    /// When the change for `asset_address` can be applied, each asset of a proposal
+    /// targeting several assets runs its own timelock from when it passed.
+    pub fn get_asset_valid_from_timestamp(&self, asset_address: &Pubkey) -> Result<i64> {
+        let passed_timestamp = match &self.target {
+            ProposalTarget::Assets => self.find_asset(asset_address)?.passed_timestamp,
+            _ => self.proposal_passed_timestamp,
+        };
+        Ok(passed_timestamp
+            .ok_or(MultisigError::ProposalNotPassed)?
+            .checked_add(i64::from(self.timelock_offset))
+            .ok_or(ProgramError::ArithmeticOverflow)?)
+    }
+
+    /// Whether a change naming `change_asset` applies to `asset_address`. Changes of a
+    /// proposal targeting several assets name the default key and apply to each of them.
+    #[inline(always)]
+    pub fn applies_to_asset(&self, change_asset: &Pubkey, asset_address: &Pubkey) -> bool {
+        match &self.target {
+            ProposalTarget::Assets => *change_asset == Pubkey::default(),
+            _ => change_asset == asset_address,
+        }
+    }
+
+    /// Records that the change was applied to `asset_address`, returns whether the
+    /// proposal is done and should be closed. A proposal targeting several assets stays
+    /// open for its remaining assets, it takes a new index when the change that was just
+    /// applied marked it stale.
+    pub fn mark_asset_executed(
+        &mut self,
+        asset_address: &Pubkey,
+        group: &mut Group,
+    ) -> Result<bool> {
+        if self.target != ProposalTarget::Assets {
+            return Ok(true);
+        }
+
+        let asset = self
+            .assets
+            .iter_mut()
+            .find(|asset| asset.asset == *asset_address)
+            .ok_or(MultisigError::InvalidAsset)?;
+        asset.state = ProposalState::Executed;
+        self.settle_assets()?;
+
+        if self.state != ProposalState::Open {
+            return Ok(true);
+        }
+
+        if self.proposal_index < group.proposal_index_after_stale {
+            self.proposal_index = group.get_and_increment_proposal_index()?;
+        }
+        Ok(false)
+    }
+
+    /// Once no asset is left to vote on or to apply, a proposal targeting several assets
+    /// is executed if any asset applied the change and failed otherwise.
+    pub fn settle_assets(&mut self) -> Result<()> {
+        let pending = self
+            .assets
+            .iter()
+            .any(|asset| matches!(asset.state, ProposalState::Open | ProposalState::Passed));
+        if pending {
+            return Ok(());
+        }
+
+        let executed = self
+            .assets
+            .iter()
+            .any(|asset| asset.state == ProposalState::Executed);
+        self.set_state(if executed {
+            ProposalState::Executed
+        } else {
+            ProposalState::Failed
+        })
+    }
+
+    #[inline(always)]
+    fn find_asset(&self, asset_address: &Pubkey) -> Result<&ConfigProposalAsset> {
+        self.assets
+            .iter()
+            .find(|asset| asset.asset == *asset_address)
+            .ok_or(MultisigError::InvalidAsset.into())
+    }
+
     #[inline(always)]
     pub fn get_valid_from_timestamp(&self) -> Result<i64> {
         Ok(self
@@ -330,6 +680,12 @@ impl ConfigProposal {
         self.against_weight = self.against_weight.saturating_sub(u64::from(weight));
     }
 
+    /// Whether any member has voted on the proposal.
+    #[inline(always)]
+    pub fn has_votes(&self) -> bool {
+        self.vote_count > 0 || self.assets.iter().any(|asset| asset.vote_count > 0)
+    }
+
     #[inline(always)]
     pub fn increment_vote_count(&mut self) -> Result<()> {
         self.vote_count = self
@@ -349,10 +705,22 @@ impl ConfigProposal {
             ProposalState::Passed
             | ProposalState::Failed
             | ProposalState::Expired
-            | ProposalState::Executed => Err(MultisigError::InvalidStateTransition.into()),
+            | ProposalState::Executed
+            | ProposalState::Cancelled => Err(MultisigError::InvalidStateTransition.into()),
         }
     }
 
+    /// Open and passed proposals can be cancelled until they are executed.
+    #[inline(always)]
+    pub fn cancel(&mut self) -> Result<()> {
+        require!(
+            matches!(self.state, ProposalState::Open | ProposalState::Passed),
+            MultisigError::ProposalNotCancellable
+        );
+        self.state = ProposalState::Cancelled;
+        Ok(())
+    }
+
     /// Check if a config proposal has enough support to be marked as passed.
     /// Returns true if the proposal was newly passed.
     pub fn check_and_mark_passed(
@@ -378,7 +746,10 @@ impl ConfigProposal {
                     ConfigChange::RemoveGroupMember { .. } => group
                         .remove_threshold
                         .less_than_or_equal(self.for_weight, total_votes_weight)?,
-                    ConfigChange::ChangeGroupConfig { .. } => group
+                    ConfigChange::ChangeGroupConfig { .. }
+                    | ConfigChange::RenewGroupMemberTerm { .. }
+                    | ConfigChange::ChangeGroupMemberPermissions { .. }
+                    | ConfigChange::SetGroupMetadata { .. } => group
                         .change_config_threshold
                         .less_than_or_equal(self.for_weight, total_votes_weight)?,
                     _ => return Err(MultisigError::UnexpectedConfigChange.into()),
@@ -394,26 +765,13 @@ impl ConfigProposal {
             ProposalTarget::Asset(_) => {
                 let asset = maybe_asset.ok_or(MultisigError::AssetNotProvided)?;
 
-                // Quorum check
-                if self.vote_count.lt(&asset.minimum_vote_count) {
-                    return Ok(false);
-                }
-
-                let total_votes_weight = self.for_weight + self.against_weight;
-
-                // Check the threshold
-                let passed_threshold_reached = match &self.config_change {
-                    ConfigChange::AddAssetMember { .. } => asset
-                        .add_threshold
-                        .less_than_or_equal(self.for_weight, total_votes_weight)?,
-                    ConfigChange::RemoveAssetMember { .. } => asset
-                        .remove_threshold
-                        .less_than_or_equal(self.for_weight, total_votes_weight)?,
-                    ConfigChange::ChangeAssetConfig { .. } => asset
-                        .change_config_threshold
-                        .less_than_or_equal(self.for_weight, total_votes_weight)?,
-                    _ => return Err(MultisigError::UnexpectedConfigChange.into()),
-                };
+                let passed_threshold_reached = asset_change_passed(
+                    asset,
+                    &self.config_change,
+                    self.for_weight,
+                    self.against_weight,
+                    self.vote_count,
+                )?;
 
                 // Set the proposal as passed if the threshold was met
                 if passed_threshold_reached {
@@ -424,6 +782,7 @@ impl ConfigProposal {
                 // Did we just pass?
                 Ok(passed_threshold_reached)
             }
+            ProposalTarget::Assets => Err(MultisigError::PerAssetVoteRequired.into()),
         }
     }
 
@@ -453,7 +812,10 @@ impl ConfigProposal {
                     ConfigChange::RemoveGroupMember { .. } => group
                         .not_remove_threshold
                         .less_than_or_equal(self.against_weight, total_votes_weight)?,
-                    ConfigChange::ChangeGroupConfig { .. } => group
+                    ConfigChange::ChangeGroupConfig { .. }
+                    | ConfigChange::RenewGroupMemberTerm { .. }
+                    | ConfigChange::ChangeGroupMemberPermissions { .. }
+                    | ConfigChange::SetGroupMetadata { .. } => group
                         .not_change_config_threshold
                         .less_than_or_equal(self.against_weight, total_votes_weight)?,
                     _ => return Err(MultisigError::UnexpectedConfigChange.into()),
@@ -470,26 +832,13 @@ impl ConfigProposal {
             ProposalTarget::Asset(_) => {
                 let asset = maybe_asset.ok_or(MultisigError::AssetNotProvided)?;
 
-                // Quorum check
-                if self.vote_count.lt(&asset.minimum_vote_count) {
-                    return Ok(false);
-                }
-
-                let total_votes_weight = self.for_weight + self.against_weight;
-
-                // Check the threshold
-                let failed_threshold_reached = match &self.config_change {
-                    ConfigChange::AddAssetMember { .. } => asset
-                        .not_add_threshold
-                        .less_than_or_equal(self.against_weight, total_votes_weight)?,
-                    ConfigChange::RemoveAssetMember { .. } => asset
-                        .not_remove_threshold
-                        .less_than_or_equal(self.against_weight, total_votes_weight)?,
-                    ConfigChange::ChangeAssetConfig { .. } => asset
-                        .not_change_config_threshold
-                        .less_than_or_equal(self.against_weight, total_votes_weight)?,
-                    _ => return Err(MultisigError::UnexpectedConfigChange.into()),
-                };
+                let failed_threshold_reached = asset_change_failed(
+                    asset,
+                    &self.config_change,
+                    self.for_weight,
+                    self.against_weight,
+                    self.vote_count,
+                )?;
 
                 // Set the proposal as failed if the threshold newly failed
                 if failed_threshold_reached {
@@ -499,17 +848,144 @@ impl ConfigProposal {
                 // Did we just fail?
                 Ok(failed_threshold_reached)
             }
+            ProposalTarget::Assets => Err(MultisigError::PerAssetVoteRequired.into()),
         }
     }
+
+    /// Check if the asset at `index` has enough support to pass the change, against that
+    /// asset's thresholds. Returns true if the asset newly passed.
+    pub fn check_and_mark_asset_passed(&mut self, index: usize, asset: &Asset) -> Result<bool> {
+        let proposal_asset = self
+            .assets
+            .get(index)
+            .ok_or(MultisigError::InvalidAssetIndex)?;
+        if proposal_asset.state != ProposalState::Open {
+            return Ok(false);
+        }
+
+        let passed_threshold_reached = asset_change_passed(
+            asset,
+            &self.config_change,
+            proposal_asset.for_weight,
+            proposal_asset.against_weight,
+            proposal_asset.vote_count,
+        )?;
+
+        if passed_threshold_reached {
+            let now = Clock::get()?.unix_timestamp;
+            let proposal_asset = &mut self.assets[index];
+            proposal_asset.state = ProposalState::Passed;
+            proposal_asset.passed_timestamp = Some(now);
+        }
+
+        Ok(passed_threshold_reached)
+    }
+
+    /// Check if the asset at `index` has enough opposition to fail the change, against that
+    /// asset's thresholds. Returns true if the asset newly failed.
+    pub fn check_and_mark_asset_failed(&mut self, index: usize, asset: &Asset) -> Result<bool> {
+        let proposal_asset = self
+            .assets
+            .get(index)
+            .ok_or(MultisigError::InvalidAssetIndex)?;
+        if proposal_asset.state != ProposalState::Open {
+            return Ok(false);
+        }
+
+        let failed_threshold_reached = asset_change_failed(
+            asset,
+            &self.config_change,
+            proposal_asset.for_weight,
+            proposal_asset.against_weight,
+            proposal_asset.vote_count,
+        )?;
+
+        if failed_threshold_reached {
+            self.assets[index].state = ProposalState::Failed;
+            self.settle_assets()?;
+        }
+
+        Ok(failed_threshold_reached)
+    }
+}
+
+/// Whether an asset change has reached the asset's quorum and passing threshold.
+fn asset_change_passed(
+    asset: &Asset,
+    config_change: &ConfigChange,
+    for_weight: u64,
+    against_weight: u64,
+    vote_count: u32,
+) -> Result<bool> {
+    // Quorum check
+    if vote_count.lt(&asset.minimum_vote_count) {
+        return Ok(false);
+    }
+
+    let total_votes_weight = for_weight + against_weight;
+
+    // Check the threshold
+    Ok(match config_change {
+        ConfigChange::AddAssetMember { .. } => asset
+            .add_threshold
+            .less_than_or_equal(for_weight, total_votes_weight)?,
+        ConfigChange::RemoveAssetMember { .. } => asset
+            .remove_threshold
+            .less_than_or_equal(for_weight, total_votes_weight)?,
+        ConfigChange::ChangeAssetConfig { .. }
+        | ConfigChange::RenewAssetMemberTerm { .. }
+        | ConfigChange::ChangeAssetMemberPermissions { .. }
+        | ConfigChange::SetAssetMetadata { .. }
+        | ConfigChange::CancelSchedule { .. } => asset
+            .change_config_threshold
+            .less_than_or_equal(for_weight, total_votes_weight)?,
+        _ => return Err(MultisigError::UnexpectedConfigChange.into()),
+    })
+}
+
+/// Whether an asset change has reached the asset's quorum and failing threshold.
+fn asset_change_failed(
+    asset: &Asset,
+    config_change: &ConfigChange,
+    for_weight: u64,
+    against_weight: u64,
+    vote_count: u32,
+) -> Result<bool> {
+    // Quorum check
+    if vote_count.lt(&asset.minimum_vote_count) {
+        return Ok(false);
+    }
+
+    let total_votes_weight = for_weight + against_weight;
+
+    // Check the threshold
+    Ok(match config_change {
+        ConfigChange::AddAssetMember { .. } => asset
+            .not_add_threshold
+            .less_than_or_equal(against_weight, total_votes_weight)?,
+        ConfigChange::RemoveAssetMember { .. } => asset
+            .not_remove_threshold
+            .less_than_or_equal(against_weight, total_votes_weight)?,
+        ConfigChange::ChangeAssetConfig { .. }
+        | ConfigChange::RenewAssetMemberTerm { .. }
+        | ConfigChange::ChangeAssetMemberPermissions { .. }
+        | ConfigChange::SetAssetMetadata { .. }
+        | ConfigChange::CancelSchedule { .. } => asset
+            .not_change_config_threshold
+            .less_than_or_equal(against_weight, total_votes_weight)?,
+        _ => return Err(MultisigError::UnexpectedConfigChange.into()),
+    })
 }
 
 /// Stores the different type of changes that could be made to an asset or group
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub enum ConfigChange {
+    /// `expires_at` sets the end of the new member's term, `None` for a permanent membership.
     AddGroupMember {
         member: Pubkey,
         weight: u32,
         permissions: Permissions,
+        expires_at: Option<i64>,
     },
     RemoveGroupMember {
         member: Pubkey,
@@ -526,11 +1002,54 @@ pub enum ConfigChange {
         asset_address: Pubkey,
     },
 
+    /// Applied in order, the resulting config is validated once all changes are applied.
     ChangeGroupConfig {
-        config_type: ConfigType,
+        #[max_len(MAX_CONFIG_CHANGES)]
+        config_types: Vec<ConfigType>,
     },
+    /// Applied in order to the asset targeted by the proposal.
     ChangeAssetConfig {
-        config_type: ConfigType,
+        #[max_len(MAX_CONFIG_CHANGES)]
+        config_types: Vec<ConfigType>,
+    },
+
+    /// Sets the end of a group member's term, `None` makes the membership permanent.
+    RenewGroupMemberTerm {
+        member: Pubkey,
+        expires_at: Option<i64>,
+    },
+    /// Sets the end of an asset member's term, `None` makes the membership permanent.
+    RenewAssetMemberTerm {
+        member: Pubkey,
+        asset_address: Pubkey,
+        expires_at: Option<i64>,
+    },
+
+    /// Replaces a group member's permissions.
+    ChangeGroupMemberPermissions {
+        member: Pubkey,
+        permissions: Permissions,
+    },
+    /// Replaces an asset member's permissions.
+    ChangeAssetMemberPermissions {
+        member: Pubkey,
+        asset_address: Pubkey,
+        permissions: Permissions,
+    },
+
+    /// Creates or replaces the group's metadata, only through config proposals.
+    SetGroupMetadata {
+        content: MetadataContent,
+    },
+    /// Creates or replaces the metadata of the asset targeted by the proposal.
+    SetAssetMetadata {
+        asset_address: Pubkey,
+        content: MetadataContent,
+    },
+    /// Closes a schedule making recurring transfers from the asset.
+    CancelSchedule {
+        asset_address: Pubkey,
+        schedule: Pubkey,
     },
 }
 
@@ -542,6 +1061,10 @@ impl ConfigChange {
             ConfigChange::AddAssetMember { .. }
                 | ConfigChange::RemoveAssetMember { .. }
                 | ConfigChange::ChangeAssetConfig { .. }
+                | ConfigChange::RenewAssetMemberTerm { .. }
+                | ConfigChange::ChangeAssetMemberPermissions { .. }
+                | ConfigChange::SetAssetMetadata { .. }
+                | ConfigChange::CancelSchedule { .. }
         )
     }
 
@@ -551,6 +1074,26 @@ impl ConfigChange {
     }
 }
 
+/// Title and pointer to the full text of a proposal, `content_hash` commits to the content
+/// behind `uri` so clients can check what they render.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct ProposalDescription {
+    #[max_len(MAX_TITLE_LEN)]
+    pub title: String,
+    #[max_len(MAX_URI_LEN)]
+    pub uri: String,
+    pub content_hash: [u8; 32],
+}
+
+impl ProposalDescription {
+    #[inline(always)]
+    pub fn validate(&self) -> Result<()> {
+        require_gte!(MAX_TITLE_LEN, self.title.len(), MultisigError::TitleTooLong);
+        require_gte!(MAX_URI_LEN, self.uri.len(), MultisigError::UriTooLong);
+        Ok(())
+    }
+}
+
 /// Stores the state for an existing proposal
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum ProposalState {
@@ -561,6 +1104,8 @@ pub enum ProposalState {
     // Deadline passed before unanimous vote
     Expired,
     Executed,
+    // Withdrawn by a member with the Cancel permission before execution
+    Cancelled,
 }
 
 /// Stores whether or not a config proposal is for a group or an asset
@@ -568,6 +1113,64 @@ pub enum ProposalState {
 pub enum ProposalTarget {
     Group,
     Asset(Pubkey),
+    /// Several assets, listed with their tallies in `ConfigProposal::assets`.
+    Assets,
+}
+
+/// Tally of one asset of a config proposal targeting several assets, each asset's
+/// members pass or fail the change on their own.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone)]
+pub struct ConfigProposalAsset {
+    pub for_weight: u64,
+    pub against_weight: u64,
+    pub asset: Pubkey,
+    pub vote_count: u32,
+    pub passed_timestamp: Option<i64>,
+    /// Open while voting, Passed or Failed once decided and Executed once applied.
+    pub state: ProposalState,
+}
+
+impl ConfigProposalAsset {
+    #[inline(always)]
+    pub fn new(asset: Pubkey) -> Self {
+        Self {
+            for_weight: 0,
+            against_weight: 0,
+            asset,
+            vote_count: 0,
+            passed_timestamp: None,
+            state: ProposalState::Open,
+        }
+    }
+
+    #[inline(always)]
+    pub fn add_weight_for(&mut self, weight: u32) {
+        self.for_weight = self.for_weight.saturating_add(u64::from(weight));
+    }
+
+    #[inline(always)]
+    pub fn sub_weight_for(&mut self, weight: u32) {
+        self.for_weight = self.for_weight.saturating_sub(u64::from(weight));
+    }
+
+    #[inline(always)]
+    pub fn add_weight_against(&mut self, weight: u32) {
+        self.against_weight = self.against_weight.saturating_add(u64::from(weight));
+    }
+
+    #[inline(always)]
+    pub fn sub_weight_against(&mut self, weight: u32) {
+        self.against_weight = self.against_weight.saturating_sub(u64::from(weight));
+    }
+
+    #[inline(always)]
+    pub fn increment_vote_count(&mut self) -> Result<()> {
+        self.vote_count = self
+            .vote_count
+            .checked_add(1)
+            .ok_or(MultisigError::TooManyVotes)?;
+        Ok(())
+    }
 }
 
 /// Stores the different type of specifc config changes that could be made to an asset or group
@@ -584,6 +1187,94 @@ pub enum ConfigType {
     ChangeConfig(FractionalThreshold),
     NotChangeConfig(FractionalThreshold),
     MinimumTimelock(u32),
+    RequireExecutePermission(bool),
+    /// Asset only, lamports paid from the asset authority to whoever completes the
+    /// execution of a proposal that uses the asset, at most `MAX_EXECUTOR_TIP_LAMPORTS`.
+    ExecutorTip(u64),
+    /// Sets the passing and failing thresholds of an action together, so a pair can
+    /// move to values that would overlap the old partner if set one at a time.
+    AddMemberThresholds {
+        pass: FractionalThreshold,
+        fail: FractionalThreshold,
+    },
+    RemoveMemberThresholds {
+        pass: FractionalThreshold,
+        fail: FractionalThreshold,
+    },
+    /// Asset only.
+    UseThresholds {
+        pass: FractionalThreshold,
+        fail: FractionalThreshold,
+    },
+    ChangeConfigThresholds {
+        pass: FractionalThreshold,
+        fail: FractionalThreshold,
+    },
+    /// Longest time in seconds a proposal can stay open for votes, zero for no limit.
+    MaximumProposalDuration(u32),
+    ConfigChangeTimelocks(ConfigChangeTimelocks),
+    /// Group only, bond taken from whoever pays for a new proposal, none when not set.
+    ProposalBond(Option<ProposalBondConfig>),
+    /// Group only, most proposals open at once in the group, zero for no limit.
+    MaxOpenProposals(u32),
+    /// Group only, most proposals each member can have open at once, zero for no limit.
+    MaxOpenProposalsPerMember(u32),
+}
+
+/// Minimum timelocks of config proposals by kind of change, on top of `minimum_timelock`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace, PartialEq, Eq)]
+pub struct ConfigChangeTimelocks {
+    pub add_member: u32,
+    pub remove_member: u32,
+    /// Lowering a passing threshold or raising a failing one.
+    pub threshold_loosening: u32,
+    /// Raising a passing threshold or lowering a failing one.
+    pub threshold_tightening: u32,
+}
+
+impl ConfigChangeTimelocks {
+    /// Timelock required to move thresholds from `current` to `proposed`, both given as
+    /// (passing, failing) pairs in the same order.
+    pub fn for_thresholds(
+        &self,
+        current: &[(FractionalThreshold, FractionalThreshold)],
+        proposed: &[(FractionalThreshold, FractionalThreshold)],
+    ) -> u32 {
+        current
+            .iter()
+            .zip(proposed)
+            .flat_map(
+                |((current_pass, current_fail), (proposed_pass, proposed_fail))| {
+                    [
+                        proposed_pass.cmp_value(current_pass),
+                        current_fail.cmp_value(proposed_fail),
+                    ]
+                },
+            )
+            .map(|ordering| match ordering {
+                Ordering::Less => self.threshold_loosening,
+                Ordering::Greater => self.threshold_tightening,
+                Ordering::Equal => 0,
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Timelock required to replace these timelocks with `proposed`, lowering one takes
+    /// as long as the timelock being lowered.
+    pub fn for_timelocks(&self, proposed: &ConfigChangeTimelocks) -> u32 {
+        [
+            (self.add_member, proposed.add_member),
+            (self.remove_member, proposed.remove_member),
+            (self.threshold_loosening, proposed.threshold_loosening),
+            (self.threshold_tightening, proposed.threshold_tightening),
+        ]
+        .into_iter()
+        .filter(|(current, proposed)| proposed < current)
+        .map(|(current, _)| current)
+        .max()
+        .unwrap_or(0)
+    }
 }
 
 /// Locates an asset within the instruction list by specifying which instruction
@@ -681,6 +1372,61 @@ impl ProposalAsset {
     }
 }
 
+/// The index and vote weight of the option with the most votes, `None` when no option
+/// has votes or several options share the lead.
+pub fn leading_option(options: &[ProposalOption]) -> Option<(u8, u64)> {
+    let mut leader: Option<(u8, u64)> = None;
+    let mut tied = false;
+
+    for (index, option) in options.iter().enumerate() {
+        match leader {
+            Some((_, weight)) if option.vote_weight == weight => tied = true,
+            Some((_, weight)) if option.vote_weight < weight => {}
+            _ => {
+                // Cannot truncate, proposals have at most `MAX_PROPOSAL_OPTIONS` options.
+                leader = Some((index as u8, option.vote_weight));
+                tied = false;
+            }
+        }
+    }
+
+    leader.filter(|(_, weight)| !tied && *weight > 0)
+}
+
+/// One candidate transaction of a multi-choice proposal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct ProposalOption {
+    /// Hashes of each instruction in the option's transaction, in order.
+    pub instruction_hashes: Vec<[u8; HASH_BYTES_LENGTH]>,
+    /// Weight of the votes for this option.
+    pub vote_weight: u64,
+}
+
+impl ProposalOption {
+    #[inline(always)]
+    pub fn new(instruction_hashes: Vec<[u8; HASH_BYTES_LENGTH]>) -> Self {
+        Self {
+            instruction_hashes,
+            vote_weight: 0,
+        }
+    }
+
+    #[inline(always)]
+    pub fn get_size(instruction_hash_count: usize) -> usize {
+        4 + HASH_BYTES_LENGTH * instruction_hash_count + size_of::<u64>()
+    }
+
+    #[inline(always)]
+    pub fn add_vote_weight(&mut self, weight: u32) {
+        self.vote_weight = self.vote_weight.saturating_add(u64::from(weight));
+    }
+
+    #[inline(always)]
+    pub fn sub_vote_weight(&mut self, weight: u32) {
+        self.vote_weight = self.vote_weight.saturating_sub(u64::from(weight));
+    }
+}
+
 /// Stores a proposal threshold state(e.g whether or not the passing or failing threshold has been met)
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
 pub enum ProposalAssetThresholdState {
@@ -689,6 +1435,168 @@ pub enum ProposalAssetThresholdState {
     NotUseThresholdReached,
 }
 
+/// Stores the group vote on a config change carried by a normal proposal
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct GroupApproval {
+    pub config_change: ConfigChange,
+    pub for_weight: u64,
+    pub against_weight: u64,
+    pub vote_count: u32,
+    pub threshold_state: ProposalAssetThresholdState,
+}
+
+impl GroupApproval {
+    #[inline(always)]
+    pub fn new(config_change: ConfigChange) -> Self {
+        Self {
+            config_change,
+            for_weight: 0,
+            against_weight: 0,
+            vote_count: 0,
+            threshold_state: ProposalAssetThresholdState::NoThresholdReached,
+        }
+    }
+
+    #[inline(always)]
+    pub fn increment_vote_count(&mut self) -> Result<()> {
+        self.vote_count = self
+            .vote_count
+            .checked_add(1)
+            .ok_or(MultisigError::TooManyVotes)?;
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn add_weight_for(&mut self, weight: u32) {
+        self.for_weight = self.for_weight.saturating_add(u64::from(weight));
+    }
+
+    #[inline(always)]
+    pub fn sub_weight_for(&mut self, weight: u32) {
+        self.for_weight = self.for_weight.saturating_sub(u64::from(weight));
+    }
+
+    #[inline(always)]
+    pub fn add_weight_against(&mut self, weight: u32) {
+        self.against_weight = self.against_weight.saturating_add(u64::from(weight));
+    }
+
+    #[inline(always)]
+    pub fn sub_weight_against(&mut self, weight: u32) {
+        self.against_weight = self.against_weight.saturating_sub(u64::from(weight));
+    }
+
+    /// The passing and failing thresholds the group applies to the config change.
+    #[inline(always)]
+    pub fn thresholds(&self, group: &Group) -> Result<(FractionalThreshold, FractionalThreshold)> {
+        match &self.config_change {
+            ConfigChange::AddGroupMember { .. } => {
+                Ok((group.add_threshold, group.not_add_threshold))
+            }
+            ConfigChange::RemoveGroupMember { .. } => {
+                Ok((group.remove_threshold, group.not_remove_threshold))
+            }
+            ConfigChange::ChangeGroupConfig { .. }
+            | ConfigChange::RenewGroupMemberTerm { .. }
+            | ConfigChange::ChangeGroupMemberPermissions { .. } => Ok((
+                group.change_config_threshold,
+                group.not_change_config_threshold,
+            )),
+            _ => Err(MultisigError::UnexpectedConfigChange.into()),
+        }
+    }
+
+    /// Whether the group has voted for the config change with quorum.
+    pub fn is_approved(&self, group: &Group) -> Result<bool> {
+        if self.vote_count.lt(&group.minimum_vote_count) {
+            return Ok(false);
+        }
+
+        let (passing_threshold, _) = self.thresholds(group)?;
+        passing_threshold.less_than_or_equal(self.for_weight, self.for_weight + self.against_weight)
+    }
+
+    /// Whether the group has voted against the config change with quorum.
+    pub fn is_rejected(&self, group: &Group) -> Result<bool> {
+        if self.vote_count.lt(&group.minimum_vote_count) {
+            return Ok(false);
+        }
+
+        let (_, failing_threshold) = self.thresholds(group)?;
+        failing_threshold
+            .less_than_or_equal(self.against_weight, self.for_weight + self.against_weight)
+    }
+}
+
+/// Caps the amount a governed asset may lose over the execution of a proposal transaction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub struct BalanceGuard {
+    /// The guarded asset, it must be one of the proposal's assets.
+    pub asset: Pubkey,
+    pub kind: BalanceGuardKind,
+    pub max_decrease: u64,
+}
+
+impl BalanceGuard {
+    /// Whether the change from `before` to `after` stays within the guard.
+    #[inline(always)]
+    pub fn allows(&self, before: u64, after: u64) -> bool {
+        before.saturating_sub(after) <= self.max_decrease
+    }
+}
+
+/// Stores which balance of a governed asset a guard applies to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub enum BalanceGuardKind {
+    /// The amount held by a token account asset.
+    TokenAmount,
+    /// The lamports held by the asset's authority.
+    Lamports,
+}
+
+/// A condition on chain state that must hold before a proposal transaction is executed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum ExecutionPrecondition {
+    /// The data of `account` starting at `offset` equals `bytes`.
+    AccountData {
+        account: Pubkey,
+        offset: u32,
+        bytes: Vec<u8>,
+    },
+    /// The token account `account` holds at least `amount`.
+    MinimumTokenBalance { account: Pubkey, amount: u64 },
+    /// The current slot is at least `slot`.
+    MinimumSlot { slot: u64 },
+}
+
+impl ExecutionPrecondition {
+    /// The account the precondition reads, if any.
+    #[inline(always)]
+    pub fn account(&self) -> Option<&Pubkey> {
+        match self {
+            ExecutionPrecondition::AccountData { account, .. }
+            | ExecutionPrecondition::MinimumTokenBalance { account, .. } => Some(account),
+            ExecutionPrecondition::MinimumSlot { .. } => None,
+        }
+    }
+
+    #[inline(always)]
+    pub fn get_size(&self) -> usize {
+        1 + match self {
+            ExecutionPrecondition::AccountData { bytes, .. } => {
+                32 + // account (Pubkey)
+                4 +  // offset (u32)
+                (4 + bytes.len()) // bytes (Vec<u8>)
+            }
+            ExecutionPrecondition::MinimumTokenBalance { .. } => {
+                32 + // account (Pubkey)
+                8 // amount (u64)
+            }
+            ExecutionPrecondition::MinimumSlot { .. } => 8, // slot (u64)
+        }
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct SerailizableAccountMeta {
     pub key: Pubkey,
@@ -749,12 +1657,18 @@ pub struct ProposalTransaction {
     /// the instruction list so the executor can derive the correct authority PDA.
     pub asset_indices: Vec<AssetIndex>,
     pub asset_authority_bumps: Vec<[u8; 1]>,
+    /// Whether each asset required the Execute permission when the transaction was attached.
+    pub asset_execute_permissions: Vec<bool>,
     /// The ordered list of instructions to execute for this proposal.
     pub instructions: Vec<SerializableInstruction>,
     pub account_bump: u8,
+    /// The option of a multi-choice proposal this is the transaction of, it is also the
+    /// last seed of the account.
+    pub option_index: Option<u8>,
 }
 
 impl ProposalTransaction {
+    #[allow(clippy::too_many_arguments)]
     #[inline(always)]
     pub fn new(
         proposal: Pubkey,
@@ -762,6 +1676,7 @@ impl ProposalTransaction {
         proposal_index: u64,
         asset_indices: Vec<AssetIndex>,
         asset_authority_bumps: Vec<[u8; 1]>,
+        asset_execute_permissions: Vec<bool>,
         instructions: Vec<SerializableInstruction>,
         account_bump: u8,
     ) -> Self {
@@ -771,13 +1686,15 @@ impl ProposalTransaction {
             proposal_index,
             asset_indices,
             asset_authority_bumps,
+            asset_execute_permissions,
             instructions,
             account_bump,
+            option_index: None,
         }
     }
 
     /// Calculate the size of ProposalTransaction.
-    /// - `asset_len`: number of asset indices (same as number of authority bumps)
+    /// - `asset_len`: number of asset indices (same as number of authority bumps and execute permissions)
     /// - `instructions_total_size`: 4-byte Vec length prefix + sum of each instruction's serialized size
     #[inline(always)]
     pub fn get_size(asset_len: usize, instructions_total_size: usize) -> usize {
@@ -786,8 +1703,10 @@ impl ProposalTransaction {
         8 +  // proposal_index (u64)
         (4 + asset_len * 2) + // asset_indices (Vec<AssetIndex>) - 2 bytes per entry
         (4 + asset_len) + // asset_authority_bumps (Vec<[u8; 1]>)
+        (4 + asset_len) + // asset_execute_permissions (Vec<bool>)
         instructions_total_size + // instructions (Vec<SerializableInstruction>)
-        1 // account_bump (u8)
+        1 + // account_bump (u8)
+        2 // option_index (Option<u8>)
     }
 }
 
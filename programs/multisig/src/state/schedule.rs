@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::state::error::MultisigError;
+
+/// Transfer made from a governed asset on every run of a schedule.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub enum ScheduledTransfer {
+    /// Lamports sent from the asset authority.
+    Lamports { destination: Pubkey, amount: u64 },
+    /// Tokens sent from the asset token account to the `destination` token account.
+    Token { destination: Pubkey, amount: u64 },
+}
+
+impl ScheduledTransfer {
+    #[inline(always)]
+    pub fn destination(&self) -> Pubkey {
+        match self {
+            ScheduledTransfer::Lamports { destination, .. }
+            | ScheduledTransfer::Token { destination, .. } => *destination,
+        }
+    }
+
+    #[inline(always)]
+    pub fn amount(&self) -> u64 {
+        match self {
+            ScheduledTransfer::Lamports { amount, .. }
+            | ScheduledTransfer::Token { amount, .. } => *amount,
+        }
+    }
+}
+
+/// Recurring transfer from a governed asset, created by a passed normal proposal.
+/// Anyone can run it once it is due, it is closed after its last run or when a
+/// CancelSchedule config proposal is applied.
+#[account]
+#[derive(InitSpace)]
+pub struct Schedule {
+    pub group: Pubkey,
+    /// Address of the governed asset the transfers are made from.
+    pub asset: Pubkey,
+    pub schedule_seed: Pubkey,
+    pub transfer: ScheduledTransfer,
+    /// Seconds between two runs.
+    pub interval: u32,
+    pub total_runs: u32,
+    pub completed_runs: u32,
+    pub next_run_timestamp: i64,
+    /// Paid for the account and gets its rent back once it is closed.
+    pub rent_payer: Pubkey,
+    pub account_bump: u8,
+    pub authority_bump: u8,
+}
+
+impl Schedule {
+    #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        group: Pubkey,
+        asset: Pubkey,
+        schedule_seed: Pubkey,
+        transfer: ScheduledTransfer,
+        interval: u32,
+        total_runs: u32,
+        start_timestamp: i64,
+        rent_payer: Pubkey,
+        account_bump: u8,
+        authority_bump: u8,
+    ) -> Result<Self> {
+        require_gt!(interval, 0, MultisigError::InvalidSchedule);
+        require_gt!(total_runs, 0, MultisigError::InvalidSchedule);
+        require_gt!(transfer.amount(), 0, MultisigError::InvalidSchedule);
+
+        Ok(Self {
+            group,
+            asset,
+            schedule_seed,
+            transfer,
+            interval,
+            total_runs,
+            completed_runs: 0,
+            next_run_timestamp: start_timestamp,
+            rent_payer,
+            account_bump,
+            authority_bump,
+        })
+    }
+
+    #[inline(always)]
+    pub fn is_due(&self, now: i64) -> bool {
+        self.completed_runs < self.total_runs && now >= self.next_run_timestamp
+    }
+
+    /// Records a run and moves the next one an interval later, a run that was missed
+    /// can be caught up straight away. Returns whether this was the last run.
+    pub fn record_run(&mut self) -> Result<bool> {
+        self.completed_runs = self
+            .completed_runs
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        self.next_run_timestamp = self
+            .next_run_timestamp
+            .checked_add(i64::from(self.interval))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        Ok(self.completed_runs == self.total_runs)
+    }
+}
@@ -9,6 +9,11 @@ pub struct VoteRecord {
     pub asset_index: Option<u8>,
     pub account_bump: u8,
     pub vote_choice: VoteChoice,
+    /// Set when the vote was cast by a delegate of `voter`, cleared once `voter` votes
+    /// themselves. A delegate can only change votes they cast.
+    pub delegate: Option<Pubkey>,
+    /// The option voted for on a multi-choice proposal.
+    pub option_index: Option<u8>,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize, InitSpace, Clone, Copy, PartialEq)]
@@ -17,6 +22,64 @@ pub enum VoteChoice {
     Against,
 }
 
+/// A vote stored in the proposal itself instead of a `VoteRecord`,
+/// for proposals created with compact votes.
+#[derive(AnchorDeserialize, AnchorSerialize, InitSpace, Clone, Copy, PartialEq)]
+pub struct CompactVote {
+    /// `GroupMember::member_index` of the voter.
+    pub member_index: u32,
+    /// The asset voted on, `None` for config proposals.
+    pub asset_index: Option<u8>,
+    pub vote_choice: VoteChoice,
+}
+
+/// The message a member signs off-chain to approve a vote submitted by someone else.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq)]
+pub struct SignedVoteMessage {
+    pub group: Pubkey,
+    pub proposal: Pubkey,
+    /// The asset voted on, `None` for config proposals.
+    pub asset_index: Option<u8>,
+    pub vote_choice: VoteChoice,
+    /// The proposal's `proposal_index`, so a signature cannot be replayed on a later
+    /// proposal created at the same address.
+    pub nonce: u64,
+}
+
+impl SignedVoteMessage {
+    /// The Borsh encoding of the message, which is what gets signed.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.serialize(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+impl CompactVote {
+    /// Records the member's vote and returns their previous vote, if any.
+    pub fn record(
+        votes: &mut Vec<CompactVote>,
+        member_index: u32,
+        asset_index: Option<u8>,
+        vote_choice: VoteChoice,
+    ) -> Option<VoteChoice> {
+        match votes
+            .iter_mut()
+            .find(|vote| vote.member_index == member_index && vote.asset_index == asset_index)
+        {
+            Some(vote) => Some(std::mem::replace(&mut vote.vote_choice, vote_choice)),
+            None => {
+                votes.push(CompactVote {
+                    member_index,
+                    asset_index,
+                    vote_choice,
+                });
+                None
+            }
+        }
+    }
+}
+
 impl VoteRecord {
     #[inline(always)]
     pub fn new(
@@ -32,6 +95,24 @@ impl VoteRecord {
             asset_index,
             account_bump,
             vote_choice,
+            delegate: None,
+            option_index: None,
+        }
+    }
+
+    /// A vote cast by `delegate` with the weight of `voter`.
+    #[inline(always)]
+    pub fn new_delegated(
+        voter: Pubkey,
+        proposal: Pubkey,
+        asset_index: Option<u8>,
+        account_bump: u8,
+        vote_choice: VoteChoice,
+        delegate: Pubkey,
+    ) -> Self {
+        Self {
+            delegate: Some(delegate),
+            ..Self::new(voter, proposal, asset_index, account_bump, vote_choice)
         }
     }
 
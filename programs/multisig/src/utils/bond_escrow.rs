@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::state::{
+    bond::{ProposalBond, ProposalBondConfig},
+    error::MultisigError,
+};
+
+/// Bond accounts of a proposal creation instruction, all omitted when the group sets no bond.
+pub struct BondDepositAccounts<'a, 'info> {
+    pub bond: Option<&'a mut Account<'info, ProposalBond>>,
+    pub bond_bump: Option<u8>,
+    pub bond_mint: Option<&'a InterfaceAccount<'info, Mint>>,
+    pub payer_bond_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub bond_escrow: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Option<&'a Interface<'info, TokenInterface>>,
+}
+
+/// Escrows the group's proposal bond, paid by `payer`, for a newly created proposal.
+/// Returns whether a bond was taken.
+pub fn deposit_proposal_bond<'info>(
+    config: Option<&ProposalBondConfig>,
+    accounts: BondDepositAccounts<'_, 'info>,
+    group: Pubkey,
+    proposal: Pubkey,
+    proposal_index: u64,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<bool> {
+    let Some(config) = config else {
+        require!(
+            accounts.bond.is_none() && accounts.bond_escrow.is_none(),
+            MultisigError::InvalidProposalBond
+        );
+        return Ok(false);
+    };
+
+    let bond = accounts
+        .bond
+        .ok_or(MultisigError::ProposalBondNotProvided)?;
+    bond.set_inner(ProposalBond::new(
+        group,
+        proposal,
+        proposal_index,
+        payer.key(),
+        config,
+        accounts
+            .bond_bump
+            .ok_or(MultisigError::ProposalBondNotProvided)?,
+    ));
+
+    match config.mint {
+        None => {
+            require!(
+                accounts.bond_escrow.is_none(),
+                MultisigError::InvalidProposalBond
+            );
+            transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    Transfer {
+                        from: payer.to_account_info(),
+                        to: bond.to_account_info(),
+                    },
+                ),
+                config.amount,
+            )?;
+        }
+        Some(mint) => {
+            let (
+                Some(bond_mint),
+                Some(payer_bond_token_account),
+                Some(bond_escrow),
+                Some(token_program),
+            ) = (
+                accounts.bond_mint,
+                accounts.payer_bond_token_account,
+                accounts.bond_escrow,
+                accounts.token_program,
+            )
+            else {
+                return Err(MultisigError::ProposalBondNotProvided.into());
+            };
+            require_keys_eq!(bond_mint.key(), mint, MultisigError::InvalidProposalBond);
+
+            transfer_checked(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    TransferChecked {
+                        from: payer_bond_token_account.to_account_info(),
+                        mint: bond_mint.to_account_info(),
+                        to: bond_escrow.to_account_info(),
+                        authority: payer.to_account_info(),
+                    },
+                ),
+                config.amount,
+                bond_mint.decimals,
+            )?;
+        }
+    }
+
+    Ok(true)
+}
+
+/// Token accounts needed to release a bond paid in a token.
+pub struct BondReleaseTokenAccounts<'a, 'info> {
+    pub bond_mint: &'a InterfaceAccount<'info, Mint>,
+    pub bond_escrow: &'a InterfaceAccount<'info, TokenAccount>,
+    /// Token account of the depositor on a refund, of the rent collector on a forfeit.
+    pub recipient: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+/// Sends a token bond to `recipient` and closes the escrow, its rent going to `depositor`.
+pub fn release_token_bond<'info>(
+    bond: &Account<'info, ProposalBond>,
+    accounts: BondReleaseTokenAccounts<'_, 'info>,
+    depositor: &AccountInfo<'info>,
+) -> Result<()> {
+    let signer_seeds: &[&[u8]] = &[
+        b"proposal-bond",
+        bond.proposal.as_ref(),
+        &[bond.account_bump],
+    ];
+
+    // The whole balance is sent, in case the mint kept a fee on the deposit.
+    transfer_checked(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: accounts.bond_escrow.to_account_info(),
+                mint: accounts.bond_mint.to_account_info(),
+                to: accounts.recipient.to_account_info(),
+                authority: bond.to_account_info(),
+            },
+            &[signer_seeds],
+        ),
+        accounts.bond_escrow.amount,
+        accounts.bond_mint.decimals,
+    )?;
+
+    close_account(CpiContext::new_with_signer(
+        accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: accounts.bond_escrow.to_account_info(),
+            destination: depositor.clone(),
+            authority: bond.to_account_info(),
+        },
+        &[signer_seeds],
+    ))
+}
@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{
+        delegation::Delegation,
+        error::MultisigError,
+        group::Group,
+        member::{AssetMember, GroupMember},
+        vote::{VoteChoice, VoteRecord},
+    },
+    utils::create_pda_account,
+};
+
+/// The weight a delegated vote carries and the delegator's previous vote, if any.
+pub struct DelegatedVote {
+    pub weight: u32,
+    pub previous_vote: Option<VoteChoice>,
+    /// The option of a multi-choice proposal the previous vote was for.
+    pub previous_option_index: Option<u8>,
+}
+
+/// Casts `vote` for every delegator in `remaining_accounts`, passed as
+/// `[delegation, delegator member, delegator vote record]` triples. The member is the
+/// delegator's `AssetMember` of `asset` when set and their `GroupMember` otherwise.
+///
+/// Votes are written to the delegator's own vote record so they cannot vote twice,
+/// a delegator who already voted themselves cannot be voted for. A delegator whose key was
+/// rotated after the proposal was created at `propose_timestamp` cannot be voted for either.
+/// `option_index` is the option voted for on a multi-choice proposal, `None` otherwise.
+/// New vote records are paid for by `payer`.
+#[allow(clippy::too_many_arguments)]
+pub fn cast_delegated_votes<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    group: &Account<'info, Group>,
+    proposal: Pubkey,
+    propose_timestamp: i64,
+    asset: Option<Pubkey>,
+    asset_index: Option<u8>,
+    voter: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    vote: VoteChoice,
+    option_index: Option<u8>,
+) -> Result<Vec<DelegatedVote>> {
+    require!(
+        remaining_accounts.chunks_exact(3).remainder().is_empty(),
+        MultisigError::LengthMismatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let group_key = group.key();
+    let index_seed = asset_index.map(|index| [index]);
+    let mut delegated_votes = Vec::with_capacity(remaining_accounts.len() / 3);
+
+    for accounts in remaining_accounts.chunks_exact(3) {
+        let (delegation_info, member_info, vote_record_info) =
+            (&accounts[0], &accounts[1], &accounts[2]);
+
+        require_keys_eq!(
+            *delegation_info.owner,
+            crate::ID,
+            MultisigError::InvalidDelegation
+        );
+        let delegation = Delegation::try_deserialize(&mut &delegation_info.try_borrow_data()?[..])?;
+        require_keys_eq!(
+            delegation.group,
+            group_key,
+            MultisigError::InvalidDelegation
+        );
+        require_keys_eq!(
+            delegation.delegate,
+            voter.key(),
+            MultisigError::InvalidDelegation
+        );
+        require!(delegation.asset == asset, MultisigError::InvalidDelegation);
+        require!(delegation.is_active(now), MultisigError::DelegationExpired);
+
+        let delegator = delegation.delegator;
+
+        // The weight is read from the delegator's current membership.
+        require_keys_eq!(*member_info.owner, crate::ID, MultisigError::InvalidMember);
+        let member_weight = match asset {
+            Some(asset) => {
+                let member =
+                    AssetMember::try_deserialize(&mut &member_info.try_borrow_data()?[..])?;
+                require!(
+                    member.user == delegator && member.group == group_key && member.asset == asset,
+                    MultisigError::InvalidMember
+                );
+                require!(member.is_active(now), MultisigError::MembershipExpired);
+                require!(
+                    member.can_vote_on_proposal_from(propose_timestamp),
+                    MultisigError::KeyRotatedAfterProposal
+                );
+                member.weight
+            }
+            None => {
+                let member =
+                    GroupMember::try_deserialize(&mut &member_info.try_borrow_data()?[..])?;
+                require!(
+                    member.user == delegator && member.group == group_key,
+                    MultisigError::InvalidMember
+                );
+                require!(member.is_active(now), MultisigError::MembershipExpired);
+                require!(
+                    member.can_vote_on_proposal_from(propose_timestamp),
+                    MultisigError::KeyRotatedAfterProposal
+                );
+                member.weight
+            }
+        };
+        let weight = member_weight.min(group.max_member_weight);
+        require_gt!(weight, 0, MultisigError::UnauthorizedVoter);
+
+        let mut seeds: Vec<&[u8]> = vec![
+            b"vote-record",
+            group_key.as_ref(),
+            proposal.as_ref(),
+            delegator.as_ref(),
+        ];
+        if let Some(index_seed) = index_seed.as_ref() {
+            seeds.push(index_seed);
+        }
+        let (expected_vote_record, bump) = Pubkey::find_program_address(&seeds, &crate::ID);
+        require_keys_eq!(
+            vote_record_info.key(),
+            expected_vote_record,
+            MultisigError::InvalidDelegation
+        );
+
+        let previous_vote = if *vote_record_info.owner == crate::ID {
+            let mut vote_record =
+                VoteRecord::try_deserialize(&mut &vote_record_info.try_borrow_data()?[..])?;
+            require!(
+                vote_record.delegate == Some(voter.key()),
+                MultisigError::DelegatorAlreadyVoted
+            );
+
+            let previous_vote = (vote_record.vote_choice, vote_record.option_index);
+            vote_record.vote_choice = vote;
+            vote_record.option_index = option_index;
+            vote_record.try_serialize(&mut &mut vote_record_info.try_borrow_mut_data()?[..])?;
+
+            Some(previous_vote)
+        } else {
+            let bump_seed = [bump];
+            seeds.push(&bump_seed);
+            create_pda_account(
+                payer,
+                vote_record_info,
+                system_program,
+                8 + VoteRecord::INIT_SPACE,
+                &seeds,
+            )?;
+
+            let vote_record = VoteRecord {
+                option_index,
+                ..VoteRecord::new_delegated(
+                    delegator,
+                    proposal,
+                    asset_index,
+                    bump,
+                    vote,
+                    voter.key(),
+                )
+            };
+            vote_record.try_serialize(&mut &mut vote_record_info.try_borrow_mut_data()?[..])?;
+
+            None
+        };
+
+        delegated_votes.push(DelegatedVote {
+            weight,
+            previous_vote: previous_vote.map(|(vote_choice, _)| vote_choice),
+            previous_option_index: previous_vote.and_then(|(_, option_index)| option_index),
+        });
+    }
+
+    Ok(delegated_votes)
+}
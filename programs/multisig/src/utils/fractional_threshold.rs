@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 use anchor_lang::prelude::*;
 
 use crate::MultisigError;
@@ -59,6 +61,13 @@ impl FractionalThreshold {
         Ok(())
     }
 
+    /// Orders thresholds by value, so 1/2 and 2/4 are equal.
+    pub fn cmp_value(&self, other: &FractionalThreshold) -> Ordering {
+        // Cannot overflow, each side is the product of two u32.
+        (u64::from(self.numerator) * u64::from(other.denominator))
+            .cmp(&(u64::from(other.numerator) * u64::from(self.denominator)))
+    }
+
     /// Compares two fractional vaules
     pub fn less_than_or_equal(&self, numerator: u64, denominator: u64) -> Result<bool> {
         if self.denominator == 0 || denominator == 0 {
@@ -1,2 +1,17 @@
 pub mod fractional_threshold;
 pub use fractional_threshold::*;
+
+pub mod account;
+pub use account::*;
+
+pub mod delegated_votes;
+pub use delegated_votes::*;
+
+pub mod signed_votes;
+pub use signed_votes::*;
+
+pub mod bond_escrow;
+pub use bond_escrow::*;
+
+pub mod open_proposals;
+pub use open_proposals::*;
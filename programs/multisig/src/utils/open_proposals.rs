@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::state::{group::Group, member::GroupMember};
+
+/// Stops counting a proposal as open for the group and for its proposer, once it was
+/// executed or closed. `proposer_group_account` is the proposer's member PDA, it is
+/// skipped when the proposer was removed or rotated their key since proposing.
+pub fn close_open_proposal(group: &mut Group, proposer_group_account: &AccountInfo) -> Result<()> {
+    group.close_open_proposal();
+
+    if proposer_group_account.owner != &crate::ID {
+        return Ok(());
+    }
+
+    let mut data = proposer_group_account.try_borrow_mut_data()?;
+    if !data.starts_with(GroupMember::DISCRIMINATOR) {
+        return Ok(());
+    }
+
+    let mut proposer = GroupMember::try_deserialize(&mut &data[..])?;
+    proposer.close_open_proposal();
+    proposer.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
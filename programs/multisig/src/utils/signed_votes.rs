@@ -0,0 +1,76 @@
+use anchor_lang::{prelude::*, solana_program::ed25519_program};
+
+#[allow(deprecated)]
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::state::error::MultisigError;
+
+/// Size of the offsets the Ed25519 program reads for each signature.
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+/// Size of the header holding the signature count and a padding byte.
+const SIGNATURE_OFFSETS_START: usize = 2;
+const PUBKEY_SIZE: usize = 32;
+
+/// Returns the signer and message of every signature checked by the Ed25519 program
+/// instruction placed right before the current instruction, in order.
+///
+/// The Ed25519 program fails the transaction if any signature is invalid, so a signer and
+/// message returned here were signed. Signatures, keys and messages must live in the Ed25519
+/// instruction itself, offsets pointing into other instructions are rejected.
+pub fn load_ed25519_signatures(
+    instructions_sysvar: &AccountInfo,
+) -> Result<Vec<(Pubkey, Vec<u8>)>> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    let ed25519_index = current_index
+        .checked_sub(1)
+        .ok_or(MultisigError::InvalidSignedVote)?;
+    let instruction = load_instruction_at_checked(usize::from(ed25519_index), instructions_sysvar)?;
+    require_keys_eq!(
+        instruction.program_id,
+        ed25519_program::ID,
+        MultisigError::InvalidSignedVote
+    );
+
+    let data = instruction.data.as_slice();
+    let signature_count = usize::from(*data.first().ok_or(MultisigError::InvalidSignedVote)?);
+
+    let read_u16 = |offset: usize| -> Result<u16> {
+        data.get(offset..offset + 2)
+            .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+            .ok_or(MultisigError::InvalidSignedVote.into())
+    };
+    let read_slice = |offset: u16, size: usize| -> Result<&[u8]> {
+        let offset = usize::from(offset);
+        data.get(offset..offset + size)
+            .ok_or(MultisigError::InvalidSignedVote.into())
+    };
+
+    (0..signature_count)
+        .map(|index| {
+            let start = SIGNATURE_OFFSETS_START + index * SIGNATURE_OFFSETS_SIZE;
+
+            // `u16::MAX` refers to the Ed25519 instruction itself.
+            let signature_instruction_index = read_u16(start + 2)?;
+            let public_key_offset = read_u16(start + 4)?;
+            let public_key_instruction_index = read_u16(start + 6)?;
+            let message_data_offset = read_u16(start + 8)?;
+            let message_data_size = read_u16(start + 10)?;
+            let message_instruction_index = read_u16(start + 12)?;
+
+            require!(
+                signature_instruction_index == u16::MAX
+                    && public_key_instruction_index == u16::MAX
+                    && message_instruction_index == u16::MAX,
+                MultisigError::InvalidSignedVote
+            );
+
+            let signer = Pubkey::try_from(read_slice(public_key_offset, PUBKEY_SIZE)?)
+                .map_err(|_| MultisigError::InvalidSignedVote)?;
+            let message = read_slice(message_data_offset, usize::from(message_data_size))?.to_vec();
+
+            Ok((signer, message))
+        })
+        .collect()
+}
@@ -0,0 +1,217 @@
+#![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::{AddGroupMemberInstructionArgs, CreateConfigProposalInstructionArgs},
+    ConfigChange, GroupMember, MultisigError, Permissions, ProposalState,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{
+    add_multisig_program, assert_multisig_instruction_error, read_group, send_tx,
+    set_config_proposal_state, set_member_invite_as_expired, setup_group,
+};
+
+// Accept membership turns the invite of a passed AddGroupMember proposal into a group member.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    Expired,
+    WrongInvitePayer,
+    WithTerm,
+}
+
+const MEMBER_TERM_END: i64 = 500;
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey)> {
+        let group_setup = setup_group(svm)?;
+        let new_member = Keypair::new();
+        svm.airdrop(&new_member.pubkey(), 1_000_000_000)
+            .map_err(|error| anyhow::anyhow!("airdrop failed: {:?}", error))?;
+
+        let proposal_seed = Pubkey::new_unique();
+        let proposal_pda = sdk::proposal_pda(&group_setup.group, &proposal_seed);
+
+        let create_args = CreateConfigProposalInstructionArgs {
+            proposal_seed,
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            config_change: ConfigChange::AddGroupMember {
+                member: new_member.pubkey(),
+                weight: 1,
+                permissions: Permissions::builder().propose(true).add_asset(true).build(),
+                expires_at: match scenario {
+                    Scenario::WithTerm => Some(MEMBER_TERM_END),
+                    _ => None,
+                },
+            },
+            compact_votes: false,
+            description: None,
+        };
+        let create_config_proposal = sdk::create_config_proposal(
+            create_args,
+            group_setup.group,
+            group_setup.payer.pubkey(),
+            None,
+        );
+        send_tx(svm, &group_setup.payer, vec![create_config_proposal], &[])?;
+        set_config_proposal_state(svm, proposal_pda.address, ProposalState::Passed, Some(0))?;
+
+        let add_group_member = sdk::add_group_member(
+            AddGroupMemberInstructionArgs {
+                new_member: new_member.pubkey(),
+            },
+            group_setup.group,
+            proposal_pda.address,
+            group_setup.payer.pubkey(),
+            group_setup.payer.pubkey(),
+        );
+        send_tx(svm, &group_setup.payer, vec![add_group_member], &[])?;
+
+        if let Scenario::Expired = scenario {
+            let invite = sdk::member_invite_pda(&group_setup.group, &new_member.pubkey());
+            set_member_invite_as_expired(svm, invite.address)?;
+        }
+
+        let invite_payer = match scenario {
+            Scenario::WrongInvitePayer => Pubkey::new_unique(),
+            _ => group_setup.payer.pubkey(),
+        };
+        let accept = sdk::accept_membership(group_setup.group, new_member.pubkey(), invite_payer);
+
+        Ok(([accept], vec![new_member], group_setup.group))
+    }
+
+    pub fn with_default(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_term(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey)> {
+        Self::builder(svm, Scenario::WithTerm)
+    }
+
+    pub fn with_expired(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _) = Self::builder(svm, Scenario::Expired)?;
+        Ok((ix, signers))
+    }
+
+    pub fn with_wrong_invite_payer(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _) = Self::builder(svm, Scenario::WrongInvitePayer)?;
+        Ok((ix, signers))
+    }
+}
+
+#[test]
+fn test_accept_membership_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, group) =
+        TestSetup::with_default(&mut svm).expect("Failed to create instruction");
+    let new_member = signers[0].pubkey();
+    let before = read_group(&svm, group).expect("read group before");
+
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&new_member),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(transaction));
+
+    let after = read_group(&svm, group).expect("read group after");
+    assert_eq!(after.member_count, before.member_count + 1);
+    assert_eq!(after.next_member_index, before.next_member_index + 1);
+
+    let member_account = svm
+        .get_account(&sdk::group_member_pda(&group, &new_member).address)
+        .expect("group member created");
+    let member = GroupMember::try_deserialize(&mut member_account.data.as_slice())
+        .expect("deserialize group member");
+    assert_eq!(member.user, new_member);
+    assert_eq!(member.weight, 1);
+    assert_eq!(member.member_index, before.next_member_index);
+
+    assert!(svm
+        .get_account(&sdk::member_invite_pda(&group, &new_member).address)
+        .is_none_or(|account| account.lamports == 0));
+}
+
+// The term set by the AddGroupMember change carries over to the new member.
+#[test]
+fn test_accept_membership_with_term() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, group) =
+        TestSetup::with_term(&mut svm).expect("Failed to create instruction");
+    let new_member = signers[0].pubkey();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&new_member),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(transaction));
+
+    let member_account = svm
+        .get_account(&sdk::group_member_pda(&group, &new_member).address)
+        .expect("group member created");
+    let member = GroupMember::try_deserialize(&mut member_account.data.as_slice())
+        .expect("deserialize group member");
+    assert_eq!(member.expires_at, Some(MEMBER_TERM_END));
+    assert!(member.is_active(MEMBER_TERM_END - 1));
+    assert!(!member.is_active(MEMBER_TERM_END));
+}
+
+#[test]
+fn test_accept_membership_fails_when_invite_expired() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_expired(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::InviteExpired,
+    );
+}
+
+#[test]
+fn test_accept_membership_fails_with_wrong_invite_payer() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_wrong_invite_payer(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::InvalidInvitePayer,
+    );
+}
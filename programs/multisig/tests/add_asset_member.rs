@@ -41,9 +41,11 @@ impl TestSetup {
             config_change: ConfigChange::AddAssetMember {
                 member: new_member,
                 weight: 1,
-                permissions: Permissions::from_flags(true, true),
+                permissions: Permissions::builder().propose(true).add_asset(true).build(),
                 asset_address: asset_setup.asset_address,
             },
+            compact_votes: false,
+            description: None,
         };
 
         let create_config_proposal = sdk::create_config_proposal(
@@ -116,9 +118,11 @@ impl TestSetup {
             config_change: ConfigChange::AddAssetMember {
                 member: new_member,
                 weight: 1,
-                permissions: Permissions::from_flags(true, true),
+                permissions: Permissions::builder().propose(true).add_asset(true).build(),
                 asset_address: asset_setup.asset_address,
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -162,9 +166,11 @@ impl TestSetup {
             config_change: ConfigChange::AddAssetMember {
                 member: new_member,
                 weight: 1,
-                permissions: Permissions::from_flags(true, true),
+                permissions: Permissions::builder().propose(true).add_asset(true).build(),
                 asset_address: asset_setup.asset_address,
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -208,9 +214,11 @@ impl TestSetup {
             config_change: ConfigChange::AddAssetMember {
                 member: new_member,
                 weight: 1,
-                permissions: Permissions::from_flags(true, true),
+                permissions: Permissions::builder().propose(true).add_asset(true).build(),
                 asset_address: asset_setup.asset_address,
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -254,9 +262,11 @@ impl TestSetup {
             config_change: ConfigChange::AddAssetMember {
                 member: new_member,
                 weight: 1,
-                permissions: Permissions::from_flags(true, true),
+                permissions: Permissions::builder().propose(true).add_asset(true).build(),
                 asset_address: asset_setup.asset_address,
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -301,8 +311,11 @@ impl TestSetup {
             config_change: ConfigChange::AddGroupMember {
                 member: new_member,
                 weight: 1,
-                permissions: Permissions::from_flags(true, true),
+                permissions: Permissions::builder().propose(true).add_asset(true).build(),
+                expires_at: None,
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
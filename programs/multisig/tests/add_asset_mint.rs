@@ -58,7 +58,7 @@ impl TestSetup {
             member_key_2: member_keys[1],
             member_key_3: member_keys[2],
             initial_weights: [1, 1, 1],
-            initial_permissions: [Permissions::from_flags(true, true); 3],
+            initial_permissions: [Permissions::builder().propose(true).add_asset(true).build(); 3],
             use_threshold: threshold(1, 2),
             not_use_threshold: threshold(2, 3),
             add_threshold: threshold(1, 2),
@@ -100,12 +100,7 @@ impl TestSetup {
         let member_keys = group_setup.member_keys;
 
         // Revoke add_asset permission from the payer's group member
-        set_group_member_permissions(
-            svm,
-            group,
-            payer.pubkey(),
-            Permissions::from_flags(false, false),
-        )?;
+        set_group_member_permissions(svm, group, payer.pubkey(), Permissions::builder().build())?;
 
         let mint_keypair = Keypair::new();
         let mint = mint_keypair.pubkey();
@@ -124,7 +119,7 @@ impl TestSetup {
             member_key_2: member_keys[1],
             member_key_3: member_keys[2],
             initial_weights: [1, 1, 1],
-            initial_permissions: [Permissions::from_flags(true, true); 3],
+            initial_permissions: [Permissions::builder().propose(true).add_asset(true).build(); 3],
             use_threshold: threshold(1, 2),
             not_use_threshold: threshold(2, 3),
             add_threshold: threshold(1, 2),
@@ -168,7 +163,7 @@ impl TestSetup {
             member_key_2: member_keys[1],
             member_key_3: member_keys[2],
             initial_weights: [1, 1, 1],
-            initial_permissions: [Permissions::from_flags(true, true); 3],
+            initial_permissions: [Permissions::builder().propose(true).add_asset(true).build(); 3],
             use_threshold: threshold(1, 2),
             not_use_threshold: threshold(2, 3),
             add_threshold: threshold(1, 2),
@@ -209,7 +204,7 @@ impl TestSetup {
             member_key_2: member_keys[1],
             member_key_3: member_keys[2],
             initial_weights: [101, 101, 101], // 101 > max_member_weight=100
-            initial_permissions: [Permissions::from_flags(true, true); 3],
+            initial_permissions: [Permissions::builder().propose(true).add_asset(true).build(); 3],
             use_threshold: threshold(1, 2),
             not_use_threshold: threshold(2, 3),
             add_threshold: threshold(1, 2),
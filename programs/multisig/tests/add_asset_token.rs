@@ -71,7 +71,7 @@ impl TestSetup {
             member_key_2: member_keys[1],
             member_key_3: member_keys[2],
             initial_weights: [1, 1, 1],
-            initial_permissions: [Permissions::from_flags(true, true); 3],
+            initial_permissions: [Permissions::builder().propose(true).add_asset(true).build(); 3],
             use_threshold: threshold(1, 2),
             not_use_threshold: threshold(2, 3),
             add_threshold: threshold(1, 2),
@@ -113,12 +113,7 @@ impl TestSetup {
         let group = group_setup.group;
         let member_keys = group_setup.member_keys;
 
-        set_group_member_permissions(
-            svm,
-            group,
-            payer.pubkey(),
-            Permissions::from_flags(false, false),
-        )?;
+        set_group_member_permissions(svm, group, payer.pubkey(), Permissions::builder().build())?;
 
         let mint_keypair = Keypair::new();
         let mint = mint_keypair.pubkey();
@@ -148,7 +143,7 @@ impl TestSetup {
             member_key_2: member_keys[1],
             member_key_3: member_keys[2],
             initial_weights: [1, 1, 1],
-            initial_permissions: [Permissions::from_flags(true, true); 3],
+            initial_permissions: [Permissions::builder().propose(true).add_asset(true).build(); 3],
             use_threshold: threshold(1, 2),
             not_use_threshold: threshold(2, 3),
             add_threshold: threshold(1, 2),
@@ -202,7 +197,7 @@ impl TestSetup {
             member_key_2: member_keys[1],
             member_key_3: member_keys[2],
             initial_weights: [1, 1, 1],
-            initial_permissions: [Permissions::from_flags(true, true); 3],
+            initial_permissions: [Permissions::builder().propose(true).add_asset(true).build(); 3],
             use_threshold: threshold(1, 2),
             not_use_threshold: threshold(2, 3),
             add_threshold: threshold(1, 2),
@@ -258,7 +253,7 @@ impl TestSetup {
             member_key_2: member_keys[1],
             member_key_3: member_keys[2],
             initial_weights: [1, 1, 1],
-            initial_permissions: [Permissions::from_flags(true, true); 3],
+            initial_permissions: [Permissions::builder().propose(true).add_asset(true).build(); 3],
             use_threshold: threshold(1, 2),
             not_use_threshold: threshold(2, 3),
             add_threshold: threshold(1, 2),
@@ -42,8 +42,11 @@ impl TestSetup {
             config_change: ConfigChange::AddGroupMember {
                 member: new_member.pubkey(),
                 weight: 1,
-                permissions: Permissions::from_flags(true, true),
+                permissions: Permissions::builder().propose(true).add_asset(true).build(),
+                expires_at: None,
             },
+            compact_votes: false,
+            description: None,
         };
 
         let create_config_proposal = sdk::create_config_proposal(
@@ -118,8 +121,11 @@ impl TestSetup {
             config_change: ConfigChange::AddGroupMember {
                 member: new_member.pubkey(),
                 weight: 1,
-                permissions: Permissions::from_flags(true, true),
+                permissions: Permissions::builder().propose(true).add_asset(true).build(),
+                expires_at: None,
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -165,8 +171,11 @@ impl TestSetup {
             config_change: ConfigChange::AddGroupMember {
                 member: new_member.pubkey(),
                 weight: 1,
-                permissions: Permissions::from_flags(true, true),
+                permissions: Permissions::builder().propose(true).add_asset(true).build(),
+                expires_at: None,
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -212,8 +221,11 @@ impl TestSetup {
             config_change: ConfigChange::AddGroupMember {
                 member: new_member.pubkey(),
                 weight: 1,
-                permissions: Permissions::from_flags(true, true),
+                permissions: Permissions::builder().propose(true).add_asset(true).build(),
+                expires_at: None,
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -259,8 +271,11 @@ impl TestSetup {
             config_change: ConfigChange::AddGroupMember {
                 member: new_member.pubkey(),
                 weight: 1,
-                permissions: Permissions::from_flags(true, true),
+                permissions: Permissions::builder().propose(true).add_asset(true).build(),
+                expires_at: None,
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -308,6 +323,8 @@ impl TestSetup {
             config_change: ConfigChange::RemoveGroupMember {
                 member: target_member,
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -346,6 +363,7 @@ fn test_add_group_member_success() {
         Err(error) => panic!("Failed to create instruction: {}", error),
     };
 
+    let before = read_group(&svm, group).expect("read group before");
     let payer = signers[0].pubkey();
     let recent_blockhash = svm.latest_blockhash();
     let transaction =
@@ -354,7 +372,9 @@ fn test_add_group_member_success() {
     let result = svm.send_transaction(transaction);
     common::assert_transaction_success(result);
 
+    // The new member is only invited, they are not counted until they accept.
     let after = read_group(&svm, group).expect("read group after");
+    assert_eq!(after.member_count, before.member_count);
     assert_eq!(after.proposal_index_after_stale, after.next_proposal_index);
 }
 
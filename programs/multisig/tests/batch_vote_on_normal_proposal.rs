@@ -0,0 +1,266 @@
+#![cfg(feature = "test-helpers")]
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::{
+        AssetVote, BatchVoteOnNormalProposalInstructionArgs, CreateNormalProposalInstructionArgs,
+        CreateProposalTransactionInstructionArgs,
+    },
+    AssetIndex, SerializableInstruction, VoteChoice,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{instruction::Instruction, signer::Signer, transaction::Transaction};
+
+mod common;
+use common::{
+    add_multisig_program, create_token_account_at, read_normal_proposal, send_tx, setup_asset_mint,
+    setup_group, to_serializable,
+};
+
+// Batch vote on normal proposal: one vote per asset of a two asset proposal.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    UnsortedVotes,
+    MissingAccounts,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<(
+        [Instruction; 1],
+        Vec<solana_sdk::signature::Keypair>,
+        solana_sdk::pubkey::Pubkey,
+    )> {
+        let group_setup = setup_group(svm)?;
+        let mut mints = [
+            setup_asset_mint(svm, &group_setup)?.mint,
+            setup_asset_mint(svm, &group_setup)?.mint,
+        ];
+        mints.sort();
+
+        let mut serializable_instructions: Vec<SerializableInstruction> = Vec::new();
+        for mint in mints.iter() {
+            let destination = solana_sdk::signature::Keypair::new();
+            create_token_account_at(
+                svm,
+                &destination.pubkey(),
+                mint,
+                &group_setup.payer.pubkey(),
+                solana_sdk::program_option::COption::None,
+                spl_token::state::AccountState::Initialized,
+                solana_sdk::program_option::COption::None,
+            )?;
+
+            let mint_to_ix = spl_token::instruction::mint_to(
+                &spl_token::ID,
+                mint,
+                &destination.pubkey(),
+                &sdk::asset_authority_pda(&group_setup.group, mint).address,
+                &[],
+                1,
+            )?;
+            serializable_instructions.push(to_serializable(&mint_to_ix));
+        }
+
+        let proposal_seed = solana_sdk::pubkey::Pubkey::new_unique();
+        let create_normal_args = CreateNormalProposalInstructionArgs {
+            proposal_seed,
+            asset_keys: mints.to_vec(),
+            asset_indices: vec![
+                AssetIndex {
+                    instruction_index: 0,
+                    account_index: 0,
+                },
+                AssetIndex {
+                    instruction_index: 1,
+                    account_index: 0,
+                },
+            ],
+            authority_bumps: mints
+                .iter()
+                .map(|mint| sdk::asset_authority_pda(&group_setup.group, mint).bump)
+                .collect(),
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            instruction_hashes: sdk::serializable_instruction_hashes(&serializable_instructions)?,
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
+            compact_votes: false,
+            description: None,
+            options: vec![],
+        };
+        let create_normal_proposal = sdk::create_normal_proposal(
+            create_normal_args,
+            group_setup.group,
+            group_setup.payer.pubkey(),
+        );
+
+        let create_tx_args = CreateProposalTransactionInstructionArgs {
+            raw_instructions: sdk::serializable_instructions_bytes(&serializable_instructions)?,
+        };
+        let create_proposal_tx = sdk::create_proposal_transaction(
+            create_tx_args,
+            group_setup.group,
+            proposal_seed,
+            group_setup.payer.pubkey(),
+            &mints,
+        );
+        send_tx(
+            svm,
+            &group_setup.payer,
+            vec![create_normal_proposal, create_proposal_tx],
+            &[],
+        )?;
+
+        let proposal = sdk::proposal_pda(&group_setup.group, &proposal_seed).address;
+
+        let (votes, asset_addresses) = match scenario {
+            Scenario::UnsortedVotes => (
+                vec![
+                    AssetVote {
+                        voting_asset_index: 1,
+                        vote: VoteChoice::For,
+                    },
+                    AssetVote {
+                        voting_asset_index: 0,
+                        vote: VoteChoice::Against,
+                    },
+                ],
+                vec![mints[1], mints[0]],
+            ),
+            Scenario::Default | Scenario::MissingAccounts => (
+                vec![
+                    AssetVote {
+                        voting_asset_index: 0,
+                        vote: VoteChoice::For,
+                    },
+                    AssetVote {
+                        voting_asset_index: 1,
+                        vote: VoteChoice::Against,
+                    },
+                ],
+                mints.to_vec(),
+            ),
+        };
+
+        let mut batch_vote = sdk::batch_vote_on_normal_proposal(
+            BatchVoteOnNormalProposalInstructionArgs { votes },
+            group_setup.group,
+            proposal,
+            &asset_addresses,
+            group_setup.payer.pubkey(),
+        );
+
+        if matches!(scenario, Scenario::MissingAccounts) {
+            batch_vote.accounts.pop();
+        }
+
+        Ok(([batch_vote], vec![group_setup.payer], proposal))
+    }
+
+    pub fn with_default(
+        svm: &mut LiteSVM,
+    ) -> Result<(
+        [Instruction; 1],
+        Vec<solana_sdk::signature::Keypair>,
+        solana_sdk::pubkey::Pubkey,
+    )> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_unsorted_votes(
+        svm: &mut LiteSVM,
+    ) -> Result<(
+        [Instruction; 1],
+        Vec<solana_sdk::signature::Keypair>,
+        solana_sdk::pubkey::Pubkey,
+    )> {
+        Self::builder(svm, Scenario::UnsortedVotes)
+    }
+
+    pub fn with_missing_accounts(
+        svm: &mut LiteSVM,
+    ) -> Result<(
+        [Instruction; 1],
+        Vec<solana_sdk::signature::Keypair>,
+        solana_sdk::pubkey::Pubkey,
+    )> {
+        Self::builder(svm, Scenario::MissingAccounts)
+    }
+}
+
+#[test]
+fn test_batch_vote_on_normal_proposal_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_default(&mut svm);
+    let (instructions, signers, proposal) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    let proposal = read_normal_proposal(&svm, proposal).expect("proposal");
+    assert_eq!(proposal.assets[0].vote_count, 1);
+    assert_eq!(proposal.assets[0].use_vote_weight, 1);
+    assert_eq!(proposal.assets[1].vote_count, 1);
+    assert_eq!(proposal.assets[1].not_use_vote_weight, 1);
+}
+
+#[test]
+fn test_batch_vote_on_normal_proposal_fails_with_unsorted_votes() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_unsorted_votes(&mut svm);
+    let (instructions, signers, _) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::AssetsNotSortedOrDuplicate,
+    );
+}
+
+#[test]
+fn test_batch_vote_on_normal_proposal_fails_with_missing_accounts() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_missing_accounts(&mut svm);
+    let (instructions, signers, _) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(result, 0, multisig::MultisigError::LengthMismatch);
+}
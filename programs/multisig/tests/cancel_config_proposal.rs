@@ -0,0 +1,162 @@
+#![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::CreateConfigProposalInstructionArgs, ConfigChange, ConfigProposal, ConfigType,
+    MultisigError, Permissions, ProposalState,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{
+    add_multisig_program, assert_multisig_instruction_error, send_tx, set_config_proposal_state,
+    set_group_member_permissions, setup_group,
+};
+
+// Cancel config proposal requires the Cancel permission and a proposal that was not executed.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    NoCancelPermission,
+    Executed,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey)> {
+        let group_setup = setup_group(svm)?;
+        let canceller = group_setup.members[0].pubkey();
+
+        let proposal_seed = Pubkey::new_unique();
+        let proposal_pda = sdk::proposal_pda(&group_setup.group, &proposal_seed);
+
+        let create_args = CreateConfigProposalInstructionArgs {
+            proposal_seed,
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            config_change: ConfigChange::ChangeGroupConfig {
+                config_types: vec![ConfigType::MinimumVoteCount(1)],
+            },
+            compact_votes: false,
+            description: None,
+        };
+        let create_config_proposal = sdk::create_config_proposal(
+            create_args,
+            group_setup.group,
+            group_setup.payer.pubkey(),
+            None,
+        );
+        send_tx(svm, &group_setup.payer, vec![create_config_proposal], &[])?;
+
+        match scenario {
+            Scenario::Default => {}
+            Scenario::NoCancelPermission => set_group_member_permissions(
+                svm,
+                group_setup.group,
+                canceller,
+                Permissions::builder()
+                    .propose(true)
+                    .propose_config(true)
+                    .build(),
+            )?,
+            Scenario::Executed => {
+                set_config_proposal_state(svm, proposal_pda.address, ProposalState::Executed, None)?
+            }
+        }
+
+        let cancel =
+            sdk::cancel_config_proposal(group_setup.group, proposal_pda.address, canceller);
+
+        let [canceller, ..] = group_setup.members;
+        Ok((
+            [cancel],
+            vec![group_setup.payer, canceller],
+            proposal_pda.address,
+        ))
+    }
+
+    pub fn with_default(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_no_cancel_permission(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _) = Self::builder(svm, Scenario::NoCancelPermission)?;
+        Ok((ix, signers))
+    }
+
+    pub fn with_executed(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _) = Self::builder(svm, Scenario::Executed)?;
+        Ok((ix, signers))
+    }
+}
+
+#[test]
+fn test_cancel_config_proposal_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, proposal) =
+        TestSetup::with_default(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(transaction));
+
+    let proposal_account = svm.get_account(&proposal).expect("config proposal");
+    let proposal = ConfigProposal::try_deserialize(&mut proposal_account.data.as_slice())
+        .expect("deserialize config proposal");
+    assert!(proposal.state == ProposalState::Cancelled);
+}
+
+#[test]
+fn test_cancel_config_proposal_fails_without_cancel_permission() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_no_cancel_permission(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::InsufficientPermissions,
+    );
+}
+
+#[test]
+fn test_cancel_config_proposal_fails_when_executed() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_executed(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::ProposalNotCancellable,
+    );
+}
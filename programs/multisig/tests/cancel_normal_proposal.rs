@@ -0,0 +1,179 @@
+#![cfg(feature = "test-helpers")]
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::CreateNormalProposalInstructionArgs, AssetIndex, MultisigError, Permissions,
+    ProposalState, SerializableInstruction,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{
+    add_multisig_program, assert_multisig_instruction_error, read_normal_proposal, send_tx,
+    set_group_member_permissions, set_normal_proposal_state, setup_asset_mint, setup_group,
+    to_serializable,
+};
+
+// Cancel normal proposal requires the Cancel permission and a proposal whose execution
+// has not started.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    NoCancelPermission,
+    Executed,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey)> {
+        let group_setup = setup_group(svm)?;
+        let asset_setup = setup_asset_mint(svm, &group_setup)?;
+        let canceller = group_setup.members[0].pubkey();
+
+        let proposal_seed = Pubkey::new_unique();
+        let proposal_pda = sdk::proposal_pda(&group_setup.group, &proposal_seed);
+
+        let mint_to_ix = spl_token::instruction::mint_to(
+            &spl_token::ID,
+            &asset_setup.mint,
+            &Pubkey::new_unique(),
+            &asset_setup.asset_authority,
+            &[],
+            1,
+        )?;
+        let serializable: SerializableInstruction = to_serializable(&mint_to_ix);
+
+        let create_args = CreateNormalProposalInstructionArgs {
+            proposal_seed,
+            asset_keys: vec![asset_setup.mint],
+            asset_indices: vec![AssetIndex {
+                instruction_index: 0,
+                account_index: 0,
+            }],
+            authority_bumps: vec![
+                sdk::asset_authority_pda(&group_setup.group, &asset_setup.mint).bump,
+            ],
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            instruction_hashes: vec![sdk::serializable_instruction_hash(&serializable)?],
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
+            compact_votes: false,
+            description: None,
+            options: vec![],
+        };
+        let create_normal =
+            sdk::create_normal_proposal(create_args, group_setup.group, group_setup.payer.pubkey());
+        send_tx(svm, &group_setup.payer, vec![create_normal], &[])?;
+
+        match scenario {
+            Scenario::Default => {}
+            Scenario::NoCancelPermission => set_group_member_permissions(
+                svm,
+                group_setup.group,
+                canceller,
+                Permissions::builder()
+                    .propose(true)
+                    .propose_config(true)
+                    .build(),
+            )?,
+            Scenario::Executed => {
+                set_normal_proposal_state(svm, proposal_pda.address, ProposalState::Executed, None)?
+            }
+        }
+
+        let cancel =
+            sdk::cancel_normal_proposal(group_setup.group, proposal_pda.address, canceller);
+
+        let [canceller, ..] = group_setup.members;
+        Ok((
+            [cancel],
+            vec![group_setup.payer, canceller],
+            proposal_pda.address,
+        ))
+    }
+
+    pub fn with_default(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_no_cancel_permission(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _) = Self::builder(svm, Scenario::NoCancelPermission)?;
+        Ok((ix, signers))
+    }
+
+    pub fn with_executed(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _) = Self::builder(svm, Scenario::Executed)?;
+        Ok((ix, signers))
+    }
+}
+
+#[test]
+fn test_cancel_normal_proposal_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, proposal) =
+        TestSetup::with_default(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(transaction));
+
+    let proposal = read_normal_proposal(&svm, proposal).expect("read normal proposal");
+    assert!(proposal.state == ProposalState::Cancelled);
+}
+
+#[test]
+fn test_cancel_normal_proposal_fails_without_cancel_permission() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_no_cancel_permission(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::InsufficientPermissions,
+    );
+}
+
+#[test]
+fn test_cancel_normal_proposal_fails_when_executed() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_executed(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::ProposalNotCancellable,
+    );
+}
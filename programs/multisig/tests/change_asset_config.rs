@@ -37,8 +37,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeAssetConfig {
-                config_type: ConfigType::Use(threshold(1, 2)),
+                config_types: vec![ConfigType::Use(threshold(1, 2))],
             },
+            compact_votes: false,
+            description: None,
         };
 
         let create_config_proposal = sdk::create_config_proposal(
@@ -103,7 +105,11 @@ impl TestSetup {
             proposal_seed,
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
-            config_change: ConfigChange::ChangeAssetConfig { config_type },
+            config_change: ConfigChange::ChangeAssetConfig {
+                config_types: vec![config_type],
+            },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -142,8 +148,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeAssetConfig {
-                config_type: ConfigType::MinimumVoteCount(2),
+                config_types: vec![ConfigType::MinimumVoteCount(2)],
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -182,8 +190,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeAssetConfig {
-                config_type: ConfigType::MinimumVoteCount(2),
+                config_types: vec![ConfigType::MinimumVoteCount(2)],
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -222,8 +232,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeAssetConfig {
-                config_type: ConfigType::MinimumVoteCount(2),
+                config_types: vec![ConfigType::MinimumVoteCount(2)],
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -262,8 +274,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeAssetConfig {
-                config_type: ConfigType::MinimumVoteCount(2),
+                config_types: vec![ConfigType::MinimumVoteCount(2)],
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -346,6 +360,24 @@ fn test_change_asset_config_all_config_types_succeed() {
         ConfigType::NotChangeConfig(threshold(2, 3)),
         ConfigType::MinimumMemberCount(2),
         ConfigType::MinimumVoteCount(2),
+        ConfigType::RequireExecutePermission(true),
+        ConfigType::ExecutorTip(5_000),
+        ConfigType::AddMemberThresholds {
+            pass: threshold(2, 3),
+            fail: threshold(1, 2),
+        },
+        ConfigType::RemoveMemberThresholds {
+            pass: threshold(2, 3),
+            fail: threshold(1, 2),
+        },
+        ConfigType::UseThresholds {
+            pass: threshold(2, 3),
+            fail: threshold(1, 2),
+        },
+        ConfigType::ChangeConfigThresholds {
+            pass: threshold(2, 3),
+            fail: threshold(1, 2),
+        },
     ];
 
     for config_type in all_config_types {
@@ -0,0 +1,186 @@
+#![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::CreateConfigProposalInstructionArgs, AssetMember, ConfigChange, MultisigError,
+    Permissions, ProposalState,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{
+    add_multisig_program, assert_multisig_instruction_error, send_tx, set_config_proposal_state,
+    set_group_member_permissions, setup_asset_mint, setup_group,
+};
+
+// Change asset member permissions requires a passed proposal naming the asset member,
+// created by a member with the Propose config permission.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    WrongMember,
+    NoProposeConfigPermission,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey, Pubkey, Pubkey)> {
+        let group_setup = setup_group(svm)?;
+        let asset_setup = setup_asset_mint(svm, &group_setup)?;
+        let [_, member, other, _, _] = group_setup.member_keys;
+
+        let proposal_seed = Pubkey::new_unique();
+        let proposal_pda = sdk::proposal_pda(&group_setup.group, &proposal_seed);
+
+        let create_args = CreateConfigProposalInstructionArgs {
+            proposal_seed,
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            config_change: ConfigChange::ChangeAssetMemberPermissions {
+                member,
+                asset_address: asset_setup.asset_address,
+                permissions: Permissions::builder().execute(true).cancel(true).build(),
+            },
+            compact_votes: false,
+            description: None,
+        };
+        let create_config_proposal = sdk::create_config_proposal(
+            create_args,
+            group_setup.group,
+            group_setup.payer.pubkey(),
+            Some(asset_setup.asset_address),
+        );
+
+        // The proposer can still create normal proposals, but not config proposals.
+        if let Scenario::NoProposeConfigPermission = scenario {
+            set_group_member_permissions(
+                svm,
+                group_setup.group,
+                group_setup.payer.pubkey(),
+                Permissions::builder().propose(true).build(),
+            )?;
+            return Ok((
+                [create_config_proposal],
+                vec![group_setup.payer],
+                group_setup.group,
+                asset_setup.asset_address,
+                member,
+            ));
+        }
+
+        send_tx(svm, &group_setup.payer, vec![create_config_proposal], &[])?;
+        set_config_proposal_state(svm, proposal_pda.address, ProposalState::Passed, Some(0))?;
+
+        let changed = match scenario {
+            Scenario::WrongMember => other,
+            _ => member,
+        };
+        let change = sdk::change_asset_member_permissions(
+            group_setup.group,
+            asset_setup.asset_address,
+            changed,
+            proposal_pda.address,
+            group_setup.payer.pubkey(),
+        );
+
+        Ok((
+            [change],
+            vec![group_setup.payer],
+            group_setup.group,
+            asset_setup.asset_address,
+            member,
+        ))
+    }
+
+    pub fn with_default(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey, Pubkey, Pubkey)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_wrong_member(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _, _, _) = Self::builder(svm, Scenario::WrongMember)?;
+        Ok((ix, signers))
+    }
+
+    pub fn with_no_propose_config_permission(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _, _, _) = Self::builder(svm, Scenario::NoProposeConfigPermission)?;
+        Ok((ix, signers))
+    }
+}
+
+#[test]
+fn test_change_asset_member_permissions_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, group, asset_address, member) =
+        TestSetup::with_default(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(transaction));
+
+    let member_account = svm
+        .get_account(&sdk::asset_member_pda(&group, &asset_address, &member).address)
+        .expect("asset member");
+    let member = AssetMember::try_deserialize(&mut member_account.data.as_slice())
+        .expect("deserialize asset member");
+    assert!(member.has_execute());
+    assert!(member.permissions.has_cancel());
+    assert!(!member.has_propose());
+    assert!(!member.permissions.has_propose_config());
+}
+
+#[test]
+fn test_change_asset_member_permissions_fails_with_wrong_member() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_wrong_member(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::InvalidMember,
+    );
+}
+
+#[test]
+fn test_change_asset_member_permissions_proposal_fails_without_propose_config_permission() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) = TestSetup::with_no_propose_config_permission(&mut svm)
+        .expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::InsufficientPermissions,
+    );
+}
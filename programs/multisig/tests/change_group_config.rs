@@ -35,11 +35,11 @@ impl TestSetup {
 
         let config_change = if use_wrong_config_change {
             ConfigChange::ChangeAssetConfig {
-                config_type: ConfigType::Use(threshold(1, 2)),
+                config_types: vec![ConfigType::Use(threshold(1, 2))],
             }
         } else {
             ConfigChange::ChangeGroupConfig {
-                config_type: ConfigType::MinimumVoteCount(1),
+                config_types: vec![ConfigType::MinimumVoteCount(1)],
             }
         };
 
@@ -48,6 +48,8 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change,
+            compact_votes: false,
+            description: None,
         };
 
         let asset = if use_wrong_config_change {
@@ -100,6 +102,19 @@ impl TestSetup {
         svm: &mut LiteSVM,
         config_type: ConfigType,
     ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        let (ix, signers, _) = Self::with_config_types_inner(svm, vec![config_type])?;
+        Ok((ix, signers))
+    }
+
+    /// Build a change-group-config instruction applying a batch of ConfigTypes.
+    fn with_config_types_inner(
+        svm: &mut LiteSVM,
+        config_types: Vec<ConfigType>,
+    ) -> Result<(
+        [Instruction; 1],
+        Vec<solana_sdk::signature::Keypair>,
+        solana_sdk::pubkey::Pubkey,
+    )> {
         let group_setup = setup_group(svm)?;
 
         let proposal_seed = solana_sdk::pubkey::Pubkey::new_unique();
@@ -109,7 +124,9 @@ impl TestSetup {
             proposal_seed,
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
-            config_change: ConfigChange::ChangeGroupConfig { config_type },
+            config_change: ConfigChange::ChangeGroupConfig { config_types },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -129,7 +146,7 @@ impl TestSetup {
             proposal_pda.address,
             group_setup.payer.pubkey(),
         );
-        Ok(([ix], vec![group_setup.payer]))
+        Ok(([ix], vec![group_setup.payer], group_setup.group))
     }
 
     /// Proposal carries a Use ConfigType which is invalid for group config.
@@ -147,8 +164,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeGroupConfig {
-                config_type: ConfigType::MinimumVoteCount(1),
+                config_types: vec![ConfigType::MinimumVoteCount(1)],
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -166,7 +185,7 @@ impl TestSetup {
             svm,
             proposal_pda.address,
             ConfigChange::ChangeGroupConfig {
-                config_type: ConfigType::Use(threshold(1, 2)),
+                config_types: vec![ConfigType::Use(threshold(1, 2))],
             },
         )?;
         set_config_proposal_state(svm, proposal_pda.address, ProposalState::Passed, Some(0))?;
@@ -193,8 +212,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeGroupConfig {
-                config_type: ConfigType::MinimumVoteCount(1),
+                config_types: vec![ConfigType::MinimumVoteCount(1)],
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -231,8 +252,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeGroupConfig {
-                config_type: ConfigType::MinimumVoteCount(1),
+                config_types: vec![ConfigType::MinimumVoteCount(1)],
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -269,8 +292,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeGroupConfig {
-                config_type: ConfigType::MinimumVoteCount(1),
+                config_types: vec![ConfigType::MinimumVoteCount(1)],
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -307,8 +332,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeGroupConfig {
-                config_type: ConfigType::MinimumVoteCount(1),
+                config_types: vec![ConfigType::MinimumVoteCount(1)],
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -388,6 +415,19 @@ fn test_change_group_config_all_valid_config_types_succeed() {
         ConfigType::NotChangeConfig(threshold(2, 3)),
         ConfigType::MinimumMemberCount(2),
         ConfigType::MinimumVoteCount(2),
+        ConfigType::RequireExecutePermission(true),
+        ConfigType::AddMemberThresholds {
+            pass: threshold(2, 3),
+            fail: threshold(1, 2),
+        },
+        ConfigType::RemoveMemberThresholds {
+            pass: threshold(2, 3),
+            fail: threshold(1, 2),
+        },
+        ConfigType::ChangeConfigThresholds {
+            pass: threshold(2, 3),
+            fail: threshold(1, 2),
+        },
     ];
 
     for config_type in valid_config_types {
@@ -535,3 +575,37 @@ fn test_change_group_config_fails_with_wrong_proposer() {
         multisig::MultisigError::InvalidProposer,
     );
 }
+
+/// A batch is applied in order and only the final config has to be valid.
+#[test]
+fn test_change_group_config_applies_batch() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let config_types = vec![
+        ConfigType::ChangeConfig(threshold(1, 3)),
+        ConfigType::NotChangeConfig(threshold(3, 4)),
+        ConfigType::MinimumVoteCount(3),
+    ];
+    let result = TestSetup::with_config_types_inner(&mut svm, config_types);
+    let (instructions, signers, group) = match result {
+        Ok(r) => r,
+        Err(e) => panic!("setup failed: {e}"),
+    };
+
+    let payer = signers[0].pubkey();
+    let tx = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&payer),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(tx));
+
+    let group_account = read_group(&svm, group).expect("group");
+    assert_eq!(group_account.change_config_threshold.numerator, 1);
+    assert_eq!(group_account.change_config_threshold.denominator, 3);
+    assert_eq!(group_account.not_change_config_threshold.numerator, 3);
+    assert_eq!(group_account.not_change_config_threshold.denominator, 4);
+    assert_eq!(group_account.minimum_vote_count, 3);
+}
@@ -25,8 +25,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeGroupConfig {
-                config_type: multisig::ConfigType::MinimumVoteCount(1),
+                config_types: vec![multisig::ConfigType::MinimumVoteCount(1)],
             },
+            compact_votes: false,
+            description: None,
         };
         let create_config_proposal = sdk::create_config_proposal(
             create_args,
@@ -74,8 +76,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeGroupConfig {
-                config_type: multisig::ConfigType::MinimumVoteCount(1),
+                config_types: vec![multisig::ConfigType::MinimumVoteCount(1)],
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -110,8 +114,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeGroupConfig {
-                config_type: multisig::ConfigType::MinimumVoteCount(1),
+                config_types: vec![multisig::ConfigType::MinimumVoteCount(1)],
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -148,8 +154,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeGroupConfig {
-                config_type: multisig::ConfigType::MinimumVoteCount(1),
+                config_types: vec![multisig::ConfigType::MinimumVoteCount(1)],
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
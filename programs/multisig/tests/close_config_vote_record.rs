@@ -41,8 +41,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeGroupConfig {
-                config_type: multisig::ConfigType::MinimumVoteCount(1),
+                config_types: vec![multisig::ConfigType::MinimumVoteCount(1)],
             },
+            compact_votes: false,
+            description: None,
         };
         let create_config_proposal = sdk::create_config_proposal(
             create_args,
@@ -0,0 +1,186 @@
+#![cfg(feature = "test-helpers")]
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::CreateNormalProposalInstructionArgs, AssetIndex, ExecutionReceipt,
+    SerializableInstruction,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{instruction::Instruction, signer::Signer, transaction::Transaction};
+
+mod common;
+use common::{
+    add_multisig_program, close_proposal_account, insert_execution_receipt, read_normal_proposal,
+    send_tx, setup_asset_mint, setup_group, to_serializable,
+};
+
+enum Scenario {
+    Default,
+    WrongRentCollector,
+    ActiveProposal,
+}
+
+// Close execution receipt requires the proposal to be closed first.
+struct TestSetup {}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        let group_setup = setup_group(svm)?;
+        let asset_setup = setup_asset_mint(svm, &group_setup)?;
+
+        let mint_to_ix = spl_token::instruction::mint_to(
+            &spl_token::ID,
+            &asset_setup.mint,
+            &group_setup.payer.pubkey(),
+            &asset_setup.asset_authority,
+            &[],
+            1,
+        )?;
+        let serializable: SerializableInstruction = to_serializable(&mint_to_ix);
+
+        let proposal_seed = solana_sdk::pubkey::Pubkey::new_unique();
+        let proposal_pda = sdk::proposal_pda(&group_setup.group, &proposal_seed);
+
+        let create_args = CreateNormalProposalInstructionArgs {
+            proposal_seed,
+            asset_keys: vec![asset_setup.mint],
+            asset_indices: vec![AssetIndex {
+                instruction_index: 0,
+                account_index: 0,
+            }],
+            authority_bumps: vec![
+                sdk::asset_authority_pda(&group_setup.group, &asset_setup.mint).bump,
+            ],
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            instruction_hashes: vec![sdk::serializable_instruction_hash(&serializable)?],
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
+            compact_votes: false,
+            description: None,
+            options: vec![],
+        };
+
+        let create_normal =
+            sdk::create_normal_proposal(create_args, group_setup.group, group_setup.payer.pubkey());
+        send_tx(svm, &group_setup.payer, vec![create_normal], &[])?;
+
+        let proposal_index = read_normal_proposal(svm, proposal_pda.address)?.proposal_index;
+
+        let receipt_pda = sdk::execution_receipt_pda(&proposal_pda.address);
+        let receipt = ExecutionReceipt::new(
+            proposal_pda.address,
+            group_setup.group,
+            proposal_index,
+            1,
+            vec![],
+            receipt_pda.bump,
+        );
+        insert_execution_receipt(svm, receipt_pda.address, receipt)?;
+
+        if !matches!(scenario, Scenario::ActiveProposal) {
+            close_proposal_account(svm, proposal_pda.address)?;
+        }
+
+        let rent_collector = match scenario {
+            Scenario::WrongRentCollector => solana_sdk::pubkey::Pubkey::new_unique(),
+            _ => group_setup.payer.pubkey(),
+        };
+
+        let close_receipt =
+            sdk::close_execution_receipt(group_setup.group, proposal_pda.address, rent_collector);
+
+        Ok(([close_receipt], vec![group_setup.payer]))
+    }
+
+    pub fn with_default(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_wrong_rent_collector(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::WrongRentCollector)
+    }
+
+    pub fn with_active_proposal(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::ActiveProposal)
+    }
+}
+
+#[test]
+fn test_close_execution_receipt_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_default(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+}
+
+#[test]
+fn test_close_execution_receipt_fails_with_wrong_rent_collector() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_wrong_rent_collector(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::UnexpectedRentCollector,
+    );
+}
+
+#[test]
+fn test_close_execution_receipt_fails_while_proposal_exists() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_active_proposal(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::ProposalStillActive,
+    );
+}
@@ -0,0 +1,193 @@
+#![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{Asset, MultisigError};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{
+    add_multisig_program, assert_multisig_instruction_error, read_group, send_tx,
+    set_asset_member_expires_at, setup_asset_mint, setup_group,
+};
+
+// Close expired asset member: anyone can close an asset member whose term has ended.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    NotExpired,
+    BelowMinimumMemberCount,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey, Pubkey, Pubkey)> {
+        let group_setup = setup_group(svm)?;
+        let asset_setup = setup_asset_mint(svm, &group_setup)?;
+        let rent_collector = group_setup.payer.pubkey();
+
+        let crank = Keypair::new();
+        svm.airdrop(&crank.pubkey(), 1_000_000_000)
+            .map_err(|error| anyhow::anyhow!("airdrop failed: {:?}", error))?;
+
+        let [_, member, other, _, _] = group_setup.member_keys;
+        match scenario {
+            Scenario::Default => {
+                set_asset_member_expires_at(
+                    svm,
+                    group_setup.group,
+                    asset_setup.asset_address,
+                    member,
+                    Some(0),
+                )?;
+            }
+            // The term ends in the future.
+            Scenario::NotExpired => {
+                set_asset_member_expires_at(
+                    svm,
+                    group_setup.group,
+                    asset_setup.asset_address,
+                    member,
+                    Some(1000),
+                )?;
+            }
+            // Closing two of the three asset members would leave one, below the minimum of two.
+            Scenario::BelowMinimumMemberCount => {
+                set_asset_member_expires_at(
+                    svm,
+                    group_setup.group,
+                    asset_setup.asset_address,
+                    other,
+                    Some(0),
+                )?;
+                let close = sdk::close_expired_asset_member(
+                    group_setup.group,
+                    asset_setup.asset_address,
+                    other,
+                    rent_collector,
+                );
+                send_tx(svm, &crank, vec![close], &[])?;
+                set_asset_member_expires_at(
+                    svm,
+                    group_setup.group,
+                    asset_setup.asset_address,
+                    member,
+                    Some(0),
+                )?;
+            }
+        }
+
+        let close = sdk::close_expired_asset_member(
+            group_setup.group,
+            asset_setup.asset_address,
+            member,
+            rent_collector,
+        );
+
+        Ok((
+            [close],
+            vec![crank],
+            group_setup.group,
+            asset_setup.asset_address,
+            member,
+        ))
+    }
+
+    pub fn with_default(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey, Pubkey, Pubkey)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_not_expired(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _, _, _) = Self::builder(svm, Scenario::NotExpired)?;
+        Ok((ix, signers))
+    }
+
+    pub fn with_below_minimum_member_count(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _, _, _) = Self::builder(svm, Scenario::BelowMinimumMemberCount)?;
+        Ok((ix, signers))
+    }
+}
+
+#[test]
+fn test_close_expired_asset_member_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, group, asset_address, member) =
+        TestSetup::with_default(&mut svm).expect("Failed to create instruction");
+    let asset_pda = sdk::asset_pda(&group, &asset_address).address;
+    let read_asset = |svm: &LiteSVM| {
+        let account = svm.get_account(&asset_pda).expect("asset");
+        Asset::try_deserialize(&mut account.data.as_slice()).expect("deserialize asset")
+    };
+    let before = read_asset(&svm);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(transaction));
+
+    assert_eq!(read_asset(&svm).member_count, before.member_count - 1);
+    let group_after = read_group(&svm, group).expect("read group after");
+    assert_eq!(
+        group_after.proposal_index_after_stale,
+        group_after.next_proposal_index
+    );
+    assert!(svm
+        .get_account(&sdk::asset_member_pda(&group, &asset_address, &member).address)
+        .is_none_or(|account| account.lamports == 0));
+}
+
+#[test]
+fn test_close_expired_asset_member_fails_when_term_not_ended() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_not_expired(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::MembershipNotExpired,
+    );
+}
+
+#[test]
+fn test_close_expired_asset_member_fails_below_minimum_member_count() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_below_minimum_member_count(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::InvalidMemberCount,
+    );
+}
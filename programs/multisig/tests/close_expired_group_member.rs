@@ -0,0 +1,150 @@
+#![cfg(feature = "test-helpers")]
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::MultisigError;
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{
+    add_multisig_program, assert_multisig_instruction_error, read_group, send_tx,
+    set_group_member_expires_at, setup_group,
+};
+
+// Close expired group member: anyone can close a member whose term has ended.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    NotExpired,
+    BelowMinimumMemberCount,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey, Pubkey)> {
+        let group_setup = setup_group(svm)?;
+        let rent_collector = group_setup.payer.pubkey();
+
+        let crank = Keypair::new();
+        svm.airdrop(&crank.pubkey(), 1_000_000_000)
+            .map_err(|error| anyhow::anyhow!("airdrop failed: {:?}", error))?;
+
+        let [member, other_1, other_2, other_3] = &group_setup.members;
+        match scenario {
+            Scenario::Default => {
+                set_group_member_expires_at(svm, group_setup.group, member.pubkey(), Some(0))?;
+            }
+            // The term ends in the future.
+            Scenario::NotExpired => {
+                set_group_member_expires_at(svm, group_setup.group, member.pubkey(), Some(1000))?;
+            }
+            // Closing three of the five members leaves the minimum of two.
+            Scenario::BelowMinimumMemberCount => {
+                for closed in [other_1, other_2, other_3] {
+                    set_group_member_expires_at(svm, group_setup.group, closed.pubkey(), Some(0))?;
+                    let close = sdk::close_expired_group_member(
+                        group_setup.group,
+                        closed.pubkey(),
+                        rent_collector,
+                    );
+                    send_tx(svm, &crank, vec![close], &[])?;
+                }
+                set_group_member_expires_at(svm, group_setup.group, member.pubkey(), Some(0))?;
+            }
+        }
+
+        let close =
+            sdk::close_expired_group_member(group_setup.group, member.pubkey(), rent_collector);
+
+        Ok(([close], vec![crank], group_setup.group, member.pubkey()))
+    }
+
+    pub fn with_default(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey, Pubkey)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_not_expired(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _, _) = Self::builder(svm, Scenario::NotExpired)?;
+        Ok((ix, signers))
+    }
+
+    pub fn with_below_minimum_member_count(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _, _) = Self::builder(svm, Scenario::BelowMinimumMemberCount)?;
+        Ok((ix, signers))
+    }
+}
+
+#[test]
+fn test_close_expired_group_member_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, group, member) =
+        TestSetup::with_default(&mut svm).expect("Failed to create instruction");
+    let before = read_group(&svm, group).expect("read group before");
+
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(transaction));
+
+    let after = read_group(&svm, group).expect("read group after");
+    assert_eq!(after.member_count, before.member_count - 1);
+    assert_eq!(after.proposal_index_after_stale, after.next_proposal_index);
+    assert!(svm
+        .get_account(&sdk::group_member_pda(&group, &member).address)
+        .is_none_or(|account| account.lamports == 0));
+}
+
+#[test]
+fn test_close_expired_group_member_fails_when_term_not_ended() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_not_expired(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::MembershipNotExpired,
+    );
+}
+
+#[test]
+fn test_close_expired_group_member_fails_below_minimum_member_count() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_below_minimum_member_count(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::InvalidMemberCount,
+    );
+}
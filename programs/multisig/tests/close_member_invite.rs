@@ -0,0 +1,152 @@
+#![cfg(feature = "test-helpers")]
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::{AddGroupMemberInstructionArgs, CreateConfigProposalInstructionArgs},
+    ConfigChange, MultisigError, Permissions, ProposalState,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{
+    add_multisig_program, assert_multisig_instruction_error, send_tx, set_config_proposal_state,
+    set_member_invite_as_expired, setup_group,
+};
+
+// Close member invite refunds an invite that was not accepted in time.
+struct TestSetup {}
+
+enum Scenario {
+    Expired,
+    NotExpired,
+}
+
+struct Invite {
+    address: Pubkey,
+    payer: Pubkey,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Invite)> {
+        let group_setup = setup_group(svm)?;
+        let new_member = Pubkey::new_unique();
+
+        let proposal_seed = Pubkey::new_unique();
+        let proposal_pda = sdk::proposal_pda(&group_setup.group, &proposal_seed);
+
+        let create_args = CreateConfigProposalInstructionArgs {
+            proposal_seed,
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            config_change: ConfigChange::AddGroupMember {
+                member: new_member,
+                weight: 1,
+                permissions: Permissions::builder().propose(true).add_asset(true).build(),
+                expires_at: None,
+            },
+            compact_votes: false,
+            description: None,
+        };
+        let create_config_proposal = sdk::create_config_proposal(
+            create_args,
+            group_setup.group,
+            group_setup.payer.pubkey(),
+            None,
+        );
+        send_tx(svm, &group_setup.payer, vec![create_config_proposal], &[])?;
+        set_config_proposal_state(svm, proposal_pda.address, ProposalState::Passed, Some(0))?;
+
+        let add_group_member = sdk::add_group_member(
+            AddGroupMemberInstructionArgs { new_member },
+            group_setup.group,
+            proposal_pda.address,
+            group_setup.payer.pubkey(),
+            group_setup.payer.pubkey(),
+        );
+        send_tx(svm, &group_setup.payer, vec![add_group_member], &[])?;
+
+        let invite = sdk::member_invite_pda(&group_setup.group, &new_member).address;
+        if let Scenario::Expired = scenario {
+            set_member_invite_as_expired(svm, invite)?;
+        }
+
+        // Anyone can close an expired invite, the rent still goes to its payer.
+        let closer = Keypair::new();
+        svm.airdrop(&closer.pubkey(), 1_000_000_000)
+            .map_err(|error| anyhow::anyhow!("airdrop failed: {:?}", error))?;
+        let close =
+            sdk::close_member_invite(group_setup.group, new_member, group_setup.payer.pubkey());
+
+        Ok((
+            [close],
+            vec![closer],
+            Invite {
+                address: invite,
+                payer: group_setup.payer.pubkey(),
+            },
+        ))
+    }
+
+    pub fn with_expired(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>, Invite)> {
+        Self::builder(svm, Scenario::Expired)
+    }
+
+    pub fn with_not_expired(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _) = Self::builder(svm, Scenario::NotExpired)?;
+        Ok((ix, signers))
+    }
+}
+
+#[test]
+fn test_close_member_invite_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, invite) =
+        TestSetup::with_expired(&mut svm).expect("Failed to create instruction");
+    let payer_before = svm.get_balance(&invite.payer).unwrap_or(0);
+    let invite_rent = svm.get_balance(&invite.address).unwrap_or(0);
+
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(transaction));
+
+    assert!(svm
+        .get_account(&invite.address)
+        .is_none_or(|account| account.lamports == 0));
+    assert_eq!(
+        svm.get_balance(&invite.payer).unwrap_or(0),
+        payer_before + invite_rent
+    );
+}
+
+#[test]
+fn test_close_member_invite_fails_when_not_expired() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_not_expired(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::InviteNotExpired,
+    );
+}
@@ -63,6 +63,13 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             instruction_hashes,
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
+            compact_votes: false,
+            description: None,
+            options: vec![],
         };
 
         let create_normal =
@@ -137,6 +144,13 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             instruction_hashes,
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
+            compact_votes: false,
+            description: None,
+            options: vec![],
         };
         send_tx(
             svm,
@@ -199,6 +213,13 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             instruction_hashes,
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
+            compact_votes: false,
+            description: None,
+            options: vec![],
         };
         send_tx(
             svm,
@@ -264,6 +285,13 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             instruction_hashes,
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
+            compact_votes: false,
+            description: None,
+            options: vec![],
         };
         send_tx(
             svm,
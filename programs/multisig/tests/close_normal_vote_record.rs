@@ -74,6 +74,13 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             instruction_hashes,
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
+            compact_votes: false,
+            description: None,
+            options: vec![],
         };
 
         let create_normal =
@@ -55,6 +55,7 @@ impl TestSetup {
                 account_index: 0,
             }],
             vec![[sdk::asset_authority_pda(&group_setup.group, &asset_setup.mint).bump]],
+            vec![false],
             vec![serializable],
             proposal_tx_pda.bump,
         );
@@ -148,6 +149,13 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             instruction_hashes,
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
+            compact_votes: false,
+            description: None,
+            options: vec![],
         };
 
         let create_normal =
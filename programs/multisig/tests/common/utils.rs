@@ -21,9 +21,11 @@ use multisig::{
         AddAssetMintInstructionArgs, AddAssetTokenInstructionArgs, CreateGroupInstructionArgs,
     },
     proposal::{
-        ConfigProposal, EmergencyResetProposal, NormalProposal, ProposalState, ProposalTransaction,
+        ConfigProposal, EmergencyResetProposal, NormalProposal, ProposalAssetThresholdState,
+        ProposalState, ProposalTransaction,
     },
-    ConfigChange, FractionalThreshold, Group, GroupMember, MultisigError, Permissions,
+    Asset, AssetMember, ConfigChange, ConfigChangeTimelocks, ExecutionReceipt, FractionalThreshold,
+    Group, GroupMember, MemberInvite, MultisigError, Permissions, ProposalBondConfig, Schedule,
     SerailizableAccountMeta, SerializableInstruction, ID as MULTISIG_PROGRAM_ID,
 };
 use multisig_sdk as sdk;
@@ -155,8 +157,15 @@ pub fn threshold(numerator: u32, denominator: u32) -> FractionalThreshold {
     FractionalThreshold::new_from_values(numerator, denominator).unwrap()
 }
 
+/// Every permission except Execute, which tests grant explicitly.
 pub fn permissions() -> Permissions {
-    Permissions::from_flags(true, true)
+    Permissions::builder()
+        .propose(true)
+        .add_asset(true)
+        .propose_config(true)
+        .cancel(true)
+        .emergency_reset(true)
+        .build()
 }
 
 pub fn get_invalid_threshold(rng: &mut rand::rngs::ThreadRng) -> FractionalThreshold {
@@ -184,9 +193,9 @@ pub fn get_invalid_permissions(rng: &mut rand::rngs::ThreadRng) -> Permissions {
     let value = rng.random::<u8>() % 4;
 
     Permissions::from_unchecked(match value {
-        0 => 0b00000011u8 | 0b00000100u8,
-        1 => 0b00000011u8 | 0b00001000u8,
-        2 => 0b00000011u8 | 0b00010000u8,
+        0 => 0b00000111u8 | 0b10000000u8,
+        1 => 0b00000011u8 | 0b10001000u8,
+        2 => 0b01111111u8 | 0b10000000u8,
         _ => 0b10000000u8 | rng.random::<u8>(),
     })
 }
@@ -729,7 +738,17 @@ pub fn insert_normal_proposal(
 ) -> Result<()> {
     let asset_len = data.assets.len();
     let hash_count = data.instruction_hashes.len();
-    let mut serialized = Vec::with_capacity(8 + NormalProposal::get_size(asset_len, hash_count));
+    let mut serialized = Vec::with_capacity(
+        8 + NormalProposal::get_size(
+            asset_len,
+            hash_count,
+            data.balance_guards.len(),
+            &data.preconditions,
+            data.group_approval.is_some(),
+            data.compact_votes.as_ref().map(Vec::len),
+            data.options.iter().map(|o| o.instruction_hashes.len()),
+        ),
+    );
     data.try_serialize(&mut serialized)?;
     let rent = svm.minimum_balance_for_rent_exemption(serialized.len());
     let account = Account {
@@ -807,6 +826,31 @@ pub fn set_normal_proposal_state(
     Ok(())
 }
 
+/// Mark the group approval of a normal proposal as reached, with enough weight
+/// and votes to meet any group threshold.
+pub fn set_normal_proposal_group_approval_reached(
+    svm: &mut LiteSVM,
+    proposal: Pubkey,
+) -> Result<()> {
+    let mut account = svm
+        .get_account(&proposal)
+        .ok_or_else(|| anyhow::anyhow!("proposal account not found"))?;
+    let mut data = account.data.as_slice();
+    let mut proposal_data = NormalProposal::try_deserialize(&mut data)?;
+    let group_approval = proposal_data
+        .group_approval
+        .as_mut()
+        .ok_or_else(|| anyhow::anyhow!("proposal has no group approval"))?;
+    group_approval.for_weight = 100;
+    group_approval.vote_count = 5;
+    group_approval.threshold_state = ProposalAssetThresholdState::UseThresholdReached;
+    let mut serialized = Vec::with_capacity(account.data.len());
+    proposal_data.try_serialize(&mut serialized)?;
+    account.data = serialized;
+    svm.set_account(proposal, account)?;
+    Ok(())
+}
+
 pub fn insert_proposal_transaction(
     svm: &mut LiteSVM,
     proposal_transaction: Pubkey,
@@ -833,6 +877,25 @@ pub fn insert_proposal_transaction(
     Ok(())
 }
 
+pub fn insert_execution_receipt(
+    svm: &mut LiteSVM,
+    execution_receipt: Pubkey,
+    data: ExecutionReceipt,
+) -> Result<()> {
+    let mut serialized = Vec::with_capacity(8 + data.get_size());
+    data.try_serialize(&mut serialized)?;
+    let rent = svm.minimum_balance_for_rent_exemption(serialized.len());
+    let account = Account {
+        lamports: rent,
+        data: serialized,
+        owner: MULTISIG_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    svm.set_account(execution_receipt, account)?;
+    Ok(())
+}
+
 pub fn close_proposal_account(svm: &mut LiteSVM, proposal: Pubkey) -> Result<()> {
     let account = Account {
         lamports: 0,
@@ -939,6 +1002,58 @@ pub fn read_group(svm: &LiteSVM, group: Pubkey) -> Result<Group> {
     Ok(Group::try_deserialize(&mut data)?)
 }
 
+/// Read and deserialize an on-chain NormalProposal account.
+pub fn read_normal_proposal(svm: &LiteSVM, proposal: Pubkey) -> Result<NormalProposal> {
+    let account = svm
+        .get_account(&proposal)
+        .ok_or_else(|| anyhow::anyhow!("proposal account not found"))?;
+    let mut data = account.data.as_slice();
+    Ok(NormalProposal::try_deserialize(&mut data)?)
+}
+
+/// Read and deserialize an on-chain ExecutionReceipt account.
+pub fn read_execution_receipt(svm: &LiteSVM, receipt: Pubkey) -> Result<ExecutionReceipt> {
+    let account = svm
+        .get_account(&receipt)
+        .ok_or_else(|| anyhow::anyhow!("execution receipt account not found"))?;
+    let mut data = account.data.as_slice();
+    Ok(ExecutionReceipt::try_deserialize(&mut data)?)
+}
+
+/// Patch the `require_execute_permission` field of a Group account.
+pub fn set_group_require_execute_permission(
+    svm: &mut LiteSVM,
+    group: Pubkey,
+    require: bool,
+) -> Result<()> {
+    let mut account = svm
+        .get_account(&group)
+        .ok_or_else(|| anyhow::anyhow!("group account not found"))?;
+    let mut data = account.data.as_slice();
+    let mut group_data = Group::try_deserialize(&mut data)?;
+    group_data.require_execute_permission = require;
+    let mut serialized = Vec::with_capacity(account.data.len());
+    group_data.try_serialize(&mut serialized)?;
+    account.data = serialized;
+    svm.set_account(group, account)?;
+    Ok(())
+}
+
+/// Patch the executor tip of an Asset account.
+pub fn set_asset_executor_tip(svm: &mut LiteSVM, asset: Pubkey, lamports: u64) -> Result<()> {
+    let mut account = svm
+        .get_account(&asset)
+        .ok_or_else(|| anyhow::anyhow!("asset account not found"))?;
+    let mut data = account.data.as_slice();
+    let mut asset_data = Asset::try_deserialize(&mut data)?;
+    asset_data.set_executor_tip(lamports);
+    let mut serialized = Vec::with_capacity(account.data.len());
+    asset_data.try_serialize(&mut serialized)?;
+    account.data = serialized;
+    svm.set_account(asset, account)?;
+    Ok(())
+}
+
 /// Patch the `permissions` field of a GroupMember PDA.
 /// Useful for testing permission-gated instructions without re-creating the group.
 pub fn set_group_member_permissions(
@@ -983,6 +1098,49 @@ pub fn set_group_member_weight(
     Ok(())
 }
 
+/// Patch the `expires_at` field of a GroupMember PDA.
+/// LiteSVM clock starts at 0, so `Some(0)` makes the member's term already ended.
+pub fn set_group_member_expires_at(
+    svm: &mut LiteSVM,
+    group: Pubkey,
+    member: Pubkey,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let member_pda = get_group_member(&group, &member);
+    let mut account = svm
+        .get_account(&member_pda)
+        .ok_or_else(|| anyhow::anyhow!("group member account not found"))?;
+    let mut data = account.data.as_slice();
+    let mut member_data = GroupMember::try_deserialize(&mut data)?;
+    member_data.expires_at = expires_at;
+    let mut serialized = Vec::with_capacity(account.data.len());
+    member_data.try_serialize(&mut serialized)?;
+    account.data = serialized;
+    svm.set_account(member_pda, account)?;
+    Ok(())
+}
+
+pub fn set_asset_member_expires_at(
+    svm: &mut LiteSVM,
+    group: Pubkey,
+    asset_address: Pubkey,
+    member: Pubkey,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let member_pda = get_asset_member(&group, &asset_address, &member);
+    let mut account = svm
+        .get_account(&member_pda)
+        .ok_or_else(|| anyhow::anyhow!("asset member account not found"))?;
+    let mut data = account.data.as_slice();
+    let mut member_data = AssetMember::try_deserialize(&mut data)?;
+    member_data.expires_at = expires_at;
+    let mut serialized = Vec::with_capacity(account.data.len());
+    member_data.try_serialize(&mut serialized)?;
+    account.data = serialized;
+    svm.set_account(member_pda, account)?;
+    Ok(())
+}
+
 /// Set a config proposal's deadline to `deadline` without changing proposal state.
 /// LiteSVM clock starts at 0, so using -1 makes an Open proposal immediately expired.
 pub fn set_config_proposal_deadline(
@@ -1060,6 +1218,137 @@ pub fn set_group_minimum_timelock(
     Ok(())
 }
 
+pub fn set_group_maximum_proposal_duration(
+    svm: &mut LiteSVM,
+    group: Pubkey,
+    maximum_proposal_duration: u32,
+) -> Result<()> {
+    let mut account = svm
+        .get_account(&group)
+        .ok_or_else(|| anyhow::anyhow!("group account not found"))?;
+    let mut data = account.data.as_slice();
+    let mut group_data = Group::try_deserialize(&mut data)?;
+    group_data.maximum_proposal_duration = maximum_proposal_duration;
+    let mut serialized = Vec::with_capacity(account.data.len());
+    group_data.try_serialize(&mut serialized)?;
+    account.data = serialized;
+    svm.set_account(group, account)?;
+    Ok(())
+}
+
+pub fn set_group_config_change_timelocks(
+    svm: &mut LiteSVM,
+    group: Pubkey,
+    config_change_timelocks: ConfigChangeTimelocks,
+) -> Result<()> {
+    let mut account = svm
+        .get_account(&group)
+        .ok_or_else(|| anyhow::anyhow!("group account not found"))?;
+    let mut data = account.data.as_slice();
+    let mut group_data = Group::try_deserialize(&mut data)?;
+    group_data.config_change_timelocks = config_change_timelocks;
+    let mut serialized = Vec::with_capacity(account.data.len());
+    group_data.try_serialize(&mut serialized)?;
+    account.data = serialized;
+    svm.set_account(group, account)?;
+    Ok(())
+}
+
+pub fn set_group_proposal_bond(
+    svm: &mut LiteSVM,
+    group: Pubkey,
+    proposal_bond: Option<ProposalBondConfig>,
+) -> Result<()> {
+    let mut account = svm
+        .get_account(&group)
+        .ok_or_else(|| anyhow::anyhow!("group account not found"))?;
+    let mut data = account.data.as_slice();
+    let mut group_data = Group::try_deserialize(&mut data)?;
+    group_data.proposal_bond = proposal_bond;
+    let mut serialized = Vec::with_capacity(account.data.len());
+    group_data.try_serialize(&mut serialized)?;
+    account.data = serialized;
+    svm.set_account(group, account)?;
+    Ok(())
+}
+
+/// Sets how many proposals can be open in the group and for each member.
+pub fn set_group_open_proposal_limits(
+    svm: &mut LiteSVM,
+    group: Pubkey,
+    max_open_proposals: u32,
+    max_open_proposals_per_member: u32,
+) -> Result<()> {
+    let mut account = svm
+        .get_account(&group)
+        .ok_or_else(|| anyhow::anyhow!("group account not found"))?;
+    let mut data = account.data.as_slice();
+    let mut group_data = Group::try_deserialize(&mut data)?;
+    group_data.max_open_proposals = max_open_proposals;
+    group_data.max_open_proposals_per_member = max_open_proposals_per_member;
+    let mut serialized = Vec::with_capacity(account.data.len());
+    group_data.try_serialize(&mut serialized)?;
+    account.data = serialized;
+    svm.set_account(group, account)?;
+    Ok(())
+}
+
+/// Sets the timelock and proposal duration limits of an asset.
+pub fn set_asset_proposal_limits(
+    svm: &mut LiteSVM,
+    asset: Pubkey,
+    minimum_timelock: u32,
+    maximum_proposal_duration: u32,
+) -> Result<()> {
+    let mut account = svm
+        .get_account(&asset)
+        .ok_or_else(|| anyhow::anyhow!("asset account not found"))?;
+    let mut data = account.data.as_slice();
+    let mut asset_data = Asset::try_deserialize(&mut data)?;
+    asset_data.minimum_timelock = minimum_timelock;
+    asset_data.maximum_proposal_duration = maximum_proposal_duration;
+    let mut serialized = Vec::with_capacity(account.data.len());
+    asset_data.try_serialize(&mut serialized)?;
+    account.data = serialized;
+    svm.set_account(asset, account)?;
+    Ok(())
+}
+
+/// Sets the number of votes an asset needs before a proposal on it can pass or fail.
+pub fn set_asset_minimum_vote_count(
+    svm: &mut LiteSVM,
+    asset: Pubkey,
+    minimum_vote_count: u32,
+) -> Result<()> {
+    let mut account = svm
+        .get_account(&asset)
+        .ok_or_else(|| anyhow::anyhow!("asset account not found"))?;
+    let mut data = account.data.as_slice();
+    let mut asset_data = Asset::try_deserialize(&mut data)?;
+    asset_data.minimum_vote_count = minimum_vote_count;
+    let mut serialized = Vec::with_capacity(account.data.len());
+    asset_data.try_serialize(&mut serialized)?;
+    account.data = serialized;
+    svm.set_account(asset, account)?;
+    Ok(())
+}
+
+/// Writes a schedule account, so runs can be tested without a proposal creating it.
+pub fn insert_schedule(svm: &mut LiteSVM, schedule: Pubkey, data: Schedule) -> Result<()> {
+    let mut serialized = Vec::with_capacity(8 + Schedule::INIT_SPACE);
+    data.try_serialize(&mut serialized)?;
+    let rent = svm.minimum_balance_for_rent_exemption(serialized.len());
+    let account = Account {
+        lamports: rent,
+        data: serialized,
+        owner: MULTISIG_PROGRAM_ID,
+        executable: false,
+        rent_epoch: 0,
+    };
+    svm.set_account(schedule, account)?;
+    Ok(())
+}
+
 /// Directly set `group.paused` and the three trusted keys in the on-chain account.
 /// Used in pause-mode tests to skip the full emergency-reset proposal flow.
 pub fn set_group_paused(
@@ -1085,3 +1374,18 @@ pub fn set_group_paused(
     svm.set_account(group, account)?;
     Ok(())
 }
+
+/// Sets the expiry of a member invite to 0, which has passed on LiteSVM's clock that starts at 0.
+pub fn set_member_invite_as_expired(svm: &mut LiteSVM, invite: Pubkey) -> Result<()> {
+    let mut account = svm
+        .get_account(&invite)
+        .ok_or_else(|| anyhow::anyhow!("member invite account not found"))?;
+    let mut data = account.data.as_slice();
+    let mut invite_data = MemberInvite::try_deserialize(&mut data)?;
+    invite_data.expiry_timestamp = 0;
+    let mut serialized = Vec::with_capacity(account.data.len());
+    invite_data.try_serialize(&mut serialized)?;
+    account.data = serialized;
+    svm.set_account(invite, account)?;
+    Ok(())
+}
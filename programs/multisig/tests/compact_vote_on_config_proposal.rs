@@ -0,0 +1,192 @@
+#![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::{
+        CompactVoteOnConfigProposalInstructionArgs, CreateConfigProposalInstructionArgs,
+        VoteOnConfigProposalInstructionArgs,
+    },
+    ConfigChange, ConfigProposal, ConfigType, VoteChoice,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{instruction::Instruction, signer::Signer, transaction::Transaction};
+
+mod common;
+use common::{add_multisig_program, send_tx, setup_group};
+
+// Compact vote on config proposal: votes are kept in the proposal account.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    CompactVotesNotEnabled,
+    VoteRecordOnCompactProposal,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<(
+        [Instruction; 1],
+        Vec<solana_sdk::signature::Keypair>,
+        solana_sdk::pubkey::Pubkey,
+    )> {
+        let group_setup = setup_group(svm)?;
+
+        let proposal_seed = solana_sdk::pubkey::Pubkey::new_unique();
+        let proposal = sdk::proposal_pda(&group_setup.group, &proposal_seed).address;
+        let create_args = CreateConfigProposalInstructionArgs {
+            proposal_seed,
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            config_change: ConfigChange::ChangeGroupConfig {
+                config_types: vec![ConfigType::MinimumVoteCount(1)],
+            },
+            compact_votes: !matches!(scenario, Scenario::CompactVotesNotEnabled),
+            description: None,
+        };
+        let create_config_proposal = sdk::create_config_proposal(
+            create_args,
+            group_setup.group,
+            group_setup.payer.pubkey(),
+            None,
+        );
+        send_tx(svm, &group_setup.payer, vec![create_config_proposal], &[])?;
+
+        let vote = match scenario {
+            Scenario::VoteRecordOnCompactProposal => sdk::vote_on_config_proposal(
+                VoteOnConfigProposalInstructionArgs {
+                    vote: VoteChoice::For,
+                },
+                group_setup.group,
+                proposal,
+                group_setup.payer.pubkey(),
+                None,
+            ),
+            Scenario::Default | Scenario::CompactVotesNotEnabled => {
+                sdk::compact_vote_on_config_proposal(
+                    CompactVoteOnConfigProposalInstructionArgs {
+                        vote: VoteChoice::For,
+                    },
+                    group_setup.group,
+                    proposal,
+                    group_setup.payer.pubkey(),
+                    None,
+                )
+            }
+        };
+
+        Ok(([vote], vec![group_setup.payer], proposal))
+    }
+
+    pub fn with_default(
+        svm: &mut LiteSVM,
+    ) -> Result<(
+        [Instruction; 1],
+        Vec<solana_sdk::signature::Keypair>,
+        solana_sdk::pubkey::Pubkey,
+    )> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_compact_votes_not_enabled(
+        svm: &mut LiteSVM,
+    ) -> Result<(
+        [Instruction; 1],
+        Vec<solana_sdk::signature::Keypair>,
+        solana_sdk::pubkey::Pubkey,
+    )> {
+        Self::builder(svm, Scenario::CompactVotesNotEnabled)
+    }
+
+    pub fn with_vote_record_on_compact_proposal(
+        svm: &mut LiteSVM,
+    ) -> Result<(
+        [Instruction; 1],
+        Vec<solana_sdk::signature::Keypair>,
+        solana_sdk::pubkey::Pubkey,
+    )> {
+        Self::builder(svm, Scenario::VoteRecordOnCompactProposal)
+    }
+}
+
+#[test]
+fn test_compact_vote_on_config_proposal_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_default(&mut svm);
+    let (instructions, signers, proposal) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    let account = svm.get_account(&proposal).expect("proposal account");
+    let proposal =
+        ConfigProposal::try_deserialize(&mut account.data.as_slice()).expect("proposal data");
+    let compact_votes = proposal.compact_votes.expect("compact votes");
+    assert_eq!(proposal.vote_count, 1);
+    assert_eq!(proposal.for_weight, 1);
+    assert_eq!(compact_votes.len(), 1);
+    // The payer is the first initial member of the group.
+    assert_eq!(compact_votes[0].member_index, 0);
+    assert!(compact_votes[0].vote_choice == VoteChoice::For);
+}
+
+#[test]
+fn test_compact_vote_on_config_proposal_fails_without_compact_votes() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_compact_votes_not_enabled(&mut svm);
+    let (instructions, signers, _) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::CompactVotesNotEnabled,
+    );
+}
+
+#[test]
+fn test_vote_on_config_proposal_fails_when_votes_are_compact() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_vote_record_on_compact_proposal(&mut svm);
+    let (instructions, signers, _) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::CompactVotesRequired,
+    );
+}
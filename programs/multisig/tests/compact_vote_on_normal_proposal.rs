@@ -0,0 +1,197 @@
+#![cfg(feature = "test-helpers")]
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::{
+        CompactVoteOnNormalProposalInstructionArgs, CreateNormalProposalInstructionArgs,
+        CreateProposalTransactionInstructionArgs,
+    },
+    AssetIndex, SerializableInstruction, VoteChoice,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{instruction::Instruction, signer::Signer, transaction::Transaction};
+
+mod common;
+use common::{
+    add_multisig_program, create_token_account_at, read_normal_proposal, send_tx, setup_asset_mint,
+    setup_group, to_serializable,
+};
+
+// Compact vote on normal proposal: votes are kept in the proposal account.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    CompactVotesNotEnabled,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<(
+        Vec<Instruction>,
+        Vec<solana_sdk::signature::Keypair>,
+        solana_sdk::pubkey::Pubkey,
+    )> {
+        let group_setup = setup_group(svm)?;
+        let asset_setup = setup_asset_mint(svm, &group_setup)?;
+
+        let destination = solana_sdk::signature::Keypair::new();
+        create_token_account_at(
+            svm,
+            &destination.pubkey(),
+            &asset_setup.mint,
+            &group_setup.payer.pubkey(),
+            solana_sdk::program_option::COption::None,
+            spl_token::state::AccountState::Initialized,
+            solana_sdk::program_option::COption::None,
+        )?;
+
+        let mint_to_ix = spl_token::instruction::mint_to(
+            &spl_token::ID,
+            &asset_setup.mint,
+            &destination.pubkey(),
+            &asset_setup.asset_authority,
+            &[],
+            1,
+        )?;
+        let serializable: SerializableInstruction = to_serializable(&mint_to_ix);
+        let instruction_hashes = vec![sdk::serializable_instruction_hash(&serializable)?];
+
+        let proposal_seed = solana_sdk::pubkey::Pubkey::new_unique();
+        let create_normal_args = CreateNormalProposalInstructionArgs {
+            proposal_seed,
+            asset_keys: vec![asset_setup.mint],
+            asset_indices: vec![AssetIndex {
+                instruction_index: 0,
+                account_index: 0,
+            }],
+            authority_bumps: vec![
+                sdk::asset_authority_pda(&group_setup.group, &asset_setup.mint).bump,
+            ],
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            instruction_hashes,
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
+            compact_votes: !matches!(scenario, Scenario::CompactVotesNotEnabled),
+            description: None,
+            options: vec![],
+        };
+
+        let create_normal_proposal = sdk::create_normal_proposal(
+            create_normal_args,
+            group_setup.group,
+            group_setup.payer.pubkey(),
+        );
+
+        let proposal = sdk::proposal_pda(&group_setup.group, &proposal_seed).address;
+        let raw_instructions = vec![sdk::serializable_instruction_bytes(&serializable)?];
+        let create_tx_args = CreateProposalTransactionInstructionArgs { raw_instructions };
+        let create_proposal_tx = sdk::create_proposal_transaction(
+            create_tx_args,
+            group_setup.group,
+            proposal_seed,
+            group_setup.payer.pubkey(),
+            &[asset_setup.mint],
+        );
+
+        let setup_ixs = vec![create_normal_proposal, create_proposal_tx];
+        send_tx(svm, &group_setup.payer, setup_ixs, &[])?;
+
+        let vote_args = CompactVoteOnNormalProposalInstructionArgs {
+            voting_asset_index: 0,
+            vote: VoteChoice::For,
+        };
+        let vote_on_proposal = sdk::compact_vote_on_normal_proposal(
+            vote_args,
+            group_setup.group,
+            proposal,
+            asset_setup.mint,
+            group_setup.payer.pubkey(),
+        );
+
+        Ok((vec![vote_on_proposal], vec![group_setup.payer], proposal))
+    }
+
+    pub fn with_default(
+        svm: &mut LiteSVM,
+    ) -> Result<(
+        Vec<Instruction>,
+        Vec<solana_sdk::signature::Keypair>,
+        solana_sdk::pubkey::Pubkey,
+    )> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_compact_votes_not_enabled(
+        svm: &mut LiteSVM,
+    ) -> Result<(
+        Vec<Instruction>,
+        Vec<solana_sdk::signature::Keypair>,
+        solana_sdk::pubkey::Pubkey,
+    )> {
+        Self::builder(svm, Scenario::CompactVotesNotEnabled)
+    }
+}
+
+#[test]
+fn test_compact_vote_on_normal_proposal_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_default(&mut svm);
+    let (instructions, signers, proposal) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    let proposal_account = read_normal_proposal(&svm, proposal).expect("proposal");
+    assert_eq!(proposal_account.assets[0].vote_count, 1);
+    assert_eq!(proposal_account.assets[0].use_vote_weight, 1);
+
+    let compact_votes = proposal_account.compact_votes.expect("compact votes");
+    assert_eq!(compact_votes.len(), 1);
+    assert_eq!(compact_votes[0].member_index, 0);
+    assert_eq!(compact_votes[0].asset_index, Some(0));
+
+    // No vote record is created for compact votes.
+    let group = proposal_account.group;
+    let vote_record = sdk::normal_vote_record_pda(&group, &proposal, &payer, 0).address;
+    assert!(svm.get_account(&vote_record).is_none());
+}
+
+#[test]
+fn test_compact_vote_on_normal_proposal_fails_without_compact_votes() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_compact_votes_not_enabled(&mut svm);
+    let (instructions, signers, _) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::CompactVotesNotEnabled,
+    );
+}
@@ -19,10 +19,13 @@ enum Scenario {
     Default,
     MissingAsset,
     NoProposePermission,
+    OnlyNormalProposePermission,
     ExpiredDeadline,
     UseConfigOnGroup,
     OverlappingThreshold,
     MismatchedAssetInConfig,
+    BatchedThresholds,
+    TooManyConfigChanges,
 }
 
 impl TestSetup {
@@ -36,7 +39,7 @@ impl TestSetup {
         let (config_change, asset, deadline) = match scenario {
             Scenario::Default => (
                 ConfigChange::ChangeGroupConfig {
-                    config_type: ConfigType::MinimumVoteCount(1),
+                    config_types: vec![ConfigType::MinimumVoteCount(1)],
                 },
                 None,
                 1000i64,
@@ -45,7 +48,7 @@ impl TestSetup {
                 ConfigChange::AddAssetMember {
                     member: group_setup.member_keys[1],
                     weight: 1,
-                    permissions: Permissions::from_flags(true, true),
+                    permissions: Permissions::builder().propose(true).add_asset(true).build(),
                     asset_address: asset_setup.asset_address,
                 },
                 None, // intentionally omitted despite being an asset change
@@ -56,11 +59,27 @@ impl TestSetup {
                     svm,
                     group_setup.group,
                     group_setup.payer.pubkey(),
-                    Permissions::from_flags(false, false),
+                    Permissions::builder().build(),
                 )?;
                 (
                     ConfigChange::ChangeGroupConfig {
-                        config_type: ConfigType::MinimumVoteCount(1),
+                        config_types: vec![ConfigType::MinimumVoteCount(1)],
+                    },
+                    None,
+                    1000i64,
+                )
+            }
+            Scenario::OnlyNormalProposePermission => {
+                // Propose only covers normal proposals, config proposals need Propose config.
+                set_group_member_permissions(
+                    svm,
+                    group_setup.group,
+                    group_setup.payer.pubkey(),
+                    Permissions::builder().propose(true).build(),
+                )?;
+                (
+                    ConfigChange::ChangeGroupConfig {
+                        config_types: vec![ConfigType::MinimumVoteCount(1)],
                     },
                     None,
                     1000i64,
@@ -68,16 +87,16 @@ impl TestSetup {
             }
             Scenario::ExpiredDeadline => (
                 ConfigChange::ChangeGroupConfig {
-                    config_type: ConfigType::MinimumVoteCount(1),
+                    config_types: vec![ConfigType::MinimumVoteCount(1)],
                 },
                 None,
                 -1i64, // LiteSVM clock starts at 0 -> already expired
             ),
             Scenario::UseConfigOnGroup => (
                 // Use/NotUse are only valid for asset proposals; using them on a group change
-                // triggers UnexpectedConfigChange inside validate_group_config_type.
+                // triggers UnexpectedConfigChange when the batch is applied to the group.
                 ConfigChange::ChangeGroupConfig {
-                    config_type: ConfigType::Use(threshold(1, 2)),
+                    config_types: vec![ConfigType::Use(threshold(1, 2))],
                 },
                 None,
                 1000i64,
@@ -86,7 +105,26 @@ impl TestSetup {
                 // The group has not_change_config_threshold = 2/3.
                 // A new change_config threshold of 1/3 overlaps because 1/3 + 2/3 == 1 ≤ 1.
                 ConfigChange::ChangeGroupConfig {
-                    config_type: ConfigType::ChangeConfig(threshold(1, 3)),
+                    config_types: vec![ConfigType::ChangeConfig(threshold(1, 3))],
+                },
+                None,
+                1000i64,
+            ),
+            Scenario::BatchedThresholds => (
+                // 1/3 alone overlaps the current not_change_config_threshold of 2/3,
+                // the batch is only validated once the new failing threshold is applied too.
+                ConfigChange::ChangeGroupConfig {
+                    config_types: vec![
+                        ConfigType::ChangeConfig(threshold(1, 3)),
+                        ConfigType::NotChangeConfig(threshold(3, 4)),
+                    ],
+                },
+                None,
+                1000i64,
+            ),
+            Scenario::TooManyConfigChanges => (
+                ConfigChange::ChangeGroupConfig {
+                    config_types: vec![ConfigType::MinimumVoteCount(1); 9],
                 },
                 None,
                 1000i64,
@@ -97,7 +135,7 @@ impl TestSetup {
                     ConfigChange::AddAssetMember {
                         member: group_setup.member_keys[1],
                         weight: 1,
-                        permissions: Permissions::from_flags(true, true),
+                        permissions: Permissions::builder().propose(true).add_asset(true).build(),
                         // Config names a different asset than the one we'll pass in the account
                         asset_address: other_asset.asset_address,
                     },
@@ -112,6 +150,8 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: deadline,
             config_change,
+            compact_votes: false,
+            description: None,
         };
 
         let create_config_proposal =
@@ -138,6 +178,12 @@ impl TestSetup {
         Self::builder(svm, Scenario::NoProposePermission)
     }
 
+    pub fn with_only_normal_propose_permission(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::OnlyNormalProposePermission)
+    }
+
     pub fn with_expired_deadline(
         svm: &mut LiteSVM,
     ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
@@ -161,6 +207,18 @@ impl TestSetup {
     ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
         Self::builder(svm, Scenario::MismatchedAssetInConfig)
     }
+
+    pub fn with_batched_thresholds(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::BatchedThresholds)
+    }
+
+    pub fn with_too_many_config_changes(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::TooManyConfigChanges)
+    }
 }
 
 #[test]
@@ -227,6 +285,30 @@ fn test_create_config_proposal_fails_without_propose_permission() {
     );
 }
 
+#[test]
+fn test_create_config_proposal_fails_with_only_normal_propose_permission() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_only_normal_propose_permission(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::InsufficientPermissions,
+    );
+}
+
 #[test]
 fn test_create_config_proposal_fails_with_expired_deadline() {
     let mut svm = LiteSVM::new();
@@ -310,3 +392,47 @@ fn test_create_config_proposal_fails_with_mismatched_asset_in_config() {
     let result = svm.send_transaction(transaction);
     common::assert_multisig_instruction_error(result, 0, multisig::MultisigError::InvalidAsset);
 }
+
+#[test]
+fn test_create_config_proposal_validates_batch_after_all_changes() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_batched_thresholds(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+}
+
+#[test]
+fn test_create_config_proposal_fails_with_too_many_config_changes() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_too_many_config_changes(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::TooManyConfigChanges,
+    );
+}
@@ -232,7 +232,7 @@ impl TestSetup {
             minimum_vote_count: 3,
             minimum_timelock: 0,
             member_weights: [20; 5],
-            member_permissions: [Permissions::from_flags(true, true); 5],
+            member_permissions: [Permissions::builder().propose(true).add_asset(true).build(); 5],
         };
 
         Self::builder(svm, create_group_instruction_args)
@@ -297,7 +297,7 @@ impl TestSetup {
             minimum_vote_count: 3,
             minimum_timelock: 0,
             member_weights: [20; 5],
-            member_permissions: [Permissions::from_flags(true, true); 5],
+            member_permissions: [Permissions::builder().propose(true).add_asset(true).build(); 5],
         };
 
         Self::builder(svm, create_group_instruction_args)
@@ -361,7 +361,7 @@ impl TestSetup {
             minimum_vote_count: 3,
             minimum_timelock: 0,
             member_weights: [20; 5],
-            member_permissions: [Permissions::from_flags(true, true); 5],
+            member_permissions: [Permissions::builder().propose(true).add_asset(true).build(); 5],
         };
 
         Self::builder(svm, create_group_instruction_args)
@@ -393,7 +393,7 @@ impl TestSetup {
             minimum_vote_count: invalid_min_vote_count,
             minimum_timelock: 0,
             member_weights: [20; 5],
-            member_permissions: [Permissions::from_flags(true, true); 5],
+            member_permissions: [Permissions::builder().propose(true).add_asset(true).build(); 5],
         };
 
         Self::builder(svm, create_group_instruction_args)
@@ -2,8 +2,8 @@
 use anyhow::Result;
 use litesvm::LiteSVM;
 use multisig::{
-    instructions::CreateNormalProposalInstructionArgs, AssetIndex, Permissions,
-    SerializableInstruction,
+    instructions::CreateNormalProposalInstructionArgs, AssetIndex, BalanceGuard, BalanceGuardKind,
+    ExecutionPrecondition, Permissions, SerializableInstruction,
 };
 use multisig_sdk as sdk;
 use solana_sdk::{instruction::Instruction, signer::Signer, transaction::Transaction};
@@ -11,7 +11,7 @@ use solana_sdk::{instruction::Instruction, signer::Signer, transaction::Transact
 mod common;
 use common::{
     add_multisig_program, create_token_account_at, set_group_member_permissions, setup_asset_mint,
-    setup_group, to_serializable,
+    setup_asset_token, setup_group, to_serializable,
 };
 
 // Create normal proposal should reject empty assets and accept a valid instruction hash.
@@ -22,6 +22,11 @@ enum Scenario {
     EmptyAssets,
     DuplicateAssetIndex,
     MissingGroupProposePermission,
+    BalanceGuardForOtherAsset,
+    EmptyPreconditionData,
+    TooManyAtomicInstructions,
+    TokenAmountGuardOnMint,
+    TokenAmountGuardOnTokenAccount,
 }
 
 impl TestSetup {
@@ -30,7 +35,10 @@ impl TestSetup {
         scenario: Scenario,
     ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
         let group_setup = setup_group(svm)?;
-        let asset_setup = setup_asset_mint(svm, &group_setup)?;
+        let asset_setup = match scenario {
+            Scenario::TokenAmountGuardOnTokenAccount => setup_asset_token(svm, &group_setup)?,
+            _ => setup_asset_mint(svm, &group_setup)?,
+        };
 
         if matches!(scenario, Scenario::MissingGroupProposePermission) {
             // Strip the propose bit from the payer's group membership AFTER asset setup
@@ -39,7 +47,7 @@ impl TestSetup {
                 svm,
                 group_setup.group,
                 group_setup.payer.pubkey(),
-                Permissions::from_flags(false, false),
+                Permissions::builder().build(),
             )?;
         }
 
@@ -63,7 +71,12 @@ impl TestSetup {
             1,
         )?;
         let serializable: SerializableInstruction = to_serializable(&mint_to_ix);
-        let instruction_hashes = vec![sdk::serializable_instruction_hash(&serializable)?];
+        let instruction_count = match scenario {
+            Scenario::TooManyAtomicInstructions => multisig::MAX_INSTRUCTIONS_PER_EXECUTION + 1,
+            _ => 1,
+        };
+        let instruction_hashes =
+            vec![sdk::serializable_instruction_hash(&serializable)?; instruction_count];
 
         let (asset_keys, asset_indices, authority_bumps) = match scenario {
             Scenario::EmptyAssets => (Vec::new(), Vec::new(), Vec::new()),
@@ -95,13 +108,19 @@ impl TestSetup {
                     assets.iter().map(|(_, bump)| *bump).collect(),
                 )
             }
-            Scenario::Default | Scenario::MissingGroupProposePermission => (
-                vec![asset_setup.mint],
+            Scenario::Default
+            | Scenario::MissingGroupProposePermission
+            | Scenario::BalanceGuardForOtherAsset
+            | Scenario::EmptyPreconditionData
+            | Scenario::TooManyAtomicInstructions
+            | Scenario::TokenAmountGuardOnMint
+            | Scenario::TokenAmountGuardOnTokenAccount => (
+                vec![asset_setup.asset_address],
                 vec![AssetIndex {
                     instruction_index: 0,
                     account_index: 0,
                 }],
-                vec![sdk::asset_authority_pda(&group_setup.group, &asset_setup.mint).bump],
+                vec![sdk::asset_authority_pda(&group_setup.group, &asset_setup.asset_address).bump],
             ),
         };
 
@@ -113,6 +132,34 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             instruction_hashes,
+            sequential_execution: false,
+            balance_guards: match scenario {
+                Scenario::BalanceGuardForOtherAsset => vec![BalanceGuard {
+                    asset: solana_sdk::pubkey::Pubkey::new_unique(),
+                    kind: BalanceGuardKind::Lamports,
+                    max_decrease: 0,
+                }],
+                Scenario::TokenAmountGuardOnMint | Scenario::TokenAmountGuardOnTokenAccount => {
+                    vec![BalanceGuard {
+                        asset: asset_setup.asset_address,
+                        kind: BalanceGuardKind::TokenAmount,
+                        max_decrease: 0,
+                    }]
+                }
+                _ => vec![],
+            },
+            preconditions: match scenario {
+                Scenario::EmptyPreconditionData => vec![ExecutionPrecondition::AccountData {
+                    account: asset_setup.mint,
+                    offset: 0,
+                    bytes: Vec::new(),
+                }],
+                _ => vec![],
+            },
+            group_config_change: None,
+            compact_votes: false,
+            description: None,
+            options: vec![],
         };
 
         let create_normal_proposal =
@@ -144,6 +191,36 @@ impl TestSetup {
     ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
         Self::builder(svm, Scenario::MissingGroupProposePermission)
     }
+
+    pub fn with_balance_guard_for_other_asset(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::BalanceGuardForOtherAsset)
+    }
+
+    pub fn with_empty_precondition_data(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::EmptyPreconditionData)
+    }
+
+    pub fn with_too_many_atomic_instructions(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::TooManyAtomicInstructions)
+    }
+
+    pub fn with_token_amount_guard_on_mint(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::TokenAmountGuardOnMint)
+    }
+
+    pub fn with_token_amount_guard_on_token_account(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::TokenAmountGuardOnTokenAccount)
+    }
 }
 
 #[test]
@@ -233,3 +310,120 @@ fn test_create_normal_proposal_fails_without_propose_permission() {
         multisig::MultisigError::InsufficientPermissions,
     );
 }
+
+#[test]
+fn test_create_normal_proposal_fails_with_balance_guard_for_other_asset() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_balance_guard_for_other_asset(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::InvalidBalanceGuard,
+    );
+}
+
+// Only a token account holds a token amount, a mint asset can only guard its authority's lamports.
+#[test]
+fn test_create_normal_proposal_fails_with_token_amount_guard_on_mint() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_token_amount_guard_on_mint(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::InvalidBalanceGuard,
+    );
+}
+
+#[test]
+fn test_create_normal_proposal_with_token_amount_guard_on_token_account() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_token_amount_guard_on_token_account(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+}
+
+#[test]
+fn test_create_normal_proposal_fails_with_empty_precondition_data() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_empty_precondition_data(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::InvalidPrecondition,
+    );
+}
+
+#[test]
+fn test_create_normal_proposal_fails_with_too_many_atomic_instructions() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_too_many_atomic_instructions(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::TooManyInstructions,
+    );
+}
@@ -0,0 +1,173 @@
+#![cfg(feature = "test-helpers")]
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::instructions::DelegateVoteInstructionArgs;
+use multisig_sdk as sdk;
+use solana_sdk::{instruction::Instruction, signer::Signer, transaction::Transaction};
+
+mod common;
+use common::{add_multisig_program, set_group_paused, setup_group};
+
+// Delegate vote: a member hands their group weight to another member.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    SelfDelegation,
+    ExpiredDelegation,
+    GroupPaused,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        let group_setup = setup_group(svm)?;
+        let [delegator, _, _, _] = group_setup.members;
+
+        let delegate = match scenario {
+            Scenario::SelfDelegation => delegator.pubkey(),
+            _ => group_setup.payer.pubkey(),
+        };
+
+        // LiteSVM clock starts at 0, so an expiry of 0 has already passed.
+        let expiry_timestamp = match scenario {
+            Scenario::ExpiredDelegation => Some(0),
+            _ => Some(1000),
+        };
+
+        if matches!(scenario, Scenario::GroupPaused) {
+            set_group_paused(
+                svm,
+                group_setup.group,
+                true,
+                group_setup.member_keys[1],
+                group_setup.member_keys[2],
+                group_setup.member_keys[3],
+            )?;
+        }
+
+        let delegate_args = DelegateVoteInstructionArgs {
+            delegate,
+            asset: None,
+            expiry_timestamp,
+        };
+        let delegate_vote =
+            sdk::delegate_vote(delegate_args, group_setup.group, delegator.pubkey());
+
+        Ok(([delegate_vote], vec![group_setup.payer, delegator]))
+    }
+
+    pub fn with_default(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_self_delegation(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::SelfDelegation)
+    }
+
+    pub fn with_expired_delegation(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::ExpiredDelegation)
+    }
+
+    pub fn with_group_paused(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::GroupPaused)
+    }
+}
+
+#[test]
+fn test_delegate_vote_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_default(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+}
+
+#[test]
+fn test_delegate_vote_fails_when_delegating_to_self() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_self_delegation(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::InvalidDelegation,
+    );
+}
+
+#[test]
+fn test_delegate_vote_fails_when_already_expired() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_expired_delegation(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::DelegationExpired,
+    );
+}
+
+#[test]
+fn test_delegate_vote_fails_when_group_paused() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_group_paused(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(result, 0, multisig::MultisigError::GroupPaused);
+}
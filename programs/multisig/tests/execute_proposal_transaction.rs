@@ -1,9 +1,16 @@
 #![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
 use anyhow::Result;
 use litesvm::LiteSVM;
 use multisig::{
-    instructions::{CreateNormalProposalInstructionArgs, CreateProposalTransactionInstructionArgs},
-    AssetIndex, ProposalState, SerializableInstruction,
+    instructions::{
+        CompactVoteOnConfigProposalInstructionArgs, CreateConfigProposalInstructionArgs,
+        CreateGroupInstructionArgs, CreateNormalProposalInstructionArgs,
+        CreateProposalTransactionInstructionArgs, ExecuteProposalTransactionInstructionArgs,
+        VoteOnNormalProposalGroupApprovalInstructionArgs,
+    },
+    AssetIndex, BalanceGuard, BalanceGuardKind, ConfigChange, ConfigType, ExecutionPrecondition,
+    ProposalState, SerializableInstruction, VoteChoice,
 };
 use multisig_sdk as sdk;
 use solana_sdk::{
@@ -13,9 +20,12 @@ use solana_sdk::{
 
 mod common;
 use common::{
-    add_multisig_program, create_token_account_at, send_tx, set_group_stale_after_index,
-    set_normal_proposal_as_timelocked, set_normal_proposal_deadline, set_normal_proposal_state,
-    setup_asset_mint, setup_group, to_serializable,
+    add_multisig_program, create_token_account_at, permissions, read_execution_receipt, read_group,
+    read_normal_proposal, send_tx, set_asset_executor_tip, set_group_member_permissions,
+    set_group_require_execute_permission, set_group_stale_after_index,
+    set_normal_proposal_as_timelocked, set_normal_proposal_deadline,
+    set_normal_proposal_group_approval_reached, set_normal_proposal_state, setup_asset_mint,
+    setup_group, threshold, to_serializable, GroupSetup,
 };
 
 enum Scenario {
@@ -25,8 +35,29 @@ enum Scenario {
     Timelocked,
     StaleProposal,
     ExpiredProposal,
+    Sequential,
+    PartialAtomic,
+    ExecutePermissionMissing,
+    ExecutePermissionGranted,
+    ExecutorTip,
+    ExecutorTipOverBalanceGuard,
+    BalanceGuardSatisfied,
+    BalanceGuardViolated,
+    PreconditionsMet,
+    PreconditionNotMet,
+    TokenPreconditionOnMint,
+    GroupConfigChange,
+    GroupApprovalNotReached,
+    UnapprovedSelfInvocation,
+    NestedMemberProposal,
+    NestedMemberOwnGroup,
+    NestedMemberCompactVote,
+    NestedMemberGroupApprovalVote,
 }
 
+const EXECUTOR_TIP_LAMPORTS: u64 = 5_000;
+const BALANCE_GUARD_MAX_DECREASE: u64 = 5_000;
+
 // Execute proposal transaction should require a passed proposal.
 struct TestSetup {}
 
@@ -58,11 +89,198 @@ impl TestSetup {
             1,
         )?;
         let serializable: SerializableInstruction = to_serializable(&mint_to_ix);
-        let instruction_hashes = vec![sdk::serializable_instruction_hash(&serializable)?];
 
         let proposal_seed = solana_sdk::pubkey::Pubkey::new_unique();
         let proposal_pda = sdk::proposal_pda(&group_setup.group, &proposal_seed);
 
+        // The nested scenarios add the group as a member of a parent group.
+        let parent_group_seed = solana_sdk::pubkey::Pubkey::new_unique();
+        let parent_group = sdk::group_pda(&parent_group_seed).address;
+        let parent_proposal_seed = solana_sdk::pubkey::Pubkey::new_unique();
+        let parent_proposal = sdk::proposal_pda(&parent_group, &parent_proposal_seed).address;
+        let parent_members = [
+            group_setup.group,
+            group_setup.payer.pubkey(),
+            solana_sdk::pubkey::Pubkey::new_unique(),
+            solana_sdk::pubkey::Pubkey::new_unique(),
+            solana_sdk::pubkey::Pubkey::new_unique(),
+        ];
+        if matches!(
+            scenario,
+            Scenario::NestedMemberProposal
+                | Scenario::NestedMemberCompactVote
+                | Scenario::NestedMemberGroupApprovalVote
+        ) {
+            let create_parent_args = CreateGroupInstructionArgs {
+                group_seed: parent_group_seed,
+                rent_collector: group_setup.payer.pubkey(),
+                add_threshold: threshold(1, 2),
+                not_add_threshold: threshold(2, 3),
+                remove_threshold: threshold(1, 2),
+                not_remove_threshold: threshold(2, 3),
+                change_config_threshold: threshold(1, 2),
+                not_change_config_threshold: threshold(2, 3),
+                minimum_member_count: 2,
+                minimum_vote_count: 2,
+                max_member_weight: 100,
+                minimum_timelock: 0,
+                member_weights: [1; 5],
+                member_permissions: [permissions(); 5],
+            };
+            let create_parent = sdk::create_group(
+                create_parent_args,
+                group_setup.payer.pubkey(),
+                parent_members,
+            );
+            send_tx(svm, &group_setup.payer, vec![create_parent], &[])?;
+        }
+
+        // The payer proposes in the parent group, the group votes on it with a compact vote.
+        if matches!(scenario, Scenario::NestedMemberCompactVote) {
+            let parent_args = CreateConfigProposalInstructionArgs {
+                proposal_seed: parent_proposal_seed,
+                timelock_offset: 0,
+                proposal_deadline_timestamp: 1000,
+                config_change: ConfigChange::ChangeGroupConfig {
+                    config_types: vec![ConfigType::MinimumTimelock(5)],
+                },
+                compact_votes: true,
+                description: None,
+            };
+            let create_parent_proposal = sdk::create_config_proposal(
+                parent_args,
+                parent_group,
+                group_setup.payer.pubkey(),
+                None,
+            );
+            send_tx(svm, &group_setup.payer, vec![create_parent_proposal], &[])?;
+        }
+
+        // The payer proposes an asset action with a group config change in the parent group,
+        // the group votes on its group approval.
+        if matches!(scenario, Scenario::NestedMemberGroupApprovalVote) {
+            let parent_setup = GroupSetup {
+                payer: group_setup.payer.insecure_clone(),
+                members: std::array::from_fn(|_| solana_sdk::signature::Keypair::new()),
+                member_keys: parent_members,
+                group_seed: parent_group_seed,
+                group: parent_group,
+            };
+            let parent_asset = setup_asset_mint(svm, &parent_setup)?;
+            let parent_args = CreateNormalProposalInstructionArgs {
+                proposal_seed: parent_proposal_seed,
+                asset_keys: vec![parent_asset.mint],
+                asset_indices: vec![AssetIndex {
+                    instruction_index: 0,
+                    account_index: 0,
+                }],
+                authority_bumps: vec![
+                    sdk::asset_authority_pda(&parent_group, &parent_asset.mint).bump,
+                ],
+                timelock_offset: 0,
+                proposal_deadline_timestamp: 1000,
+                instruction_hashes: vec![sdk::serializable_instruction_hash(&serializable)?],
+                sequential_execution: false,
+                balance_guards: vec![],
+                preconditions: vec![],
+                group_config_change: Some(ConfigChange::ChangeGroupConfig {
+                    config_types: vec![ConfigType::MinimumTimelock(5)],
+                }),
+                compact_votes: false,
+                description: None,
+                options: vec![],
+            };
+            let create_parent_proposal =
+                sdk::create_normal_proposal(parent_args, parent_group, group_setup.payer.pubkey());
+            send_tx(svm, &group_setup.payer, vec![create_parent_proposal], &[])?;
+        }
+
+        // The chunked scenarios mint twice so the transaction can be split,
+        // the balance guard scenarios also move lamports out of the asset authority
+        // and the group config change scenarios call back into the multisig program.
+        let serializable_instructions = match scenario {
+            Scenario::Sequential | Scenario::PartialAtomic => {
+                vec![serializable.clone(), serializable]
+            }
+            Scenario::BalanceGuardSatisfied
+            | Scenario::BalanceGuardViolated
+            | Scenario::ExecutorTipOverBalanceGuard => {
+                let lamports = match scenario {
+                    Scenario::BalanceGuardViolated => BALANCE_GUARD_MAX_DECREASE + 1,
+                    _ => BALANCE_GUARD_MAX_DECREASE,
+                };
+                let transfer_ix = solana_sdk::system_instruction::transfer(
+                    &asset_setup.asset_authority,
+                    &group_setup.payer.pubkey(),
+                    lamports,
+                );
+                vec![serializable, to_serializable(&transfer_ix)]
+            }
+            Scenario::GroupConfigChange
+            | Scenario::GroupApprovalNotReached
+            | Scenario::UnapprovedSelfInvocation => {
+                let apply_ix = sdk::apply_group_config_change(
+                    group_setup.group,
+                    proposal_pda.address,
+                    None,
+                    None,
+                    None,
+                );
+                vec![serializable, to_serializable(&apply_ix)]
+            }
+            // The group proposes in a parent group it is a member of, the asset authority
+            // pays for the parent's proposal.
+            Scenario::NestedMemberProposal | Scenario::NestedMemberOwnGroup => {
+                let target_group = match scenario {
+                    Scenario::NestedMemberProposal => parent_group,
+                    _ => group_setup.group,
+                };
+                let nested_args = CreateConfigProposalInstructionArgs {
+                    proposal_seed: parent_proposal_seed,
+                    timelock_offset: 0,
+                    proposal_deadline_timestamp: 1000,
+                    config_change: ConfigChange::ChangeGroupConfig {
+                        config_types: vec![ConfigType::MinimumTimelock(5)],
+                    },
+                    compact_votes: false,
+                    description: None,
+                };
+                let nested_ix = sdk::with_rent_payer(
+                    sdk::create_config_proposal(nested_args, target_group, group_setup.group, None),
+                    asset_setup.asset_authority,
+                );
+                vec![serializable, to_serializable(&nested_ix)]
+            }
+            Scenario::NestedMemberCompactVote => {
+                let nested_ix = sdk::compact_vote_on_config_proposal(
+                    CompactVoteOnConfigProposalInstructionArgs {
+                        vote: VoteChoice::For,
+                    },
+                    parent_group,
+                    parent_proposal,
+                    group_setup.group,
+                    None,
+                );
+                vec![serializable, to_serializable(&nested_ix)]
+            }
+            Scenario::NestedMemberGroupApprovalVote => {
+                let nested_ix = sdk::with_rent_payer(
+                    sdk::vote_on_normal_proposal_group_approval(
+                        VoteOnNormalProposalGroupApprovalInstructionArgs {
+                            vote: VoteChoice::For,
+                        },
+                        parent_group,
+                        parent_proposal,
+                        group_setup.group,
+                    ),
+                    asset_setup.asset_authority,
+                );
+                vec![serializable, to_serializable(&nested_ix)]
+            }
+            _ => vec![serializable],
+        };
+        let instruction_hashes = sdk::serializable_instruction_hashes(&serializable_instructions)?;
+
         let create_args = CreateNormalProposalInstructionArgs {
             proposal_seed,
             asset_keys: vec![asset_setup.mint],
@@ -76,12 +294,62 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             instruction_hashes,
+            sequential_execution: matches!(scenario, Scenario::Sequential),
+            balance_guards: match scenario {
+                Scenario::BalanceGuardSatisfied
+                | Scenario::BalanceGuardViolated
+                | Scenario::ExecutorTipOverBalanceGuard => {
+                    vec![BalanceGuard {
+                        asset: asset_setup.mint,
+                        kind: BalanceGuardKind::Lamports,
+                        max_decrease: BALANCE_GUARD_MAX_DECREASE,
+                    }]
+                }
+                _ => vec![],
+            },
+            preconditions: match scenario {
+                // The mint authority sits at offset 4 of the mint, after the COption tag.
+                Scenario::PreconditionsMet => vec![
+                    ExecutionPrecondition::AccountData {
+                        account: asset_setup.mint,
+                        offset: 4,
+                        bytes: asset_setup.asset_authority.to_bytes().to_vec(),
+                    },
+                    ExecutionPrecondition::MinimumTokenBalance {
+                        account: destination.pubkey(),
+                        amount: 0,
+                    },
+                    ExecutionPrecondition::MinimumSlot { slot: 0 },
+                ],
+                Scenario::PreconditionNotMet => vec![ExecutionPrecondition::MinimumTokenBalance {
+                    account: destination.pubkey(),
+                    amount: 1,
+                }],
+                Scenario::TokenPreconditionOnMint => {
+                    vec![ExecutionPrecondition::MinimumTokenBalance {
+                        account: asset_setup.mint,
+                        amount: 0,
+                    }]
+                }
+                _ => vec![],
+            },
+            group_config_change: match scenario {
+                Scenario::GroupConfigChange | Scenario::GroupApprovalNotReached => {
+                    Some(ConfigChange::ChangeGroupConfig {
+                        config_types: vec![ConfigType::MinimumTimelock(5)],
+                    })
+                }
+                _ => None,
+            },
+            compact_votes: false,
+            description: None,
+            options: vec![],
         };
 
         let create_normal =
             sdk::create_normal_proposal(create_args, group_setup.group, group_setup.payer.pubkey());
 
-        let raw_instructions = vec![sdk::serializable_instruction_bytes(&serializable)?];
+        let raw_instructions = sdk::serializable_instructions_bytes(&serializable_instructions)?;
         let create_tx_args = CreateProposalTransactionInstructionArgs { raw_instructions };
         let create_proposal_tx = sdk::create_proposal_transaction(
             create_tx_args,
@@ -116,11 +384,44 @@ impl TestSetup {
                 // deadline=-1 < now=0 -> ProposalExpired
                 set_normal_proposal_deadline(svm, proposal_pda.address, -1)?;
             }
+            Scenario::ExecutePermissionMissing => {
+                set_group_require_execute_permission(svm, group_setup.group, true)?;
+            }
+            Scenario::ExecutePermissionGranted => {
+                set_group_require_execute_permission(svm, group_setup.group, true)?;
+                let mut executor_permissions = permissions();
+                executor_permissions.set_execute(true);
+                set_group_member_permissions(
+                    svm,
+                    group_setup.group,
+                    group_setup.payer.pubkey(),
+                    executor_permissions,
+                )?;
+            }
+            Scenario::ExecutorTip | Scenario::ExecutorTipOverBalanceGuard => {
+                set_asset_executor_tip(svm, asset_setup.asset, EXECUTOR_TIP_LAMPORTS)?;
+            }
+            Scenario::GroupConfigChange => {
+                set_normal_proposal_group_approval_reached(svm, proposal_pda.address)?;
+            }
             _ => {}
         }
 
+        // The tip vault is the asset authority, fund it well above the rent exempt minimum.
+        let asset_authority_lamports = match scenario {
+            Scenario::ExecutorTip
+            | Scenario::ExecutorTipOverBalanceGuard
+            | Scenario::BalanceGuardSatisfied
+            | Scenario::BalanceGuardViolated
+            | Scenario::NestedMemberProposal
+            | Scenario::NestedMemberOwnGroup
+            | Scenario::NestedMemberCompactVote
+            | Scenario::NestedMemberGroupApprovalVote => 1_000_000_000,
+            _ => 1,
+        };
+
         let asset_authority_account = Account {
-            lamports: 1,
+            lamports: asset_authority_lamports,
             data: Vec::new(),
             owner: system_program::ID,
             executable: false,
@@ -128,23 +429,109 @@ impl TestSetup {
         };
         svm.set_account(asset_setup.asset_authority, asset_authority_account)?;
 
-        let remaining_accounts = vec![
-            AccountMeta::new(asset_setup.mint, false),
-            AccountMeta::new(destination.pubkey(), false),
-            AccountMeta::new_readonly(asset_setup.asset_authority, false),
-            AccountMeta::new_readonly(spl_token::ID, false),
-        ];
+        let remaining_accounts = match scenario {
+            Scenario::BalanceGuardSatisfied
+            | Scenario::BalanceGuardViolated
+            | Scenario::ExecutorTipOverBalanceGuard => vec![
+                AccountMeta::new(asset_setup.mint, false),
+                AccountMeta::new(destination.pubkey(), false),
+                AccountMeta::new(asset_setup.asset_authority, false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+                AccountMeta::new(group_setup.payer.pubkey(), false),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+            Scenario::GroupConfigChange
+            | Scenario::GroupApprovalNotReached
+            | Scenario::UnapprovedSelfInvocation => vec![
+                AccountMeta::new(asset_setup.mint, false),
+                AccountMeta::new(destination.pubkey(), false),
+                AccountMeta::new_readonly(asset_setup.asset_authority, false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+                AccountMeta::new(group_setup.group, false),
+                AccountMeta::new(proposal_pda.address, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+                AccountMeta::new_readonly(multisig::ID, false),
+            ],
+            // The parent proposal is third from the end, the tests read it from there.
+            Scenario::NestedMemberProposal
+            | Scenario::NestedMemberOwnGroup
+            | Scenario::NestedMemberCompactVote => vec![
+                AccountMeta::new(asset_setup.mint, false),
+                AccountMeta::new(destination.pubkey(), false),
+                AccountMeta::new(asset_setup.asset_authority, false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+                AccountMeta::new(group_setup.group, false),
+                AccountMeta::new(parent_group, false),
+                AccountMeta::new(
+                    sdk::group_member_pda(&parent_group, &group_setup.group).address,
+                    false,
+                ),
+                AccountMeta::new(parent_proposal, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+                AccountMeta::new_readonly(multisig::ID, false),
+            ],
+            Scenario::NestedMemberGroupApprovalVote => vec![
+                AccountMeta::new(asset_setup.mint, false),
+                AccountMeta::new(destination.pubkey(), false),
+                AccountMeta::new(asset_setup.asset_authority, false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+                AccountMeta::new(group_setup.group, false),
+                AccountMeta::new(parent_group, false),
+                AccountMeta::new(
+                    sdk::group_member_pda(&parent_group, &group_setup.group).address,
+                    false,
+                ),
+                AccountMeta::new(
+                    sdk::config_vote_record_pda(
+                        &parent_group,
+                        &parent_proposal,
+                        &group_setup.group,
+                    )
+                    .address,
+                    false,
+                ),
+                AccountMeta::new(parent_proposal, false),
+                AccountMeta::new_readonly(system_program::ID, false),
+                AccountMeta::new_readonly(multisig::ID, false),
+            ],
+            _ => vec![
+                AccountMeta::new(asset_setup.mint, false),
+                AccountMeta::new(destination.pubkey(), false),
+                AccountMeta::new_readonly(asset_setup.asset_authority, false),
+                AccountMeta::new_readonly(spl_token::ID, false),
+            ],
+        };
 
         let rent_collector = match scenario {
             Scenario::WrongRentCollector => solana_sdk::pubkey::Pubkey::new_unique(),
             _ => group_setup.payer.pubkey(),
         };
 
+        let instruction_count = match scenario {
+            Scenario::Sequential | Scenario::PartialAtomic => 1,
+            _ => serializable_instructions.len() as u8,
+        };
+
         let execute = sdk::execute_proposal_transaction(
+            ExecuteProposalTransactionInstructionArgs { instruction_count },
             group_setup.group,
             proposal_pda.address,
+            group_setup.payer.pubkey(),
             proposal_tx_pda.address,
             rent_collector,
+            group_setup.payer.pubkey(),
+            match scenario {
+                Scenario::ExecutePermissionMissing | Scenario::ExecutePermissionGranted => Some(
+                    sdk::group_member_pda(&group_setup.group, &group_setup.payer.pubkey()).address,
+                ),
+                _ => None,
+            },
+            match scenario {
+                Scenario::ExecutorTip | Scenario::ExecutorTipOverBalanceGuard => {
+                    Some(asset_setup.mint)
+                }
+                _ => None,
+            },
             remaining_accounts,
         );
 
@@ -186,6 +573,114 @@ impl TestSetup {
     ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
         Self::builder(svm, Scenario::ExpiredProposal)
     }
+
+    pub fn with_sequential_proposal(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::Sequential)
+    }
+
+    pub fn with_partial_atomic_execution(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::PartialAtomic)
+    }
+
+    pub fn with_missing_execute_permission(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::ExecutePermissionMissing)
+    }
+
+    pub fn with_execute_permission(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::ExecutePermissionGranted)
+    }
+
+    pub fn with_executor_tip(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::ExecutorTip)
+    }
+
+    pub fn with_executor_tip_over_balance_guard(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::ExecutorTipOverBalanceGuard)
+    }
+
+    pub fn with_satisfied_balance_guard(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::BalanceGuardSatisfied)
+    }
+
+    pub fn with_violated_balance_guard(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::BalanceGuardViolated)
+    }
+
+    pub fn with_preconditions_met(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::PreconditionsMet)
+    }
+
+    pub fn with_precondition_not_met(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::PreconditionNotMet)
+    }
+
+    pub fn with_token_precondition_on_mint(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::TokenPreconditionOnMint)
+    }
+
+    pub fn with_group_config_change(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::GroupConfigChange)
+    }
+
+    pub fn with_group_approval_not_reached(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::GroupApprovalNotReached)
+    }
+
+    pub fn with_unapproved_self_invocation(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::UnapprovedSelfInvocation)
+    }
+
+    pub fn with_nested_member_proposal(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::NestedMemberProposal)
+    }
+
+    pub fn with_nested_member_own_group(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::NestedMemberOwnGroup)
+    }
+
+    pub fn with_nested_member_compact_vote(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::NestedMemberCompactVote)
+    }
+
+    pub fn with_nested_member_group_approval_vote(
+        svm: &mut LiteSVM,
+    ) -> Result<(Instruction, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::NestedMemberGroupApprovalVote)
+    }
 }
 
 #[test]
@@ -343,3 +838,590 @@ fn test_execute_proposal_transaction_fails_when_expired() {
     let result = svm.send_transaction(transaction);
     common::assert_multisig_instruction_error(result, 0, multisig::MultisigError::ProposalExpired);
 }
+
+#[test]
+fn test_execute_proposal_transaction_records_receipt() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_default(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let proposal = instruction.accounts[0].pubkey;
+    let proposal_transaction = instruction.accounts[1].pubkey;
+    let execution_receipt = sdk::execution_receipt_pda(&proposal).address;
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    let receipt = read_execution_receipt(&svm, execution_receipt).expect("receipt");
+    assert_eq!(receipt.proposal, proposal);
+    assert_eq!(receipt.instruction_count, 1);
+    assert_eq!(receipt.executed_instructions.len(), 1);
+    assert_eq!(receipt.executed_instructions[0].instruction_index, 0);
+    assert_eq!(receipt.executed_instructions[0].executor, payer);
+    assert!(receipt.executed_instructions[0].compute_units > 0);
+    assert!(receipt.executed_instructions[0].return_data.is_none());
+
+    let proposal_account = read_normal_proposal(&svm, proposal).expect("proposal");
+    assert!(proposal_account.state == ProposalState::Executed);
+    assert!(svm
+        .get_account(&proposal_transaction)
+        .is_none_or(|account| account.lamports == 0));
+}
+
+#[test]
+fn test_execute_proposal_transaction_sequential_chunks() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_sequential_proposal(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let proposal = instruction.accounts[0].pubkey;
+    let execution_receipt = sdk::execution_receipt_pda(&proposal).address;
+    let payer = signers[0].pubkey();
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction.clone()],
+        Some(&payer),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(transaction));
+
+    let proposal_account = read_normal_proposal(&svm, proposal).expect("proposal");
+    assert!(proposal_account.state == ProposalState::Passed);
+    assert_eq!(proposal_account.next_instruction_index, 1);
+    assert_eq!(
+        read_execution_receipt(&svm, execution_receipt)
+            .expect("receipt")
+            .executed_instructions
+            .len(),
+        1
+    );
+
+    svm.expire_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(transaction));
+
+    let proposal_account = read_normal_proposal(&svm, proposal).expect("proposal");
+    assert!(proposal_account.state == ProposalState::Executed);
+
+    let receipt = read_execution_receipt(&svm, execution_receipt).expect("receipt");
+    assert_eq!(receipt.executed_instructions.len(), 2);
+    assert_eq!(receipt.executed_instructions[1].instruction_index, 1);
+}
+
+#[test]
+fn test_execute_proposal_transaction_fails_partial_atomic_execution() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_partial_atomic_execution(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::InvalidInstructionCount,
+    );
+}
+
+#[test]
+fn test_execute_proposal_transaction_fails_without_execute_permission() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_missing_execute_permission(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::InsufficientPermissions,
+    );
+}
+
+#[test]
+fn test_execute_proposal_transaction_with_execute_permission() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_execute_permission(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+}
+
+#[test]
+fn test_execute_proposal_transaction_pays_executor_tip() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_executor_tip(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let tip_vault = instruction.accounts[8].pubkey;
+    let vault_lamports_before = svm.get_account(&tip_vault).expect("vault").lamports;
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    let vault_lamports_after = svm.get_account(&tip_vault).expect("vault").lamports;
+    assert_eq!(
+        vault_lamports_before - vault_lamports_after,
+        EXECUTOR_TIP_LAMPORTS
+    );
+}
+
+// The tip leaves the asset authority too, so it counts against a lamports guard on the asset.
+#[test]
+fn test_execute_proposal_transaction_executor_tip_counts_against_balance_guard() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_executor_tip_over_balance_guard(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::BalanceGuardViolated,
+    );
+}
+
+#[test]
+fn test_execute_proposal_transaction_within_balance_guard() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_satisfied_balance_guard(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let execution_receipt = sdk::execution_receipt_pda(&instruction.accounts[0].pubkey).address;
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    let receipt = read_execution_receipt(&svm, execution_receipt).expect("receipt");
+    assert_eq!(receipt.balance_baselines, vec![1_000_000_000]);
+}
+
+#[test]
+fn test_execute_proposal_transaction_fails_when_balance_guard_violated() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_violated_balance_guard(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::BalanceGuardViolated,
+    );
+}
+
+#[test]
+fn test_execute_proposal_transaction_with_preconditions_met() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_preconditions_met(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+}
+
+#[test]
+fn test_execute_proposal_transaction_fails_when_precondition_not_met() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_precondition_not_met(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::PreconditionNotMet,
+    );
+}
+
+// A token balance precondition on an account that is not a token account is simply not met.
+#[test]
+fn test_execute_proposal_transaction_fails_when_token_precondition_is_not_a_token_account() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_token_precondition_on_mint(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::PreconditionNotMet,
+    );
+}
+
+#[test]
+fn test_execute_proposal_transaction_applies_group_config_change() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_group_config_change(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let group = instruction.accounts[2].pubkey;
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    let group_account = read_group(&svm, group).expect("group");
+    assert_eq!(group_account.minimum_timelock, 5);
+}
+
+#[test]
+fn test_execute_proposal_transaction_fails_when_group_approval_not_reached() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_group_approval_not_reached(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::GroupApprovalNotMet,
+    );
+}
+
+#[test]
+fn test_execute_proposal_transaction_fails_with_unapproved_self_invocation() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_unapproved_self_invocation(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::SelfInvocationNotAllowed,
+    );
+}
+
+#[test]
+fn test_execute_proposal_transaction_proposes_as_member_of_parent_group() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_nested_member_proposal(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let group = instruction.accounts[2].pubkey;
+    let parent_proposal = instruction.accounts[instruction.accounts.len() - 3].pubkey;
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    let account = svm
+        .get_account(&parent_proposal)
+        .expect("parent proposal account");
+    let proposal =
+        multisig::ConfigProposal::try_deserialize(&mut account.data.as_slice()).expect("proposal");
+    assert_eq!(proposal.proposer, group);
+}
+
+#[test]
+fn test_execute_proposal_transaction_fails_when_nested_member_targets_own_group() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_nested_member_own_group(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::SelfInvocationNotAllowed,
+    );
+}
+
+#[test]
+fn test_execute_proposal_transaction_compact_votes_as_member_of_parent_group() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_nested_member_compact_vote(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let parent_proposal = instruction.accounts[instruction.accounts.len() - 3].pubkey;
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    let account = svm
+        .get_account(&parent_proposal)
+        .expect("parent proposal account");
+    let proposal =
+        multisig::ConfigProposal::try_deserialize(&mut account.data.as_slice()).expect("proposal");
+    assert_eq!(proposal.vote_count, 1);
+    assert_eq!(proposal.for_weight, 1);
+    assert_eq!(proposal.compact_votes.expect("compact votes").len(), 1);
+}
+
+#[test]
+fn test_execute_proposal_transaction_votes_on_group_approval_as_member_of_parent_group() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_nested_member_group_approval_vote(&mut svm);
+    let (instruction, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let parent_proposal = instruction.accounts[instruction.accounts.len() - 3].pubkey;
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&payer),
+        &signers,
+        recent_blockhash,
+    );
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    let proposal = read_normal_proposal(&svm, parent_proposal).expect("parent proposal");
+    let group_approval = proposal.group_approval.expect("group approval");
+    assert_eq!(group_approval.vote_count, 1);
+    assert_eq!(group_approval.for_weight, 1);
+}
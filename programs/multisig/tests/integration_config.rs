@@ -27,6 +27,8 @@ fn create_config_proposal(
         timelock_offset: 0,
         proposal_deadline_timestamp: 1000,
         config_change,
+        compact_votes: false,
+        description: None,
     };
     let create_ix = sdk::create_config_proposal(
         create_args,
@@ -157,13 +159,26 @@ fn test_config_cycle_add_group_member() {
         ConfigChange::AddGroupMember {
             member: new_member.pubkey(),
             weight: 1,
-            permissions: Permissions::from_flags(true, true),
+            permissions: Permissions::builder().propose(true).add_asset(true).build(),
+            expires_at: None,
         },
         None,
     );
     vote_config_group(&mut svm, &group_setup, proposal, VoteChoice::For);
     execute_add_group_member(&mut svm, &group_setup, proposal, new_member.pubkey());
 
+    let invited = read_group(&svm, group_setup.group).expect("read group after invite");
+    assert_eq!(invited.member_count, before.member_count);
+
+    svm.airdrop(&new_member.pubkey(), 1_000_000_000)
+        .expect("airdrop to new member");
+    let accept = sdk::accept_membership(
+        group_setup.group,
+        new_member.pubkey(),
+        group_setup.payer.pubkey(),
+    );
+    send_tx(&mut svm, &new_member, vec![accept], &[]).expect("accept membership");
+
     let after = read_group(&svm, group_setup.group).expect("read group after");
     assert_eq!(after.member_count, before.member_count + 1);
     assert_eq!(after.proposal_index_after_stale, after.next_proposal_index);
@@ -185,7 +200,9 @@ fn test_config_cycle_three_group_config_changes() {
         let proposal = create_config_proposal(
             &mut svm,
             &group_setup,
-            ConfigChange::ChangeGroupConfig { config_type },
+            ConfigChange::ChangeGroupConfig {
+                config_types: vec![config_type],
+            },
             None,
         );
         vote_config_group(&mut svm, &group_setup, proposal, VoteChoice::For);
@@ -213,7 +230,9 @@ fn test_config_cycle_three_asset_config_changes() {
         let proposal = create_config_proposal(
             &mut svm,
             &group_setup,
-            ConfigChange::ChangeAssetConfig { config_type },
+            ConfigChange::ChangeAssetConfig {
+                config_types: vec![config_type],
+            },
             Some(asset_setup.asset_address),
         );
         vote_config_asset(
@@ -243,7 +262,8 @@ fn test_config_declined_proposal_can_be_closed() {
         ConfigChange::AddGroupMember {
             member: new_member.pubkey(),
             weight: 1,
-            permissions: Permissions::from_flags(true, true),
+            permissions: Permissions::builder().propose(true).add_asset(true).build(),
+            expires_at: None,
         },
         None,
     );
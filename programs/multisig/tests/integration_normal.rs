@@ -4,7 +4,7 @@ use litesvm::LiteSVM;
 use multisig::{
     instructions::{
         CreateNormalProposalInstructionArgs, CreateProposalTransactionInstructionArgs,
-        VoteOnNormalProposalInstructionArgs,
+        ExecuteProposalTransactionInstructionArgs, VoteOnNormalProposalInstructionArgs,
     },
     AssetIndex, SerializableInstruction, VoteChoice,
 };
@@ -106,6 +106,13 @@ fn create_normal_with_transaction(
         timelock_offset: 0,
         proposal_deadline_timestamp: 1000,
         instruction_hashes,
+        sequential_execution: false,
+        balance_guards: vec![],
+        preconditions: vec![],
+        group_config_change: None,
+        compact_votes: false,
+        description: None,
+        options: vec![],
     };
     let create_normal =
         sdk::create_normal_proposal(create_args, group_setup.group, group_setup.payer.pubkey());
@@ -196,11 +203,21 @@ fn execute_normal(
     ];
     remaining_accounts.extend(extra_remaining_accounts);
 
+    let instruction_count = common::read_normal_proposal(svm, proposal)
+        .expect("read proposal")
+        .instruction_hashes
+        .len() as u8;
+
     let execute = sdk::execute_proposal_transaction(
+        ExecuteProposalTransactionInstructionArgs { instruction_count },
         group_setup.group,
         proposal,
+        group_setup.payer.pubkey(),
         proposal_tx,
         group_setup.payer.pubkey(),
+        group_setup.payer.pubkey(),
+        None,
+        None,
         remaining_accounts,
     );
     send_tx(svm, &group_setup.payer, vec![execute], &[]).expect("execute proposal");
@@ -314,6 +331,13 @@ fn test_normal_proposal_rejects_mismatched_instruction_count() {
         timelock_offset: 0,
         proposal_deadline_timestamp: 1000,
         instruction_hashes: vec![sdk::serializable_instruction_hash(&mint_to).unwrap()],
+        sequential_execution: false,
+        balance_guards: vec![],
+        preconditions: vec![],
+        group_config_change: None,
+        compact_votes: false,
+        description: None,
+        options: vec![],
     };
     let create_normal =
         sdk::create_normal_proposal(create_args, group_setup.group, group_setup.payer.pubkey());
@@ -3,7 +3,7 @@ use anyhow::Result;
 use litesvm::LiteSVM;
 use multisig::{
     instructions::{CreateConfigProposalInstructionArgs, CreateNormalProposalInstructionArgs},
-    ConfigChange, ConfigType, MultisigError,
+    ConfigChange, ConfigChangeTimelocks, ConfigType, MultisigError,
 };
 use multisig_sdk as sdk;
 use solana_sdk::{pubkey::Pubkey, signer::Signer};
@@ -36,6 +36,10 @@ fn test_normal_proposal_fails_when_below_minimum_timelock() {
             timelock_offset: 5, // below minimum_timelock=10
             proposal_deadline_timestamp: i64::MAX,
             instruction_hashes: vec![[0u8; 32]],
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
             asset_keys: vec![asset_setup.mint],
             authority_bumps: vec![
                 sdk::asset_authority_pda(&group_setup.group, &asset_setup.mint).bump,
@@ -44,6 +48,9 @@ fn test_normal_proposal_fails_when_below_minimum_timelock() {
                 instruction_index: 0,
                 account_index: 0,
             }],
+            compact_votes: false,
+            description: None,
+            options: vec![],
         },
         group_setup.group,
         group_setup.payer.pubkey(),
@@ -74,6 +81,10 @@ fn test_normal_proposal_succeeds_at_minimum_timelock() {
             timelock_offset: 10, // exactly minimum_timelock
             proposal_deadline_timestamp: i64::MAX,
             instruction_hashes: vec![[0u8; 32]],
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
             asset_keys: vec![asset_setup.mint],
             authority_bumps: vec![
                 sdk::asset_authority_pda(&group_setup.group, &asset_setup.mint).bump,
@@ -82,6 +93,9 @@ fn test_normal_proposal_succeeds_at_minimum_timelock() {
                 instruction_index: 0,
                 account_index: 0,
             }],
+            compact_votes: false,
+            description: None,
+            options: vec![],
         },
         group_setup.group,
         group_setup.payer.pubkey(),
@@ -110,8 +124,10 @@ fn test_config_proposal_minimum_timelock_change_succeeds() {
             timelock_offset: 0,
             proposal_deadline_timestamp: i64::MAX,
             config_change: ConfigChange::ChangeGroupConfig {
-                config_type: ConfigType::MinimumTimelock(30),
+                config_types: vec![ConfigType::MinimumTimelock(30)],
             },
+            compact_votes: false,
+            description: None,
         },
         group_setup.group,
         group_setup.payer.pubkey(),
@@ -142,8 +158,10 @@ fn test_config_proposal_fails_when_below_minimum_timelock() {
             timelock_offset: 0, // below minimum_timelock=60
             proposal_deadline_timestamp: i64::MAX,
             config_change: ConfigChange::ChangeGroupConfig {
-                config_type: ConfigType::MinimumVoteCount(1),
+                config_types: vec![ConfigType::MinimumVoteCount(1)],
             },
+            compact_votes: false,
+            description: None,
         },
         group_setup.group,
         group_setup.payer.pubkey(),
@@ -159,3 +177,288 @@ fn test_config_proposal_fails_when_below_minimum_timelock() {
 
     assert_multisig_instruction_error(result, 0, MultisigError::TimelockBelowMinimum);
 }
+
+fn normal_proposal_ix(
+    group: Pubkey,
+    proposer: Pubkey,
+    mut asset_keys: Vec<Pubkey>,
+    timelock_offset: u32,
+    proposal_deadline_timestamp: i64,
+) -> solana_sdk::instruction::Instruction {
+    asset_keys.sort();
+    let authority_bumps = asset_keys
+        .iter()
+        .map(|asset| sdk::asset_authority_pda(&group, asset).bump)
+        .collect();
+    let asset_indices = (0..asset_keys.len())
+        .map(|account_index| multisig::AssetIndex {
+            instruction_index: 0,
+            account_index: account_index as u8,
+        })
+        .collect();
+
+    sdk::create_normal_proposal(
+        CreateNormalProposalInstructionArgs {
+            proposal_seed: Pubkey::new_unique(),
+            timelock_offset,
+            proposal_deadline_timestamp,
+            instruction_hashes: vec![[0u8; 32]],
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
+            asset_keys,
+            authority_bumps,
+            asset_indices,
+            compact_votes: false,
+            description: None,
+            options: vec![],
+        },
+        group,
+        proposer,
+    )
+}
+
+#[test]
+fn test_normal_proposal_uses_strictest_asset_timelock() {
+    let mut svm = LiteSVM::new();
+    setup(&mut svm).unwrap();
+    let group_setup = setup_group(&mut svm).unwrap();
+    let ops_wallet = setup_asset_mint(&mut svm, &group_setup).unwrap();
+    let cold_storage = setup_asset_mint(&mut svm, &group_setup).unwrap();
+    common::utils::set_asset_proposal_limits(&mut svm, ops_wallet.asset, 3_600, 0).unwrap();
+    common::utils::set_asset_proposal_limits(&mut svm, cold_storage.asset, 604_800, 0).unwrap();
+
+    let ix = normal_proposal_ix(
+        group_setup.group,
+        group_setup.payer.pubkey(),
+        vec![ops_wallet.mint, cold_storage.mint],
+        3_600, // enough for the ops wallet only
+        i64::MAX,
+    );
+    let result = svm.send_transaction(solana_sdk::transaction::Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&group_setup.payer.pubkey()),
+        &[&group_setup.payer],
+        svm.latest_blockhash(),
+    ));
+    assert_multisig_instruction_error(result, 0, MultisigError::TimelockBelowMinimum);
+
+    let ix = normal_proposal_ix(
+        group_setup.group,
+        group_setup.payer.pubkey(),
+        vec![ops_wallet.mint, cold_storage.mint],
+        604_800,
+        i64::MAX,
+    );
+    let result = svm.send_transaction(solana_sdk::transaction::Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&group_setup.payer.pubkey()),
+        &[&group_setup.payer],
+        svm.latest_blockhash(),
+    ));
+    assert_transaction_success(result);
+}
+
+#[test]
+fn test_normal_proposal_fails_without_asset_accounts() {
+    let mut svm = LiteSVM::new();
+    setup(&mut svm).unwrap();
+    let group_setup = setup_group(&mut svm).unwrap();
+    let asset_setup = setup_asset_mint(&mut svm, &group_setup).unwrap();
+
+    let mut ix = normal_proposal_ix(
+        group_setup.group,
+        group_setup.payer.pubkey(),
+        vec![asset_setup.mint],
+        0,
+        i64::MAX,
+    );
+    // Drop the asset account, which would skip the asset's limits.
+    ix.accounts.pop();
+
+    let result = svm.send_transaction(solana_sdk::transaction::Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&group_setup.payer.pubkey()),
+        &[&group_setup.payer],
+        svm.latest_blockhash(),
+    ));
+    assert_multisig_instruction_error(result, 0, MultisigError::AssetNotProvided);
+}
+
+#[test]
+fn test_normal_proposal_fails_past_group_maximum_duration() {
+    let mut svm = LiteSVM::new();
+    setup(&mut svm).unwrap();
+    let group_setup = setup_group(&mut svm).unwrap();
+    let asset_setup = setup_asset_mint(&mut svm, &group_setup).unwrap();
+    common::utils::set_group_maximum_proposal_duration(&mut svm, group_setup.group, 100).unwrap();
+
+    let ix = normal_proposal_ix(
+        group_setup.group,
+        group_setup.payer.pubkey(),
+        vec![asset_setup.mint],
+        0,
+        101, // LiteSVM's clock starts at 0
+    );
+    let result = svm.send_transaction(solana_sdk::transaction::Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&group_setup.payer.pubkey()),
+        &[&group_setup.payer],
+        svm.latest_blockhash(),
+    ));
+    assert_multisig_instruction_error(result, 0, MultisigError::ProposalDurationTooLong);
+
+    let ix = normal_proposal_ix(
+        group_setup.group,
+        group_setup.payer.pubkey(),
+        vec![asset_setup.mint],
+        0,
+        100,
+    );
+    let result = svm.send_transaction(solana_sdk::transaction::Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&group_setup.payer.pubkey()),
+        &[&group_setup.payer],
+        svm.latest_blockhash(),
+    ));
+    assert_transaction_success(result);
+}
+
+#[test]
+fn test_normal_proposal_fails_past_asset_maximum_duration() {
+    let mut svm = LiteSVM::new();
+    setup(&mut svm).unwrap();
+    let group_setup = setup_group(&mut svm).unwrap();
+    let asset_setup = setup_asset_mint(&mut svm, &group_setup).unwrap();
+    common::utils::set_asset_proposal_limits(&mut svm, asset_setup.asset, 0, 100).unwrap();
+
+    let ix = normal_proposal_ix(
+        group_setup.group,
+        group_setup.payer.pubkey(),
+        vec![asset_setup.mint],
+        0,
+        1_000,
+    );
+    let result = svm.send_transaction(solana_sdk::transaction::Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&group_setup.payer.pubkey()),
+        &[&group_setup.payer],
+        svm.latest_blockhash(),
+    ));
+    assert_multisig_instruction_error(result, 0, MultisigError::ProposalDurationTooLong);
+}
+
+#[test]
+fn test_asset_config_proposal_fails_below_asset_minimum_timelock() {
+    let mut svm = LiteSVM::new();
+    setup(&mut svm).unwrap();
+    let group_setup = setup_group(&mut svm).unwrap();
+    let asset_setup = setup_asset_mint(&mut svm, &group_setup).unwrap();
+    common::utils::set_asset_proposal_limits(&mut svm, asset_setup.asset, 60, 0).unwrap();
+
+    let ix = sdk::create_config_proposal(
+        CreateConfigProposalInstructionArgs {
+            proposal_seed: Pubkey::new_unique(),
+            timelock_offset: 0, // below the asset's minimum_timelock=60
+            proposal_deadline_timestamp: i64::MAX,
+            config_change: ConfigChange::ChangeAssetConfig {
+                config_types: vec![ConfigType::MinimumTimelock(120)],
+            },
+            compact_votes: false,
+            description: None,
+        },
+        group_setup.group,
+        group_setup.payer.pubkey(),
+        Some(asset_setup.mint),
+    );
+
+    let result = svm.send_transaction(solana_sdk::transaction::Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&group_setup.payer.pubkey()),
+        &[&group_setup.payer],
+        svm.latest_blockhash(),
+    ));
+
+    assert_multisig_instruction_error(result, 0, MultisigError::TimelockBelowMinimum);
+}
+
+fn group_config_proposal_ix(
+    group: Pubkey,
+    proposer: Pubkey,
+    config_change: ConfigChange,
+    timelock_offset: u32,
+) -> solana_sdk::instruction::Instruction {
+    sdk::create_config_proposal(
+        CreateConfigProposalInstructionArgs {
+            proposal_seed: Pubkey::new_unique(),
+            timelock_offset,
+            proposal_deadline_timestamp: i64::MAX,
+            config_change,
+            compact_votes: false,
+            description: None,
+        },
+        group,
+        proposer,
+        None,
+    )
+}
+
+#[test]
+fn test_config_proposal_uses_timelock_of_its_kind_of_change() {
+    let mut svm = LiteSVM::new();
+    setup(&mut svm).unwrap();
+    let group_setup = setup_group(&mut svm).unwrap();
+    common::utils::set_group_config_change_timelocks(
+        &mut svm,
+        group_setup.group,
+        ConfigChangeTimelocks {
+            add_member: 0,
+            remove_member: 600,
+            threshold_loosening: 900,
+            threshold_tightening: 60,
+        },
+    )
+    .unwrap();
+
+    let remove_member = ConfigChange::RemoveGroupMember {
+        member: group_setup.members[3].pubkey(),
+    };
+    // Group thresholds are 1/2 to pass and 2/3 to fail.
+    let loosen = ConfigChange::ChangeGroupConfig {
+        config_types: vec![ConfigType::NotRemoveMember(common::threshold(3, 4))],
+    };
+    let tighten = ConfigChange::ChangeGroupConfig {
+        config_types: vec![ConfigType::RemoveMember(common::threshold(3, 5))],
+    };
+
+    for (config_change, timelock_offset, expected) in [
+        (remove_member.clone(), 599, Err(())),
+        (remove_member, 600, Ok(())),
+        (loosen.clone(), 60, Err(())),
+        (loosen, 900, Ok(())),
+        (tighten.clone(), 59, Err(())),
+        (tighten, 60, Ok(())),
+    ] {
+        let ix = group_config_proposal_ix(
+            group_setup.group,
+            group_setup.payer.pubkey(),
+            config_change,
+            timelock_offset,
+        );
+        let result =
+            svm.send_transaction(solana_sdk::transaction::Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&group_setup.payer.pubkey()),
+                &[&group_setup.payer],
+                svm.latest_blockhash(),
+            ));
+
+        match expected {
+            Ok(()) => assert_transaction_success(result),
+            Err(()) => {
+                assert_multisig_instruction_error(result, 0, MultisigError::TimelockBelowMinimum)
+            }
+        }
+    }
+}
@@ -0,0 +1,482 @@
+#![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
+use anchor_spl::token::spl_token::state::Account as TokenAccount;
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::{
+        CreateNormalProposalInstructionArgs, CreateProposalOptionTransactionInstructionArgs,
+        CreateProposalTransactionInstructionArgs, DelegateVoteInstructionArgs,
+        ExecuteProposalTransactionInstructionArgs, VoteOnProposalOptionInstructionArgs,
+    },
+    AssetIndex, MultisigError, ProposalState, ProposalTransaction, SerializableInstruction,
+    VoteRecord,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{
+    add_multisig_program, assert_instruction_error, assert_multisig_instruction_error,
+    assert_transaction_success, create_token_account_at, read_normal_proposal, setup_asset_mint,
+    setup_group, to_serializable, AssetSetup, GroupSetup,
+};
+
+const OPTION_COUNT: usize = 3;
+
+// Members vote between the candidate transactions of a multi-choice proposal.
+struct TestSetup {}
+
+struct MultiChoiceSetup {
+    group_setup: GroupSetup,
+    asset_setup: AssetSetup,
+    proposal_seed: Pubkey,
+    proposal: Pubkey,
+    /// Token account each option mints to.
+    destinations: [Pubkey; OPTION_COUNT],
+    options: Vec<SerializableInstruction>,
+}
+
+enum Scenario {
+    Default,
+    SingleOption,
+    WithInstructionHashes,
+}
+
+fn send(
+    svm: &mut LiteSVM,
+    payer: &Keypair,
+    instructions: Vec<Instruction>,
+    extra_signers: &[&Keypair],
+) -> litesvm::types::TransactionResult {
+    let mut signers = vec![payer];
+    signers.extend_from_slice(extra_signers);
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&payer.pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(transaction)
+}
+
+fn token_amount(svm: &LiteSVM, key: &Pubkey) -> u64 {
+    let account = svm.get_account(key).expect("token account");
+    TokenAccount::unpack(&account.data)
+        .expect("token data")
+        .amount
+}
+
+fn create_option_transaction_ix(setup: &MultiChoiceSetup, option_index: u8) -> Instruction {
+    sdk::create_proposal_option_transaction(
+        CreateProposalOptionTransactionInstructionArgs {
+            option_index,
+            raw_instructions: sdk::serializable_instructions_bytes(std::slice::from_ref(
+                &setup.options[usize::from(option_index)],
+            ))
+            .expect("serialize instructions"),
+        },
+        setup.group_setup.group,
+        setup.proposal_seed,
+        setup.group_setup.payer.pubkey(),
+        &[setup.asset_setup.asset_address],
+    )
+}
+
+fn vote_ix(setup: &MultiChoiceSetup, voter: &Keypair, option_index: Option<u8>) -> Instruction {
+    sdk::vote_on_proposal_option(
+        VoteOnProposalOptionInstructionArgs { option_index },
+        setup.group_setup.group,
+        setup.proposal,
+        setup.asset_setup.asset_address,
+        voter.pubkey(),
+    )
+}
+
+fn execute_ix(setup: &MultiChoiceSetup, option_index: u8) -> Instruction {
+    let payer = setup.group_setup.payer.pubkey();
+    sdk::execute_proposal_transaction(
+        ExecuteProposalTransactionInstructionArgs {
+            instruction_count: 1,
+        },
+        setup.group_setup.group,
+        setup.proposal,
+        payer,
+        sdk::proposal_option_transaction_pda(&setup.proposal, option_index).address,
+        payer,
+        payer,
+        None,
+        None,
+        vec![
+            AccountMeta::new(setup.asset_setup.mint, false),
+            AccountMeta::new(setup.destinations[usize::from(option_index)], false),
+            AccountMeta::new_readonly(setup.asset_setup.asset_authority, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+        ],
+    )
+}
+
+impl TestSetup {
+    fn builder(svm: &mut LiteSVM, scenario: Scenario) -> Result<(Instruction, MultiChoiceSetup)> {
+        let group_setup = setup_group(svm)?;
+        let asset_setup = setup_asset_mint(svm, &group_setup)?;
+
+        let mut destinations = [Pubkey::default(); OPTION_COUNT];
+        let mut options = Vec::with_capacity(OPTION_COUNT);
+        for destination in destinations.iter_mut() {
+            *destination = Pubkey::new_unique();
+            create_token_account_at(
+                svm,
+                destination,
+                &asset_setup.mint,
+                &group_setup.payer.pubkey(),
+                COption::None,
+                spl_token::state::AccountState::Initialized,
+                COption::None,
+            )?;
+            options.push(to_serializable(&spl_token::instruction::mint_to(
+                &spl_token::ID,
+                &asset_setup.mint,
+                destination,
+                &asset_setup.asset_authority,
+                &[],
+                1,
+            )?));
+        }
+
+        let option_count = match scenario {
+            Scenario::SingleOption => 1,
+            _ => OPTION_COUNT,
+        };
+        let option_hashes = options[..option_count]
+            .iter()
+            .map(|option| sdk::serializable_instruction_hashes(std::slice::from_ref(option)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let instruction_hashes = match scenario {
+            Scenario::WithInstructionHashes => option_hashes[0].clone(),
+            _ => vec![],
+        };
+
+        let proposal_seed = Pubkey::new_unique();
+        let proposal = sdk::proposal_pda(&group_setup.group, &proposal_seed).address;
+        let create = sdk::create_normal_proposal(
+            CreateNormalProposalInstructionArgs {
+                proposal_seed,
+                asset_keys: vec![asset_setup.asset_address],
+                asset_indices: vec![AssetIndex {
+                    instruction_index: 0,
+                    account_index: 0,
+                }],
+                authority_bumps: vec![
+                    sdk::asset_authority_pda(&group_setup.group, &asset_setup.asset_address).bump,
+                ],
+                timelock_offset: 0,
+                proposal_deadline_timestamp: 1000,
+                instruction_hashes,
+                sequential_execution: false,
+                balance_guards: vec![],
+                preconditions: vec![],
+                group_config_change: None,
+                compact_votes: false,
+                description: None,
+                options: option_hashes,
+            },
+            group_setup.group,
+            group_setup.payer.pubkey(),
+        );
+
+        Ok((
+            create,
+            MultiChoiceSetup {
+                group_setup,
+                asset_setup,
+                proposal_seed,
+                proposal,
+                destinations,
+                options,
+            },
+        ))
+    }
+
+    pub fn with_default(svm: &mut LiteSVM) -> Result<(Instruction, MultiChoiceSetup)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_single_option(svm: &mut LiteSVM) -> Result<(Instruction, MultiChoiceSetup)> {
+        Self::builder(svm, Scenario::SingleOption)
+    }
+
+    pub fn with_instruction_hashes(svm: &mut LiteSVM) -> Result<(Instruction, MultiChoiceSetup)> {
+        Self::builder(svm, Scenario::WithInstructionHashes)
+    }
+
+    /// Creates the proposal and the transaction of every option.
+    pub fn with_option_transactions(svm: &mut LiteSVM) -> Result<MultiChoiceSetup> {
+        let (create, setup) = Self::with_default(svm)?;
+        let payer = &setup.group_setup.payer;
+        assert_transaction_success(send(svm, payer, vec![create], &[]));
+        for option_index in 0..OPTION_COUNT as u8 {
+            let create_option = create_option_transaction_ix(&setup, option_index);
+            assert_transaction_success(send(svm, payer, vec![create_option], &[]));
+        }
+        Ok(setup)
+    }
+}
+
+#[test]
+fn test_create_multi_choice_proposal() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let setup = TestSetup::with_option_transactions(&mut svm).expect("setup");
+
+    let proposal = read_normal_proposal(&svm, setup.proposal).expect("proposal");
+    assert_eq!(proposal.options.len(), OPTION_COUNT);
+    assert!(proposal.instruction_hashes.is_empty());
+    assert_eq!(proposal.winning_option, None);
+
+    for option_index in 0..OPTION_COUNT as u8 {
+        let address = sdk::proposal_option_transaction_pda(&setup.proposal, option_index).address;
+        let account = svm.get_account(&address).expect("option transaction");
+        let transaction =
+            <ProposalTransaction as anchor_lang::AccountDeserialize>::try_deserialize(
+                &mut account.data.as_slice(),
+            )
+            .expect("proposal transaction");
+        assert_eq!(transaction.option_index, Some(option_index));
+        assert_eq!(transaction.proposal, setup.proposal);
+    }
+}
+
+#[test]
+fn test_create_multi_choice_proposal_fails_with_single_option() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let (create, setup) = TestSetup::with_single_option(&mut svm).expect("setup");
+
+    let result = send(&mut svm, &setup.group_setup.payer, vec![create], &[]);
+    assert_multisig_instruction_error(result, 0, MultisigError::InvalidProposalOptions);
+}
+
+#[test]
+fn test_create_multi_choice_proposal_fails_with_instruction_hashes() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let (create, setup) = TestSetup::with_instruction_hashes(&mut svm).expect("setup");
+
+    let result = send(&mut svm, &setup.group_setup.payer, vec![create], &[]);
+    assert_multisig_instruction_error(result, 0, MultisigError::InvalidProposalOptions);
+}
+
+#[test]
+fn test_single_choice_transaction_fails_for_multi_choice_proposal() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let (create, setup) = TestSetup::with_default(&mut svm).expect("setup");
+    let payer = &setup.group_setup.payer;
+    assert_transaction_success(send(&mut svm, payer, vec![create], &[]));
+
+    let create_tx = sdk::create_proposal_transaction(
+        CreateProposalTransactionInstructionArgs {
+            raw_instructions: sdk::serializable_instructions_bytes(&setup.options[..1])
+                .expect("serialize instructions"),
+        },
+        setup.group_setup.group,
+        setup.proposal_seed,
+        payer.pubkey(),
+        &[setup.asset_setup.asset_address],
+    );
+    let result = send(&mut svm, payer, vec![create_tx], &[]);
+    assert_multisig_instruction_error(result, 0, MultisigError::OptionTransactionRequired);
+}
+
+#[test]
+fn test_plurality_option_passes_and_only_it_executes() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let setup = TestSetup::with_option_transactions(&mut svm).expect("setup");
+    let payer = &setup.group_setup.payer;
+    let members = &setup.group_setup.members;
+
+    // Two options share the lead, so neither passes.
+    let votes = vec![
+        vote_ix(&setup, payer, Some(0)),
+        vote_ix(&setup, &members[0], Some(1)),
+    ];
+    assert_transaction_success(send(&mut svm, payer, votes, &[&members[0]]));
+    let proposal = read_normal_proposal(&svm, setup.proposal).expect("proposal");
+    assert!(proposal.state == ProposalState::Open);
+
+    let vote = vote_ix(&setup, &members[1], Some(0));
+    assert_transaction_success(send(&mut svm, payer, vec![vote], &[&members[1]]));
+    let proposal = read_normal_proposal(&svm, setup.proposal).expect("proposal");
+    assert!(proposal.state == ProposalState::Passed);
+    assert_eq!(proposal.winning_option, Some(0));
+    assert_eq!(proposal.options[0].vote_weight, 2);
+    assert_eq!(proposal.options[1].vote_weight, 1);
+
+    let result = send(&mut svm, payer, vec![execute_ix(&setup, 1)], &[]);
+    assert_instruction_error(
+        result,
+        0,
+        anchor_lang::error::ErrorCode::ConstraintSeeds as u32,
+    );
+
+    // The losing transactions can be closed straight away.
+    let losing_transaction = sdk::proposal_option_transaction_pda(&setup.proposal, 1).address;
+    let close = sdk::close_proposal_transaction(
+        setup.group_setup.group,
+        setup.proposal,
+        losing_transaction,
+        payer.pubkey(),
+    );
+    assert_transaction_success(send(&mut svm, payer, vec![close], &[]));
+    assert!(svm
+        .get_account(&losing_transaction)
+        .is_none_or(|account| account.data.is_empty()));
+
+    assert_transaction_success(send(&mut svm, payer, vec![execute_ix(&setup, 0)], &[]));
+    assert_eq!(token_amount(&svm, &setup.destinations[0]), 1);
+    assert_eq!(token_amount(&svm, &setup.destinations[1]), 0);
+}
+
+#[test]
+fn test_revote_moves_weight_to_other_option() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let setup = TestSetup::with_option_transactions(&mut svm).expect("setup");
+    let payer = &setup.group_setup.payer;
+    let members = &setup.group_setup.members;
+
+    let vote = vote_ix(&setup, payer, Some(0));
+    assert_transaction_success(send(&mut svm, payer, vec![vote], &[]));
+
+    let votes = vec![
+        vote_ix(&setup, payer, Some(2)),
+        vote_ix(&setup, &members[0], Some(2)),
+    ];
+    assert_transaction_success(send(&mut svm, payer, votes, &[&members[0]]));
+
+    let proposal = read_normal_proposal(&svm, setup.proposal).expect("proposal");
+    assert!(proposal.state == ProposalState::Passed);
+    assert_eq!(proposal.winning_option, Some(2));
+    assert_eq!(proposal.options[0].vote_weight, 0);
+    assert_eq!(proposal.options[2].vote_weight, 2);
+    assert_eq!(proposal.assets[0].vote_count, 2);
+}
+
+#[test]
+fn test_votes_against_every_option_fail_proposal() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let setup = TestSetup::with_option_transactions(&mut svm).expect("setup");
+    let payer = &setup.group_setup.payer;
+    let members = &setup.group_setup.members;
+
+    let votes = vec![
+        vote_ix(&setup, payer, Some(0)),
+        vote_ix(&setup, &members[0], None),
+        vote_ix(&setup, &members[1], None),
+    ];
+    assert_transaction_success(send(&mut svm, payer, votes, &[&members[0], &members[1]]));
+
+    let proposal = read_normal_proposal(&svm, setup.proposal).expect("proposal");
+    assert!(proposal.state == ProposalState::Failed);
+    assert_eq!(proposal.winning_option, None);
+}
+
+#[test]
+fn test_vote_for_option_fails_without_its_transaction() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let (create, setup) = TestSetup::with_default(&mut svm).expect("setup");
+    let payer = &setup.group_setup.payer;
+    assert_transaction_success(send(&mut svm, payer, vec![create], &[]));
+
+    let result = send(&mut svm, payer, vec![vote_ix(&setup, payer, Some(0))], &[]);
+    assert_instruction_error(
+        result,
+        0,
+        anchor_lang::error::ErrorCode::ConstraintOwner as u32,
+    );
+}
+
+#[test]
+fn test_against_vote_can_pass_leading_option() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let setup = TestSetup::with_option_transactions(&mut svm).expect("setup");
+    let payer = &setup.group_setup.payer;
+    let members = &setup.group_setup.members;
+
+    // Two options share the lead, so neither passes.
+    let votes = vec![
+        vote_ix(&setup, payer, Some(0)),
+        vote_ix(&setup, &members[0], Some(1)),
+    ];
+    assert_transaction_success(send(&mut svm, payer, votes, &[&members[0]]));
+
+    // Moving a vote against every option leaves option 0 leading with half the weight.
+    let vote = vote_ix(&setup, &members[0], None);
+    assert_transaction_success(send(&mut svm, payer, vec![vote], &[&members[0]]));
+
+    let proposal = read_normal_proposal(&svm, setup.proposal).expect("proposal");
+    assert!(proposal.state == ProposalState::Passed);
+    assert_eq!(proposal.winning_option, Some(0));
+    assert_eq!(proposal.options[1].vote_weight, 0);
+}
+
+#[test]
+fn test_delegate_votes_for_option_on_behalf_of_delegator() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let setup = TestSetup::with_option_transactions(&mut svm).expect("setup");
+    let payer = &setup.group_setup.payer;
+    let delegator = &setup.group_setup.members[0];
+    let group = setup.group_setup.group;
+    let asset_address = setup.asset_setup.asset_address;
+
+    let delegate_vote = sdk::delegate_vote(
+        DelegateVoteInstructionArgs {
+            delegate: payer.pubkey(),
+            asset: Some(asset_address),
+            expiry_timestamp: Some(1000),
+        },
+        group,
+        delegator.pubkey(),
+    );
+    assert_transaction_success(send(&mut svm, payer, vec![delegate_vote], &[delegator]));
+
+    let mut vote = vote_ix(&setup, payer, Some(1));
+    vote.accounts.extend(sdk::normal_delegated_vote_accounts(
+        &group,
+        &setup.proposal,
+        &asset_address,
+        0,
+        &[delegator.pubkey()],
+    ));
+    assert_transaction_success(send(&mut svm, payer, vec![vote], &[]));
+
+    let proposal = read_normal_proposal(&svm, setup.proposal).expect("proposal");
+    assert!(proposal.state == ProposalState::Passed);
+    assert_eq!(proposal.winning_option, Some(1));
+    assert_eq!(proposal.options[1].vote_weight, 2);
+    assert_eq!(proposal.assets[0].vote_count, 2);
+
+    let vote_record_account = svm
+        .get_account(
+            &sdk::normal_vote_record_pda(&group, &setup.proposal, &delegator.pubkey(), 0).address,
+        )
+        .expect("delegator vote record");
+    let vote_record = VoteRecord::try_deserialize(&mut vote_record_account.data.as_slice())
+        .expect("deserialize vote record");
+    assert_eq!(vote_record.option_index, Some(1));
+    assert_eq!(vote_record.delegate, Some(payer.pubkey()));
+}
@@ -0,0 +1,237 @@
+#![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::CreateConfigProposalInstructionArgs, ConfigChange, ConfigType, GroupMember,
+    MultisigError, ProposalState,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{
+    add_multisig_program, assert_multisig_instruction_error, assert_transaction_success,
+    read_group, send_tx, set_config_proposal_state, set_group_open_proposal_limits, setup_group,
+    GroupSetup,
+};
+
+// Create a config proposal once the group or its proposer may already have reached
+// their open proposal limit.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    MemberLimitReached,
+    GroupLimitReached,
+    ClosedBeforeLimit,
+    AppliedBeforeLimit,
+}
+
+fn create_config_proposal_ix(
+    group_setup: &GroupSetup,
+    proposer: Pubkey,
+    proposal_seed: Pubkey,
+) -> Instruction {
+    sdk::create_config_proposal(
+        CreateConfigProposalInstructionArgs {
+            proposal_seed,
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            config_change: ConfigChange::ChangeGroupConfig {
+                config_types: vec![ConfigType::MinimumVoteCount(3)],
+            },
+            compact_votes: false,
+            description: None,
+        },
+        group_setup.group,
+        proposer,
+        None,
+    )
+}
+
+fn open_proposal_count(svm: &LiteSVM, group: &Pubkey, member: &Pubkey) -> u32 {
+    let account = svm
+        .get_account(&sdk::group_member_pda(group, member).address)
+        .expect("group member account");
+    GroupMember::try_deserialize(&mut account.data.as_slice())
+        .expect("group member data")
+        .open_proposal_count
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, GroupSetup)> {
+        let group_setup = setup_group(svm)?;
+
+        match scenario {
+            Scenario::Default => {}
+            Scenario::MemberLimitReached | Scenario::ClosedBeforeLimit => {
+                set_group_open_proposal_limits(svm, group_setup.group, 0, 1)?;
+            }
+            Scenario::GroupLimitReached | Scenario::AppliedBeforeLimit => {
+                set_group_open_proposal_limits(svm, group_setup.group, 1, 0)?;
+            }
+        }
+
+        if !matches!(scenario, Scenario::Default) {
+            let proposal_seed = Pubkey::new_unique();
+            let proposal = sdk::proposal_pda(&group_setup.group, &proposal_seed).address;
+            let create =
+                create_config_proposal_ix(&group_setup, group_setup.payer.pubkey(), proposal_seed);
+            send_tx(svm, &group_setup.payer, vec![create], &[])?;
+
+            match scenario {
+                Scenario::ClosedBeforeLimit => {
+                    set_config_proposal_state(svm, proposal, ProposalState::Failed, None)?;
+                    let close = sdk::close_config_proposal(
+                        group_setup.group,
+                        proposal,
+                        group_setup.payer.pubkey(),
+                    );
+                    send_tx(svm, &group_setup.payer, vec![close], &[])?;
+                }
+                Scenario::AppliedBeforeLimit => {
+                    set_config_proposal_state(svm, proposal, ProposalState::Passed, Some(0))?;
+                    let apply = sdk::change_group_config(
+                        group_setup.group,
+                        proposal,
+                        group_setup.payer.pubkey(),
+                    );
+                    send_tx(svm, &group_setup.payer, vec![apply], &[])?;
+                }
+                _ => {}
+            }
+        }
+
+        // The group limit applies to every member, the member limit only to the first proposer.
+        let proposer = match scenario {
+            Scenario::GroupLimitReached => group_setup.members[0].insecure_clone(),
+            _ => group_setup.payer.insecure_clone(),
+        };
+        let create =
+            create_config_proposal_ix(&group_setup, proposer.pubkey(), Pubkey::new_unique());
+
+        Ok(([create], vec![proposer], group_setup))
+    }
+
+    pub fn with_default(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>, GroupSetup)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_member_limit_reached(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, GroupSetup)> {
+        Self::builder(svm, Scenario::MemberLimitReached)
+    }
+
+    pub fn with_group_limit_reached(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, GroupSetup)> {
+        Self::builder(svm, Scenario::GroupLimitReached)
+    }
+
+    pub fn with_closed_before_limit(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, GroupSetup)> {
+        Self::builder(svm, Scenario::ClosedBeforeLimit)
+    }
+
+    pub fn with_applied_before_limit(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, GroupSetup)> {
+        Self::builder(svm, Scenario::AppliedBeforeLimit)
+    }
+}
+
+fn send(
+    svm: &mut LiteSVM,
+    instructions: &[Instruction],
+    signers: &[Keypair],
+) -> litesvm::types::TransactionResult {
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(instructions, Some(&payer), signers, recent_blockhash);
+    svm.send_transaction(transaction)
+}
+
+#[test]
+fn test_create_config_proposal_counts_open_proposal() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, group_setup) =
+        TestSetup::with_default(&mut svm).expect("Failed to create instruction");
+
+    assert_transaction_success(send(&mut svm, &instructions, &signers));
+
+    let group = read_group(&svm, group_setup.group).expect("group");
+    assert_eq!(group.open_proposal_count, 1);
+    assert_eq!(
+        open_proposal_count(&svm, &group_setup.group, &group_setup.payer.pubkey()),
+        1
+    );
+}
+
+#[test]
+fn test_create_config_proposal_fails_over_member_limit() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, _) =
+        TestSetup::with_member_limit_reached(&mut svm).expect("Failed to create instruction");
+
+    let result = send(&mut svm, &instructions, &signers);
+    assert_multisig_instruction_error(result, 0, MultisigError::TooManyOpenProposals);
+}
+
+#[test]
+fn test_create_config_proposal_fails_over_group_limit() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, _) =
+        TestSetup::with_group_limit_reached(&mut svm).expect("Failed to create instruction");
+
+    let result = send(&mut svm, &instructions, &signers);
+    assert_multisig_instruction_error(result, 0, MultisigError::TooManyOpenProposals);
+}
+
+#[test]
+fn test_close_config_proposal_releases_open_proposal() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, group_setup) =
+        TestSetup::with_closed_before_limit(&mut svm).expect("Failed to create instruction");
+
+    let group = read_group(&svm, group_setup.group).expect("group");
+    assert_eq!(group.open_proposal_count, 0);
+    assert_eq!(
+        open_proposal_count(&svm, &group_setup.group, &group_setup.payer.pubkey()),
+        0
+    );
+
+    assert_transaction_success(send(&mut svm, &instructions, &signers));
+}
+
+#[test]
+fn test_change_group_config_releases_open_proposal() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, group_setup) =
+        TestSetup::with_applied_before_limit(&mut svm).expect("Failed to create instruction");
+
+    let group = read_group(&svm, group_setup.group).expect("group");
+    assert_eq!(group.open_proposal_count, 0);
+    assert_eq!(group.minimum_vote_count, 3);
+
+    assert_transaction_success(send(&mut svm, &instructions, &signers));
+}
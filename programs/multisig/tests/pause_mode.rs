@@ -47,6 +47,10 @@ fn test_create_normal_proposal_fails_when_paused() {
             timelock_offset: 0,
             proposal_deadline_timestamp: i64::MAX,
             instruction_hashes: vec![[0u8; 32]],
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
             asset_keys: vec![asset_setup.mint],
             authority_bumps: vec![
                 sdk::asset_authority_pda(&group_setup.group, &asset_setup.mint).bump,
@@ -55,6 +59,9 @@ fn test_create_normal_proposal_fails_when_paused() {
                 instruction_index: 0,
                 account_index: 0,
             }],
+            compact_votes: false,
+            description: None,
+            options: vec![],
         },
         group_setup.group,
         group_setup.payer.pubkey(),
@@ -84,8 +91,10 @@ fn test_create_config_proposal_fails_when_paused() {
             timelock_offset: 0,
             proposal_deadline_timestamp: i64::MAX,
             config_change: ConfigChange::ChangeGroupConfig {
-                config_type: ConfigType::MinimumVoteCount(1),
+                config_types: vec![ConfigType::MinimumVoteCount(1)],
             },
+            compact_votes: false,
+            description: None,
         },
         group_setup.group,
         group_setup.payer.pubkey(),
@@ -121,7 +130,10 @@ fn test_add_group_member_fails_when_paused() {
                 member: new_member,
                 weight: 1,
                 permissions: permissions(),
+                expires_at: None,
             },
+            compact_votes: false,
+            description: None,
         },
         group_setup.group,
         group_setup.payer.pubkey(),
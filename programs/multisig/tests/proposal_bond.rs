@@ -0,0 +1,520 @@
+#![cfg(feature = "test-helpers")]
+use anchor_lang::{AccountDeserialize, Space};
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::{
+        CreateConfigProposalInstructionArgs, VoteOnConfigProposalInstructionArgs,
+        VoteOnMultiAssetConfigProposalInstructionArgs,
+    },
+    ConfigChange, ConfigProposal, ConfigType, MultisigError, ProposalBond, ProposalBondConfig,
+    ProposalState, VoteChoice,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, program_option::COption, pubkey::Pubkey, signature::Keypair,
+    signer::Signer, transaction::Transaction,
+};
+use spl_token::{
+    solana_program::program_pack::Pack,
+    state::{Account as TokenAccount, AccountState},
+};
+
+mod common;
+use common::{
+    add_multisig_program, assert_multisig_instruction_error, assert_transaction_success,
+    create_mint, create_token_account, send_tx, set_config_proposal_state, set_group_proposal_bond,
+    setup_asset_mint, setup_group, threshold, GroupSetup,
+};
+
+const BOND_AMOUNT: u64 = 1_000_000;
+
+// Release the bond of a settled config proposal, the group's rent collector is its payer.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    OverwhelminglyRejected,
+    Cancelled,
+    StillOpen,
+}
+
+struct BondedProposal {
+    group_setup: GroupSetup,
+    depositor: Keypair,
+    proposal: Pubkey,
+}
+
+fn bond_config(mint: Option<Pubkey>) -> ProposalBondConfig {
+    ProposalBondConfig {
+        mint,
+        amount: BOND_AMOUNT,
+        forfeit_threshold: threshold(3, 4),
+    }
+}
+
+fn create_config_proposal_ix(group_setup: &GroupSetup, proposal_seed: Pubkey) -> Instruction {
+    sdk::create_config_proposal(
+        CreateConfigProposalInstructionArgs {
+            proposal_seed,
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            config_change: ConfigChange::ChangeGroupConfig {
+                config_types: vec![ConfigType::MinimumVoteCount(3)],
+            },
+            compact_votes: false,
+            description: None,
+        },
+        group_setup.group,
+        group_setup.payer.pubkey(),
+        None,
+    )
+}
+
+impl TestSetup {
+    /// Creates a config proposal whose bond is paid by a depositor other than the proposer.
+    fn build_bonded_proposal(
+        svm: &mut LiteSVM,
+        token_bond: Option<sdk::TokenBondAccounts>,
+        depositor: Keypair,
+    ) -> Result<BondedProposal> {
+        let group_setup = setup_group(svm)?;
+        set_group_proposal_bond(
+            svm,
+            group_setup.group,
+            Some(bond_config(token_bond.map(|token_bond| token_bond.mint))),
+        )?;
+        svm.airdrop(&depositor.pubkey(), 1_000_000_000)
+            .expect("Could not airdrop to depositor");
+
+        let proposal_seed = Pubkey::new_unique();
+        let proposal = sdk::proposal_pda(&group_setup.group, &proposal_seed).address;
+        let create_config_proposal = sdk::with_proposal_bond(
+            sdk::with_rent_payer(
+                create_config_proposal_ix(&group_setup, proposal_seed),
+                depositor.pubkey(),
+            ),
+            proposal,
+            token_bond,
+        );
+        send_tx(
+            svm,
+            &group_setup.payer,
+            vec![create_config_proposal],
+            &[&depositor],
+        )?;
+
+        Ok(BondedProposal {
+            group_setup,
+            depositor,
+            proposal,
+        })
+    }
+
+    fn vote(
+        svm: &mut LiteSVM,
+        group_setup: &GroupSetup,
+        proposal: Pubkey,
+        voter: &Keypair,
+        vote: VoteChoice,
+    ) -> Result<()> {
+        let vote = sdk::vote_on_config_proposal(
+            VoteOnConfigProposalInstructionArgs { vote },
+            group_setup.group,
+            proposal,
+            voter.pubkey(),
+            None,
+        );
+        send_tx(svm, voter, vec![vote], &[])
+    }
+
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, BondedProposal)> {
+        let bonded = Self::build_bonded_proposal(svm, None, Keypair::new())?;
+        let group_setup = &bonded.group_setup;
+        let proposal = bonded.proposal;
+
+        match scenario {
+            // Two thirds against fails the proposal, short of the forfeit threshold.
+            Scenario::Default => {
+                Self::vote(
+                    svm,
+                    group_setup,
+                    proposal,
+                    &group_setup.payer,
+                    VoteChoice::For,
+                )?;
+                for voter in group_setup.members[..2].iter() {
+                    Self::vote(svm, group_setup, proposal, voter, VoteChoice::Against)?;
+                }
+            }
+            Scenario::OverwhelminglyRejected => {
+                for voter in group_setup.members[..2].iter() {
+                    Self::vote(svm, group_setup, proposal, voter, VoteChoice::Against)?;
+                }
+            }
+            Scenario::Cancelled => {
+                let cancel = sdk::cancel_config_proposal(
+                    group_setup.group,
+                    proposal,
+                    group_setup.members[0].pubkey(),
+                );
+                send_tx(svm, &group_setup.members[0], vec![cancel], &[])?;
+            }
+            Scenario::StillOpen => {}
+        }
+
+        let release = sdk::release_proposal_bond(
+            group_setup.group,
+            proposal,
+            bonded.depositor.pubkey(),
+            group_setup.payer.pubkey(),
+            None,
+        );
+
+        // Neither the depositor nor the rent collector pays the fee, so their balances
+        // only move by the bond and its rent.
+        let fee_payer = group_setup.members[2].insecure_clone();
+        Ok(([release], vec![fee_payer], bonded))
+    }
+
+    pub fn with_default(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, BondedProposal)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_overwhelmingly_rejected(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, BondedProposal)> {
+        Self::builder(svm, Scenario::OverwhelminglyRejected)
+    }
+
+    pub fn with_cancelled(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, BondedProposal)> {
+        Self::builder(svm, Scenario::Cancelled)
+    }
+
+    pub fn with_still_open(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, BondedProposal)> {
+        Self::builder(svm, Scenario::StillOpen)
+    }
+}
+
+fn lamports(svm: &LiteSVM, key: &Pubkey) -> u64 {
+    svm.get_account(key).map_or(0, |account| account.lamports)
+}
+
+fn bond_rent(svm: &LiteSVM) -> u64 {
+    svm.minimum_balance_for_rent_exemption(8 + ProposalBond::INIT_SPACE)
+}
+
+fn send(
+    svm: &mut LiteSVM,
+    instructions: &[Instruction],
+    signers: &[Keypair],
+) -> litesvm::types::TransactionResult {
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(instructions, Some(&payer), signers, recent_blockhash);
+    svm.send_transaction(transaction)
+}
+
+#[test]
+fn test_create_config_proposal_escrows_bond() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let bonded =
+        TestSetup::build_bonded_proposal(&mut svm, None, Keypair::new()).expect("proposal");
+
+    let bond_address = sdk::proposal_bond_pda(&bonded.proposal).address;
+    assert_eq!(lamports(&svm, &bond_address), bond_rent(&svm) + BOND_AMOUNT);
+    let bond_account = svm.get_account(&bond_address).expect("bond");
+    let bond = ProposalBond::try_deserialize(&mut bond_account.data.as_slice()).expect("bond data");
+    assert_eq!(bond.depositor, bonded.depositor.pubkey());
+    assert_eq!(bond.amount, BOND_AMOUNT);
+
+    let proposal_account = svm.get_account(&bonded.proposal).expect("proposal");
+    let proposal = ConfigProposal::try_deserialize(&mut proposal_account.data.as_slice())
+        .expect("proposal data");
+    assert!(proposal.bonded);
+}
+
+#[test]
+fn test_create_config_proposal_fails_without_bond() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let group_setup = setup_group(&mut svm).expect("group");
+    set_group_proposal_bond(&mut svm, group_setup.group, Some(bond_config(None)))
+        .expect("bond config");
+
+    let create_config_proposal = create_config_proposal_ix(&group_setup, Pubkey::new_unique());
+    let result = send(
+        &mut svm,
+        &[create_config_proposal],
+        &[group_setup.payer.insecure_clone()],
+    );
+    assert_multisig_instruction_error(result, 0, MultisigError::ProposalBondNotProvided);
+}
+
+#[test]
+fn test_close_config_proposal_fails_while_bonded() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let bonded =
+        TestSetup::build_bonded_proposal(&mut svm, None, Keypair::new()).expect("proposal");
+    let group_setup = &bonded.group_setup;
+    set_config_proposal_state(&mut svm, bonded.proposal, ProposalState::Failed, None)
+        .expect("failed");
+
+    let close = sdk::close_config_proposal(
+        group_setup.group,
+        bonded.proposal,
+        group_setup.payer.pubkey(),
+    );
+    let result = send(&mut svm, &[close], &[group_setup.payer.insecure_clone()]);
+    assert_multisig_instruction_error(result, 0, MultisigError::ProposalBondNotReleased);
+}
+
+#[test]
+fn test_release_proposal_bond_refunds_normal_failure() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, bonded) =
+        TestSetup::with_default(&mut svm).expect("Failed to create instruction");
+    let group_setup = &bonded.group_setup;
+    let depositor = bonded.depositor.pubkey();
+    let rent_collector = group_setup.payer.pubkey();
+    let depositor_before = lamports(&svm, &depositor);
+    let rent_collector_before = lamports(&svm, &rent_collector);
+
+    assert_transaction_success(send(&mut svm, &instructions, &signers));
+
+    assert_eq!(
+        lamports(&svm, &depositor),
+        depositor_before + bond_rent(&svm) + BOND_AMOUNT
+    );
+    assert_eq!(lamports(&svm, &rent_collector), rent_collector_before);
+    assert_eq!(
+        lamports(&svm, &sdk::proposal_bond_pda(&bonded.proposal).address),
+        0
+    );
+
+    // The proposal can be closed once its bond is released.
+    let close = sdk::close_config_proposal(group_setup.group, bonded.proposal, rent_collector);
+    assert_transaction_success(send(&mut svm, &[close], &signers));
+}
+
+#[test]
+fn test_release_proposal_bond_forfeits_overwhelming_rejection() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, bonded) =
+        TestSetup::with_overwhelmingly_rejected(&mut svm).expect("Failed to create instruction");
+    let depositor = bonded.depositor.pubkey();
+    let rent_collector = bonded.group_setup.payer.pubkey();
+    let depositor_before = lamports(&svm, &depositor);
+    let rent_collector_before = lamports(&svm, &rent_collector);
+
+    assert_transaction_success(send(&mut svm, &instructions, &signers));
+
+    assert_eq!(
+        lamports(&svm, &depositor),
+        depositor_before + bond_rent(&svm)
+    );
+    assert_eq!(
+        lamports(&svm, &rent_collector),
+        rent_collector_before + BOND_AMOUNT
+    );
+}
+
+#[test]
+fn test_release_proposal_bond_forfeits_cancelled_proposal() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, bonded) =
+        TestSetup::with_cancelled(&mut svm).expect("Failed to create instruction");
+    let rent_collector = bonded.group_setup.payer.pubkey();
+    let rent_collector_before = lamports(&svm, &rent_collector);
+
+    assert_transaction_success(send(&mut svm, &instructions, &signers));
+
+    assert_eq!(
+        lamports(&svm, &rent_collector),
+        rent_collector_before + BOND_AMOUNT
+    );
+}
+
+#[test]
+fn test_release_proposal_bond_fails_while_proposal_is_open() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, _) =
+        TestSetup::with_still_open(&mut svm).expect("Failed to create instruction");
+
+    let result = send(&mut svm, &instructions, &signers);
+    assert_multisig_instruction_error(result, 0, MultisigError::ProposalStillActive);
+}
+
+#[test]
+fn test_token_bond_is_escrowed_and_refunded() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let depositor = Keypair::new();
+    let mint = create_mint(&mut svm, COption::None, COption::None, true)
+        .expect("mint")
+        .pubkey();
+    let depositor_token_account = create_token_account(
+        &mut svm,
+        &mint,
+        &depositor.pubkey(),
+        COption::None,
+        AccountState::Initialized,
+        COption::None,
+    )
+    .expect("token account");
+    let token_bond = sdk::TokenBondAccounts {
+        mint,
+        token_account: depositor_token_account,
+        token_program: spl_token::ID,
+    };
+
+    let bonded =
+        TestSetup::build_bonded_proposal(&mut svm, Some(token_bond), depositor).expect("proposal");
+    let group_setup = &bonded.group_setup;
+
+    let token_amount = |svm: &LiteSVM, key: &Pubkey| {
+        let account = svm.get_account(key).expect("token account");
+        TokenAccount::unpack(&account.data)
+            .expect("token data")
+            .amount
+    };
+    let escrow = sdk::bond_escrow_pda(&bonded.proposal).address;
+    let depositor_tokens = token_amount(&svm, &depositor_token_account);
+    assert_eq!(token_amount(&svm, &escrow), BOND_AMOUNT);
+
+    set_config_proposal_state(&mut svm, bonded.proposal, ProposalState::Failed, None)
+        .expect("failed");
+
+    let release = sdk::release_proposal_bond(
+        group_setup.group,
+        bonded.proposal,
+        bonded.depositor.pubkey(),
+        group_setup.payer.pubkey(),
+        Some(token_bond),
+    );
+    let fee_payer = group_setup.members[2].insecure_clone();
+    assert_transaction_success(send(&mut svm, &[release], &[fee_payer]));
+
+    assert_eq!(
+        token_amount(&svm, &depositor_token_account),
+        depositor_tokens + BOND_AMOUNT
+    );
+    assert_eq!(lamports(&svm, &escrow), 0);
+}
+
+#[test]
+fn test_release_proposal_bond_waits_for_reindexed_multi_asset_proposal() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let group_setup = setup_group(&mut svm).expect("group");
+    let group = group_setup.group;
+    let payer = &group_setup.payer;
+    let voter = &group_setup.members[0];
+    set_group_proposal_bond(&mut svm, group, Some(bond_config(None))).expect("bond config");
+    let mut asset_addresses = vec![
+        setup_asset_mint(&mut svm, &group_setup)
+            .expect("asset")
+            .asset_address,
+        setup_asset_mint(&mut svm, &group_setup)
+            .expect("asset")
+            .asset_address,
+    ];
+    asset_addresses.sort();
+
+    let proposal_seed = Pubkey::new_unique();
+    let proposal = sdk::proposal_pda(&group, &proposal_seed).address;
+    let create_config_proposal = sdk::with_proposal_bond(
+        sdk::create_multi_asset_config_proposal(
+            CreateConfigProposalInstructionArgs {
+                proposal_seed,
+                timelock_offset: 0,
+                proposal_deadline_timestamp: 1000,
+                config_change: ConfigChange::ChangeAssetConfig {
+                    config_types: vec![ConfigType::MinimumVoteCount(3)],
+                },
+                compact_votes: false,
+                description: None,
+            },
+            group,
+            payer.pubkey(),
+            &asset_addresses,
+        ),
+        proposal,
+        None,
+    );
+    send_tx(&mut svm, payer, vec![create_config_proposal], &[]).expect("create proposal");
+
+    for (index, asset_address) in asset_addresses.iter().enumerate() {
+        for (voter_key, signers) in [(payer.pubkey(), vec![]), (voter.pubkey(), vec![voter])] {
+            let vote = sdk::vote_on_multi_asset_config_proposal(
+                VoteOnMultiAssetConfigProposalInstructionArgs {
+                    voting_asset_index: index as u8,
+                    vote: VoteChoice::For,
+                },
+                group,
+                proposal,
+                *asset_address,
+                voter_key,
+            );
+            send_tx(&mut svm, payer, vec![vote], &signers).expect("vote");
+        }
+    }
+
+    // Applying the first asset stales the group, the proposal stays open under a new index.
+    let change_asset_config =
+        sdk::change_asset_config(group, asset_addresses[0], proposal, payer.pubkey());
+    send_tx(&mut svm, payer, vec![change_asset_config], &[]).expect("apply first asset");
+
+    let read_proposal = |svm: &LiteSVM| {
+        let account = svm.get_account(&proposal).expect("proposal");
+        ConfigProposal::try_deserialize(&mut account.data.as_slice()).expect("proposal data")
+    };
+    let reindexed = read_proposal(&svm);
+    assert!(reindexed.state == ProposalState::Open);
+    assert_ne!(reindexed.proposal_index, reindexed.created_proposal_index);
+    assert!(reindexed.bonded);
+
+    let release = sdk::release_proposal_bond(group, proposal, payer.pubkey(), payer.pubkey(), None);
+    let fee_payer = group_setup.members[2].insecure_clone();
+    let result = send(&mut svm, &[release.clone()], &[fee_payer.insecure_clone()]);
+    assert_multisig_instruction_error(result, 0, MultisigError::ProposalStillActive);
+    assert!(read_proposal(&svm).bonded);
+
+    // The last asset applying the change closes the proposal, its bond is then refunded.
+    let change_asset_config =
+        sdk::change_asset_config(group, asset_addresses[1], proposal, payer.pubkey());
+    send_tx(&mut svm, payer, vec![change_asset_config], &[]).expect("apply second asset");
+
+    let depositor_before = lamports(&svm, &payer.pubkey());
+    svm.expire_blockhash();
+    assert_transaction_success(send(&mut svm, &[release], &[fee_payer]));
+    assert_eq!(
+        lamports(&svm, &payer.pubkey()),
+        depositor_before + bond_rent(&svm) + BOND_AMOUNT
+    );
+}
@@ -47,7 +47,10 @@ impl TestSetup {
             member_key_2: group_setup.member_keys[1],
             member_key_3: group_setup.member_keys[2],
             initial_weights: [1, 1, 1],
-            initial_permissions: [multisig::Permissions::from_flags(true, true); 3],
+            initial_permissions: [multisig::Permissions::builder()
+                .propose(true)
+                .add_asset(true)
+                .build(); 3],
             use_threshold: threshold(1, 2),
             not_use_threshold: threshold(2, 3),
             add_threshold: threshold(1, 2),
@@ -83,6 +86,8 @@ impl TestSetup {
                 member: target_member,
                 asset_address: mint,
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
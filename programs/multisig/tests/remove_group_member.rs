@@ -39,6 +39,8 @@ impl TestSetup {
             config_change: ConfigChange::RemoveGroupMember {
                 member: target_member,
             },
+            compact_votes: false,
+            description: None,
         };
         send_tx(
             svm,
@@ -0,0 +1,146 @@
+#![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::CreateConfigProposalInstructionArgs, AssetMember, ConfigChange, MultisigError,
+    ProposalState,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{
+    add_multisig_program, assert_multisig_instruction_error, send_tx, set_asset_member_expires_at,
+    set_config_proposal_state, setup_asset_mint, setup_group,
+};
+
+// Renew asset member term requires a passed proposal naming the asset member.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    WrongMember,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey, Pubkey, Pubkey)> {
+        let group_setup = setup_group(svm)?;
+        let asset_setup = setup_asset_mint(svm, &group_setup)?;
+        let [_, member, other, _, _] = group_setup.member_keys;
+
+        // The member's term has already ended, they can still be renewed until closed.
+        set_asset_member_expires_at(
+            svm,
+            group_setup.group,
+            asset_setup.asset_address,
+            member,
+            Some(0),
+        )?;
+
+        let proposal_seed = Pubkey::new_unique();
+        let proposal_pda = sdk::proposal_pda(&group_setup.group, &proposal_seed);
+
+        let create_args = CreateConfigProposalInstructionArgs {
+            proposal_seed,
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            config_change: ConfigChange::RenewAssetMemberTerm {
+                member,
+                asset_address: asset_setup.asset_address,
+                expires_at: Some(2000),
+            },
+            compact_votes: false,
+            description: None,
+        };
+        let create_config_proposal = sdk::create_config_proposal(
+            create_args,
+            group_setup.group,
+            group_setup.payer.pubkey(),
+            Some(asset_setup.asset_address),
+        );
+        send_tx(svm, &group_setup.payer, vec![create_config_proposal], &[])?;
+        set_config_proposal_state(svm, proposal_pda.address, ProposalState::Passed, Some(0))?;
+
+        let renewed = match scenario {
+            Scenario::WrongMember => other,
+            Scenario::Default => member,
+        };
+        let renew = sdk::renew_asset_member_term(
+            group_setup.group,
+            asset_setup.asset_address,
+            renewed,
+            proposal_pda.address,
+            group_setup.payer.pubkey(),
+        );
+
+        Ok((
+            [renew],
+            vec![group_setup.payer],
+            group_setup.group,
+            asset_setup.asset_address,
+            member,
+        ))
+    }
+
+    pub fn with_default(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey, Pubkey, Pubkey)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_wrong_member(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _, _, _) = Self::builder(svm, Scenario::WrongMember)?;
+        Ok((ix, signers))
+    }
+}
+
+#[test]
+fn test_renew_asset_member_term_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, group, asset_address, member) =
+        TestSetup::with_default(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(transaction));
+
+    let member_account = svm
+        .get_account(&sdk::asset_member_pda(&group, &asset_address, &member).address)
+        .expect("asset member");
+    let member = AssetMember::try_deserialize(&mut member_account.data.as_slice())
+        .expect("deserialize asset member");
+    assert_eq!(member.expires_at, Some(2000));
+    assert!(member.is_active(0));
+}
+
+#[test]
+fn test_renew_asset_member_term_fails_with_wrong_member() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_wrong_member(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::InvalidMember,
+    );
+}
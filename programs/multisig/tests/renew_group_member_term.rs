@@ -0,0 +1,136 @@
+#![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::CreateConfigProposalInstructionArgs, ConfigChange, GroupMember, MultisigError,
+    ProposalState,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{
+    add_multisig_program, assert_multisig_instruction_error, send_tx, set_config_proposal_state,
+    set_group_member_expires_at, setup_group,
+};
+
+// Renew group member term requires a passed proposal naming the member.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    WrongMember,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey, Pubkey)> {
+        let group_setup = setup_group(svm)?;
+        let [member, other, _, _] = &group_setup.members;
+
+        // The member's term has already ended, they can still be renewed until closed.
+        set_group_member_expires_at(svm, group_setup.group, member.pubkey(), Some(0))?;
+
+        let proposal_seed = Pubkey::new_unique();
+        let proposal_pda = sdk::proposal_pda(&group_setup.group, &proposal_seed);
+
+        let create_args = CreateConfigProposalInstructionArgs {
+            proposal_seed,
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            config_change: ConfigChange::RenewGroupMemberTerm {
+                member: member.pubkey(),
+                expires_at: Some(2000),
+            },
+            compact_votes: false,
+            description: None,
+        };
+        let create_config_proposal = sdk::create_config_proposal(
+            create_args,
+            group_setup.group,
+            group_setup.payer.pubkey(),
+            None,
+        );
+        send_tx(svm, &group_setup.payer, vec![create_config_proposal], &[])?;
+        set_config_proposal_state(svm, proposal_pda.address, ProposalState::Passed, Some(0))?;
+
+        let renewed = match scenario {
+            Scenario::WrongMember => other.pubkey(),
+            Scenario::Default => member.pubkey(),
+        };
+        let renew = sdk::renew_group_member_term(
+            group_setup.group,
+            renewed,
+            proposal_pda.address,
+            group_setup.payer.pubkey(),
+        );
+
+        Ok((
+            [renew],
+            vec![group_setup.payer],
+            group_setup.group,
+            member.pubkey(),
+        ))
+    }
+
+    pub fn with_default(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey, Pubkey)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_wrong_member(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _, _) = Self::builder(svm, Scenario::WrongMember)?;
+        Ok((ix, signers))
+    }
+}
+
+#[test]
+fn test_renew_group_member_term_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, group, member) =
+        TestSetup::with_default(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(transaction));
+
+    let member_account = svm
+        .get_account(&sdk::group_member_pda(&group, &member).address)
+        .expect("group member");
+    let member = GroupMember::try_deserialize(&mut member_account.data.as_slice())
+        .expect("deserialize group member");
+    assert_eq!(member.expires_at, Some(2000));
+    assert!(member.is_active(0));
+}
+
+#[test]
+fn test_renew_group_member_term_fails_with_wrong_member() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_wrong_member(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::InvalidMember,
+    );
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "test-helpers")]
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::instructions::DelegateVoteInstructionArgs;
+use multisig_sdk as sdk;
+use solana_sdk::{instruction::Instruction, signer::Signer, transaction::Transaction};
+
+mod common;
+use common::{add_multisig_program, send_tx, setup_group};
+
+// Revoke delegation: only the delegator can close their delegation.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    NotDelegator,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        let group_setup = setup_group(svm)?;
+        let [delegator, other_member, _, _] = group_setup.members;
+
+        let delegate_args = DelegateVoteInstructionArgs {
+            delegate: group_setup.payer.pubkey(),
+            asset: None,
+            expiry_timestamp: None,
+        };
+        let delegate_vote =
+            sdk::delegate_vote(delegate_args, group_setup.group, delegator.pubkey());
+        send_tx(svm, &group_setup.payer, vec![delegate_vote], &[&delegator])?;
+
+        let signer = match scenario {
+            Scenario::NotDelegator => other_member,
+            Scenario::Default => delegator,
+        };
+
+        // A different signer derives a delegation address that was never created.
+        let revoke = sdk::revoke_delegation(group_setup.group, signer.pubkey(), None);
+
+        Ok(([revoke], vec![group_setup.payer, signer]))
+    }
+
+    pub fn with_default(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_not_delegator(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::NotDelegator)
+    }
+}
+
+#[test]
+fn test_revoke_delegation_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_default(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let delegation = instructions[0].accounts[1].pubkey;
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    assert!(svm
+        .get_account(&delegation)
+        .is_none_or(|account| account.lamports == 0));
+}
+
+#[test]
+fn test_revoke_delegation_fails_for_other_member() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_not_delegator(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_instruction_error(
+        result,
+        0,
+        u32::from(anchor_lang::error::ErrorCode::AccountNotInitialized),
+    );
+}
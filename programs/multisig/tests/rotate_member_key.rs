@@ -0,0 +1,330 @@
+#![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::{
+        CreateNormalProposalInstructionArgs, CreateProposalTransactionInstructionArgs,
+        VoteOnNormalProposalInstructionArgs,
+    },
+    AssetIndex, AssetMember, GroupMember, VoteChoice,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{
+    add_multisig_program, read_group, send_tx, setup_asset_mint, setup_group, to_serializable,
+};
+
+// Rotate member key: the member and their asset memberships move to a new key.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    MissingAssetMember,
+    OpenProposal,
+}
+
+struct Rotation {
+    group: Pubkey,
+    asset_address: Pubkey,
+    old_key: Pubkey,
+    new_key: Pubkey,
+    /// Proposal the old key voted on before the rotation.
+    proposal: Option<Pubkey>,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Rotation)> {
+        let group_setup = setup_group(svm)?;
+        let asset_setup = setup_asset_mint(svm, &group_setup)?;
+
+        // The first non-payer member is also a member of the asset.
+        let [old_key, _, _, _] = group_setup.members;
+        let new_key = Keypair::new();
+        svm.airdrop(&new_key.pubkey(), 1_000_000_000)
+            .map_err(|error| anyhow::anyhow!("airdrop failed: {:?}", error))?;
+
+        // The old key votes on an open proposal before it is rotated out.
+        let proposal = match scenario {
+            Scenario::OpenProposal => {
+                svm.airdrop(&old_key.pubkey(), 1_000_000_000)
+                    .map_err(|error| anyhow::anyhow!("airdrop failed: {:?}", error))?;
+
+                let mint_to_ix = spl_token::instruction::mint_to(
+                    &spl_token::ID,
+                    &asset_setup.mint,
+                    &asset_setup.asset_authority,
+                    &asset_setup.asset_authority,
+                    &[],
+                    1,
+                )?;
+                let serializable = to_serializable(&mint_to_ix);
+                let proposal_seed = Pubkey::new_unique();
+                let create_args = CreateNormalProposalInstructionArgs {
+                    proposal_seed,
+                    asset_keys: vec![asset_setup.mint],
+                    asset_indices: vec![AssetIndex {
+                        instruction_index: 0,
+                        account_index: 0,
+                    }],
+                    authority_bumps: vec![
+                        sdk::asset_authority_pda(&group_setup.group, &asset_setup.mint).bump,
+                    ],
+                    timelock_offset: 0,
+                    proposal_deadline_timestamp: 1000,
+                    instruction_hashes: vec![sdk::serializable_instruction_hash(&serializable)?],
+                    sequential_execution: false,
+                    balance_guards: vec![],
+                    preconditions: vec![],
+                    group_config_change: None,
+                    compact_votes: false,
+                    description: None,
+                    options: vec![],
+                };
+                let create_proposal = sdk::create_normal_proposal(
+                    create_args,
+                    group_setup.group,
+                    group_setup.payer.pubkey(),
+                );
+                let create_proposal_tx = sdk::create_proposal_transaction(
+                    CreateProposalTransactionInstructionArgs {
+                        raw_instructions: vec![sdk::serializable_instruction_bytes(&serializable)?],
+                    },
+                    group_setup.group,
+                    proposal_seed,
+                    group_setup.payer.pubkey(),
+                    &[asset_setup.mint],
+                );
+                send_tx(
+                    svm,
+                    &group_setup.payer,
+                    vec![create_proposal, create_proposal_tx],
+                    &[],
+                )?;
+
+                let proposal = sdk::proposal_pda(&group_setup.group, &proposal_seed).address;
+                let vote = sdk::vote_on_normal_proposal(
+                    VoteOnNormalProposalInstructionArgs {
+                        voting_asset_index: 0,
+                        vote: VoteChoice::For,
+                    },
+                    group_setup.group,
+                    proposal,
+                    asset_setup.mint,
+                    old_key.pubkey(),
+                );
+                send_tx(svm, &old_key, vec![vote], &[])?;
+
+                Some(proposal)
+            }
+            _ => None,
+        };
+
+        let asset_addresses = match scenario {
+            Scenario::MissingAssetMember => vec![],
+            Scenario::Default | Scenario::OpenProposal => vec![asset_setup.asset_address],
+        };
+        let rotate = sdk::rotate_member_key(
+            group_setup.group,
+            old_key.pubkey(),
+            new_key.pubkey(),
+            &asset_addresses,
+        );
+
+        let rotation = Rotation {
+            group: group_setup.group,
+            asset_address: asset_setup.asset_address,
+            old_key: old_key.pubkey(),
+            new_key: new_key.pubkey(),
+            proposal,
+        };
+
+        Ok((
+            [rotate],
+            vec![group_setup.payer, old_key, new_key],
+            rotation,
+        ))
+    }
+
+    pub fn with_default(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>, Rotation)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_missing_asset_member(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Rotation)> {
+        Self::builder(svm, Scenario::MissingAssetMember)
+    }
+
+    pub fn with_open_proposal(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Rotation)> {
+        Self::builder(svm, Scenario::OpenProposal)
+    }
+}
+
+fn send(
+    svm: &mut LiteSVM,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> litesvm::types::TransactionResult {
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&signers[0].pubkey()),
+        signers,
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(transaction)
+}
+
+#[test]
+fn test_rotate_member_key_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_default(&mut svm);
+    let (instructions, signers, rotation) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let group_before = read_group(&svm, rotation.group).expect("group");
+    let old_member = sdk::group_member_pda(&rotation.group, &rotation.old_key).address;
+    let account = svm.get_account(&old_member).expect("old group member");
+    let old_member_data =
+        GroupMember::try_deserialize(&mut account.data.as_slice()).expect("group member data");
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    let new_member = sdk::group_member_pda(&rotation.group, &rotation.new_key).address;
+    let account = svm.get_account(&new_member).expect("new group member");
+    let new_member_data =
+        GroupMember::try_deserialize(&mut account.data.as_slice()).expect("group member data");
+    assert_eq!(new_member_data.user, rotation.new_key);
+    assert_eq!(new_member_data.weight, old_member_data.weight);
+    assert_eq!(new_member_data.member_index, old_member_data.member_index);
+    assert_eq!(new_member_data.asset_member_count, 1);
+    assert_eq!(new_member_data.key_rotated_at, Some(0));
+
+    let new_asset_member =
+        sdk::asset_member_pda(&rotation.group, &rotation.asset_address, &rotation.new_key).address;
+    let account = svm
+        .get_account(&new_asset_member)
+        .expect("new asset member");
+    let asset_member_data =
+        AssetMember::try_deserialize(&mut account.data.as_slice()).expect("asset member data");
+    assert_eq!(asset_member_data.user, rotation.new_key);
+    assert_eq!(asset_member_data.asset, rotation.asset_address);
+
+    let old_asset_member =
+        sdk::asset_member_pda(&rotation.group, &rotation.asset_address, &rotation.old_key).address;
+    assert!(svm
+        .get_account(&old_asset_member)
+        .map_or(true, |account| account.data.is_empty()));
+    assert!(svm
+        .get_account(&old_member)
+        .map_or(true, |account| account.data.is_empty()));
+
+    // Open proposals are not staled by the rotation.
+    let group = read_group(&svm, rotation.group).expect("group");
+    assert_eq!(
+        group.proposal_index_after_stale,
+        group_before.proposal_index_after_stale
+    );
+}
+
+// Proposals opened before a rotation stay open for every other member.
+#[test]
+fn test_rotate_member_key_keeps_open_proposals_valid() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_open_proposal(&mut svm);
+    let (instructions, signers, rotation) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+    let proposal = rotation.proposal.expect("proposal");
+
+    let signer_refs: Vec<&Keypair> = signers.iter().collect();
+    common::assert_transaction_success(send(&mut svm, &instructions, &signer_refs));
+
+    let vote = sdk::vote_on_normal_proposal(
+        VoteOnNormalProposalInstructionArgs {
+            voting_asset_index: 0,
+            vote: VoteChoice::For,
+        },
+        rotation.group,
+        proposal,
+        rotation.asset_address,
+        signers[0].pubkey(),
+    );
+    common::assert_transaction_success(send(&mut svm, &[vote], &[&signers[0]]));
+}
+
+// The old key may already have voted, and its vote record is not found under the new key.
+#[test]
+fn test_rotate_member_key_new_key_cannot_vote_on_earlier_proposals() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_open_proposal(&mut svm);
+    let (instructions, signers, rotation) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+    let proposal = rotation.proposal.expect("proposal");
+
+    let signer_refs: Vec<&Keypair> = signers.iter().collect();
+    common::assert_transaction_success(send(&mut svm, &instructions, &signer_refs));
+
+    let vote = sdk::vote_on_normal_proposal(
+        VoteOnNormalProposalInstructionArgs {
+            voting_asset_index: 0,
+            vote: VoteChoice::For,
+        },
+        rotation.group,
+        proposal,
+        rotation.asset_address,
+        rotation.new_key,
+    );
+    common::assert_multisig_instruction_error(
+        send(&mut svm, &[vote], &[&signers[2]]),
+        0,
+        multisig::MultisigError::KeyRotatedAfterProposal,
+    );
+}
+
+#[test]
+fn test_rotate_member_key_fails_without_all_asset_members() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_missing_asset_member(&mut svm);
+    let (instructions, signers, _) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(result, 0, multisig::MultisigError::LengthMismatch);
+}
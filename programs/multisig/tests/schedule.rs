@@ -0,0 +1,570 @@
+#![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
+use anchor_spl::token::spl_token::state::Account as TokenAccount;
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::{
+        CreateConfigProposalInstructionArgs, CreateNormalProposalInstructionArgs,
+        CreateProposalTransactionInstructionArgs, CreateScheduleInstructionArgs,
+        ExecuteProposalTransactionInstructionArgs, VoteOnNormalProposalInstructionArgs,
+    },
+    AssetIndex, ConfigChange, MultisigError, ProposalState, Schedule, ScheduledTransfer,
+    VoteChoice,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    clock::Clock,
+    instruction::{AccountMeta, Instruction},
+    program_option::COption,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_program,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{
+    add_multisig_program, assert_multisig_instruction_error, assert_transaction_success,
+    insert_schedule, set_config_proposal_state, set_group_paused, setup_asset_mint,
+    setup_asset_token, setup_group, to_serializable, AssetSetup, GroupSetup,
+};
+
+const AMOUNT: u64 = 1_000_000;
+const INTERVAL: u32 = 30 * 24 * 60 * 60;
+
+// Run a schedule of recurring transfers from an asset.
+struct TestSetup {}
+
+struct ScheduleSetup {
+    group_setup: GroupSetup,
+    asset_setup: AssetSetup,
+    schedule_seed: Pubkey,
+    schedule: Pubkey,
+    destination: Pubkey,
+}
+
+enum Scenario {
+    Default,
+    TokenTransfer,
+    LastRun,
+    NotDue,
+    WrongDestination,
+    GroupPaused,
+}
+
+fn send(
+    svm: &mut LiteSVM,
+    payer: &Keypair,
+    instructions: Vec<Instruction>,
+    extra_signers: &[&Keypair],
+) -> litesvm::types::TransactionResult {
+    let mut signers = vec![payer];
+    signers.extend_from_slice(extra_signers);
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&payer.pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(transaction)
+}
+
+fn read_schedule(svm: &LiteSVM, schedule: &Pubkey) -> Option<Schedule> {
+    svm.get_account(schedule)
+        .filter(|account| !account.data.is_empty())
+        .map(|account| Schedule::try_deserialize(&mut account.data.as_slice()).expect("schedule"))
+}
+
+fn token_amount(svm: &LiteSVM, key: &Pubkey) -> u64 {
+    let account = svm.get_account(key).expect("token account");
+    TokenAccount::unpack(&account.data)
+        .expect("token data")
+        .amount
+}
+
+fn run_schedule_ix(setup: &ScheduleSetup, destination: Pubkey, token: bool) -> Instruction {
+    sdk::run_schedule(
+        setup.group_setup.group,
+        setup.schedule_seed,
+        setup.asset_setup.asset_address,
+        destination,
+        setup.group_setup.payer.pubkey(),
+        token.then_some(sdk::ScheduleTokenAccounts {
+            mint: setup.asset_setup.mint,
+            token_program: spl_token::ID,
+        }),
+    )
+}
+
+impl TestSetup {
+    fn builder(svm: &mut LiteSVM, scenario: Scenario) -> Result<(Instruction, ScheduleSetup)> {
+        let group_setup = setup_group(svm)?;
+        let token = matches!(scenario, Scenario::TokenTransfer);
+        let asset_setup = if token {
+            setup_asset_token(svm, &group_setup)?
+        } else {
+            setup_asset_mint(svm, &group_setup)?
+        };
+        svm.airdrop(&asset_setup.asset_authority, 1_000_000_000)
+            .expect("fund asset authority");
+
+        let destination = Pubkey::new_unique();
+        let transfer = if token {
+            common::create_token_account_at(
+                svm,
+                &destination,
+                &asset_setup.mint,
+                &group_setup.members[0].pubkey(),
+                COption::None,
+                spl_token::state::AccountState::Initialized,
+                COption::None,
+            )?;
+            ScheduledTransfer::Token {
+                destination,
+                amount: AMOUNT,
+            }
+        } else {
+            ScheduledTransfer::Lamports {
+                destination,
+                amount: AMOUNT,
+            }
+        };
+
+        let schedule_seed = Pubkey::new_unique();
+        let schedule = sdk::schedule_pda(&group_setup.group, &schedule_seed);
+        let total_runs = if matches!(scenario, Scenario::LastRun) {
+            1
+        } else {
+            3
+        };
+        let start_timestamp = if matches!(scenario, Scenario::NotDue) {
+            1000
+        } else {
+            0
+        };
+        insert_schedule(
+            svm,
+            schedule.address,
+            Schedule::new(
+                group_setup.group,
+                asset_setup.asset_address,
+                schedule_seed,
+                transfer,
+                INTERVAL,
+                total_runs,
+                start_timestamp,
+                group_setup.payer.pubkey(),
+                schedule.bump,
+                sdk::asset_authority_pda(&group_setup.group, &asset_setup.asset_address).bump,
+            )?,
+        )?;
+
+        if matches!(scenario, Scenario::GroupPaused) {
+            set_group_paused(
+                svm,
+                group_setup.group,
+                true,
+                group_setup.members[1].pubkey(),
+                group_setup.members[2].pubkey(),
+                group_setup.members[3].pubkey(),
+            )?;
+        }
+
+        let setup = ScheduleSetup {
+            group_setup,
+            asset_setup,
+            schedule_seed,
+            schedule: schedule.address,
+            destination,
+        };
+        let run_destination = match scenario {
+            Scenario::WrongDestination => Pubkey::new_unique(),
+            _ => destination,
+        };
+        let run = run_schedule_ix(&setup, run_destination, token);
+
+        Ok((run, setup))
+    }
+
+    pub fn with_default(svm: &mut LiteSVM) -> Result<(Instruction, ScheduleSetup)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_token_transfer(svm: &mut LiteSVM) -> Result<(Instruction, ScheduleSetup)> {
+        Self::builder(svm, Scenario::TokenTransfer)
+    }
+
+    pub fn with_last_run(svm: &mut LiteSVM) -> Result<(Instruction, ScheduleSetup)> {
+        Self::builder(svm, Scenario::LastRun)
+    }
+
+    pub fn with_not_due(svm: &mut LiteSVM) -> Result<(Instruction, ScheduleSetup)> {
+        Self::builder(svm, Scenario::NotDue)
+    }
+
+    pub fn with_wrong_destination(svm: &mut LiteSVM) -> Result<(Instruction, ScheduleSetup)> {
+        Self::builder(svm, Scenario::WrongDestination)
+    }
+
+    pub fn with_group_paused(svm: &mut LiteSVM) -> Result<(Instruction, ScheduleSetup)> {
+        Self::builder(svm, Scenario::GroupPaused)
+    }
+}
+
+#[test]
+fn test_run_schedule_transfers_lamports_and_waits_for_next_run() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let (run, setup) = TestSetup::with_default(&mut svm).expect("setup");
+    let payer = &setup.group_setup.payer;
+
+    assert_transaction_success(send(&mut svm, payer, vec![run.clone()], &[]));
+    assert_eq!(svm.get_balance(&setup.destination), Some(AMOUNT));
+
+    let schedule = read_schedule(&svm, &setup.schedule).expect("schedule still open");
+    assert_eq!(schedule.completed_runs, 1);
+    assert_eq!(schedule.next_run_timestamp, i64::from(INTERVAL));
+
+    svm.expire_blockhash();
+    assert_multisig_instruction_error(
+        send(&mut svm, payer, vec![run.clone()], &[]),
+        0,
+        MultisigError::ScheduleNotDue,
+    );
+
+    let mut clock = svm.get_sysvar::<Clock>();
+    clock.unix_timestamp = i64::from(INTERVAL);
+    svm.set_sysvar(&clock);
+
+    assert_transaction_success(send(&mut svm, payer, vec![run], &[]));
+    assert_eq!(svm.get_balance(&setup.destination), Some(2 * AMOUNT));
+}
+
+#[test]
+fn test_run_schedule_transfers_tokens() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let (run, setup) = TestSetup::with_token_transfer(&mut svm).expect("setup");
+
+    let asset_before = token_amount(&svm, &setup.asset_setup.asset_address);
+    let destination_before = token_amount(&svm, &setup.destination);
+
+    assert_transaction_success(send(&mut svm, &setup.group_setup.payer, vec![run], &[]));
+    assert_eq!(
+        token_amount(&svm, &setup.asset_setup.asset_address),
+        asset_before - AMOUNT
+    );
+    assert_eq!(
+        token_amount(&svm, &setup.destination),
+        destination_before + AMOUNT
+    );
+}
+
+#[test]
+fn test_run_schedule_closes_schedule_after_last_run() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let (run, setup) = TestSetup::with_last_run(&mut svm).expect("setup");
+    let payer = setup.group_setup.payer.pubkey();
+    let schedule_rent = svm.get_balance(&setup.schedule).expect("schedule");
+    let payer_before = svm.get_balance(&payer).expect("payer");
+
+    assert_transaction_success(send(&mut svm, &setup.group_setup.payer, vec![run], &[]));
+    assert!(read_schedule(&svm, &setup.schedule).is_none());
+    // The payer also paid the transaction fee.
+    assert_eq!(
+        svm.get_balance(&payer),
+        Some(payer_before + schedule_rent - 5000)
+    );
+}
+
+#[test]
+fn test_run_schedule_fails_when_not_due() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let (run, setup) = TestSetup::with_not_due(&mut svm).expect("setup");
+
+    assert_multisig_instruction_error(
+        send(&mut svm, &setup.group_setup.payer, vec![run], &[]),
+        0,
+        MultisigError::ScheduleNotDue,
+    );
+}
+
+#[test]
+fn test_run_schedule_fails_with_wrong_destination() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let (run, setup) = TestSetup::with_wrong_destination(&mut svm).expect("setup");
+
+    assert_multisig_instruction_error(
+        send(&mut svm, &setup.group_setup.payer, vec![run], &[]),
+        0,
+        MultisigError::InvalidSchedule,
+    );
+}
+
+#[test]
+fn test_run_schedule_fails_when_group_paused() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let (run, setup) = TestSetup::with_group_paused(&mut svm).expect("setup");
+
+    assert_multisig_instruction_error(
+        send(&mut svm, &setup.group_setup.payer, vec![run], &[]),
+        0,
+        MultisigError::GroupPaused,
+    );
+}
+
+#[test]
+fn test_create_schedule_through_passed_normal_proposal() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let group_setup = setup_group(&mut svm).expect("group setup");
+    let asset_setup = setup_asset_mint(&mut svm, &group_setup).expect("asset setup");
+    let group = group_setup.group;
+    let payer = &group_setup.payer;
+    svm.airdrop(&asset_setup.asset_authority, 1_000_000_000)
+        .expect("fund asset authority");
+
+    let schedule_seed = Pubkey::new_unique();
+    let schedule = sdk::schedule_pda(&group, &schedule_seed).address;
+    let destination = Pubkey::new_unique();
+    let create_schedule = to_serializable(&sdk::create_schedule(
+        CreateScheduleInstructionArgs {
+            schedule_seed,
+            transfer: ScheduledTransfer::Lamports {
+                destination,
+                amount: AMOUNT,
+            },
+            interval: INTERVAL,
+            total_runs: 12,
+            start_timestamp: 0,
+        },
+        group,
+        asset_setup.asset_address,
+        asset_setup.asset_authority,
+    ));
+    let instructions = [create_schedule];
+
+    let proposal_seed = Pubkey::new_unique();
+    let proposal = sdk::proposal_pda(&group, &proposal_seed).address;
+    let create_normal = sdk::create_normal_proposal(
+        CreateNormalProposalInstructionArgs {
+            proposal_seed,
+            asset_keys: vec![asset_setup.asset_address],
+            // The asset address is the second account of `create_schedule`.
+            asset_indices: vec![AssetIndex {
+                instruction_index: 0,
+                account_index: 1,
+            }],
+            authority_bumps: vec![
+                sdk::asset_authority_pda(&group, &asset_setup.asset_address).bump,
+            ],
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            instruction_hashes: sdk::serializable_instruction_hashes(&instructions)
+                .expect("hash instructions"),
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
+            compact_votes: false,
+            description: None,
+            options: vec![],
+        },
+        group,
+        payer.pubkey(),
+    );
+    let create_tx = sdk::create_proposal_transaction(
+        CreateProposalTransactionInstructionArgs {
+            raw_instructions: sdk::serializable_instructions_bytes(&instructions)
+                .expect("serialize instructions"),
+        },
+        group,
+        proposal_seed,
+        payer.pubkey(),
+        &[asset_setup.asset_address],
+    );
+    assert_transaction_success(send(&mut svm, payer, vec![create_normal, create_tx], &[]));
+
+    let votes = [payer, &group_setup.members[0]]
+        .iter()
+        .map(|voter| {
+            sdk::vote_on_normal_proposal(
+                VoteOnNormalProposalInstructionArgs {
+                    voting_asset_index: 0,
+                    vote: VoteChoice::For,
+                },
+                group,
+                proposal,
+                asset_setup.asset_address,
+                voter.pubkey(),
+            )
+        })
+        .collect();
+    assert_transaction_success(send(&mut svm, payer, votes, &[&group_setup.members[0]]));
+
+    let execute = sdk::execute_proposal_transaction(
+        ExecuteProposalTransactionInstructionArgs {
+            instruction_count: 1,
+        },
+        group,
+        proposal,
+        payer.pubkey(),
+        sdk::proposal_transaction_pda(&proposal).address,
+        payer.pubkey(),
+        payer.pubkey(),
+        None,
+        None,
+        vec![
+            AccountMeta::new_readonly(group, false),
+            AccountMeta::new_readonly(asset_setup.asset_address, false),
+            AccountMeta::new_readonly(asset_setup.asset, false),
+            AccountMeta::new(asset_setup.asset_authority, false),
+            AccountMeta::new(schedule, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(sdk::PROGRAM_ID, false),
+        ],
+    );
+    assert_transaction_success(send(&mut svm, payer, vec![execute], &[]));
+
+    let schedule = read_schedule(&svm, &schedule).expect("schedule created");
+    assert_eq!(schedule.group, group);
+    assert_eq!(schedule.asset, asset_setup.asset_address);
+    assert!(
+        schedule.transfer
+            == ScheduledTransfer::Lamports {
+                destination,
+                amount: AMOUNT,
+            }
+    );
+    assert_eq!(schedule.total_runs, 12);
+    assert_eq!(schedule.completed_runs, 0);
+    assert_eq!(schedule.rent_payer, asset_setup.asset_authority);
+}
+
+#[test]
+fn test_create_schedule_requires_asset_authority_signature() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let group_setup = setup_group(&mut svm).expect("group setup");
+    let asset_setup = setup_asset_mint(&mut svm, &group_setup).expect("asset setup");
+
+    let mut create_schedule = sdk::create_schedule(
+        CreateScheduleInstructionArgs {
+            schedule_seed: Pubkey::new_unique(),
+            transfer: ScheduledTransfer::Lamports {
+                destination: Pubkey::new_unique(),
+                amount: AMOUNT,
+            },
+            interval: INTERVAL,
+            total_runs: 12,
+            start_timestamp: 0,
+        },
+        group_setup.group,
+        asset_setup.asset_address,
+        group_setup.payer.pubkey(),
+    );
+    create_schedule.accounts[3].is_signer = false;
+
+    common::assert_instruction_error(
+        send(&mut svm, &group_setup.payer, vec![create_schedule], &[]),
+        0,
+        anchor_lang::error::ErrorCode::AccountNotSigner as u32,
+    );
+}
+
+#[test]
+fn test_cancel_schedule_through_passed_config_proposal() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let (_, setup) = TestSetup::with_default(&mut svm).expect("setup");
+    let group_setup = &setup.group_setup;
+    let payer = &group_setup.payer;
+
+    let proposal_seed = Pubkey::new_unique();
+    let proposal = sdk::proposal_pda(&group_setup.group, &proposal_seed).address;
+    let create = sdk::create_config_proposal(
+        CreateConfigProposalInstructionArgs {
+            proposal_seed,
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            config_change: ConfigChange::CancelSchedule {
+                asset_address: setup.asset_setup.asset_address,
+                schedule: setup.schedule,
+            },
+            compact_votes: false,
+            description: None,
+        },
+        group_setup.group,
+        payer.pubkey(),
+        Some(setup.asset_setup.asset_address),
+    );
+    assert_transaction_success(send(&mut svm, payer, vec![create], &[]));
+    set_config_proposal_state(&mut svm, proposal, ProposalState::Passed, Some(0))
+        .expect("pass proposal");
+
+    let cancel = sdk::cancel_schedule(
+        group_setup.group,
+        setup.asset_setup.asset_address,
+        setup.schedule,
+        payer.pubkey(),
+        proposal,
+        payer.pubkey(),
+    );
+    assert_transaction_success(send(&mut svm, payer, vec![cancel], &[]));
+    assert!(read_schedule(&svm, &setup.schedule).is_none());
+    assert!(svm
+        .get_account(&proposal)
+        .is_none_or(|account| account.data.is_empty()));
+}
+
+#[test]
+fn test_cancel_schedule_fails_for_other_schedule() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+    let (_, setup) = TestSetup::with_default(&mut svm).expect("setup");
+    let group_setup = &setup.group_setup;
+    let payer = &group_setup.payer;
+
+    let proposal_seed = Pubkey::new_unique();
+    let proposal = sdk::proposal_pda(&group_setup.group, &proposal_seed).address;
+    let create = sdk::create_config_proposal(
+        CreateConfigProposalInstructionArgs {
+            proposal_seed,
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            config_change: ConfigChange::CancelSchedule {
+                asset_address: setup.asset_setup.asset_address,
+                schedule: Pubkey::new_unique(),
+            },
+            compact_votes: false,
+            description: None,
+        },
+        group_setup.group,
+        payer.pubkey(),
+        Some(setup.asset_setup.asset_address),
+    );
+    assert_transaction_success(send(&mut svm, payer, vec![create], &[]));
+    set_config_proposal_state(&mut svm, proposal, ProposalState::Passed, Some(0))
+        .expect("pass proposal");
+
+    let cancel = sdk::cancel_schedule(
+        group_setup.group,
+        setup.asset_setup.asset_address,
+        setup.schedule,
+        payer.pubkey(),
+        proposal,
+        payer.pubkey(),
+    );
+    assert_multisig_instruction_error(
+        send(&mut svm, payer, vec![cancel], &[]),
+        0,
+        MultisigError::InvalidConfigChange,
+    );
+}
@@ -0,0 +1,141 @@
+#![cfg(feature = "test-helpers")]
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{MetadataContent, MultisigError};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{add_multisig_program, assert_multisig_instruction_error, setup_group};
+
+// Set member metadata is signed by the member and validates the name and URI lengths.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    NameTooLong,
+    NotAMember,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey, Pubkey)> {
+        let group_setup = setup_group(svm)?;
+        let [member, ..] = group_setup.members;
+
+        let name = match scenario {
+            Scenario::NameTooLong => "n".repeat(33),
+            Scenario::Default | Scenario::NotAMember => "treasurer".to_string(),
+        };
+        let content = MetadataContent {
+            name,
+            uri: "https://example.com/treasurer.json".to_string(),
+            description_hash: [1; 32],
+        };
+
+        let signer = match scenario {
+            Scenario::NotAMember => {
+                let outsider = Keypair::new();
+                svm.airdrop(&outsider.pubkey(), 1_000_000_000)
+                    .expect("airdrop to outsider");
+                outsider
+            }
+            Scenario::Default | Scenario::NameTooLong => member,
+        };
+
+        let ix = sdk::set_member_metadata(content, group_setup.group, signer.pubkey());
+        let signer_key = signer.pubkey();
+
+        Ok(([ix], vec![signer], group_setup.group, signer_key))
+    }
+
+    pub fn with_default(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey, Pubkey)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_name_too_long(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _, _) = Self::builder(svm, Scenario::NameTooLong)?;
+        Ok((ix, signers))
+    }
+
+    pub fn with_not_a_member(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _, _) = Self::builder(svm, Scenario::NotAMember)?;
+        Ok((ix, signers))
+    }
+}
+
+#[test]
+fn test_set_member_metadata_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, group, member) =
+        TestSetup::with_default(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(transaction));
+
+    let metadata = sdk::fetch_group_member_metadata(&group, &member, |address| {
+        svm.get_account(address).map(|account| account.data)
+    })
+    .expect("deserialize metadata")
+    .expect("metadata");
+    assert_eq!(metadata.content.name, "treasurer");
+    assert_eq!(metadata.group, group);
+    assert_eq!(metadata.payer, member);
+    assert_eq!(
+        metadata.subject,
+        sdk::group_member_pda(&group, &member).address
+    );
+}
+
+#[test]
+fn test_set_member_metadata_fails_with_name_too_long() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_name_too_long(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::NameTooLong,
+    );
+}
+
+#[test]
+fn test_set_member_metadata_fails_for_non_member() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_not_a_member(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        u32::from(anchor_lang::error::ErrorCode::AccountNotInitialized),
+    );
+}
@@ -0,0 +1,161 @@
+#![cfg(feature = "test-helpers")]
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::{CreateConfigProposalInstructionArgs, VoteOnConfigProposalInstructionArgs},
+    ConfigChange, ConfigType, MetadataContent, MultisigError, VoteChoice,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{add_multisig_program, assert_multisig_instruction_error, send_tx, setup_group};
+
+// Set proposal metadata is signed by the proposer and locked once voting has started.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    NotProposer,
+    AfterVote,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey)> {
+        let group_setup = setup_group(svm)?;
+        let [voter, ..] = group_setup.members;
+
+        let proposal_seed = Pubkey::new_unique();
+        let proposal = sdk::proposal_pda(&group_setup.group, &proposal_seed).address;
+
+        let create_args = CreateConfigProposalInstructionArgs {
+            proposal_seed,
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            config_change: ConfigChange::ChangeGroupConfig {
+                config_types: vec![ConfigType::MinimumVoteCount(1)],
+            },
+            compact_votes: false,
+            description: None,
+        };
+        let create_config_proposal = sdk::create_config_proposal(
+            create_args,
+            group_setup.group,
+            group_setup.payer.pubkey(),
+            None,
+        );
+        send_tx(svm, &group_setup.payer, vec![create_config_proposal], &[])?;
+
+        if let Scenario::AfterVote = scenario {
+            let vote = sdk::vote_on_config_proposal(
+                VoteOnConfigProposalInstructionArgs {
+                    vote: VoteChoice::For,
+                },
+                group_setup.group,
+                proposal,
+                voter.pubkey(),
+                None,
+            );
+            send_tx(svm, &voter, vec![vote], &[])?;
+        }
+
+        let content = MetadataContent {
+            name: "q3 budget".to_string(),
+            uri: "https://example.com/q3-budget.json".to_string(),
+            description_hash: [1; 32],
+        };
+
+        let signer = match scenario {
+            Scenario::NotProposer => voter,
+            Scenario::Default | Scenario::AfterVote => group_setup.payer,
+        };
+        let ix = sdk::set_proposal_metadata(content, group_setup.group, proposal, signer.pubkey());
+
+        Ok(([ix], vec![signer], proposal))
+    }
+
+    pub fn with_default(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_not_proposer(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _) = Self::builder(svm, Scenario::NotProposer)?;
+        Ok((ix, signers))
+    }
+
+    pub fn with_after_vote(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _) = Self::builder(svm, Scenario::AfterVote)?;
+        Ok((ix, signers))
+    }
+}
+
+#[test]
+fn test_set_proposal_metadata_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, proposal) =
+        TestSetup::with_default(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(transaction));
+
+    let metadata = sdk::fetch_proposal_metadata(&proposal, |address| {
+        svm.get_account(address).map(|account| account.data)
+    })
+    .expect("deserialize metadata")
+    .expect("metadata");
+    assert_eq!(metadata.content.name, "q3 budget");
+    assert_eq!(metadata.subject, proposal);
+    assert_eq!(metadata.payer, signers[0].pubkey());
+}
+
+#[test]
+fn test_set_proposal_metadata_fails_for_non_proposer() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_not_proposer(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::InvalidProposer,
+    );
+}
+
+#[test]
+fn test_set_proposal_metadata_fails_after_vote() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_after_vote(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::ProposalMetadataLocked,
+    );
+}
@@ -1,6 +1,11 @@
 use multisig::{
-    Asset, FractionalThreshold, Group, GroupMember, Permissions, ProposalAsset,
-    ProposalAssetThresholdState,
+    leading_option, Asset, BalanceGuard, BalanceGuardKind, CompactVote, ConfigChange,
+    ConfigChangeTimelocks, ConfigProposal, ConfigProposalAsset, ConfigType, Delegation,
+    FractionalThreshold, Group, GroupMember, InstructionReturnData, MemberInvite, Metadata,
+    MetadataContent, Permissions, ProposalAsset, ProposalAssetThresholdState, ProposalBond,
+    ProposalBondConfig, ProposalDescription, ProposalOption, ProposalState, ProposalTarget,
+    Schedule, ScheduledTransfer, SignedVoteMessage, VoteChoice, MAX_EXECUTOR_TIP_LAMPORTS,
+    MAX_RETURN_DATA_LEN, MEMBER_INVITE_DURATION,
 };
 use solana_sdk::pubkey::Pubkey;
 
@@ -51,15 +56,66 @@ fn fractional_threshold_rejects_overlapping_pairs_and_allows_unanimity() {
 
 #[test]
 fn permissions_reject_unknown_bits() {
-    assert!(Permissions::try_from(0b0000_0011).is_ok());
+    assert!(Permissions::try_from(0b0111_1111).is_ok());
     assert_anchor_error(
-        Permissions::try_from(0b0000_0100),
+        Permissions::try_from(0b1000_0000),
         "InvalidPermissions",
         6051,
         "Invalid permissions",
     );
 }
 
+#[test]
+fn permissions_execute_bit_is_independent() {
+    let mut permissions = Permissions::try_from(0b0000_0011).unwrap();
+    assert!(!permissions.has_execute());
+
+    permissions.set_execute(true);
+    assert!(permissions.has_execute());
+    assert!(permissions.has_propose());
+    assert!(permissions.has_add_asset());
+
+    permissions.set_execute(false);
+    assert!(!permissions.has_execute());
+    assert!(permissions.has_propose());
+}
+
+#[test]
+fn permissions_builder_sets_each_action_bit() {
+    let permissions = Permissions::builder()
+        .propose_config(true)
+        .cancel(true)
+        .emergency_reset(true)
+        .manage_spending_limits(true)
+        .build();
+
+    assert!(permissions.is_valid().is_ok());
+    assert!(permissions.has_propose_config());
+    assert!(permissions.has_cancel());
+    assert!(permissions.has_emergency_reset());
+    assert!(permissions.has_manage_spending_limits());
+    assert!(!permissions.has_propose());
+    assert!(!permissions.has_add_asset());
+    assert!(!permissions.has_execute());
+
+    let without_cancel = Permissions::builder().cancel(true).cancel(false).build();
+    assert!(!without_cancel.has_cancel());
+}
+
+#[test]
+fn balance_guard_limits_decrease_only() {
+    let guard = BalanceGuard {
+        asset: Pubkey::new_unique(),
+        kind: BalanceGuardKind::TokenAmount,
+        max_decrease: 10,
+    };
+
+    assert!(guard.allows(100, 90));
+    assert!(!guard.allows(100, 89));
+    assert!(guard.allows(100, 1_000));
+    assert!(guard.allows(5, 0));
+}
+
 #[test]
 fn member_constructors_reject_zero_and_oversized_weight() {
     let user = Pubkey::new_unique();
@@ -67,13 +123,13 @@ fn member_constructors_reject_zero_and_oversized_weight() {
     let permissions = Permissions::try_from(0b0000_0011).unwrap();
 
     assert_anchor_error(
-        GroupMember::new(user, group, permissions, 0, 255, 100),
+        GroupMember::new(user, group, permissions, 0, 0, 255, 100),
         "InvalidMemberWeight",
         6046,
         "Invalid member weight",
     );
     assert_anchor_error(
-        GroupMember::new(user, group, permissions, 101, 255, 100),
+        GroupMember::new(user, group, permissions, 101, 0, 255, 100),
         "InvalidMemberWeight",
         6046,
         "Invalid member weight",
@@ -138,13 +194,15 @@ fn group_and_asset_quorum_counts_are_positive_and_can_equal_member_count() {
         255,
     )
     .unwrap();
+    group.set_minimum_vote_count(0);
     assert_anchor_error(
-        group.set_minimum_vote_count(0),
+        group.validate_config(),
         "InvalidMemberCount",
         6045,
         "Invalid member count",
     );
-    assert!(group.set_minimum_vote_count(5).is_ok());
+    group.set_minimum_vote_count(5);
+    assert!(group.validate_config().is_ok());
 
     let mut asset = Asset::new(
         Pubkey::new_unique(),
@@ -163,13 +221,15 @@ fn group_and_asset_quorum_counts_are_positive_and_can_equal_member_count() {
         253,
     )
     .unwrap();
+    asset.set_minimum_vote_count(1);
     assert_anchor_error(
-        asset.set_minimum_vote_count(1),
+        asset.validate_config(),
         "InvalidThreshold",
         6036,
         "Invalid threshold configuration",
     );
-    assert!(asset.set_minimum_vote_count(3).is_ok());
+    asset.set_minimum_vote_count(3);
+    assert!(asset.validate_config().is_ok());
 }
 
 #[test]
@@ -207,3 +267,878 @@ fn proposal_asset_rejects_noop_threshold_transition() {
         "Invalid state transition",
     );
 }
+
+#[test]
+fn group_config_batch_is_validated_after_all_changes() {
+    let pass = FractionalThreshold::new_from_values(1, 2).unwrap();
+    let fail = FractionalThreshold::new_from_values(2, 3).unwrap();
+    let group = Group::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        pass,
+        fail,
+        pass,
+        fail,
+        pass,
+        fail,
+        1,
+        1,
+        100,
+        0,
+        5,
+        255,
+    )
+    .unwrap();
+
+    let lower_add = FractionalThreshold::new_from_values(1, 3).unwrap();
+    let higher_not_add = FractionalThreshold::new_from_values(3, 4).unwrap();
+    assert_anchor_error(
+        group.with_config_types(&[ConfigType::AddMember(lower_add)]),
+        "InvalidThreshold",
+        6036,
+        "Invalid threshold configuration",
+    );
+
+    let updated = group
+        .with_config_types(&[
+            ConfigType::AddMember(lower_add),
+            ConfigType::NotAddMember(higher_not_add),
+        ])
+        .unwrap();
+    assert_eq!(updated.add_threshold.numerator, 1);
+    assert_eq!(updated.not_add_threshold.denominator, 4);
+}
+
+#[test]
+fn threshold_pairs_can_swap_in_one_config_change() {
+    let two_thirds = FractionalThreshold::new_from_values(2, 3).unwrap();
+    let half = FractionalThreshold::new_from_values(1, 2).unwrap();
+    let asset = Asset::new(
+        Pubkey::new_unique(),
+        two_thirds,
+        half,
+        two_thirds,
+        half,
+        two_thirds,
+        half,
+        two_thirds,
+        half,
+        3,
+        3,
+        3,
+        254,
+        253,
+    )
+    .unwrap();
+
+    // Either threshold on its own overlaps its old partner.
+    assert_anchor_error(
+        asset.with_config_types(&[ConfigType::Use(half)]),
+        "InvalidThreshold",
+        6036,
+        "Invalid threshold configuration",
+    );
+
+    let swapped = asset
+        .with_config_types(&[ConfigType::UseThresholds {
+            pass: half,
+            fail: two_thirds,
+        }])
+        .unwrap();
+    assert_eq!(swapped.use_threshold.denominator, 2);
+    assert_eq!(swapped.not_use_threshold.denominator, 3);
+
+    assert_anchor_error(
+        asset.with_config_types(&[ConfigType::UseThresholds {
+            pass: half,
+            fail: half,
+        }]),
+        "InvalidThreshold",
+        6036,
+        "Invalid threshold configuration",
+    );
+}
+
+#[test]
+fn delegation_is_active_until_expiry() {
+    let group = Pubkey::new_unique();
+    let delegator = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+
+    let expiring = Delegation::new(group, delegator, delegate, None, Some(100), 255);
+    assert!(expiring.is_active(99));
+    assert!(!expiring.is_active(100));
+
+    let open_ended = Delegation::new(group, delegator, delegate, None, None, 255);
+    assert!(open_ended.is_active(i64::MAX));
+}
+
+#[test]
+fn new_members_take_indices_after_the_initial_members() {
+    let pass = FractionalThreshold::new_from_values(1, 2).unwrap();
+    let fail = FractionalThreshold::new_from_values(2, 3).unwrap();
+    let mut group = Group::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        pass,
+        fail,
+        pass,
+        fail,
+        pass,
+        fail,
+        1,
+        1,
+        100,
+        0,
+        3,
+        255,
+    )
+    .unwrap();
+
+    assert_eq!(group.take_member_index().unwrap(), 3);
+    assert_eq!(group.take_member_index().unwrap(), 4);
+}
+
+#[test]
+fn compact_votes_are_replaced_per_member_and_asset() {
+    let mut votes = Vec::new();
+
+    assert!(CompactVote::record(&mut votes, 0, Some(0), VoteChoice::For).is_none());
+    assert!(CompactVote::record(&mut votes, 0, Some(1), VoteChoice::Against).is_none());
+    assert_eq!(votes.len(), 2);
+
+    let previous = CompactVote::record(&mut votes, 0, Some(0), VoteChoice::Against);
+    assert!(previous == Some(VoteChoice::For));
+    assert_eq!(votes.len(), 2);
+    assert!(votes[0].vote_choice == VoteChoice::Against);
+}
+
+#[test]
+fn config_proposal_reserves_compact_votes_only_when_enabled() {
+    use anchor_lang::{AnchorSerialize, Space};
+
+    let member_count = 5;
+    let mut proposal = ConfigProposal {
+        propose_timestamp: 0,
+        proposal_deadline_timestamp: 100,
+        proposal_passed_timestamp: None,
+        proposal_index: 0,
+        for_weight: 0,
+        against_weight: 0,
+        group: Pubkey::new_unique(),
+        proposer: Pubkey::new_unique(),
+        proposal_seed: Pubkey::new_unique(),
+        timelock_offset: 0,
+        vote_count: 0,
+        target: ProposalTarget::Group,
+        config_change: ConfigChange::ChangeGroupConfig {
+            config_types: vec![ConfigType::MinimumVoteCount(2)],
+        },
+        compact_votes: None,
+        state: ProposalState::Open,
+        account_bump: 255,
+        description: None,
+        assets: vec![],
+        bonded: false,
+        created_proposal_index: 0,
+    };
+    assert!(proposal.try_to_vec().unwrap().len() <= ConfigProposal::get_size(None));
+
+    let mut votes = Vec::new();
+    for member_index in 0..member_count {
+        CompactVote::record(&mut votes, member_index, None, VoteChoice::For);
+    }
+    proposal.compact_votes = Some(votes);
+    assert!(
+        proposal.try_to_vec().unwrap().len()
+            <= ConfigProposal::get_size(Some(member_count as usize))
+    );
+    assert_eq!(
+        ConfigProposal::get_size(Some(member_count as usize)) - ConfigProposal::get_size(None),
+        CompactVote::INIT_SPACE * member_count as usize
+    );
+}
+
+#[test]
+fn signed_vote_message_is_borsh_encoded() {
+    let group = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let message = SignedVoteMessage {
+        group,
+        proposal,
+        asset_index: Some(2),
+        vote_choice: VoteChoice::Against,
+        nonce: 5,
+    }
+    .to_bytes()
+    .unwrap();
+
+    assert_eq!(message.len(), 32 + 32 + 2 + 1 + 8);
+    assert_eq!(&message[..32], group.as_ref());
+    assert_eq!(&message[32..64], proposal.as_ref());
+    assert_eq!(&message[64..67], &[1, 2, 1]);
+    assert_eq!(&message[67..], &5u64.to_le_bytes());
+}
+
+#[test]
+fn rotated_member_keeps_weight_index_and_asset_memberships() {
+    let group = Pubkey::new_unique();
+    let new_user = Pubkey::new_unique();
+    let permissions = Permissions::try_from(0b0000_0101).unwrap();
+
+    let mut member =
+        GroupMember::new(Pubkey::new_unique(), group, permissions, 7, 3, 255, 100).unwrap();
+    member.increment_asset_member_count().unwrap();
+    member.increment_asset_member_count().unwrap();
+    member.decrement_asset_member_count();
+
+    let rotated = member.rotated(new_user, 254, 50);
+    assert_eq!(rotated.user, new_user);
+    assert_eq!(rotated.group, group);
+    assert_eq!(rotated.weight, 7);
+    assert_eq!(rotated.member_index, 3);
+    assert_eq!(rotated.asset_member_count, 1);
+    assert_eq!(rotated.account_bump, 254);
+    assert!(rotated.has_propose() && rotated.has_execute() && !rotated.has_add_asset());
+
+    // The new key only votes on proposals created after the rotation.
+    assert!(member.can_vote_on_proposal_from(0));
+    assert!(!rotated.can_vote_on_proposal_from(49));
+    assert!(!rotated.can_vote_on_proposal_from(50));
+    assert!(rotated.can_vote_on_proposal_from(51));
+}
+
+#[test]
+fn member_invite_expires_after_the_invite_duration() {
+    let permissions = Permissions::try_from(0b0000_0001).unwrap();
+    let invite = MemberInvite::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        permissions,
+        1,
+        Some(500),
+        Pubkey::new_unique(),
+        100,
+        255,
+    )
+    .unwrap();
+
+    assert_eq!(invite.expiry_timestamp, 100 + MEMBER_INVITE_DURATION);
+    assert_eq!(invite.expires_at, Some(500));
+    assert!(invite.is_active(100 + MEMBER_INVITE_DURATION - 1));
+    assert!(!invite.is_active(100 + MEMBER_INVITE_DURATION));
+}
+
+#[test]
+fn members_without_a_term_never_expire() {
+    let group = Pubkey::new_unique();
+    let permissions = Permissions::try_from(0b0000_0001).unwrap();
+
+    let mut member =
+        GroupMember::new(Pubkey::new_unique(), group, permissions, 1, 0, 255, 100).unwrap();
+    assert!(member.is_active(i64::MAX));
+
+    member.expires_at = Some(100);
+    assert!(member.is_active(99));
+    assert!(!member.is_active(100));
+
+    // The term carries over to a rotated key.
+    assert_eq!(
+        member.rotated(Pubkey::new_unique(), 254, 0).expires_at,
+        Some(100)
+    );
+}
+
+#[test]
+fn metadata_keeps_its_subject_and_payer_on_update() {
+    let content = |name: &str, uri: &str| MetadataContent {
+        name: name.to_string(),
+        uri: uri.to_string(),
+        description_hash: [0; 32],
+    };
+    let subject = Pubkey::new_unique();
+    let group = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let mut metadata = Metadata {
+        subject: Pubkey::default(),
+        group: Pubkey::default(),
+        content: content("", ""),
+        payer: Pubkey::default(),
+        account_bump: 0,
+    };
+
+    metadata
+        .set(subject, group, content("ops", "https://a"), payer, 254)
+        .unwrap();
+    metadata
+        .set(
+            subject,
+            group,
+            content("treasury", "https://b"),
+            Pubkey::new_unique(),
+            1,
+        )
+        .unwrap();
+    assert_eq!(metadata.payer, payer);
+    assert_eq!(metadata.account_bump, 254);
+    assert_eq!(metadata.content.name, "treasury");
+
+    assert_anchor_error(
+        content(&"n".repeat(33), "").validate(),
+        "NameTooLong",
+        6002,
+        "The name provided is greater than 32.",
+    );
+    assert_anchor_error(
+        content("", &"u".repeat(65)).validate(),
+        "UriTooLong",
+        6003,
+        "The URI provided is greater than 64.",
+    );
+}
+
+#[test]
+fn proposal_description_limits_title_and_uri_length() {
+    let description = |title: &str, uri: &str| ProposalDescription {
+        title: title.to_string(),
+        uri: uri.to_string(),
+        content_hash: [0; 32],
+    };
+
+    assert!(description(&"t".repeat(64), &"u".repeat(64))
+        .validate()
+        .is_ok());
+    assert_anchor_error(
+        description(&"t".repeat(65), "").validate(),
+        "TitleTooLong",
+        6098,
+        "The proposal title provided is greater than 64.",
+    );
+    assert_anchor_error(
+        description("", &"u".repeat(65)).validate(),
+        "UriTooLong",
+        6003,
+        "The URI provided is greater than 64.",
+    );
+}
+
+#[test]
+fn multi_asset_config_proposal_fails_once_every_asset_fails() {
+    let pass = FractionalThreshold::new_from_values(1, 2).unwrap();
+    let fail = FractionalThreshold::new_from_values(2, 3).unwrap();
+    let asset = Asset::new(
+        Pubkey::new_unique(),
+        pass,
+        fail,
+        pass,
+        fail,
+        pass,
+        fail,
+        pass,
+        fail,
+        2,
+        2,
+        2,
+        254,
+        253,
+    )
+    .unwrap();
+    let other_asset = Pubkey::new_unique();
+
+    let mut proposal = ConfigProposal {
+        propose_timestamp: 0,
+        proposal_deadline_timestamp: 100,
+        proposal_passed_timestamp: None,
+        proposal_index: 0,
+        for_weight: 0,
+        against_weight: 0,
+        group: Pubkey::new_unique(),
+        proposer: Pubkey::new_unique(),
+        proposal_seed: Pubkey::new_unique(),
+        timelock_offset: 0,
+        vote_count: 0,
+        target: ProposalTarget::Assets,
+        config_change: ConfigChange::ChangeAssetConfig {
+            config_types: vec![ConfigType::MinimumVoteCount(1)],
+        },
+        compact_votes: None,
+        state: ProposalState::Open,
+        account_bump: 255,
+        description: None,
+        assets: vec![
+            ConfigProposalAsset::new(asset.asset_address),
+            ConfigProposalAsset::new(other_asset),
+        ],
+        bonded: false,
+        created_proposal_index: 0,
+    };
+
+    // The change names the default key and applies to every listed asset.
+    assert!(proposal.applies_to_asset(&Pubkey::default(), &other_asset));
+    assert!(!proposal.applies_to_asset(&other_asset, &other_asset));
+
+    for index in 0..proposal.assets.len() {
+        let proposal_asset = proposal.get_asset_mut(index).unwrap();
+        for _ in 0..2 {
+            proposal_asset.increment_vote_count().unwrap();
+            proposal_asset.add_weight_against(1);
+        }
+    }
+
+    assert!(proposal.check_and_mark_asset_failed(0, &asset).unwrap());
+    assert!(proposal.get_asset_state(&asset.asset_address).unwrap() == ProposalState::Failed);
+    assert!(proposal.state == ProposalState::Open);
+    assert!(!proposal.check_and_mark_asset_failed(0, &asset).unwrap());
+
+    assert!(proposal.check_and_mark_asset_failed(1, &asset).unwrap());
+    assert!(proposal.state == ProposalState::Failed);
+    assert!(proposal.get_asset_state(&other_asset).unwrap() == ProposalState::Failed);
+}
+
+#[test]
+fn asset_timelock_and_proposal_duration_are_configurable() {
+    let pass = FractionalThreshold::new_from_values(1, 2).unwrap();
+    let fail = FractionalThreshold::new_from_values(2, 3).unwrap();
+    let asset = Asset::new(
+        Pubkey::new_unique(),
+        pass,
+        fail,
+        pass,
+        fail,
+        pass,
+        fail,
+        pass,
+        fail,
+        2,
+        2,
+        2,
+        254,
+        253,
+    )
+    .unwrap();
+
+    // No limit until one is set.
+    assert!(asset.validate_proposal_deadline(0, i64::MAX).is_ok());
+
+    let asset = asset
+        .with_config_types(&[
+            ConfigType::MinimumTimelock(604_800),
+            ConfigType::MaximumProposalDuration(86_400),
+        ])
+        .unwrap();
+    assert_eq!(asset.minimum_timelock, 604_800);
+    assert!(asset.validate_proposal_deadline(100, 86_500).is_ok());
+    assert_anchor_error(
+        asset.validate_proposal_deadline(100, 86_501),
+        "ProposalDurationTooLong",
+        6101,
+        "Proposal deadline is further away than the maximum proposal duration",
+    );
+}
+
+#[test]
+fn executor_tip_is_an_asset_config_with_a_cap() {
+    let pass = FractionalThreshold::new_from_values(1, 2).unwrap();
+    let fail = FractionalThreshold::new_from_values(2, 3).unwrap();
+    let asset = Asset::new(
+        Pubkey::new_unique(),
+        pass,
+        fail,
+        pass,
+        fail,
+        pass,
+        fail,
+        pass,
+        fail,
+        2,
+        2,
+        2,
+        254,
+        253,
+    )
+    .unwrap();
+    assert_eq!(asset.executor_tip_lamports, 0);
+
+    let tipped = asset
+        .with_config_types(&[ConfigType::ExecutorTip(MAX_EXECUTOR_TIP_LAMPORTS)])
+        .unwrap();
+    assert_eq!(tipped.executor_tip_lamports, MAX_EXECUTOR_TIP_LAMPORTS);
+
+    assert_anchor_error(
+        asset.with_config_types(&[ConfigType::ExecutorTip(MAX_EXECUTOR_TIP_LAMPORTS + 1)]),
+        "ExecutorTipTooLarge",
+        6111,
+        "The executor tip is above the maximum",
+    );
+
+    // The group cannot spend an asset's lamports without the asset's approval.
+    let group = Group::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        pass,
+        fail,
+        pass,
+        fail,
+        pass,
+        fail,
+        2,
+        2,
+        100,
+        0,
+        3,
+        255,
+    )
+    .unwrap();
+    assert_anchor_error(
+        group.with_config_types(&[ConfigType::ExecutorTip(1)]),
+        "UnexpectedConfigChange",
+        6011,
+        "Configuration change does not match the expected",
+    );
+}
+
+#[test]
+fn config_change_timelock_detects_loosening_and_tightening() {
+    let timelocks = ConfigChangeTimelocks {
+        add_member: 10,
+        remove_member: 20,
+        threshold_loosening: 30,
+        threshold_tightening: 5,
+    };
+    let half = FractionalThreshold::new_from_values(1, 2).unwrap();
+    let two_quarters = FractionalThreshold::new_from_values(2, 4).unwrap();
+    let two_thirds = FractionalThreshold::new_from_values(2, 3).unwrap();
+    let three_quarters = FractionalThreshold::new_from_values(3, 4).unwrap();
+
+    // The same value written differently changes nothing.
+    assert_eq!(
+        timelocks.for_thresholds(&[(half, two_thirds)], &[(two_quarters, two_thirds)]),
+        0
+    );
+    // A higher passing threshold is tighter, a higher failing threshold is looser.
+    assert_eq!(
+        timelocks.for_thresholds(&[(half, two_thirds)], &[(two_thirds, two_thirds)]),
+        5
+    );
+    assert_eq!(
+        timelocks.for_thresholds(&[(half, two_thirds)], &[(half, three_quarters)]),
+        30
+    );
+    assert_eq!(
+        timelocks.for_thresholds(&[(two_thirds, two_thirds)], &[(half, two_thirds)]),
+        30
+    );
+
+    // Lowering a timelock takes as long as the timelock being lowered.
+    let mut proposed = timelocks;
+    proposed.remove_member = 0;
+    proposed.add_member = 100;
+    assert_eq!(timelocks.for_timelocks(&proposed), 20);
+
+    let mut group = Group::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        half,
+        two_thirds,
+        half,
+        two_thirds,
+        half,
+        two_thirds,
+        2,
+        2,
+        100,
+        15,
+        3,
+        255,
+    )
+    .unwrap();
+    group.set_config_change_timelocks(timelocks);
+
+    let remove_member = ConfigChange::RemoveGroupMember {
+        member: Pubkey::new_unique(),
+    };
+    assert_eq!(group.config_change_timelock(&remove_member).unwrap(), 20);
+    // `minimum_timelock` is the floor of every change.
+    let add_member = ConfigChange::AddGroupMember {
+        member: Pubkey::new_unique(),
+        weight: 1,
+        permissions: Permissions::builder().build(),
+        expires_at: None,
+    };
+    assert_eq!(group.config_change_timelock(&add_member).unwrap(), 15);
+    let loosen_and_tighten = ConfigChange::ChangeGroupConfig {
+        config_types: vec![
+            ConfigType::AddMember(two_thirds),
+            ConfigType::NotRemoveMember(three_quarters),
+        ],
+    };
+    assert_eq!(
+        group.config_change_timelock(&loosen_and_tighten).unwrap(),
+        30
+    );
+}
+
+#[test]
+fn proposal_bond_is_forfeited_when_cancelled_or_overwhelmingly_rejected() {
+    let half = FractionalThreshold::new_from_values(1, 2).unwrap();
+    let two_thirds = FractionalThreshold::new_from_values(2, 3).unwrap();
+    let group = Group::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        half,
+        two_thirds,
+        half,
+        two_thirds,
+        half,
+        two_thirds,
+        2,
+        2,
+        100,
+        0,
+        3,
+        255,
+    )
+    .unwrap();
+
+    let config = ProposalBondConfig {
+        mint: None,
+        amount: 1_000_000,
+        forfeit_threshold: FractionalThreshold::new_from_values(3, 4).unwrap(),
+    };
+    let bonded_group = group
+        .with_config_types(&[ConfigType::ProposalBond(Some(config))])
+        .unwrap();
+    assert!(bonded_group.proposal_bond == Some(config));
+    assert_anchor_error(
+        group.with_config_types(&[ConfigType::ProposalBond(Some(ProposalBondConfig {
+            amount: 0,
+            ..config
+        }))]),
+        "InvalidProposalBond",
+        6102,
+        "Invalid proposal bond",
+    );
+
+    let mut proposal = ConfigProposal {
+        propose_timestamp: 0,
+        proposal_deadline_timestamp: 100,
+        proposal_passed_timestamp: None,
+        proposal_index: 0,
+        for_weight: 1,
+        against_weight: 2,
+        group: Pubkey::new_unique(),
+        proposer: Pubkey::new_unique(),
+        proposal_seed: Pubkey::new_unique(),
+        timelock_offset: 0,
+        vote_count: 3,
+        target: ProposalTarget::Group,
+        config_change: ConfigChange::ChangeGroupConfig {
+            config_types: vec![ConfigType::MinimumVoteCount(3)],
+        },
+        compact_votes: None,
+        state: ProposalState::Failed,
+        account_bump: 255,
+        description: None,
+        assets: vec![],
+        bonded: true,
+        created_proposal_index: 0,
+    };
+    let bond = ProposalBond::new(
+        proposal.group,
+        Pubkey::new_unique(),
+        0,
+        proposal.proposer,
+        &config,
+        254,
+    );
+
+    // Two thirds against is a normal failure, three quarters is overwhelming.
+    assert!(!bond.is_forfeited_by_config_proposal(&proposal).unwrap());
+    proposal.against_weight = 3;
+    assert!(bond.is_forfeited_by_config_proposal(&proposal).unwrap());
+
+    proposal.against_weight = 0;
+    proposal.state = ProposalState::Cancelled;
+    assert!(bond.is_forfeited_by_config_proposal(&proposal).unwrap());
+}
+
+#[test]
+fn open_proposals_are_limited_per_group_and_per_member() {
+    let half = FractionalThreshold::new_from_values(1, 2).unwrap();
+    let two_thirds = FractionalThreshold::new_from_values(2, 3).unwrap();
+    let mut group = Group::new(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        half,
+        two_thirds,
+        half,
+        two_thirds,
+        half,
+        two_thirds,
+        2,
+        2,
+        100,
+        0,
+        3,
+        255,
+    )
+    .unwrap()
+    .with_config_types(&[
+        ConfigType::MaxOpenProposals(3),
+        ConfigType::MaxOpenProposalsPerMember(2),
+    ])
+    .unwrap();
+    let permissions = Permissions::try_from(0b0000_0101).unwrap();
+    let mut first = GroupMember::new(
+        Pubkey::new_unique(),
+        group.group_seed,
+        permissions,
+        1,
+        0,
+        255,
+        100,
+    )
+    .unwrap();
+    let mut second = GroupMember::new(
+        Pubkey::new_unique(),
+        group.group_seed,
+        permissions,
+        1,
+        1,
+        255,
+        100,
+    )
+    .unwrap();
+
+    group.open_proposal(&mut first).unwrap();
+    group.open_proposal(&mut first).unwrap();
+    assert_anchor_error(
+        group.open_proposal(&mut first),
+        "TooManyOpenProposals",
+        6105,
+        "Too many proposals are open in the group or for the proposer",
+    );
+
+    group.open_proposal(&mut second).unwrap();
+    assert_eq!(group.open_proposal_count, 3);
+    assert_anchor_error(
+        group.open_proposal(&mut second),
+        "TooManyOpenProposals",
+        6105,
+        "Too many proposals are open in the group or for the proposer",
+    );
+
+    group.close_open_proposal();
+    first.close_open_proposal();
+    group.open_proposal(&mut first).unwrap();
+    assert_eq!(first.open_proposal_count, 2);
+
+    // Key rotation releases the proposals opened with the old key.
+    assert_eq!(
+        first
+            .rotated(Pubkey::new_unique(), 254, 0)
+            .open_proposal_count,
+        0
+    );
+
+    // A group without limits only counts.
+    group
+        .apply_config_types(&[ConfigType::MaxOpenProposals(0)])
+        .unwrap();
+    group.open_proposal(&mut second).unwrap();
+    assert_eq!(group.open_proposal_count, 4);
+}
+
+#[test]
+fn schedule_runs_once_per_interval_until_the_last_run() {
+    let transfer = ScheduledTransfer::Lamports {
+        destination: Pubkey::new_unique(),
+        amount: 10,
+    };
+    let new_schedule = |transfer, interval, total_runs| {
+        Schedule::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            transfer,
+            interval,
+            total_runs,
+            100,
+            Pubkey::new_unique(),
+            255,
+            254,
+        )
+    };
+
+    for (interval, total_runs, amount) in [(0, 2, 10), (60, 0, 10), (60, 2, 0)] {
+        let transfer = ScheduledTransfer::Token {
+            destination: Pubkey::new_unique(),
+            amount,
+        };
+        assert_anchor_error(
+            new_schedule(transfer, interval, total_runs),
+            "InvalidSchedule",
+            6106,
+            "A schedule needs a non-zero interval, run count and amount",
+        );
+    }
+
+    let mut schedule = new_schedule(transfer, 60, 2).unwrap();
+    assert!(!schedule.is_due(99));
+    assert!(schedule.is_due(100));
+    assert!(!schedule.record_run().unwrap());
+    assert_eq!(schedule.next_run_timestamp, 160);
+
+    // A missed run can be caught up straight away.
+    assert!(schedule.is_due(500));
+    assert!(schedule.record_run().unwrap());
+    assert!(!schedule.is_due(500));
+}
+
+#[test]
+fn leading_option_needs_a_unique_plurality() {
+    let options = |weights: &[u64]| -> Vec<ProposalOption> {
+        weights
+            .iter()
+            .map(|&vote_weight| ProposalOption {
+                vote_weight,
+                ..ProposalOption::new(vec![[0; 32]])
+            })
+            .collect()
+    };
+
+    assert_eq!(leading_option(&options(&[1, 3, 2])), Some((1, 3)));
+    assert_eq!(leading_option(&options(&[4, 1, 1])), Some((0, 4)));
+    // A tie for the lead passes no option, a tie behind it does not matter.
+    assert_eq!(leading_option(&options(&[2, 2, 1])), None);
+    assert_eq!(leading_option(&options(&[1, 1, 2])), Some((2, 2)));
+    assert_eq!(leading_option(&options(&[0, 0])), None);
+
+    let mut option = ProposalOption::new(vec![[0; 32]]);
+    option.add_vote_weight(3);
+    option.sub_vote_weight(5);
+    assert_eq!(option.vote_weight, 0);
+}
+
+#[test]
+fn instruction_return_data_keeps_bounded_data() {
+    use anchor_lang::AnchorSerialize;
+
+    let program_id = Pubkey::new_unique();
+
+    let short = InstructionReturnData::new(program_id, &[1, 2, 3]);
+    assert_eq!(short.data, vec![1, 2, 3]);
+    assert_eq!(short.data_len, 3);
+    assert!(!short.is_truncated());
+    assert_eq!(short.try_to_vec().unwrap().len(), short.get_size());
+
+    let long_data = vec![7; MAX_RETURN_DATA_LEN + 10];
+    let long = InstructionReturnData::new(program_id, &long_data);
+    assert_eq!(long.data, long_data[..MAX_RETURN_DATA_LEN]);
+    assert_eq!(usize::from(long.data_len), MAX_RETURN_DATA_LEN + 10);
+    assert!(long.is_truncated());
+    assert_eq!(
+        long.try_to_vec().unwrap().len(),
+        InstructionReturnData::MAX_SIZE
+    );
+}
@@ -0,0 +1,241 @@
+#![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::{
+        CreateConfigProposalInstructionArgs, SubmitSignedVotesOnConfigProposalInstructionArgs,
+    },
+    ConfigChange, ConfigProposal, ConfigType, VoteChoice, VoteRecord,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{add_multisig_program, send_tx, setup_group};
+
+// Submit signed votes on config proposal: members vote without sending a transaction.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    Replayed,
+    WrongNonce,
+    MissingSignatures,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<(Vec<Instruction>, Vec<Keypair>, Pubkey, Vec<Pubkey>)> {
+        let group_setup = setup_group(svm)?;
+
+        let proposal_seed = Pubkey::new_unique();
+        let proposal = sdk::proposal_pda(&group_setup.group, &proposal_seed).address;
+        let create_args = CreateConfigProposalInstructionArgs {
+            proposal_seed,
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            config_change: ConfigChange::ChangeGroupConfig {
+                config_types: vec![ConfigType::MinimumVoteCount(1)],
+            },
+            compact_votes: false,
+            description: None,
+        };
+        let create_config_proposal = sdk::create_config_proposal(
+            create_args,
+            group_setup.group,
+            group_setup.payer.pubkey(),
+            None,
+        );
+        send_tx(svm, &group_setup.payer, vec![create_config_proposal], &[])?;
+
+        let proposal_account = svm.get_account(&proposal).expect("proposal account");
+        let proposal_index =
+            ConfigProposal::try_deserialize(&mut proposal_account.data.as_slice())?.proposal_index;
+        let nonce = match scenario {
+            Scenario::WrongNonce => proposal_index + 1,
+            _ => proposal_index,
+        };
+
+        // Two members sign off-chain, the payer submits their votes.
+        let signers = [&group_setup.members[0], &group_setup.members[1]];
+        let signed_messages = signers
+            .iter()
+            .map(|member| {
+                let message = sdk::signed_vote_message(
+                    group_setup.group,
+                    proposal,
+                    None,
+                    VoteChoice::For,
+                    nonce,
+                )?;
+                let signature = member.sign_message(&message);
+                Ok((member.pubkey(), <[u8; 64]>::from(signature), message))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let voters: Vec<Pubkey> = signers.iter().map(|member| member.pubkey()).collect();
+
+        let submit_signed_votes = sdk::submit_signed_votes_on_config_proposal(
+            SubmitSignedVotesOnConfigProposalInstructionArgs {
+                votes: vec![VoteChoice::For; voters.len()],
+            },
+            group_setup.group,
+            proposal,
+            None,
+            &voters,
+            group_setup.payer.pubkey(),
+        );
+        let ed25519_verify = sdk::ed25519_verify_instruction(&signed_messages);
+
+        if let Scenario::Replayed = scenario {
+            send_tx(
+                svm,
+                &group_setup.payer,
+                vec![ed25519_verify.clone(), submit_signed_votes.clone()],
+                &[],
+            )?;
+            svm.expire_blockhash();
+        }
+
+        let instructions = match scenario {
+            Scenario::MissingSignatures => vec![submit_signed_votes],
+            _ => vec![ed25519_verify, submit_signed_votes],
+        };
+
+        Ok((instructions, vec![group_setup.payer], proposal, voters))
+    }
+
+    pub fn with_default(
+        svm: &mut LiteSVM,
+    ) -> Result<(Vec<Instruction>, Vec<Keypair>, Pubkey, Vec<Pubkey>)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_replayed_votes(
+        svm: &mut LiteSVM,
+    ) -> Result<(Vec<Instruction>, Vec<Keypair>, Pubkey, Vec<Pubkey>)> {
+        Self::builder(svm, Scenario::Replayed)
+    }
+
+    pub fn with_wrong_nonce(
+        svm: &mut LiteSVM,
+    ) -> Result<(Vec<Instruction>, Vec<Keypair>, Pubkey, Vec<Pubkey>)> {
+        Self::builder(svm, Scenario::WrongNonce)
+    }
+
+    pub fn with_missing_signatures(
+        svm: &mut LiteSVM,
+    ) -> Result<(Vec<Instruction>, Vec<Keypair>, Pubkey, Vec<Pubkey>)> {
+        Self::builder(svm, Scenario::MissingSignatures)
+    }
+}
+
+#[test]
+fn test_submit_signed_votes_on_config_proposal_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_default(&mut svm);
+    let (instructions, signers, proposal, voters) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    let account = svm.get_account(&proposal).expect("proposal account");
+    let proposal_account =
+        ConfigProposal::try_deserialize(&mut account.data.as_slice()).expect("proposal data");
+    assert_eq!(proposal_account.vote_count, 2);
+
+    let group = proposal_account.group;
+    let vote_record = sdk::config_vote_record_pda(&group, &proposal, &voters[1]).address;
+    let account = svm.get_account(&vote_record).expect("vote record");
+    let vote_record =
+        VoteRecord::try_deserialize(&mut account.data.as_slice()).expect("vote record data");
+    assert_eq!(vote_record.voter, voters[1]);
+    assert!(vote_record.vote_choice == VoteChoice::For);
+}
+
+#[test]
+fn test_submit_signed_votes_on_config_proposal_fails_when_replayed() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_replayed_votes(&mut svm);
+    let (instructions, signers, _, _) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        1,
+        multisig::MultisigError::VoteAlreadyRecorded,
+    );
+}
+
+#[test]
+fn test_submit_signed_votes_on_config_proposal_fails_with_wrong_nonce() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_wrong_nonce(&mut svm);
+    let (instructions, signers, _, _) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        1,
+        multisig::MultisigError::InvalidSignedVote,
+    );
+}
+
+#[test]
+fn test_submit_signed_votes_on_config_proposal_fails_without_signatures() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_missing_signatures(&mut svm);
+    let (instructions, signers, _, _) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::InvalidSignedVote,
+    );
+}
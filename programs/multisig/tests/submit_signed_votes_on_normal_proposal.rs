@@ -0,0 +1,202 @@
+#![cfg(feature = "test-helpers")]
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::{
+        AssetVote, CreateNormalProposalInstructionArgs, CreateProposalTransactionInstructionArgs,
+        SubmitSignedVotesOnNormalProposalInstructionArgs,
+    },
+    AssetIndex, SerializableInstruction, VoteChoice,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{
+    add_multisig_program, create_token_account_at, read_normal_proposal, send_tx, setup_asset_mint,
+    setup_group, to_serializable,
+};
+
+// Submit signed votes on normal proposal: a member's first vote can be signed off-chain.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    VoteDoesNotMatchSignature,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<(Vec<Instruction>, Vec<Keypair>, Pubkey)> {
+        let group_setup = setup_group(svm)?;
+        let asset_setup = setup_asset_mint(svm, &group_setup)?;
+
+        let destination = Keypair::new();
+        create_token_account_at(
+            svm,
+            &destination.pubkey(),
+            &asset_setup.mint,
+            &group_setup.payer.pubkey(),
+            solana_sdk::program_option::COption::None,
+            spl_token::state::AccountState::Initialized,
+            solana_sdk::program_option::COption::None,
+        )?;
+
+        let mint_to_ix = spl_token::instruction::mint_to(
+            &spl_token::ID,
+            &asset_setup.mint,
+            &destination.pubkey(),
+            &asset_setup.asset_authority,
+            &[],
+            1,
+        )?;
+        let serializable: SerializableInstruction = to_serializable(&mint_to_ix);
+        let instruction_hashes = vec![sdk::serializable_instruction_hash(&serializable)?];
+
+        let proposal_seed = Pubkey::new_unique();
+        let create_normal_args = CreateNormalProposalInstructionArgs {
+            proposal_seed,
+            asset_keys: vec![asset_setup.mint],
+            asset_indices: vec![AssetIndex {
+                instruction_index: 0,
+                account_index: 0,
+            }],
+            authority_bumps: vec![
+                sdk::asset_authority_pda(&group_setup.group, &asset_setup.mint).bump,
+            ],
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            instruction_hashes,
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
+            compact_votes: false,
+            description: None,
+            options: vec![],
+        };
+
+        let create_normal_proposal = sdk::create_normal_proposal(
+            create_normal_args,
+            group_setup.group,
+            group_setup.payer.pubkey(),
+        );
+
+        let proposal = sdk::proposal_pda(&group_setup.group, &proposal_seed).address;
+        let raw_instructions = vec![sdk::serializable_instruction_bytes(&serializable)?];
+        let create_tx_args = CreateProposalTransactionInstructionArgs { raw_instructions };
+        let create_proposal_tx = sdk::create_proposal_transaction(
+            create_tx_args,
+            group_setup.group,
+            proposal_seed,
+            group_setup.payer.pubkey(),
+            &[asset_setup.mint],
+        );
+
+        let setup_ixs = vec![create_normal_proposal, create_proposal_tx];
+        send_tx(svm, &group_setup.payer, setup_ixs, &[])?;
+
+        // The payer is the asset's member, they sign and a relayer submits.
+        let voter = &group_setup.payer;
+
+        let nonce = read_normal_proposal(svm, proposal)?.proposal_index;
+        let message =
+            sdk::signed_vote_message(group_setup.group, proposal, Some(0), VoteChoice::For, nonce)?;
+        let signature = voter.sign_message(&message);
+        let ed25519_verify = sdk::ed25519_verify_instruction(&[(
+            voter.pubkey(),
+            <[u8; 64]>::from(signature),
+            message,
+        )]);
+
+        let relayer = Keypair::new();
+        svm.airdrop(&relayer.pubkey(), 1_000_000_000)
+            .map_err(|error| anyhow::anyhow!("airdrop failed: {:?}", error))?;
+
+        let submit_signed_votes = sdk::submit_signed_votes_on_normal_proposal(
+            SubmitSignedVotesOnNormalProposalInstructionArgs {
+                votes: vec![AssetVote {
+                    voting_asset_index: 0,
+                    vote: match scenario {
+                        Scenario::VoteDoesNotMatchSignature => VoteChoice::Against,
+                        Scenario::Default => VoteChoice::For,
+                    },
+                }],
+            },
+            group_setup.group,
+            proposal,
+            &[voter.pubkey()],
+            &[asset_setup.mint],
+            relayer.pubkey(),
+        );
+
+        Ok((
+            vec![ed25519_verify, submit_signed_votes],
+            vec![relayer],
+            proposal,
+        ))
+    }
+
+    pub fn with_default(svm: &mut LiteSVM) -> Result<(Vec<Instruction>, Vec<Keypair>, Pubkey)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_vote_not_matching_signature(
+        svm: &mut LiteSVM,
+    ) -> Result<(Vec<Instruction>, Vec<Keypair>, Pubkey)> {
+        Self::builder(svm, Scenario::VoteDoesNotMatchSignature)
+    }
+}
+
+#[test]
+fn test_submit_signed_votes_on_normal_proposal_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_default(&mut svm);
+    let (instructions, signers, proposal) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    let proposal = read_normal_proposal(&svm, proposal).expect("proposal");
+    assert_eq!(proposal.assets[0].vote_count, 1);
+    assert_eq!(proposal.assets[0].use_vote_weight, 1);
+}
+
+#[test]
+fn test_submit_signed_votes_on_normal_proposal_fails_when_vote_does_not_match_signature() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_vote_not_matching_signature(&mut svm);
+    let (instructions, signers, _) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        1,
+        multisig::MultisigError::InvalidSignedVote,
+    );
+}
@@ -0,0 +1,167 @@
+#![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::{CreateConfigProposalInstructionArgs, VoteOnConfigProposalInstructionArgs},
+    ConfigChange, ConfigProposal, ConfigType, MultisigError, ProposalDescription, VoteChoice,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{add_multisig_program, assert_multisig_instruction_error, send_tx, setup_group};
+
+// The proposer can change the description of a config proposal until the first vote.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    AfterFirstVote,
+    NotProposer,
+}
+
+fn description(title: &str, content: &[u8]) -> ProposalDescription {
+    ProposalDescription {
+        title: title.to_string(),
+        uri: "https://example.com/proposals/quorum.md".to_string(),
+        content_hash: sdk::proposal_content_hash(content),
+    }
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey)> {
+        let group_setup = setup_group(svm)?;
+
+        let proposal_seed = Pubkey::new_unique();
+        let proposal_pda = sdk::proposal_pda(&group_setup.group, &proposal_seed);
+        let create_args = CreateConfigProposalInstructionArgs {
+            proposal_seed,
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            config_change: ConfigChange::ChangeGroupConfig {
+                config_types: vec![ConfigType::MinimumVoteCount(1)],
+            },
+            compact_votes: false,
+            description: Some(description("Lower the quorum", b"draft")),
+        };
+        let create_config_proposal = sdk::create_config_proposal(
+            create_args,
+            group_setup.group,
+            group_setup.payer.pubkey(),
+            None,
+        );
+        send_tx(svm, &group_setup.payer, vec![create_config_proposal], &[])?;
+
+        if let Scenario::AfterFirstVote = scenario {
+            // The group needs two votes to pass, so one vote keeps the proposal open.
+            let vote = sdk::vote_on_config_proposal(
+                VoteOnConfigProposalInstructionArgs {
+                    vote: VoteChoice::For,
+                },
+                group_setup.group,
+                proposal_pda.address,
+                group_setup.payer.pubkey(),
+                None,
+            );
+            send_tx(svm, &group_setup.payer, vec![vote], &[])?;
+        }
+
+        let [member, ..] = group_setup.members;
+        let signer = match scenario {
+            Scenario::NotProposer => member,
+            Scenario::Default | Scenario::AfterFirstVote => group_setup.payer,
+        };
+        let update = sdk::update_config_proposal_description(
+            Some(description("Lower the quorum to one vote", b"final")),
+            group_setup.group,
+            proposal_pda.address,
+            signer.pubkey(),
+        );
+
+        Ok(([update], vec![signer], proposal_pda.address))
+    }
+
+    pub fn with_default(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>, Pubkey)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_after_first_vote(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _) = Self::builder(svm, Scenario::AfterFirstVote)?;
+        Ok((ix, signers))
+    }
+
+    pub fn with_not_proposer(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let (ix, signers, _) = Self::builder(svm, Scenario::NotProposer)?;
+        Ok((ix, signers))
+    }
+}
+
+#[test]
+fn test_update_config_proposal_description_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers, proposal) =
+        TestSetup::with_default(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    common::assert_transaction_success(svm.send_transaction(transaction));
+
+    let proposal_account = svm.get_account(&proposal).expect("config proposal");
+    let proposal = ConfigProposal::try_deserialize(&mut proposal_account.data.as_slice())
+        .expect("deserialize config proposal");
+    let description = proposal.description.expect("description");
+    assert_eq!(description.title, "Lower the quorum to one vote");
+    assert!(sdk::verify_proposal_description(&description, b"final"));
+}
+
+#[test]
+fn test_update_config_proposal_description_fails_after_first_vote() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_after_first_vote(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::ProposalDescriptionLocked,
+    );
+}
+
+#[test]
+fn test_update_config_proposal_description_fails_for_other_signer() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let (instructions, signers) =
+        TestSetup::with_not_proposer(&mut svm).expect("Failed to create instruction");
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&signers[0].pubkey()),
+        &signers,
+        svm.latest_blockhash(),
+    );
+    assert_multisig_instruction_error(
+        svm.send_transaction(transaction),
+        0,
+        MultisigError::InvalidProposer,
+    );
+}
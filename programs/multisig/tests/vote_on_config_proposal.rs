@@ -1,9 +1,13 @@
 #![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
 use anyhow::Result;
 use litesvm::LiteSVM;
 use multisig::{
-    instructions::{CreateConfigProposalInstructionArgs, VoteOnConfigProposalInstructionArgs},
-    ConfigChange, ConfigType, ProposalState, VoteChoice,
+    instructions::{
+        CreateConfigProposalInstructionArgs, DelegateVoteInstructionArgs,
+        VoteOnConfigProposalInstructionArgs,
+    },
+    ConfigChange, ConfigType, ProposalState, VoteChoice, VoteRecord,
 };
 use multisig_sdk as sdk;
 use solana_sdk::{instruction::Instruction, signer::Signer, transaction::Transaction};
@@ -11,7 +15,7 @@ use solana_sdk::{instruction::Instruction, signer::Signer, transaction::Transact
 mod common;
 use common::{
     add_multisig_program, send_tx, set_config_proposal_deadline, set_config_proposal_state,
-    set_group_member_weight, set_group_stale_after_index, setup_group,
+    set_group_member_expires_at, set_group_member_weight, set_group_stale_after_index, setup_group,
 };
 
 // Vote on config proposal: open succeeds; various invalid states reject.
@@ -23,6 +27,9 @@ enum Scenario {
     ExpiredProposal,
     StaleProposal,
     ZeroWeightVoter,
+    ExpiredMember,
+    DelegatedVote,
+    DelegatorAlreadyVoted,
 }
 
 impl TestSetup {
@@ -32,8 +39,10 @@ impl TestSetup {
         solana_sdk::pubkey::Pubkey,
         solana_sdk::pubkey::Pubkey,
         solana_sdk::signature::Keypair,
+        solana_sdk::signature::Keypair,
     )> {
         let group_setup = setup_group(svm)?;
+        let [delegator, _, _, _] = group_setup.members;
 
         let proposal_seed = solana_sdk::pubkey::Pubkey::new_unique();
         let proposal_pda = sdk::proposal_pda(&group_setup.group, &proposal_seed);
@@ -42,8 +51,10 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             config_change: ConfigChange::ChangeGroupConfig {
-                config_type: ConfigType::MinimumVoteCount(1),
+                config_types: vec![ConfigType::MinimumVoteCount(1)],
             },
+            compact_votes: false,
+            description: None,
         };
         let create_config_proposal = sdk::create_config_proposal(
             create_args,
@@ -53,14 +64,19 @@ impl TestSetup {
         );
         send_tx(svm, &group_setup.payer, vec![create_config_proposal], &[])?;
 
-        Ok((group_setup.group, proposal_pda.address, group_setup.payer))
+        Ok((
+            group_setup.group,
+            proposal_pda.address,
+            group_setup.payer,
+            delegator,
+        ))
     }
 
     fn builder(
         svm: &mut LiteSVM,
         scenario: Scenario,
     ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
-        let (group, proposal, payer) = Self::build_open_proposal(svm)?;
+        let (group, proposal, payer, delegator) = Self::build_open_proposal(svm)?;
 
         match scenario {
             Scenario::ProposalPassed => {
@@ -77,15 +93,50 @@ impl TestSetup {
             Scenario::ZeroWeightVoter => {
                 set_group_member_weight(svm, group, payer.pubkey(), 0)?;
             }
+            Scenario::ExpiredMember => {
+                set_group_member_expires_at(svm, group, payer.pubkey(), Some(0))?;
+            }
+            Scenario::DelegatedVote | Scenario::DelegatorAlreadyVoted => {
+                let delegate_args = DelegateVoteInstructionArgs {
+                    delegate: payer.pubkey(),
+                    asset: None,
+                    expiry_timestamp: None,
+                };
+                let delegate_vote = sdk::delegate_vote(delegate_args, group, delegator.pubkey());
+                send_tx(svm, &payer, vec![delegate_vote], &[&delegator])?;
+            }
             Scenario::Default => {}
         }
 
+        if matches!(scenario, Scenario::DelegatorAlreadyVoted) {
+            let vote_args = VoteOnConfigProposalInstructionArgs {
+                vote: VoteChoice::Against,
+            };
+            let delegator_vote =
+                sdk::vote_on_config_proposal(vote_args, group, proposal, delegator.pubkey(), None);
+            send_tx(svm, &payer, vec![delegator_vote], &[&delegator])?;
+        }
+
         let vote_args = VoteOnConfigProposalInstructionArgs {
             vote: VoteChoice::For,
         };
-        let vote_on_config =
+        let mut vote_on_config =
             sdk::vote_on_config_proposal(vote_args, group, proposal, payer.pubkey(), None);
 
+        if matches!(
+            scenario,
+            Scenario::DelegatedVote | Scenario::DelegatorAlreadyVoted
+        ) {
+            vote_on_config
+                .accounts
+                .extend(sdk::config_delegated_vote_accounts(
+                    &group,
+                    &proposal,
+                    None,
+                    &[delegator.pubkey()],
+                ));
+        }
+
         Ok(([vote_on_config], vec![payer]))
     }
 
@@ -118,6 +169,24 @@ impl TestSetup {
     ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
         Self::builder(svm, Scenario::ZeroWeightVoter)
     }
+
+    pub fn with_expired_member(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::ExpiredMember)
+    }
+
+    pub fn with_delegated_vote(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::DelegatedVote)
+    }
+
+    pub fn with_delegator_already_voted(
+        svm: &mut LiteSVM,
+    ) -> Result<([Instruction; 1], Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::DelegatorAlreadyVoted)
+    }
 }
 
 #[test]
@@ -223,3 +292,82 @@ fn test_vote_on_config_proposal_fails_with_zero_weight_voter() {
         multisig::MultisigError::UnauthorizedVoter,
     );
 }
+
+#[test]
+fn test_vote_on_config_proposal_fails_when_membership_expired() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_expired_member(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::MembershipExpired,
+    );
+}
+
+#[test]
+fn test_vote_on_config_proposal_casts_delegated_vote() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_delegated_vote(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    // The last account is the delegator's vote record.
+    let delegator_vote_record = instructions[0].accounts.last().expect("vote record").pubkey;
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    let account = svm
+        .get_account(&delegator_vote_record)
+        .expect("delegator vote record");
+    let vote_record =
+        VoteRecord::try_deserialize(&mut account.data.as_slice()).expect("vote record data");
+    assert!(vote_record.vote_choice == VoteChoice::For);
+    assert_eq!(vote_record.delegate, Some(payer));
+}
+
+#[test]
+fn test_vote_on_config_proposal_fails_when_delegator_already_voted() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_delegator_already_voted(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::DelegatorAlreadyVoted,
+    );
+}
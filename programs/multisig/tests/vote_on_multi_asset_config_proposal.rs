@@ -0,0 +1,297 @@
+#![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::{
+        CreateConfigProposalInstructionArgs, VoteOnConfigProposalInstructionArgs,
+        VoteOnMultiAssetConfigProposalInstructionArgs,
+    },
+    Asset, ConfigChange, ConfigProposal, ConfigType, ProposalState, VoteChoice,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    transaction::Transaction,
+};
+
+mod common;
+use common::{
+    add_multisig_program, send_tx, set_group_paused, setup_asset_mint, setup_group, GroupSetup,
+};
+
+// Vote on one asset of a config proposal targeting several assets.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    WrongAsset,
+    SingleTallyVote,
+    GroupPaused,
+}
+
+struct MultiAssetProposal {
+    group_setup: GroupSetup,
+    proposal: Pubkey,
+    asset_addresses: Vec<Pubkey>,
+}
+
+impl TestSetup {
+    fn build_open_proposal(svm: &mut LiteSVM) -> Result<MultiAssetProposal> {
+        let group_setup = setup_group(svm)?;
+        let mut asset_addresses = vec![
+            setup_asset_mint(svm, &group_setup)?.asset_address,
+            setup_asset_mint(svm, &group_setup)?.asset_address,
+        ];
+        asset_addresses.sort();
+
+        let proposal_seed = Pubkey::new_unique();
+        let proposal = sdk::proposal_pda(&group_setup.group, &proposal_seed).address;
+        let create_args = CreateConfigProposalInstructionArgs {
+            proposal_seed,
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            config_change: ConfigChange::ChangeAssetConfig {
+                config_types: vec![ConfigType::MinimumVoteCount(3)],
+            },
+            compact_votes: false,
+            description: None,
+        };
+        let create_config_proposal = sdk::create_multi_asset_config_proposal(
+            create_args,
+            group_setup.group,
+            group_setup.payer.pubkey(),
+            &asset_addresses,
+        );
+        send_tx(svm, &group_setup.payer, vec![create_config_proposal], &[])?;
+
+        Ok(MultiAssetProposal {
+            group_setup,
+            proposal,
+            asset_addresses,
+        })
+    }
+
+    fn builder(svm: &mut LiteSVM, scenario: Scenario) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        let MultiAssetProposal {
+            group_setup,
+            proposal,
+            asset_addresses,
+        } = Self::build_open_proposal(svm)?;
+        let group = group_setup.group;
+        let payer = group_setup.payer;
+
+        let vote_args = VoteOnMultiAssetConfigProposalInstructionArgs {
+            voting_asset_index: 0,
+            vote: VoteChoice::For,
+        };
+
+        let vote = match scenario {
+            Scenario::WrongAsset => sdk::vote_on_multi_asset_config_proposal(
+                vote_args,
+                group,
+                proposal,
+                asset_addresses[1],
+                payer.pubkey(),
+            ),
+            Scenario::SingleTallyVote => sdk::vote_on_config_proposal(
+                VoteOnConfigProposalInstructionArgs {
+                    vote: VoteChoice::For,
+                },
+                group,
+                proposal,
+                payer.pubkey(),
+                Some(asset_addresses[0]),
+            ),
+            Scenario::GroupPaused | Scenario::Default => {
+                if matches!(scenario, Scenario::GroupPaused) {
+                    set_group_paused(
+                        svm,
+                        group,
+                        true,
+                        Pubkey::new_unique(),
+                        Pubkey::new_unique(),
+                        Pubkey::new_unique(),
+                    )?;
+                }
+                sdk::vote_on_multi_asset_config_proposal(
+                    vote_args,
+                    group,
+                    proposal,
+                    asset_addresses[0],
+                    payer.pubkey(),
+                )
+            }
+        };
+
+        Ok(([vote], vec![payer]))
+    }
+
+    pub fn with_default(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_wrong_asset(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        Self::builder(svm, Scenario::WrongAsset)
+    }
+
+    pub fn with_single_tally_vote(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        Self::builder(svm, Scenario::SingleTallyVote)
+    }
+
+    pub fn with_group_paused(svm: &mut LiteSVM) -> Result<([Instruction; 1], Vec<Keypair>)> {
+        Self::builder(svm, Scenario::GroupPaused)
+    }
+}
+
+fn read_config_proposal(svm: &LiteSVM, proposal: Pubkey) -> ConfigProposal {
+    let account = svm.get_account(&proposal).expect("proposal");
+    ConfigProposal::try_deserialize(&mut account.data.as_slice()).expect("proposal data")
+}
+
+#[test]
+fn test_vote_on_multi_asset_config_proposal_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_default(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let proposal = instructions[0].accounts[1].pubkey;
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    let proposal = read_config_proposal(&svm, proposal);
+    assert_eq!(proposal.assets[0].vote_count, 1);
+    assert_eq!(proposal.assets[0].for_weight, 1);
+    assert_eq!(proposal.assets[1].vote_count, 0);
+    assert_eq!(proposal.vote_count, 0);
+}
+
+#[test]
+fn test_vote_on_multi_asset_config_proposal_fails_with_wrong_asset() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_wrong_asset(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(result, 0, multisig::MultisigError::InvalidAsset);
+}
+
+#[test]
+fn test_vote_on_config_proposal_fails_for_multi_asset_proposal() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_single_tally_vote(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::PerAssetVoteRequired,
+    );
+}
+
+#[test]
+fn test_vote_on_multi_asset_config_proposal_fails_when_paused() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_group_paused(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(result, 0, multisig::MultisigError::GroupPaused);
+}
+
+#[test]
+fn test_multi_asset_config_proposal_applies_each_asset_separately() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let MultiAssetProposal {
+        group_setup,
+        proposal,
+        asset_addresses,
+    } = TestSetup::build_open_proposal(&mut svm).expect("proposal");
+    let group = group_setup.group;
+    let payer = &group_setup.payer;
+    let voter = &group_setup.members[0];
+
+    // Both assets pass, but only the first asset applies the change for now.
+    for (index, asset_address) in asset_addresses.iter().enumerate() {
+        for (voter_key, signers) in [(payer.pubkey(), vec![]), (voter.pubkey(), vec![voter])] {
+            let vote = sdk::vote_on_multi_asset_config_proposal(
+                VoteOnMultiAssetConfigProposalInstructionArgs {
+                    voting_asset_index: index as u8,
+                    vote: VoteChoice::For,
+                },
+                group,
+                proposal,
+                *asset_address,
+                voter_key,
+            );
+            send_tx(&mut svm, payer, vec![vote], &signers).expect("vote");
+        }
+    }
+
+    let change_asset_config =
+        sdk::change_asset_config(group, asset_addresses[0], proposal, payer.pubkey());
+    send_tx(&mut svm, payer, vec![change_asset_config], &[]).expect("apply first asset");
+
+    let asset_account = svm
+        .get_account(&sdk::asset_pda(&group, &asset_addresses[0]).address)
+        .expect("asset");
+    let asset = Asset::try_deserialize(&mut asset_account.data.as_slice()).expect("asset data");
+    assert_eq!(asset.minimum_vote_count, 3);
+
+    let proposal_state = read_config_proposal(&svm, proposal);
+    assert!(proposal_state.state == ProposalState::Open);
+    assert!(proposal_state.assets[0].state == ProposalState::Executed);
+    assert!(proposal_state.assets[1].state == ProposalState::Passed);
+
+    // The last asset applying the change closes the proposal.
+    let change_asset_config =
+        sdk::change_asset_config(group, asset_addresses[1], proposal, payer.pubkey());
+    send_tx(&mut svm, payer, vec![change_asset_config], &[]).expect("apply second asset");
+
+    assert!(svm
+        .get_account(&proposal)
+        .is_none_or(|account| account.lamports == 0));
+}
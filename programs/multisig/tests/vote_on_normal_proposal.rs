@@ -1,21 +1,22 @@
 #![cfg(feature = "test-helpers")]
+use anchor_lang::AccountDeserialize;
 use anyhow::Result;
 use litesvm::LiteSVM;
 use multisig::{
     instructions::{
         CreateNormalProposalInstructionArgs, CreateProposalTransactionInstructionArgs,
-        VoteOnNormalProposalInstructionArgs,
+        DelegateVoteInstructionArgs, VoteOnNormalProposalInstructionArgs,
     },
-    AssetIndex, ProposalState, SerializableInstruction, VoteChoice,
+    AssetIndex, ProposalState, SerializableInstruction, VoteChoice, VoteRecord,
 };
 use multisig_sdk as sdk;
 use solana_sdk::{instruction::Instruction, signer::Signer, transaction::Transaction};
 
 mod common;
 use common::{
-    add_multisig_program, create_token_account_at, send_tx, set_group_stale_after_index,
-    set_normal_proposal_deadline, set_normal_proposal_state, setup_asset_mint, setup_group,
-    to_serializable,
+    add_multisig_program, create_token_account_at, read_normal_proposal, send_tx,
+    set_asset_minimum_vote_count, set_group_stale_after_index, set_normal_proposal_deadline,
+    set_normal_proposal_state, setup_asset_mint, setup_group, to_serializable,
 };
 
 // Vote on normal proposal should reject invalid asset index.
@@ -27,6 +28,10 @@ enum Scenario {
     ProposalNotOpen,
     ExpiredProposal,
     StaleProposal,
+    DelegatedAssetVote,
+    DelegatorVotedFirst,
+    DelegatorOverridesDelegate,
+    RevokedDelegation,
 }
 
 impl TestSetup {
@@ -73,6 +78,13 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             instruction_hashes,
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
+            compact_votes: false,
+            description: None,
+            options: vec![],
         };
 
         let create_normal_proposal = sdk::create_normal_proposal(
@@ -101,7 +113,7 @@ impl TestSetup {
             voting_asset_index,
             vote: VoteChoice::For,
         };
-        let vote_on_proposal = sdk::vote_on_normal_proposal(
+        let mut vote_on_proposal = sdk::vote_on_normal_proposal(
             vote_args,
             group_setup.group,
             proposal,
@@ -123,9 +135,81 @@ impl TestSetup {
             Scenario::StaleProposal => {
                 set_group_stale_after_index(svm, group_setup.group, u64::MAX)?;
             }
+            Scenario::DelegatedAssetVote
+            | Scenario::DelegatorVotedFirst
+            | Scenario::DelegatorOverridesDelegate
+            | Scenario::RevokedDelegation => {
+                let delegator = &group_setup.members[0];
+                let delegate_args = DelegateVoteInstructionArgs {
+                    delegate: group_setup.payer.pubkey(),
+                    asset: Some(asset_setup.mint),
+                    expiry_timestamp: Some(1000),
+                };
+                let delegate_vote =
+                    sdk::delegate_vote(delegate_args, group_setup.group, delegator.pubkey());
+                send_tx(svm, &group_setup.payer, vec![delegate_vote], &[delegator])?;
+
+                match scenario {
+                    Scenario::DelegatorVotedFirst => {
+                        let own_vote = sdk::vote_on_normal_proposal(
+                            VoteOnNormalProposalInstructionArgs {
+                                voting_asset_index,
+                                vote: VoteChoice::For,
+                            },
+                            group_setup.group,
+                            proposal,
+                            asset_setup.mint,
+                            delegator.pubkey(),
+                        );
+                        send_tx(svm, &group_setup.payer, vec![own_vote], &[delegator])?;
+                    }
+                    // A third vote is needed, so the proposal is still open for the delegator.
+                    Scenario::DelegatorOverridesDelegate => {
+                        set_asset_minimum_vote_count(svm, asset_setup.asset, 3)?;
+                    }
+                    Scenario::RevokedDelegation => {
+                        let revoke = sdk::revoke_delegation(
+                            group_setup.group,
+                            delegator.pubkey(),
+                            Some(asset_setup.mint),
+                        );
+                        send_tx(svm, &group_setup.payer, vec![revoke], &[delegator])?;
+                    }
+                    _ => {}
+                }
+
+                vote_on_proposal
+                    .accounts
+                    .extend(sdk::normal_delegated_vote_accounts(
+                        &group_setup.group,
+                        &proposal,
+                        &asset_setup.mint,
+                        voting_asset_index,
+                        &[delegator.pubkey()],
+                    ));
+            }
             _ => {}
         }
 
+        // The delegator votes against in the same transaction, after their delegate.
+        if let Scenario::DelegatorOverridesDelegate = scenario {
+            let [delegator, ..] = group_setup.members;
+            let own_vote = sdk::vote_on_normal_proposal(
+                VoteOnNormalProposalInstructionArgs {
+                    voting_asset_index,
+                    vote: VoteChoice::Against,
+                },
+                group_setup.group,
+                proposal,
+                asset_setup.mint,
+                delegator.pubkey(),
+            );
+            return Ok((
+                vec![vote_on_proposal, own_vote],
+                vec![group_setup.payer, delegator],
+            ));
+        }
+
         Ok((vec![vote_on_proposal], vec![group_setup.payer]))
     }
 
@@ -184,6 +268,13 @@ impl TestSetup {
             timelock_offset: 0,
             proposal_deadline_timestamp: 1000,
             instruction_hashes,
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: None,
+            compact_votes: false,
+            description: None,
+            options: vec![],
         };
 
         let create_normal_proposal = sdk::create_normal_proposal(
@@ -221,6 +312,30 @@ impl TestSetup {
         Self::builder(svm, Scenario::ExpiredProposal)
     }
 
+    pub fn with_delegated_asset_vote(
+        svm: &mut LiteSVM,
+    ) -> Result<(Vec<Instruction>, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::DelegatedAssetVote)
+    }
+
+    pub fn with_delegator_voted_first(
+        svm: &mut LiteSVM,
+    ) -> Result<(Vec<Instruction>, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::DelegatorVotedFirst)
+    }
+
+    pub fn with_delegator_overriding_delegate(
+        svm: &mut LiteSVM,
+    ) -> Result<(Vec<Instruction>, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::DelegatorOverridesDelegate)
+    }
+
+    pub fn with_revoked_delegation(
+        svm: &mut LiteSVM,
+    ) -> Result<(Vec<Instruction>, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::RevokedDelegation)
+    }
+
     pub fn with_stale_proposal(
         svm: &mut LiteSVM,
     ) -> Result<(Vec<Instruction>, Vec<solana_sdk::signature::Keypair>)> {
@@ -356,3 +471,119 @@ fn test_vote_on_normal_proposal_fails_when_stale() {
     let result = svm.send_transaction(transaction);
     common::assert_multisig_instruction_error(result, 0, multisig::MultisigError::ProposalStale);
 }
+
+#[test]
+fn test_vote_on_normal_proposal_casts_delegated_asset_vote() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_delegated_asset_vote(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let proposal = instructions[0].accounts[1].pubkey;
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    // The delegate's own weight and the delegator's weight are both counted for.
+    let proposal = read_normal_proposal(&svm, proposal).expect("read normal proposal");
+    assert_eq!(proposal.assets[0].use_vote_weight, 2);
+    assert_eq!(proposal.assets[0].not_use_vote_weight, 0);
+    assert_eq!(proposal.assets[0].vote_count, 2);
+}
+
+#[test]
+fn test_vote_on_normal_proposal_fails_for_delegator_who_already_voted() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_delegator_voted_first(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::DelegatorAlreadyVoted,
+    );
+}
+
+#[test]
+fn test_vote_on_normal_proposal_delegator_overrides_delegated_vote() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_delegator_overriding_delegate(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let group = instructions[1].accounts[0].pubkey;
+    let proposal = instructions[1].accounts[1].pubkey;
+    let vote_record = instructions[1].accounts[6].pubkey;
+    let payer = signers[0].pubkey();
+    let delegator = signers[1].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    // The delegator's weight moves from for to against, without counting a new vote.
+    let proposal_state = read_normal_proposal(&svm, proposal).expect("read normal proposal");
+    assert!(proposal_state.state == ProposalState::Open);
+    assert_eq!(proposal_state.assets[0].use_vote_weight, 1);
+    assert_eq!(proposal_state.assets[0].not_use_vote_weight, 1);
+    assert_eq!(proposal_state.assets[0].vote_count, 2);
+
+    assert_eq!(
+        vote_record,
+        common::get_vote_record(&group, &proposal, &delegator, Some(0))
+    );
+    let vote_record_account = svm.get_account(&vote_record).expect("vote record");
+    let vote_record = VoteRecord::try_deserialize(&mut vote_record_account.data.as_slice())
+        .expect("deserialize vote record");
+    assert!(vote_record.vote_choice == VoteChoice::Against);
+    assert!(vote_record.delegate.is_none());
+}
+
+#[test]
+fn test_vote_on_normal_proposal_fails_with_revoked_delegation() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_revoked_delegation(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::InvalidDelegation,
+    );
+}
@@ -0,0 +1,164 @@
+#![cfg(feature = "test-helpers")]
+use anyhow::Result;
+use litesvm::LiteSVM;
+use multisig::{
+    instructions::{
+        CreateNormalProposalInstructionArgs, VoteOnNormalProposalGroupApprovalInstructionArgs,
+    },
+    AssetIndex, ConfigChange, ConfigType, ProposalAssetThresholdState, ProposalState,
+    SerializableInstruction, VoteChoice,
+};
+use multisig_sdk as sdk;
+use solana_sdk::{instruction::Instruction, signer::Signer, transaction::Transaction};
+
+mod common;
+use common::{
+    add_multisig_program, read_normal_proposal, send_tx, setup_asset_mint, setup_group,
+    to_serializable,
+};
+
+// Group approval votes are only accepted on proposals that carry a group config change.
+struct TestSetup {}
+
+enum Scenario {
+    Default,
+    NoGroupConfigChange,
+}
+
+impl TestSetup {
+    fn builder(
+        svm: &mut LiteSVM,
+        scenario: Scenario,
+    ) -> Result<(Vec<Instruction>, Vec<solana_sdk::signature::Keypair>)> {
+        let group_setup = setup_group(svm)?;
+        let asset_setup = setup_asset_mint(svm, &group_setup)?;
+
+        let mint_to_ix = spl_token::instruction::mint_to(
+            &spl_token::ID,
+            &asset_setup.mint,
+            &group_setup.payer.pubkey(),
+            &asset_setup.asset_authority,
+            &[],
+            1,
+        )?;
+        let serializable: SerializableInstruction = to_serializable(&mint_to_ix);
+
+        let proposal_seed = solana_sdk::pubkey::Pubkey::new_unique();
+        let proposal = sdk::proposal_pda(&group_setup.group, &proposal_seed).address;
+
+        let create_args = CreateNormalProposalInstructionArgs {
+            proposal_seed,
+            asset_keys: vec![asset_setup.mint],
+            asset_indices: vec![AssetIndex {
+                instruction_index: 0,
+                account_index: 0,
+            }],
+            authority_bumps: vec![
+                sdk::asset_authority_pda(&group_setup.group, &asset_setup.mint).bump,
+            ],
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 1000,
+            instruction_hashes: vec![sdk::serializable_instruction_hash(&serializable)?],
+            sequential_execution: false,
+            balance_guards: vec![],
+            preconditions: vec![],
+            group_config_change: match scenario {
+                Scenario::NoGroupConfigChange => None,
+                _ => Some(ConfigChange::ChangeGroupConfig {
+                    config_types: vec![ConfigType::MinimumTimelock(5)],
+                }),
+            },
+            compact_votes: false,
+            description: None,
+            options: vec![],
+        };
+
+        let create_normal =
+            sdk::create_normal_proposal(create_args, group_setup.group, group_setup.payer.pubkey());
+        send_tx(svm, &group_setup.payer, vec![create_normal], &[])?;
+
+        // The group requires two votes, so both the payer and a second member vote for.
+        let voters = [group_setup.payer.pubkey(), group_setup.members[0].pubkey()];
+        let votes = voters
+            .iter()
+            .map(|voter| {
+                sdk::vote_on_normal_proposal_group_approval(
+                    VoteOnNormalProposalGroupApprovalInstructionArgs {
+                        vote: VoteChoice::For,
+                    },
+                    group_setup.group,
+                    proposal,
+                    *voter,
+                )
+            })
+            .collect();
+
+        let [member, ..] = group_setup.members;
+        Ok((votes, vec![group_setup.payer, member]))
+    }
+
+    pub fn with_default(
+        svm: &mut LiteSVM,
+    ) -> Result<(Vec<Instruction>, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::Default)
+    }
+
+    pub fn with_no_group_config_change(
+        svm: &mut LiteSVM,
+    ) -> Result<(Vec<Instruction>, Vec<solana_sdk::signature::Keypair>)> {
+        Self::builder(svm, Scenario::NoGroupConfigChange)
+    }
+}
+
+#[test]
+fn test_vote_on_normal_proposal_group_approval_success() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_default(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let proposal = instructions[0].accounts[1].pubkey;
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_transaction_success(result);
+
+    // The assets have not voted yet, so the proposal stays open.
+    let proposal_account = read_normal_proposal(&svm, proposal).expect("proposal");
+    let group_approval = proposal_account.group_approval.expect("group approval");
+    assert!(group_approval.threshold_state == ProposalAssetThresholdState::UseThresholdReached);
+    assert_eq!(group_approval.vote_count, 2);
+    assert!(proposal_account.state == ProposalState::Open);
+}
+
+#[test]
+fn test_vote_on_normal_proposal_group_approval_fails_without_group_config_change() {
+    let mut svm = LiteSVM::new();
+    add_multisig_program(&mut svm).expect("program load");
+
+    let result = TestSetup::with_no_group_config_change(&mut svm);
+    let (instructions, signers) = match result {
+        Ok(result) => result,
+        Err(error) => panic!("Failed to create instruction: {}", error),
+    };
+
+    let payer = signers[0].pubkey();
+    let recent_blockhash = svm.latest_blockhash();
+    let transaction =
+        Transaction::new_signed_with_payer(&instructions, Some(&payer), &signers, recent_blockhash);
+
+    let result = svm.send_transaction(transaction);
+    common::assert_multisig_instruction_error(
+        result,
+        0,
+        multisig::MultisigError::GroupApprovalNotRequired,
+    );
+}
@@ -1,18 +1,32 @@
-use anchor_lang::{solana_program::hash, AnchorSerialize, InstructionData};
+use anchor_lang::{solana_program::hash, AccountDeserialize, AnchorSerialize, InstructionData};
 use multisig::{
     instruction as ix_data,
     instructions::{
         AddAssetMemberInstructionArgs, AddAssetMintInstructionArgs, AddAssetTokenInstructionArgs,
         AddGroupMemberInstructionArgs, AddMemberInResetModeArgs,
-        CloseNormalVoteRecordInstructionArgs, CreateConfigProposalInstructionArgs,
-        CreateEmergencyResetProposalArgs, CreateGroupInstructionArgs,
-        CreateNormalProposalInstructionArgs, CreateProposalTransactionInstructionArgs,
-        ExitPauseModeArgs, VoteOnConfigProposalInstructionArgs, VoteOnEmergencyResetArgs,
-        VoteOnNormalProposalInstructionArgs,
+        BatchVoteOnNormalProposalInstructionArgs, CloseNormalVoteRecordInstructionArgs,
+        CompactVoteOnConfigProposalInstructionArgs, CompactVoteOnNormalProposalInstructionArgs,
+        CreateConfigProposalInstructionArgs, CreateEmergencyResetProposalArgs,
+        CreateGroupInstructionArgs, CreateNormalProposalInstructionArgs,
+        CreateProposalOptionTransactionInstructionArgs, CreateProposalTransactionInstructionArgs,
+        CreateScheduleInstructionArgs, DelegateVoteInstructionArgs,
+        ExecuteProposalTransactionInstructionArgs, ExitPauseModeArgs,
+        SetMemberMetadataInstructionArgs, SetProposalMetadataInstructionArgs,
+        SubmitSignedVotesOnConfigProposalInstructionArgs,
+        SubmitSignedVotesOnNormalProposalInstructionArgs,
+        UpdateConfigProposalDescriptionInstructionArgs,
+        UpdateNormalProposalDescriptionInstructionArgs, VoteOnConfigProposalInstructionArgs,
+        VoteOnEmergencyResetArgs, VoteOnMultiAssetConfigProposalInstructionArgs,
+        VoteOnNormalProposalGroupApprovalInstructionArgs, VoteOnNormalProposalInstructionArgs,
+        VoteOnProposalOptionInstructionArgs,
     },
-    SerializableInstruction,
+    BalanceGuardKind, Metadata, MetadataContent, ProposalDescription, SerializableInstruction,
+    SignedVoteMessage, VoteChoice,
 };
+#[allow(deprecated)]
+use solana_sdk::sysvar::instructions::ID as INSTRUCTIONS_SYSVAR_ID;
 use solana_sdk::{
+    ed25519_program,
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
     system_program,
@@ -66,6 +80,27 @@ pub fn proposal_transaction_pda(proposal: &Pubkey) -> Pda {
     pda(&[b"proposal-transaction", proposal.as_ref()])
 }
 
+/// Transaction of one option of a multi-choice proposal.
+pub fn proposal_option_transaction_pda(proposal: &Pubkey, option_index: u8) -> Pda {
+    pda(&[b"proposal-transaction", proposal.as_ref(), &[option_index]])
+}
+
+pub fn execution_receipt_pda(proposal: &Pubkey) -> Pda {
+    pda(&[b"execution-receipt", proposal.as_ref()])
+}
+
+pub fn proposal_bond_pda(proposal: &Pubkey) -> Pda {
+    pda(&[b"proposal-bond", proposal.as_ref()])
+}
+
+pub fn bond_escrow_pda(proposal: &Pubkey) -> Pda {
+    pda(&[b"bond-escrow", proposal.as_ref()])
+}
+
+pub fn schedule_pda(group: &Pubkey, schedule_seed: &Pubkey) -> Pda {
+    pda(&[b"schedule", group.as_ref(), schedule_seed.as_ref()])
+}
+
 pub fn normal_vote_record_pda(
     group: &Pubkey,
     proposal: &Pubkey,
@@ -90,6 +125,67 @@ pub fn config_vote_record_pda(group: &Pubkey, proposal: &Pubkey, voter: &Pubkey)
     ])
 }
 
+/// Delegation of `delegator`'s weight on `asset_address`, or on the group when `None`.
+pub fn delegation_pda(group: &Pubkey, delegator: &Pubkey, asset_address: Option<&Pubkey>) -> Pda {
+    pda(&[
+        b"delegation",
+        group.as_ref(),
+        delegator.as_ref(),
+        asset_address.unwrap_or(group).as_ref(),
+    ])
+}
+
+pub fn member_invite_pda(group: &Pubkey, member: &Pubkey) -> Pda {
+    pda(&[b"invite", group.as_ref(), member.as_ref()])
+}
+
+/// `subject` is the address of the group, group member, asset or proposal account.
+pub fn metadata_pda(subject: &Pubkey) -> Pda {
+    pda(&[b"metadata", subject.as_ref()])
+}
+
+/// Reads the metadata of `subject`, `get_account_data` returns the data of an account
+/// (from an RPC client, LiteSVM, ...) or `None` when it does not exist.
+/// Returns `None` when no metadata was set.
+pub fn fetch_metadata(
+    subject: &Pubkey,
+    get_account_data: impl FnOnce(&Pubkey) -> Option<Vec<u8>>,
+) -> anchor_lang::Result<Option<Metadata>> {
+    get_account_data(&metadata_pda(subject).address)
+        .map(|data| Metadata::try_deserialize(&mut data.as_slice()))
+        .transpose()
+}
+
+pub fn fetch_group_metadata(
+    group: &Pubkey,
+    get_account_data: impl FnOnce(&Pubkey) -> Option<Vec<u8>>,
+) -> anchor_lang::Result<Option<Metadata>> {
+    fetch_metadata(group, get_account_data)
+}
+
+pub fn fetch_group_member_metadata(
+    group: &Pubkey,
+    member: &Pubkey,
+    get_account_data: impl FnOnce(&Pubkey) -> Option<Vec<u8>>,
+) -> anchor_lang::Result<Option<Metadata>> {
+    fetch_metadata(&group_member_pda(group, member).address, get_account_data)
+}
+
+pub fn fetch_asset_metadata(
+    group: &Pubkey,
+    asset_address: &Pubkey,
+    get_account_data: impl FnOnce(&Pubkey) -> Option<Vec<u8>>,
+) -> anchor_lang::Result<Option<Metadata>> {
+    fetch_metadata(&asset_pda(group, asset_address).address, get_account_data)
+}
+
+pub fn fetch_proposal_metadata(
+    proposal: &Pubkey,
+    get_account_data: impl FnOnce(&Pubkey) -> Option<Vec<u8>>,
+) -> anchor_lang::Result<Option<Metadata>> {
+    fetch_metadata(proposal, get_account_data)
+}
+
 pub fn serializable_instruction_bytes(
     instruction: &SerializableInstruction,
 ) -> std::io::Result<Vec<u8>> {
@@ -115,6 +211,18 @@ pub fn serializable_instruction_hashes(
         .collect()
 }
 
+/// Hash of the full proposal text, use it as `content_hash` when building a
+/// [`ProposalDescription`].
+pub fn proposal_content_hash(content: &[u8]) -> [u8; hash::HASH_BYTES] {
+    hash::hash(content).to_bytes()
+}
+
+/// Whether `content`, fetched from the description's `uri`, is the text the proposer
+/// committed to. Clients should only render content that passes this check.
+pub fn verify_proposal_description(description: &ProposalDescription, content: &[u8]) -> bool {
+    proposal_content_hash(content) == description.content_hash
+}
+
 /// Serialises every instruction and returns the raw byte vectors - pass the result
 /// directly as `raw_instructions` when building a [`CreateProposalTransactionInstructionArgs`].
 pub fn serializable_instructions_bytes(
@@ -151,6 +259,67 @@ fn optional_account(key: Option<Pubkey>, writable: bool) -> AccountMeta {
     }
 }
 
+/// Accounts of a proposal bond paid in a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenBondAccounts {
+    pub mint: Pubkey,
+    /// The payer's token account when the bond is paid, the recipient's when it is released.
+    pub token_account: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// Optional bond accounts of the proposal creation instructions, left out until
+/// [`with_proposal_bond`] adds them.
+fn no_proposal_bond() -> [AccountMeta; 5] {
+    std::array::from_fn(|_| optional_account(None, false))
+}
+
+/// Adds the escrow of the group's proposal bond to `create_normal_proposal`,
+/// `create_config_proposal` or `create_multi_asset_config_proposal`. The bond is paid by the
+/// rent payer, so call it after [`with_rent_payer`]. `token_bond` is required when the group's
+/// bond is paid in a token.
+pub fn with_proposal_bond(
+    mut instruction: Instruction,
+    proposal: Pubkey,
+    token_bond: Option<TokenBondAccounts>,
+) -> Instruction {
+    // The bond accounts always come right after the system program.
+    if let Some(position) = instruction
+        .accounts
+        .iter()
+        .position(|meta| meta.pubkey == system_program::ID)
+    {
+        let bond_accounts = [
+            writable(proposal_bond_pda(&proposal).address),
+            optional_account(token_bond.map(|token_bond| token_bond.mint), false),
+            optional_account(token_bond.map(|token_bond| token_bond.token_account), true),
+            optional_account(token_bond.map(|_| bond_escrow_pda(&proposal).address), true),
+            optional_account(token_bond.map(|token_bond| token_bond.token_program), false),
+        ];
+        instruction.accounts[position + 1..position + 6].clone_from_slice(&bond_accounts);
+    }
+    instruction
+}
+
+/// Replaces the rent payer of `accept_membership`, `create_normal_proposal`,
+/// `create_config_proposal`, `create_multi_asset_config_proposal`, `vote_on_normal_proposal`,
+/// `batch_vote_on_normal_proposal`, `vote_on_normal_proposal_group_approval`,
+/// `vote_on_config_proposal` or `vote_on_multi_asset_config_proposal`, which defaults to the
+/// member. Use it when the member is another group's PDA invoking the
+/// instruction from its own proposal, `payer` is then usually one of its asset authorities.
+pub fn with_rent_payer(mut instruction: Instruction, payer: Pubkey) -> Instruction {
+    // The rent payer always comes right before the system program.
+    if let Some(position) = instruction
+        .accounts
+        .iter()
+        .position(|meta| meta.pubkey == system_program::ID)
+        .and_then(|position| position.checked_sub(1))
+    {
+        instruction.accounts[position] = signer(payer);
+    }
+    instruction
+}
+
 pub fn create_group(
     args: CreateGroupInstructionArgs,
     payer: Pubkey,
@@ -203,7 +372,7 @@ pub fn add_asset_mint(
     accounts.extend(
         member_keys
             .iter()
-            .map(|member| readonly(group_member_pda(&group, member).address)),
+            .map(|member| writable(group_member_pda(&group, member).address)),
     );
     accounts.extend(
         member_keys
@@ -240,7 +409,7 @@ pub fn add_asset_token(
     accounts.extend(
         member_keys
             .iter()
-            .map(|member| readonly(group_member_pda(&group, member).address)),
+            .map(|member| writable(group_member_pda(&group, member).address)),
     );
     accounts.push(writable(asset));
     accounts.push(readonly(authority));
@@ -259,6 +428,9 @@ pub fn add_asset_token(
     }
 }
 
+/// The asset accounts of `args.asset_keys` are passed as remaining accounts, so the
+/// program can check their timelock and proposal duration limits, followed by the governed
+/// account of each token amount balance guard so the program can check it is a token account.
 pub fn create_normal_proposal(
     args: CreateNormalProposalInstructionArgs,
     group: Pubkey,
@@ -266,15 +438,30 @@ pub fn create_normal_proposal(
 ) -> Instruction {
     let proposal = proposal_pda(&group, &args.proposal_seed).address;
 
+    let mut accounts = vec![
+        writable(group),
+        readonly_signer(proposer),
+        writable(group_member_pda(&group, &proposer).address),
+        writable(proposal),
+        signer(proposer),
+        readonly(system_program::ID),
+    ];
+    accounts.extend(no_proposal_bond());
+    accounts.extend(
+        args.asset_keys
+            .iter()
+            .map(|asset_key| readonly(asset_pda(&group, asset_key).address)),
+    );
+    accounts.extend(
+        args.balance_guards
+            .iter()
+            .filter(|guard| guard.kind == BalanceGuardKind::TokenAmount)
+            .map(|guard| readonly(guard.asset)),
+    );
+
     Instruction {
         program_id: PROGRAM_ID,
-        accounts: vec![
-            writable(group),
-            signer(proposer),
-            readonly(group_member_pda(&group, &proposer).address),
-            writable(proposal),
-            readonly(system_program::ID),
-        ],
+        accounts,
         data: ix_data::CreateNormalProposal { args }.data(),
     }
 }
@@ -288,20 +475,46 @@ pub fn create_config_proposal(
     let proposal = proposal_pda(&group, &args.proposal_seed).address;
     let asset = asset_address.map(|asset_address| asset_pda(&group, &asset_address).address);
 
+    let mut accounts = vec![
+        readonly_signer(proposer),
+        writable(group),
+        optional_account(asset, true),
+        writable(group_member_pda(&group, &proposer).address),
+        writable(proposal),
+        signer(proposer),
+        readonly(system_program::ID),
+    ];
+    accounts.extend(no_proposal_bond());
+
     Instruction {
         program_id: PROGRAM_ID,
-        accounts: vec![
-            signer(proposer),
-            writable(group),
-            optional_account(asset, true),
-            readonly(group_member_pda(&group, &proposer).address),
-            writable(proposal),
-            readonly(system_program::ID),
-        ],
+        accounts,
         data: ix_data::CreateConfigProposal { args }.data(),
     }
 }
 
+/// Config proposal applying the same asset change to each of `asset_addresses`, the
+/// change must name the default key as its asset address.
+pub fn create_multi_asset_config_proposal(
+    args: CreateConfigProposalInstructionArgs,
+    group: Pubkey,
+    proposer: Pubkey,
+    asset_addresses: &[Pubkey],
+) -> Instruction {
+    let mut asset_addresses = asset_addresses.to_vec();
+    asset_addresses.sort();
+
+    let mut instruction =
+        create_config_proposal(args, group, proposer, asset_addresses.first().copied());
+    instruction.accounts.extend(
+        asset_addresses
+            .iter()
+            .skip(1)
+            .map(|asset_address| readonly(asset_pda(&group, asset_address).address)),
+    );
+    instruction
+}
+
 pub fn create_proposal_transaction(
     args: CreateProposalTransactionInstructionArgs,
     group: Pubkey,
@@ -344,7 +557,8 @@ pub fn add_group_member(
             writable(group),
             writable(proposal),
             writable(proposer),
-            writable(group_member_pda(&group, &args.new_member).address),
+            writable(group_member_pda(&group, &proposer).address),
+            writable(member_invite_pda(&group, &args.new_member).address),
             signer(payer),
             readonly(system_program::ID),
         ],
@@ -369,7 +583,8 @@ pub fn add_asset_member(
             writable(asset),
             writable(proposal),
             writable(proposer),
-            readonly(group_member_pda(&group, &args.new_member).address),
+            writable(group_member_pda(&group, &proposer).address),
+            writable(group_member_pda(&group, &args.new_member).address),
             writable(asset_member_pda(&group, &asset_address, &args.new_member).address),
             signer(payer),
             readonly(system_program::ID),
@@ -393,6 +608,7 @@ pub fn remove_group_member(
             writable(proposal),
             writable(rent_collector),
             writable(proposer),
+            writable(group_member_pda(&group, &proposer).address),
             readonly(system_program::ID),
         ],
         data: ix_data::RemoveGroupMember {}.data(),
@@ -415,9 +631,11 @@ pub fn remove_asset_member(
             writable(group),
             writable(asset),
             writable(asset_member_pda(&group, &asset_address, &member).address),
+            writable(group_member_pda(&group, &member).address),
             writable(proposal),
             writable(rent_collector),
             writable(proposer),
+            writable(group_member_pda(&group, &proposer).address),
             readonly(system_program::ID),
         ],
         data: ix_data::RemoveAssetMember {}.data(),
@@ -427,7 +645,12 @@ pub fn remove_asset_member(
 pub fn change_group_config(group: Pubkey, proposal: Pubkey, proposer: Pubkey) -> Instruction {
     Instruction {
         program_id: PROGRAM_ID,
-        accounts: vec![writable(group), writable(proposal), writable(proposer)],
+        accounts: vec![
+            writable(group),
+            writable(proposal),
+            writable(proposer),
+            writable(group_member_pda(&group, &proposer).address),
+        ],
         data: ix_data::ChangeGroupConfig {}.data(),
     }
 }
@@ -445,6 +668,7 @@ pub fn change_asset_config(
             writable(asset_pda(&group, &asset_address).address),
             writable(proposal),
             writable(proposer),
+            writable(group_member_pda(&group, &proposer).address),
         ],
         data: ix_data::ChangeAssetConfig {}.data(),
     }
@@ -472,6 +696,7 @@ pub fn vote_on_normal_proposal(
             writable(
                 normal_vote_record_pda(&group, &proposal, &voter, args.voting_asset_index).address,
             ),
+            readonly_signer(voter),
             signer(voter),
             readonly(system_program::ID),
         ],
@@ -479,172 +704,1014 @@ pub fn vote_on_normal_proposal(
     }
 }
 
-pub fn vote_on_config_proposal(
-    args: VoteOnConfigProposalInstructionArgs,
+/// `asset_addresses` are the assets voted on, in the same order as `args.votes`.
+pub fn batch_vote_on_normal_proposal(
+    args: BatchVoteOnNormalProposalInstructionArgs,
     group: Pubkey,
     proposal: Pubkey,
+    asset_addresses: &[Pubkey],
     voter: Pubkey,
-    asset_address: Option<Pubkey>,
-) -> Instruction {
-    let asset = asset_address.map(|asset_address| asset_pda(&group, &asset_address).address);
-    let asset_member =
-        asset_address.map(|asset_address| asset_member_pda(&group, &asset_address, &voter).address);
-
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![
-            writable(group),
-            writable(proposal),
-            optional_account(asset, true),
-            writable(group_member_pda(&group, &voter).address),
-            optional_account(asset_member, true),
-            writable(config_vote_record_pda(&group, &proposal, &voter).address),
-            signer(voter),
-            readonly(system_program::ID),
-        ],
-        data: ix_data::VoteOnConfigProposal { args }.data(),
-    }
-}
-
-pub fn execute_proposal_transaction(
-    group: Pubkey,
-    proposal: Pubkey,
-    proposal_transaction: Pubkey,
-    rent_collector: Pubkey,
-    remaining_accounts: Vec<AccountMeta>,
 ) -> Instruction {
     let mut accounts = vec![
+        readonly(group),
         writable(proposal),
-        writable(proposal_transaction),
-        writable(group),
-        writable(rent_collector),
+        readonly(proposal_transaction_pda(&proposal).address),
+        readonly(group_member_pda(&group, &voter).address),
+        readonly_signer(voter),
+        signer(voter),
+        readonly(system_program::ID),
     ];
-    accounts.extend(remaining_accounts);
+    accounts.extend(args.votes.iter().zip(asset_addresses).flat_map(
+        |(asset_vote, asset_address)| {
+            [
+                readonly(asset_pda(&group, asset_address).address),
+                readonly(asset_member_pda(&group, asset_address, &voter).address),
+                writable(
+                    normal_vote_record_pda(
+                        &group,
+                        &proposal,
+                        &voter,
+                        asset_vote.voting_asset_index,
+                    )
+                    .address,
+                ),
+            ]
+        },
+    ));
 
     Instruction {
         program_id: PROGRAM_ID,
         accounts,
-        data: ix_data::ExecuteProposalTransaction {}.data(),
+        data: ix_data::BatchVoteOnNormalProposal { args }.data(),
     }
 }
 
-pub fn close_proposal_transaction(
+pub fn vote_on_normal_proposal_group_approval(
+    args: VoteOnNormalProposalGroupApprovalInstructionArgs,
     group: Pubkey,
     proposal: Pubkey,
-    proposal_transaction: Pubkey,
-    rent_collector: Pubkey,
+    voter: Pubkey,
 ) -> Instruction {
     Instruction {
         program_id: PROGRAM_ID,
         accounts: vec![
             readonly(group),
-            readonly(proposal),
-            writable(proposal_transaction),
-            writable(rent_collector),
+            writable(proposal),
+            readonly(group_member_pda(&group, &voter).address),
+            writable(config_vote_record_pda(&group, &proposal, &voter).address),
+            readonly_signer(voter),
+            signer(voter),
+            readonly(system_program::ID),
         ],
-        data: ix_data::CloseProposalTransactionInstruction {}.data(),
+        data: ix_data::VoteOnNormalProposalGroupApproval { args }.data(),
     }
 }
 
-pub fn close_config_proposal(group: Pubkey, proposal: Pubkey, proposer: Pubkey) -> Instruction {
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![readonly(group), writable(proposal), writable(proposer)],
-        data: ix_data::CloseProposalInstruction {}.data(),
-    }
-}
-
-pub fn close_normal_proposal(group: Pubkey, proposal: Pubkey, proposer: Pubkey) -> Instruction {
-    Instruction {
-        program_id: PROGRAM_ID,
-        accounts: vec![readonly(group), writable(proposal), writable(proposer)],
-        data: ix_data::CloseNormalProposalInstruction {}.data(),
-    }
-}
-
-pub fn close_normal_vote_record(
+pub fn vote_on_config_proposal(
+    args: VoteOnConfigProposalInstructionArgs,
     group: Pubkey,
     proposal: Pubkey,
     voter: Pubkey,
-    asset_index: u8,
+    asset_address: Option<Pubkey>,
 ) -> Instruction {
+    let asset = asset_address.map(|asset_address| asset_pda(&group, &asset_address).address);
+    let asset_member =
+        asset_address.map(|asset_address| asset_member_pda(&group, &asset_address, &voter).address);
+
     Instruction {
         program_id: PROGRAM_ID,
         accounts: vec![
-            readonly(group),
-            readonly(proposal),
-            writable(normal_vote_record_pda(&group, &proposal, &voter, asset_index).address),
+            writable(group),
+            writable(proposal),
+            optional_account(asset, true),
+            writable(group_member_pda(&group, &voter).address),
+            optional_account(asset_member, true),
+            writable(config_vote_record_pda(&group, &proposal, &voter).address),
+            readonly_signer(voter),
             signer(voter),
+            readonly(system_program::ID),
         ],
-        data: ix_data::CloseNormalVoteRecordInstruction {
-            args: CloseNormalVoteRecordInstructionArgs { asset_index },
-        }
-        .data(),
+        data: ix_data::VoteOnConfigProposal { args }.data(),
     }
 }
 
-pub fn close_config_vote_record(group: Pubkey, proposal: Pubkey, voter: Pubkey) -> Instruction {
+/// `asset_address` is the asset at `args.voting_asset_index` in the proposal's assets.
+pub fn vote_on_multi_asset_config_proposal(
+    args: VoteOnMultiAssetConfigProposalInstructionArgs,
+    group: Pubkey,
+    proposal: Pubkey,
+    asset_address: Pubkey,
+    voter: Pubkey,
+) -> Instruction {
     Instruction {
         program_id: PROGRAM_ID,
         accounts: vec![
             readonly(group),
-            readonly(proposal),
-            writable(config_vote_record_pda(&group, &proposal, &voter).address),
+            writable(proposal),
+            readonly(asset_pda(&group, &asset_address).address),
+            readonly(group_member_pda(&group, &voter).address),
+            readonly(asset_member_pda(&group, &asset_address, &voter).address),
+            writable(
+                normal_vote_record_pda(&group, &proposal, &voter, args.voting_asset_index).address,
+            ),
+            readonly_signer(voter),
             signer(voter),
+            readonly(system_program::ID),
         ],
-        data: ix_data::CloseConfigVoteRecordInstruction {}.data(),
+        data: ix_data::VoteOnMultiAssetConfigProposal { args }.data(),
     }
 }
 
-pub fn clean_up_asset_member(
+pub fn compact_vote_on_normal_proposal(
+    args: CompactVoteOnNormalProposalInstructionArgs,
     group: Pubkey,
+    proposal: Pubkey,
     asset_address: Pubkey,
-    member: Pubkey,
-    rent_collector: Pubkey,
+    voter: Pubkey,
 ) -> Instruction {
     Instruction {
         program_id: PROGRAM_ID,
         accounts: vec![
             readonly(group),
-            writable(asset_member_pda(&group, &asset_address, &member).address),
-            writable(asset_pda(&group, &asset_address).address),
-            readonly(group_member_pda(&group, &member).address),
-            readonly(member),
-            writable(rent_collector),
+            writable(proposal),
+            readonly(proposal_transaction_pda(&proposal).address),
+            readonly(asset_pda(&group, &asset_address).address),
+            readonly(group_member_pda(&group, &voter).address),
+            readonly(asset_member_pda(&group, &asset_address, &voter).address),
+            readonly_signer(voter),
         ],
-        data: ix_data::CleanUpAssetMemberInstruction {}.data(),
+        data: ix_data::CompactVoteOnNormalProposal { args }.data(),
     }
 }
 
-pub fn emergency_reset_proposal_pda(group: &Pubkey, proposal_seed: &Pubkey) -> Pda {
-    pda(&[b"emergency-reset", group.as_ref(), proposal_seed.as_ref()])
-}
-
-pub fn emergency_reset_vote_record_pda(group: &Pubkey, proposal: &Pubkey, voter: &Pubkey) -> Pda {
-    config_vote_record_pda(group, proposal, voter)
-}
-
-pub fn create_emergency_reset_proposal(
-    args: CreateEmergencyResetProposalArgs,
+pub fn compact_vote_on_config_proposal(
+    args: CompactVoteOnConfigProposalInstructionArgs,
     group: Pubkey,
-    proposer: Pubkey,
+    proposal: Pubkey,
+    voter: Pubkey,
+    asset_address: Option<Pubkey>,
 ) -> Instruction {
-    let proposal = emergency_reset_proposal_pda(&group, &args.proposal_seed).address;
+    let asset = asset_address.map(|asset_address| asset_pda(&group, &asset_address).address);
+    let asset_member =
+        asset_address.map(|asset_address| asset_member_pda(&group, &asset_address, &voter).address);
+
     Instruction {
         program_id: PROGRAM_ID,
         accounts: vec![
-            signer(proposer),
-            writable(group),
-            readonly(group_member_pda(&group, &proposer).address),
+            readonly(group),
             writable(proposal),
-            readonly(system_program::ID),
+            optional_account(asset, false),
+            readonly(group_member_pda(&group, &voter).address),
+            optional_account(asset_member, false),
+            readonly_signer(voter),
         ],
-        data: ix_data::CreateEmergencyResetProposal { args }.data(),
+        data: ix_data::CompactVoteOnConfigProposal { args }.data(),
     }
 }
 
-pub fn vote_on_emergency_reset_proposal(
-    args: VoteOnEmergencyResetArgs,
+/// The bytes a member signs to approve `vote` off-chain, `nonce` is the proposal's
+/// `proposal_index` and `asset_index` is `None` for config proposals.
+pub fn signed_vote_message(
+    group: Pubkey,
+    proposal: Pubkey,
+    asset_index: Option<u8>,
+    vote: VoteChoice,
+    nonce: u64,
+) -> std::io::Result<Vec<u8>> {
+    let message = SignedVoteMessage {
+        group,
+        proposal,
+        asset_index,
+        vote_choice: vote,
+        nonce,
+    };
+    let mut data = Vec::new();
+    message.serialize(&mut data)?;
+    Ok(data)
+}
+
+/// Ed25519 program instruction checking each `(signer, signature, message)`, to be placed
+/// right before `submit_signed_votes_on_*`. Keys, signatures and messages are all stored
+/// in the instruction itself.
+pub fn ed25519_verify_instruction(signed_messages: &[(Pubkey, [u8; 64], Vec<u8>)]) -> Instruction {
+    const OFFSETS_START: usize = 2;
+    const OFFSETS_SIZE: usize = 14;
+
+    let mut offsets = Vec::with_capacity(signed_messages.len() * OFFSETS_SIZE);
+    let mut payload = Vec::new();
+    let payload_start = OFFSETS_START + signed_messages.len() * OFFSETS_SIZE;
+
+    for (signer, signature, message) in signed_messages {
+        let public_key_offset = payload_start + payload.len();
+        payload.extend_from_slice(signer.as_ref());
+        let signature_offset = payload_start + payload.len();
+        payload.extend_from_slice(signature);
+        let message_data_offset = payload_start + payload.len();
+        payload.extend_from_slice(message);
+
+        for value in [
+            signature_offset as u16,
+            u16::MAX,
+            public_key_offset as u16,
+            u16::MAX,
+            message_data_offset as u16,
+            message.len() as u16,
+            u16::MAX,
+        ] {
+            offsets.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    let mut data = vec![signed_messages.len() as u8, 0];
+    data.extend(offsets);
+    data.extend(payload);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+/// `voters` and `asset_addresses` are the signer and asset of each vote, in the same order
+/// as `args.votes`.
+pub fn submit_signed_votes_on_normal_proposal(
+    args: SubmitSignedVotesOnNormalProposalInstructionArgs,
+    group: Pubkey,
+    proposal: Pubkey,
+    voters: &[Pubkey],
+    asset_addresses: &[Pubkey],
+    payer: Pubkey,
+) -> Instruction {
+    let mut accounts = vec![
+        readonly(group),
+        writable(proposal),
+        readonly(proposal_transaction_pda(&proposal).address),
+        readonly(INSTRUCTIONS_SYSVAR_ID),
+        signer(payer),
+        readonly(system_program::ID),
+    ];
+    accounts.extend(
+        args.votes
+            .iter()
+            .zip(voters.iter().zip(asset_addresses))
+            .flat_map(|(asset_vote, (voter, asset_address))| {
+                [
+                    readonly(group_member_pda(&group, voter).address),
+                    readonly(asset_pda(&group, asset_address).address),
+                    readonly(asset_member_pda(&group, asset_address, voter).address),
+                    writable(
+                        normal_vote_record_pda(
+                            &group,
+                            &proposal,
+                            voter,
+                            asset_vote.voting_asset_index,
+                        )
+                        .address,
+                    ),
+                ]
+            }),
+    );
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data: ix_data::SubmitSignedVotesOnNormalProposal { args }.data(),
+    }
+}
+
+/// `voters` are the signers of each vote, in the same order as `args.votes`,
+/// `asset_address` is the asset targeted by the proposal if any.
+pub fn submit_signed_votes_on_config_proposal(
+    args: SubmitSignedVotesOnConfigProposalInstructionArgs,
+    group: Pubkey,
+    proposal: Pubkey,
+    asset_address: Option<Pubkey>,
+    voters: &[Pubkey],
+    payer: Pubkey,
+) -> Instruction {
+    let asset = asset_address.map(|asset_address| asset_pda(&group, &asset_address).address);
+
+    let mut accounts = vec![
+        readonly(group),
+        writable(proposal),
+        optional_account(asset, false),
+        readonly(INSTRUCTIONS_SYSVAR_ID),
+        signer(payer),
+        readonly(system_program::ID),
+    ];
+    accounts.extend(voters.iter().flat_map(|voter| {
+        let member = match asset_address {
+            Some(asset_address) => asset_member_pda(&group, &asset_address, voter).address,
+            None => group_member_pda(&group, voter).address,
+        };
+        [
+            readonly(member),
+            writable(config_vote_record_pda(&group, &proposal, voter).address),
+        ]
+    }));
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data: ix_data::SubmitSignedVotesOnConfigProposal { args }.data(),
+    }
+}
+
+/// Remaining accounts that let the voter of `vote_on_normal_proposal` also vote for
+/// each delegator who delegated their weight on `asset_address`. Votes on a multi-choice
+/// proposal with `vote_on_proposal_option` use `voting_asset_index` 0.
+pub fn normal_delegated_vote_accounts(
+    group: &Pubkey,
+    proposal: &Pubkey,
+    asset_address: &Pubkey,
+    voting_asset_index: u8,
+    delegators: &[Pubkey],
+) -> Vec<AccountMeta> {
+    delegators
+        .iter()
+        .flat_map(|delegator| {
+            [
+                readonly(delegation_pda(group, delegator, Some(asset_address)).address),
+                readonly(asset_member_pda(group, asset_address, delegator).address),
+                writable(
+                    normal_vote_record_pda(group, proposal, delegator, voting_asset_index).address,
+                ),
+            ]
+        })
+        .collect()
+}
+
+/// Remaining accounts that let the voter of `vote_on_config_proposal` also vote for
+/// each delegator, `asset_address` is the asset targeted by the proposal if any.
+pub fn config_delegated_vote_accounts(
+    group: &Pubkey,
+    proposal: &Pubkey,
+    asset_address: Option<&Pubkey>,
+    delegators: &[Pubkey],
+) -> Vec<AccountMeta> {
+    delegators
+        .iter()
+        .flat_map(|delegator| {
+            let member = match asset_address {
+                Some(asset_address) => asset_member_pda(group, asset_address, delegator),
+                None => group_member_pda(group, delegator),
+            };
+
+            [
+                readonly(delegation_pda(group, delegator, asset_address).address),
+                readonly(member.address),
+                writable(config_vote_record_pda(group, proposal, delegator).address),
+            ]
+        })
+        .collect()
+}
+
+pub fn delegate_vote(
+    args: DelegateVoteInstructionArgs,
+    group: Pubkey,
+    delegator: Pubkey,
+) -> Instruction {
+    let delegator_asset_member = args
+        .asset
+        .map(|asset_address| asset_member_pda(&group, &asset_address, &delegator).address);
+    let delegate_asset_member = args
+        .asset
+        .map(|asset_address| asset_member_pda(&group, &asset_address, &args.delegate).address);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            readonly(group_member_pda(&group, &delegator).address),
+            readonly(group_member_pda(&group, &args.delegate).address),
+            optional_account(delegator_asset_member, false),
+            optional_account(delegate_asset_member, false),
+            writable(delegation_pda(&group, &delegator, args.asset.as_ref()).address),
+            signer(delegator),
+            readonly(system_program::ID),
+        ],
+        data: ix_data::DelegateVote { args }.data(),
+    }
+}
+
+pub fn revoke_delegation(
+    group: Pubkey,
+    delegator: Pubkey,
+    asset_address: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            writable(delegation_pda(&group, &delegator, asset_address.as_ref()).address),
+            signer(delegator),
+        ],
+        data: ix_data::RevokeDelegation {}.data(),
+    }
+}
+
+/// `asset_addresses` must list every asset `old_key` is a member of.
+pub fn rotate_member_key(
+    group: Pubkey,
+    old_key: Pubkey,
+    new_key: Pubkey,
+    asset_addresses: &[Pubkey],
+) -> Instruction {
+    let mut accounts = vec![
+        readonly(group),
+        writable(group_member_pda(&group, &old_key).address),
+        writable(group_member_pda(&group, &new_key).address),
+        signer(old_key),
+        signer(new_key),
+        readonly(system_program::ID),
+    ];
+    accounts.extend(asset_addresses.iter().flat_map(|asset_address| {
+        [
+            writable(asset_member_pda(&group, asset_address, &old_key).address),
+            writable(asset_member_pda(&group, asset_address, &new_key).address),
+        ]
+    }));
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data: ix_data::RotateMemberKey {}.data(),
+    }
+}
+
+/// `invite_payer` is the payer stored in the invite, it receives the invite rent.
+pub fn accept_membership(group: Pubkey, new_member: Pubkey, invite_payer: Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            writable(group),
+            writable(member_invite_pda(&group, &new_member).address),
+            writable(group_member_pda(&group, &new_member).address),
+            readonly_signer(new_member),
+            writable(invite_payer),
+            signer(new_member),
+            readonly(system_program::ID),
+        ],
+        data: ix_data::AcceptMembership {}.data(),
+    }
+}
+
+pub fn close_member_invite(group: Pubkey, member: Pubkey, invite_payer: Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            writable(member_invite_pda(&group, &member).address),
+            writable(invite_payer),
+        ],
+        data: ix_data::CloseMemberInvite {}.data(),
+    }
+}
+
+pub fn renew_group_member_term(
+    group: Pubkey,
+    member: Pubkey,
+    proposal: Pubkey,
+    proposer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            writable(group),
+            writable(group_member_pda(&group, &member).address),
+            writable(proposal),
+            writable(proposer),
+            writable(group_member_pda(&group, &proposer).address),
+        ],
+        data: ix_data::RenewGroupMemberTerm {}.data(),
+    }
+}
+
+pub fn renew_asset_member_term(
+    group: Pubkey,
+    asset_address: Pubkey,
+    member: Pubkey,
+    proposal: Pubkey,
+    proposer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            writable(group),
+            readonly(asset_pda(&group, &asset_address).address),
+            writable(asset_member_pda(&group, &asset_address, &member).address),
+            writable(proposal),
+            writable(proposer),
+            writable(group_member_pda(&group, &proposer).address),
+        ],
+        data: ix_data::RenewAssetMemberTerm {}.data(),
+    }
+}
+
+pub fn close_expired_group_member(
+    group: Pubkey,
+    member: Pubkey,
+    rent_collector: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            writable(group),
+            writable(group_member_pda(&group, &member).address),
+            writable(rent_collector),
+        ],
+        data: ix_data::CloseExpiredGroupMember {}.data(),
+    }
+}
+
+pub fn close_expired_asset_member(
+    group: Pubkey,
+    asset_address: Pubkey,
+    member: Pubkey,
+    rent_collector: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            writable(group),
+            writable(asset_pda(&group, &asset_address).address),
+            writable(asset_member_pda(&group, &asset_address, &member).address),
+            writable(group_member_pda(&group, &member).address),
+            writable(rent_collector),
+        ],
+        data: ix_data::CloseExpiredAssetMember {}.data(),
+    }
+}
+
+pub fn change_group_member_permissions(
+    group: Pubkey,
+    member: Pubkey,
+    proposal: Pubkey,
+    proposer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            writable(group),
+            writable(group_member_pda(&group, &member).address),
+            writable(proposal),
+            writable(proposer),
+            writable(group_member_pda(&group, &proposer).address),
+        ],
+        data: ix_data::ChangeGroupMemberPermissions {}.data(),
+    }
+}
+
+pub fn change_asset_member_permissions(
+    group: Pubkey,
+    asset_address: Pubkey,
+    member: Pubkey,
+    proposal: Pubkey,
+    proposer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            writable(group),
+            readonly(asset_pda(&group, &asset_address).address),
+            writable(asset_member_pda(&group, &asset_address, &member).address),
+            writable(proposal),
+            writable(proposer),
+            writable(group_member_pda(&group, &proposer).address),
+        ],
+        data: ix_data::ChangeAssetMemberPermissions {}.data(),
+    }
+}
+
+pub fn cancel_normal_proposal(group: Pubkey, proposal: Pubkey, canceller: Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            writable(proposal),
+            readonly(group_member_pda(&group, &canceller).address),
+            readonly_signer(canceller),
+        ],
+        data: ix_data::CancelNormalProposal {}.data(),
+    }
+}
+
+pub fn cancel_config_proposal(group: Pubkey, proposal: Pubkey, canceller: Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            writable(proposal),
+            readonly(group_member_pda(&group, &canceller).address),
+            readonly_signer(canceller),
+        ],
+        data: ix_data::CancelConfigProposal {}.data(),
+    }
+}
+
+pub fn set_group_metadata(
+    group: Pubkey,
+    proposal: Pubkey,
+    proposer: Pubkey,
+    payer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            writable(group),
+            writable(metadata_pda(&group).address),
+            writable(proposal),
+            writable(proposer),
+            writable(group_member_pda(&group, &proposer).address),
+            signer(payer),
+            readonly(system_program::ID),
+        ],
+        data: ix_data::SetGroupMetadata {}.data(),
+    }
+}
+
+pub fn set_asset_metadata(
+    group: Pubkey,
+    asset_address: Pubkey,
+    proposal: Pubkey,
+    proposer: Pubkey,
+    payer: Pubkey,
+) -> Instruction {
+    let asset = asset_pda(&group, &asset_address).address;
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            writable(group),
+            readonly(asset),
+            writable(metadata_pda(&asset).address),
+            writable(proposal),
+            writable(proposer),
+            writable(group_member_pda(&group, &proposer).address),
+            signer(payer),
+            readonly(system_program::ID),
+        ],
+        data: ix_data::SetAssetMetadata {}.data(),
+    }
+}
+
+pub fn set_member_metadata(content: MetadataContent, group: Pubkey, member: Pubkey) -> Instruction {
+    let group_member = group_member_pda(&group, &member).address;
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            readonly(group_member),
+            writable(metadata_pda(&group_member).address),
+            signer(member),
+            readonly(system_program::ID),
+        ],
+        data: ix_data::SetMemberMetadata {
+            args: SetMemberMetadataInstructionArgs { content },
+        }
+        .data(),
+    }
+}
+
+pub fn set_proposal_metadata(
+    content: MetadataContent,
+    group: Pubkey,
+    proposal: Pubkey,
+    proposer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            readonly(proposal),
+            writable(metadata_pda(&proposal).address),
+            signer(proposer),
+            readonly(system_program::ID),
+        ],
+        data: ix_data::SetProposalMetadata {
+            args: SetProposalMetadataInstructionArgs { content },
+        }
+        .data(),
+    }
+}
+
+/// `subject` is the closed account the metadata described, `metadata_payer` paid for it.
+pub fn close_metadata(subject: Pubkey, metadata_payer: Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            writable(metadata_pda(&subject).address),
+            readonly(subject),
+            writable(metadata_payer),
+        ],
+        data: ix_data::CloseMetadata {}.data(),
+    }
+}
+
+pub fn update_normal_proposal_description(
+    description: Option<ProposalDescription>,
+    group: Pubkey,
+    proposal: Pubkey,
+    proposer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            writable(proposal),
+            readonly_signer(proposer),
+        ],
+        data: ix_data::UpdateNormalProposalDescription {
+            args: UpdateNormalProposalDescriptionInstructionArgs { description },
+        }
+        .data(),
+    }
+}
+
+pub fn update_config_proposal_description(
+    description: Option<ProposalDescription>,
+    group: Pubkey,
+    proposal: Pubkey,
+    proposer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            writable(proposal),
+            readonly_signer(proposer),
+        ],
+        data: ix_data::UpdateConfigProposalDescription {
+            args: UpdateConfigProposalDescriptionInstructionArgs { description },
+        }
+        .data(),
+    }
+}
+
+/// `proposer` is the proposal's proposer, whose open proposal count is updated once it is executed.
+/// `executor_group_member` is only needed when the group requires the Execute permission,
+/// `executor_tip_asset` is one of the proposal's assets when the executor wants to collect
+/// the tip it pays.
+/// When assets require the Execute permission, prefix `remaining_accounts` with
+/// [`executor_asset_member_accounts`].
+#[allow(clippy::too_many_arguments)]
+pub fn execute_proposal_transaction(
+    args: ExecuteProposalTransactionInstructionArgs,
+    group: Pubkey,
+    proposal: Pubkey,
+    proposer: Pubkey,
+    proposal_transaction: Pubkey,
+    rent_collector: Pubkey,
+    executor: Pubkey,
+    executor_group_member: Option<Pubkey>,
+    executor_tip_asset: Option<Pubkey>,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut accounts = vec![
+        writable(proposal),
+        writable(proposal_transaction),
+        writable(group),
+        writable(rent_collector),
+        writable(execution_receipt_pda(&proposal).address),
+        signer(executor),
+        optional_account(executor_group_member, false),
+        optional_account(
+            executor_tip_asset.map(|asset| asset_pda(&group, &asset).address),
+            false,
+        ),
+        optional_account(
+            executor_tip_asset.map(|asset| asset_authority_pda(&group, &asset).address),
+            true,
+        ),
+        readonly(system_program::ID),
+        writable(group_member_pda(&group, &proposer).address),
+    ];
+    accounts.extend(remaining_accounts);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data: ix_data::ExecuteProposalTransaction { args }.data(),
+    }
+}
+
+/// Instruction to include in a normal proposal transaction to apply the proposal's
+/// group config change. `member` is the member invited, removed or renewed by the change, and
+/// `payer`, usually an asset authority, funds the invite of an added member.
+pub fn apply_group_config_change(
+    group: Pubkey,
+    proposal: Pubkey,
+    member: Option<Pubkey>,
+    payer: Option<Pubkey>,
+    rent_collector: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            signer(group),
+            readonly(proposal),
+            optional_account(
+                member.map(|member| group_member_pda(&group, &member).address),
+                true,
+            ),
+            optional_account(
+                member.map(|member| member_invite_pda(&group, &member).address),
+                true,
+            ),
+            match payer {
+                Some(payer) => signer(payer),
+                None => optional_account(None, true),
+            },
+            optional_account(rent_collector, true),
+            readonly(system_program::ID),
+        ],
+        data: ix_data::ApplyGroupConfigChange {}.data(),
+    }
+}
+
+/// The executor's asset member accounts for each asset that requires the Execute
+/// permission, in the same order as the proposal's assets.
+pub fn executor_asset_member_accounts(
+    group: &Pubkey,
+    executor: &Pubkey,
+    asset_addresses: &[Pubkey],
+) -> Vec<AccountMeta> {
+    asset_addresses
+        .iter()
+        .map(|asset| readonly(asset_member_pda(group, asset, executor).address))
+        .collect()
+}
+
+pub fn close_execution_receipt(
+    group: Pubkey,
+    proposal: Pubkey,
+    rent_collector: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            readonly(proposal),
+            writable(execution_receipt_pda(&proposal).address),
+            writable(rent_collector),
+        ],
+        data: ix_data::CloseExecutionReceipt {}.data(),
+    }
+}
+
+pub fn close_proposal_transaction(
+    group: Pubkey,
+    proposal: Pubkey,
+    proposal_transaction: Pubkey,
+    rent_collector: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            readonly(proposal),
+            writable(proposal_transaction),
+            writable(rent_collector),
+        ],
+        data: ix_data::CloseProposalTransactionInstruction {}.data(),
+    }
+}
+
+pub fn close_config_proposal(group: Pubkey, proposal: Pubkey, proposer: Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            writable(group),
+            writable(proposal),
+            writable(proposer),
+            writable(group_member_pda(&group, &proposer).address),
+        ],
+        data: ix_data::CloseProposalInstruction {}.data(),
+    }
+}
+
+pub fn close_normal_proposal(group: Pubkey, proposal: Pubkey, proposer: Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            writable(group),
+            writable(proposal),
+            writable(proposer),
+            writable(group_member_pda(&group, &proposer).address),
+        ],
+        data: ix_data::CloseNormalProposalInstruction {}.data(),
+    }
+}
+
+/// `token_bond` is required for a bond paid in a token, its token account receives the bond:
+/// the depositor's on a refund, the rent collector's when the bond is forfeited.
+pub fn release_proposal_bond(
+    group: Pubkey,
+    proposal: Pubkey,
+    depositor: Pubkey,
+    rent_collector: Pubkey,
+    token_bond: Option<TokenBondAccounts>,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            writable(proposal),
+            writable(proposal_bond_pda(&proposal).address),
+            writable(depositor),
+            writable(rent_collector),
+            optional_account(token_bond.map(|token_bond| token_bond.mint), false),
+            optional_account(token_bond.map(|_| bond_escrow_pda(&proposal).address), true),
+            optional_account(token_bond.map(|token_bond| token_bond.token_account), true),
+            optional_account(token_bond.map(|token_bond| token_bond.token_program), false),
+        ],
+        data: ix_data::ReleaseProposalBond {}.data(),
+    }
+}
+
+pub fn close_normal_vote_record(
+    group: Pubkey,
+    proposal: Pubkey,
+    voter: Pubkey,
+    asset_index: u8,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            readonly(proposal),
+            writable(normal_vote_record_pda(&group, &proposal, &voter, asset_index).address),
+            signer(voter),
+        ],
+        data: ix_data::CloseNormalVoteRecordInstruction {
+            args: CloseNormalVoteRecordInstructionArgs { asset_index },
+        }
+        .data(),
+    }
+}
+
+pub fn close_config_vote_record(group: Pubkey, proposal: Pubkey, voter: Pubkey) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            readonly(proposal),
+            writable(config_vote_record_pda(&group, &proposal, &voter).address),
+            signer(voter),
+        ],
+        data: ix_data::CloseConfigVoteRecordInstruction {}.data(),
+    }
+}
+
+pub fn clean_up_asset_member(
+    group: Pubkey,
+    asset_address: Pubkey,
+    member: Pubkey,
+    rent_collector: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            writable(asset_member_pda(&group, &asset_address, &member).address),
+            writable(asset_pda(&group, &asset_address).address),
+            readonly(group_member_pda(&group, &member).address),
+            readonly(member),
+            writable(rent_collector),
+        ],
+        data: ix_data::CleanUpAssetMemberInstruction {}.data(),
+    }
+}
+
+pub fn emergency_reset_proposal_pda(group: &Pubkey, proposal_seed: &Pubkey) -> Pda {
+    pda(&[b"emergency-reset", group.as_ref(), proposal_seed.as_ref()])
+}
+
+pub fn emergency_reset_vote_record_pda(group: &Pubkey, proposal: &Pubkey, voter: &Pubkey) -> Pda {
+    config_vote_record_pda(group, proposal, voter)
+}
+
+pub fn create_emergency_reset_proposal(
+    args: CreateEmergencyResetProposalArgs,
+    group: Pubkey,
+    proposer: Pubkey,
+) -> Instruction {
+    let proposal = emergency_reset_proposal_pda(&group, &args.proposal_seed).address;
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            signer(proposer),
+            writable(group),
+            readonly(group_member_pda(&group, &proposer).address),
+            writable(proposal),
+            readonly(system_program::ID),
+        ],
+        data: ix_data::CreateEmergencyResetProposal { args }.data(),
+    }
+}
+
+pub fn vote_on_emergency_reset_proposal(
+    args: VoteOnEmergencyResetArgs,
     group: Pubkey,
     proposal: Pubkey,
     voter: Pubkey,
@@ -765,3 +1832,145 @@ pub fn exit_pause_mode(
         data: ix_data::ExitPauseMode { args }.data(),
     }
 }
+
+/// Creates a schedule, signed by the asset authority. Meant to be run by a normal proposal
+/// whose asset index points at `asset_address`, the second account.
+pub fn create_schedule(
+    args: CreateScheduleInstructionArgs,
+    group: Pubkey,
+    asset_address: Pubkey,
+    payer: Pubkey,
+) -> Instruction {
+    let schedule = schedule_pda(&group, &args.schedule_seed).address;
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            readonly(asset_address),
+            readonly(asset_pda(&group, &asset_address).address),
+            readonly_signer(asset_authority_pda(&group, &asset_address).address),
+            writable(schedule),
+            signer(payer),
+            readonly(system_program::ID),
+        ],
+        data: ix_data::CreateSchedule { args }.data(),
+    }
+}
+
+/// Token accounts of a schedule making token transfers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScheduleTokenAccounts {
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+}
+
+/// `destination` is the destination of the scheduled transfer, a token account for token
+/// transfers, which also require `token` and are made from `asset_address`.
+pub fn run_schedule(
+    group: Pubkey,
+    schedule_seed: Pubkey,
+    asset_address: Pubkey,
+    destination: Pubkey,
+    rent_payer: Pubkey,
+    token: Option<ScheduleTokenAccounts>,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            writable(schedule_pda(&group, &schedule_seed).address),
+            writable(asset_authority_pda(&group, &asset_address).address),
+            writable(destination),
+            optional_account(token.map(|_| asset_address), true),
+            optional_account(token.map(|token| token.mint), false),
+            optional_account(token.map(|token| token.token_program), false),
+            writable(rent_payer),
+            readonly(system_program::ID),
+        ],
+        data: ix_data::RunSchedule {}.data(),
+    }
+}
+
+pub fn cancel_schedule(
+    group: Pubkey,
+    asset_address: Pubkey,
+    schedule: Pubkey,
+    rent_payer: Pubkey,
+    proposal: Pubkey,
+    proposer: Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            writable(group),
+            readonly(asset_pda(&group, &asset_address).address),
+            writable(schedule),
+            writable(rent_payer),
+            writable(proposal),
+            writable(proposer),
+            writable(group_member_pda(&group, &proposer).address),
+        ],
+        data: ix_data::CancelSchedule {}.data(),
+    }
+}
+
+/// Creates the transaction of option `args.option_index`, `asset_addresses` are the
+/// proposal's assets, in order.
+pub fn create_proposal_option_transaction(
+    args: CreateProposalOptionTransactionInstructionArgs,
+    group: Pubkey,
+    proposal_seed: Pubkey,
+    payer: Pubkey,
+    asset_addresses: &[Pubkey],
+) -> Instruction {
+    let proposal = proposal_pda(&group, &proposal_seed).address;
+
+    let mut accounts = vec![
+        readonly(group),
+        readonly(proposal),
+        writable(proposal_option_transaction_pda(&proposal, args.option_index).address),
+        signer(payer),
+        readonly(system_program::ID),
+    ];
+    accounts.extend(
+        asset_addresses
+            .iter()
+            .map(|asset_address| readonly(asset_pda(&group, asset_address).address)),
+    );
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts,
+        data: ix_data::CreateProposalOptionTransaction { args }.data(),
+    }
+}
+
+/// `asset_address` is the single asset of the multi-choice proposal.
+pub fn vote_on_proposal_option(
+    args: VoteOnProposalOptionInstructionArgs,
+    group: Pubkey,
+    proposal: Pubkey,
+    asset_address: Pubkey,
+    voter: Pubkey,
+) -> Instruction {
+    let proposal_transaction = args
+        .option_index
+        .map(|index| proposal_option_transaction_pda(&proposal, index).address);
+
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            readonly(group),
+            writable(proposal),
+            optional_account(proposal_transaction, false),
+            readonly(asset_pda(&group, &asset_address).address),
+            readonly(group_member_pda(&group, &voter).address),
+            readonly(asset_member_pda(&group, &asset_address, &voter).address),
+            writable(normal_vote_record_pda(&group, &proposal, &voter, 0).address),
+            readonly_signer(voter),
+            signer(voter),
+            readonly(system_program::ID),
+        ],
+        data: ix_data::VoteOnProposalOption { args }.data(),
+    }
+}
@@ -1,10 +1,14 @@
 use multisig::{
     instructions::{
         CreateConfigProposalInstructionArgs, CreateGroupInstructionArgs,
-        CreateNormalProposalInstructionArgs, CreateProposalTransactionInstructionArgs,
-        VoteOnConfigProposalInstructionArgs, VoteOnNormalProposalInstructionArgs,
+        CreateNormalProposalInstructionArgs, CreateProposalOptionTransactionInstructionArgs,
+        CreateProposalTransactionInstructionArgs, CreateScheduleInstructionArgs,
+        ExecuteProposalTransactionInstructionArgs, VoteOnConfigProposalInstructionArgs,
+        VoteOnMultiAssetConfigProposalInstructionArgs, VoteOnNormalProposalInstructionArgs,
+        VoteOnProposalOptionInstructionArgs,
     },
-    AssetIndex, ConfigChange, ConfigType, FractionalThreshold, Permissions, VoteChoice,
+    AssetIndex, ConfigChange, ConfigType, FractionalThreshold, Metadata, MetadataContent,
+    Permissions, ProposalDescription, ScheduledTransfer, VoteChoice,
 };
 use solana_sdk::{hash, pubkey::Pubkey, system_program};
 
@@ -83,7 +87,7 @@ fn builds_create_group_instruction_accounts() {
 }
 
 #[test]
-fn create_normal_proposal_does_not_attach_registered_asset_accounts() {
+fn create_normal_proposal_attaches_asset_accounts_as_remaining_accounts() {
     let group = Pubkey::new_unique();
     let proposer = Pubkey::new_unique();
     let asset = Pubkey::new_unique();
@@ -98,11 +102,24 @@ fn create_normal_proposal_does_not_attach_registered_asset_accounts() {
         timelock_offset: 0,
         proposal_deadline_timestamp: 100,
         instruction_hashes: vec![hash::Hash::new_unique().to_bytes()],
+        sequential_execution: false,
+        balance_guards: vec![],
+        preconditions: vec![],
+        group_config_change: None,
+        compact_votes: false,
+        description: None,
+        options: vec![],
     };
 
     let ix = multisig_sdk::create_normal_proposal(args, group, proposer);
 
-    assert_eq!(ix.accounts.len(), 5);
+    // The asset accounts follow the optional proposal bond accounts.
+    assert_eq!(ix.accounts.len(), 12);
+    assert_eq!(
+        ix.accounts[11].pubkey,
+        multisig_sdk::asset_pda(&group, &asset).address
+    );
+    assert!(!ix.accounts[11].is_writable);
 }
 
 #[test]
@@ -145,6 +162,48 @@ fn vote_on_normal_proposal_includes_preimage_transaction_account() {
     assert!(!ix.accounts[2].is_writable);
 }
 
+#[test]
+fn execute_proposal_transaction_includes_receipt_and_executor() {
+    let group = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let proposer = Pubkey::new_unique();
+    let rent_collector = Pubkey::new_unique();
+    let executor = Pubkey::new_unique();
+    let args = ExecuteProposalTransactionInstructionArgs {
+        instruction_count: 1,
+    };
+
+    let ix = multisig_sdk::execute_proposal_transaction(
+        args,
+        group,
+        proposal,
+        proposer,
+        multisig_sdk::proposal_transaction_pda(&proposal).address,
+        rent_collector,
+        executor,
+        None,
+        None,
+        Vec::new(),
+    );
+
+    assert_eq!(
+        ix.accounts[4].pubkey,
+        multisig_sdk::execution_receipt_pda(&proposal).address
+    );
+    assert!(ix.accounts[4].is_writable);
+    assert_eq!(ix.accounts[5].pubkey, executor);
+    assert!(ix.accounts[5].is_signer);
+    assert_eq!(ix.accounts[6].pubkey, multisig_sdk::PROGRAM_ID);
+    assert_eq!(ix.accounts[7].pubkey, multisig_sdk::PROGRAM_ID);
+    assert_eq!(ix.accounts[8].pubkey, multisig_sdk::PROGRAM_ID);
+    assert_eq!(ix.accounts[9].pubkey, system_program::ID);
+    assert_eq!(
+        ix.accounts[10].pubkey,
+        multisig_sdk::group_member_pda(&group, &proposer).address
+    );
+    assert!(ix.accounts[10].is_writable);
+}
+
 #[test]
 fn optional_sdk_accounts_use_readonly_program_id_sentinel() {
     let group = Pubkey::new_unique();
@@ -155,8 +214,10 @@ fn optional_sdk_accounts_use_readonly_program_id_sentinel() {
         timelock_offset: 0,
         proposal_deadline_timestamp: 100,
         config_change: ConfigChange::ChangeGroupConfig {
-            config_type: ConfigType::MinimumVoteCount(1),
+            config_types: vec![ConfigType::MinimumVoteCount(1)],
         },
+        compact_votes: false,
+        description: None,
     };
 
     let create_ix = multisig_sdk::create_config_proposal(create_args, group, proposer, None);
@@ -173,3 +234,641 @@ fn optional_sdk_accounts_use_readonly_program_id_sentinel() {
     assert_eq!(vote_ix.accounts[4].pubkey, multisig::ID);
     assert!(!vote_ix.accounts[4].is_writable);
 }
+
+#[test]
+fn apply_group_config_change_is_signed_by_group() {
+    let group = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let member = Pubkey::new_unique();
+
+    let ix = multisig_sdk::apply_group_config_change(group, proposal, Some(member), None, None);
+
+    assert_eq!(ix.program_id, multisig::ID);
+    assert_eq!(ix.accounts[0].pubkey, group);
+    assert!(ix.accounts[0].is_signer);
+    assert!(!ix.accounts[1].is_writable);
+    assert_eq!(
+        ix.accounts[2].pubkey,
+        multisig_sdk::group_member_pda(&group, &member).address
+    );
+    assert!(ix.accounts[2].is_writable);
+    assert_eq!(
+        ix.accounts[3].pubkey,
+        multisig_sdk::member_invite_pda(&group, &member).address
+    );
+    assert!(ix.accounts[3].is_writable);
+    assert_eq!(ix.accounts[4].pubkey, multisig::ID);
+    assert!(!ix.accounts[4].is_signer);
+    assert_eq!(ix.accounts[5].pubkey, multisig::ID);
+    assert_eq!(ix.accounts[6].pubkey, system_program::ID);
+}
+
+#[test]
+fn accept_membership_moves_the_invite_to_a_member_account() {
+    let group = Pubkey::new_unique();
+    let new_member = Pubkey::new_unique();
+    let invite_payer = Pubkey::new_unique();
+
+    let ix = multisig_sdk::accept_membership(group, new_member, invite_payer);
+
+    assert_eq!(
+        ix.accounts[1].pubkey,
+        multisig_sdk::member_invite_pda(&group, &new_member).address
+    );
+    assert_eq!(
+        ix.accounts[2].pubkey,
+        multisig_sdk::group_member_pda(&group, &new_member).address
+    );
+    assert!(ix.accounts[3].is_signer);
+    assert_eq!(ix.accounts[4].pubkey, invite_payer);
+    assert!(ix.accounts[4].is_writable);
+}
+
+#[test]
+fn delegated_vote_accounts_are_triples_per_delegator() {
+    let group = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let asset = Pubkey::new_unique();
+    let delegators = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let accounts =
+        multisig_sdk::normal_delegated_vote_accounts(&group, &proposal, &asset, 1, &delegators);
+    assert_eq!(accounts.len(), 6);
+    assert_eq!(
+        accounts[3].pubkey,
+        multisig_sdk::delegation_pda(&group, &delegators[1], Some(&asset)).address
+    );
+    assert_eq!(
+        accounts[4].pubkey,
+        multisig_sdk::asset_member_pda(&group, &asset, &delegators[1]).address
+    );
+    assert_eq!(
+        accounts[5].pubkey,
+        multisig_sdk::normal_vote_record_pda(&group, &proposal, &delegators[1], 1).address
+    );
+    assert!(!accounts[3].is_writable);
+    assert!(accounts[5].is_writable);
+
+    // Group scoped delegations use the group itself as the scope seed.
+    let accounts =
+        multisig_sdk::config_delegated_vote_accounts(&group, &proposal, None, &delegators[..1]);
+    assert_eq!(
+        accounts[0].pubkey,
+        multisig_sdk::delegation_pda(&group, &delegators[0], Some(&group)).address
+    );
+    assert_eq!(
+        accounts[1].pubkey,
+        multisig_sdk::group_member_pda(&group, &delegators[0]).address
+    );
+}
+
+#[test]
+fn batch_vote_on_normal_proposal_appends_accounts_per_vote() {
+    use multisig::{
+        instructions::{AssetVote, BatchVoteOnNormalProposalInstructionArgs},
+        VoteChoice,
+    };
+
+    let group = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let voter = Pubkey::new_unique();
+    let assets = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let args = BatchVoteOnNormalProposalInstructionArgs {
+        votes: vec![
+            AssetVote {
+                voting_asset_index: 0,
+                vote: VoteChoice::For,
+            },
+            AssetVote {
+                voting_asset_index: 2,
+                vote: VoteChoice::Against,
+            },
+        ],
+    };
+    let ix = multisig_sdk::batch_vote_on_normal_proposal(args, group, proposal, &assets, voter);
+
+    assert_eq!(ix.accounts.len(), 7 + 6);
+    assert_eq!(
+        ix.accounts[10].pubkey,
+        multisig_sdk::asset_pda(&group, &assets[1]).address
+    );
+    assert_eq!(
+        ix.accounts[11].pubkey,
+        multisig_sdk::asset_member_pda(&group, &assets[1], &voter).address
+    );
+    assert_eq!(
+        ix.accounts[12].pubkey,
+        multisig_sdk::normal_vote_record_pda(&group, &proposal, &voter, 2).address
+    );
+    assert!(ix.accounts[12].is_writable);
+}
+
+#[test]
+fn compact_votes_do_not_touch_vote_records() {
+    use multisig::instructions::{
+        CompactVoteOnConfigProposalInstructionArgs, CompactVoteOnNormalProposalInstructionArgs,
+    };
+
+    let group = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let voter = Pubkey::new_unique();
+    let asset = Pubkey::new_unique();
+
+    let ix = multisig_sdk::compact_vote_on_normal_proposal(
+        CompactVoteOnNormalProposalInstructionArgs {
+            voting_asset_index: 0,
+            vote: VoteChoice::For,
+        },
+        group,
+        proposal,
+        asset,
+        voter,
+    );
+    assert_eq!(ix.accounts.len(), 7);
+    assert_eq!(
+        ix.accounts[5].pubkey,
+        multisig_sdk::asset_member_pda(&group, &asset, &voter).address
+    );
+    // Only the proposal is written, the voter pays for nothing.
+    assert!(ix
+        .accounts
+        .iter()
+        .all(|meta| meta.is_writable == (meta.pubkey == proposal)));
+    assert!(ix.accounts[6].is_signer);
+
+    let ix = multisig_sdk::compact_vote_on_config_proposal(
+        CompactVoteOnConfigProposalInstructionArgs {
+            vote: VoteChoice::Against,
+        },
+        group,
+        proposal,
+        voter,
+        None,
+    );
+    assert_eq!(ix.accounts.len(), 6);
+    assert!(ix
+        .accounts
+        .iter()
+        .all(|meta| meta.is_writable == (meta.pubkey == proposal)));
+}
+
+#[test]
+fn ed25519_verify_instruction_keeps_signatures_in_its_own_data() {
+    let signer = Pubkey::new_unique();
+    let message = multisig_sdk::signed_vote_message(
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        None,
+        VoteChoice::For,
+        7,
+    )
+    .unwrap();
+
+    let ix = multisig_sdk::ed25519_verify_instruction(&[(signer, [9; 64], message.clone())]);
+    let data = ix.data.as_slice();
+    let read_u16 = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+
+    assert_eq!(data[0], 1);
+    // Signature, public key and message all point into this instruction.
+    assert_eq!(read_u16(4), u16::MAX);
+    assert_eq!(read_u16(8), u16::MAX);
+    assert_eq!(read_u16(14), u16::MAX);
+
+    let public_key_offset = usize::from(read_u16(6));
+    assert_eq!(
+        &data[public_key_offset..public_key_offset + 32],
+        signer.as_ref()
+    );
+    let signature_offset = usize::from(read_u16(2));
+    assert_eq!(&data[signature_offset..signature_offset + 64], &[9; 64]);
+    let message_offset = usize::from(read_u16(10));
+    let message_size = usize::from(read_u16(12));
+    assert_eq!(
+        &data[message_offset..message_offset + message_size],
+        message.as_slice()
+    );
+}
+
+#[test]
+fn submit_signed_votes_passes_member_and_vote_record_per_voter() {
+    use multisig::instructions::SubmitSignedVotesOnConfigProposalInstructionArgs;
+
+    let group = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let voters = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let ix = multisig_sdk::submit_signed_votes_on_config_proposal(
+        SubmitSignedVotesOnConfigProposalInstructionArgs {
+            votes: vec![VoteChoice::For, VoteChoice::Against],
+        },
+        group,
+        proposal,
+        None,
+        &voters,
+        payer,
+    );
+
+    assert_eq!(ix.accounts.len(), 6 + 4);
+    assert_eq!(
+        ix.accounts[8].pubkey,
+        multisig_sdk::group_member_pda(&group, &voters[1]).address
+    );
+    assert_eq!(
+        ix.accounts[9].pubkey,
+        multisig_sdk::config_vote_record_pda(&group, &proposal, &voters[1]).address
+    );
+    // Only the payer signs, voters approve through the Ed25519 instruction.
+    assert_eq!(ix.accounts.iter().filter(|meta| meta.is_signer).count(), 1);
+}
+
+#[test]
+fn rotate_member_key_moves_each_asset_member() {
+    let group = Pubkey::new_unique();
+    let old_key = Pubkey::new_unique();
+    let new_key = Pubkey::new_unique();
+    let asset = Pubkey::new_unique();
+
+    let ix = multisig_sdk::rotate_member_key(group, old_key, new_key, &[asset]);
+
+    assert_eq!(ix.accounts.len(), 6 + 2);
+    assert_eq!(
+        ix.accounts[2].pubkey,
+        multisig_sdk::group_member_pda(&group, &new_key).address
+    );
+    assert_eq!(
+        ix.accounts[6].pubkey,
+        multisig_sdk::asset_member_pda(&group, &asset, &old_key).address
+    );
+    assert_eq!(
+        ix.accounts[7].pubkey,
+        multisig_sdk::asset_member_pda(&group, &asset, &new_key).address
+    );
+    assert!(ix.accounts[3].is_signer && ix.accounts[4].is_signer);
+}
+
+#[test]
+fn cancel_proposal_checks_the_cancellers_membership() {
+    let group = Pubkey::new_unique();
+    let proposal = Pubkey::new_unique();
+    let canceller = Pubkey::new_unique();
+
+    let ix = multisig_sdk::cancel_normal_proposal(group, proposal, canceller);
+
+    assert_eq!(ix.accounts.len(), 4);
+    assert!(ix.accounts[1].is_writable);
+    assert_eq!(
+        ix.accounts[2].pubkey,
+        multisig_sdk::group_member_pda(&group, &canceller).address
+    );
+    assert!(ix.accounts[3].is_signer && !ix.accounts[3].is_writable);
+}
+
+#[test]
+fn fetch_metadata_reads_the_member_metadata_pda() {
+    use anchor_lang::{AccountSerialize, Discriminator};
+
+    let group = Pubkey::new_unique();
+    let member = Pubkey::new_unique();
+    let group_member = multisig_sdk::group_member_pda(&group, &member).address;
+    let metadata = Metadata {
+        subject: group_member,
+        group,
+        content: MetadataContent {
+            name: "treasurer".to_string(),
+            uri: "https://example.com/treasurer.json".to_string(),
+            description_hash: [7; 32],
+        },
+        payer: member,
+        account_bump: multisig_sdk::metadata_pda(&group_member).bump,
+    };
+    let mut data = Vec::new();
+    metadata.try_serialize(&mut data).unwrap();
+    assert_eq!(&data[..8], Metadata::DISCRIMINATOR);
+
+    let fetched = multisig_sdk::fetch_group_member_metadata(&group, &member, |address| {
+        assert_eq!(*address, multisig_sdk::metadata_pda(&group_member).address);
+        Some(data)
+    })
+    .unwrap()
+    .expect("metadata");
+    assert!(fetched.content == metadata.content);
+    assert_eq!(fetched.subject, group_member);
+
+    assert!(multisig_sdk::fetch_group_metadata(&group, |_| None)
+        .unwrap()
+        .is_none());
+
+    let ix = multisig_sdk::set_member_metadata(metadata.content, group, member);
+    assert_eq!(ix.accounts[1].pubkey, group_member);
+    assert_eq!(
+        ix.accounts[2].pubkey,
+        multisig_sdk::metadata_pda(&group_member).address
+    );
+}
+
+#[test]
+fn proposal_description_is_verified_against_its_content_hash() {
+    let content = b"# Fund the audit\nPay 10,000 USDC to the auditors.";
+    let description = ProposalDescription {
+        title: "Fund the audit".to_string(),
+        uri: "https://example.com/proposals/audit.md".to_string(),
+        content_hash: multisig_sdk::proposal_content_hash(content),
+    };
+
+    assert!(multisig_sdk::verify_proposal_description(
+        &description,
+        content
+    ));
+    assert!(!multisig_sdk::verify_proposal_description(
+        &description,
+        b"# Fund the audit\nPay 100,000 USDC to the auditors."
+    ));
+}
+
+#[test]
+fn with_rent_payer_lets_a_group_vote_as_a_member_of_another_group() {
+    let parent_group = Pubkey::new_unique();
+    let child_group = Pubkey::new_unique();
+    let asset_authority = multisig_sdk::asset_authority_pda(&child_group, &Pubkey::new_unique());
+    let proposal = Pubkey::new_unique();
+
+    let vote_ix = multisig_sdk::vote_on_config_proposal(
+        VoteOnConfigProposalInstructionArgs {
+            vote: VoteChoice::For,
+        },
+        parent_group,
+        proposal,
+        child_group,
+        None,
+    );
+    let payer_position = vote_ix.accounts.len() - 2;
+    assert_eq!(vote_ix.accounts[payer_position].pubkey, child_group);
+
+    let vote_ix = multisig_sdk::with_rent_payer(vote_ix, asset_authority.address);
+
+    let voter = vote_ix
+        .accounts
+        .iter()
+        .find(|meta| meta.pubkey == child_group)
+        .expect("voter");
+    assert!(voter.is_signer);
+    assert!(!voter.is_writable);
+    assert_eq!(
+        vote_ix.accounts[payer_position].pubkey,
+        asset_authority.address
+    );
+    assert!(vote_ix.accounts[payer_position].is_signer);
+    assert!(vote_ix.accounts[payer_position].is_writable);
+    assert_eq!(
+        vote_ix.accounts[payer_position + 1].pubkey,
+        system_program::ID
+    );
+}
+
+#[test]
+fn multi_asset_config_proposal_passes_assets_in_address_order() {
+    let group = Pubkey::new_unique();
+    let proposer = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let mut asset_addresses = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    asset_addresses.reverse();
+
+    let create_ix = multisig_sdk::create_multi_asset_config_proposal(
+        CreateConfigProposalInstructionArgs {
+            proposal_seed: Pubkey::new_unique(),
+            timelock_offset: 0,
+            proposal_deadline_timestamp: 100,
+            config_change: ConfigChange::ChangeAssetConfig {
+                config_types: vec![ConfigType::MinimumVoteCount(2)],
+            },
+            compact_votes: false,
+            description: None,
+        },
+        group,
+        proposer,
+        &asset_addresses,
+    );
+    let create_ix = multisig_sdk::with_rent_payer(create_ix, payer);
+
+    asset_addresses.sort();
+    let asset_pdas: Vec<Pubkey> = asset_addresses
+        .iter()
+        .map(|asset_address| multisig_sdk::asset_pda(&group, asset_address).address)
+        .collect();
+    assert_eq!(create_ix.accounts[2].pubkey, asset_pdas[0]);
+
+    let system_position = create_ix
+        .accounts
+        .iter()
+        .position(|meta| meta.pubkey == system_program::ID)
+        .expect("system program");
+    assert_eq!(create_ix.accounts[system_position - 1].pubkey, payer);
+    let remaining: Vec<Pubkey> = create_ix.accounts[system_position + 6..]
+        .iter()
+        .map(|meta| meta.pubkey)
+        .collect();
+    assert_eq!(remaining, asset_pdas[1..]);
+
+    let proposal = Pubkey::new_unique();
+    let vote_ix = multisig_sdk::vote_on_multi_asset_config_proposal(
+        VoteOnMultiAssetConfigProposalInstructionArgs {
+            voting_asset_index: 1,
+            vote: VoteChoice::For,
+        },
+        group,
+        proposal,
+        asset_addresses[1],
+        proposer,
+    );
+    assert_eq!(vote_ix.accounts[2].pubkey, asset_pdas[1]);
+    assert_eq!(
+        vote_ix.accounts[5].pubkey,
+        multisig_sdk::normal_vote_record_pda(&group, &proposal, &proposer, 1).address
+    );
+}
+
+#[test]
+fn with_proposal_bond_fills_in_the_bond_accounts() {
+    let group = Pubkey::new_unique();
+    let proposer = Pubkey::new_unique();
+    let payer = Pubkey::new_unique();
+    let args = CreateConfigProposalInstructionArgs {
+        proposal_seed: Pubkey::new_unique(),
+        timelock_offset: 0,
+        proposal_deadline_timestamp: 100,
+        config_change: ConfigChange::ChangeGroupConfig {
+            config_types: vec![ConfigType::MinimumVoteCount(2)],
+        },
+        compact_votes: false,
+        description: None,
+    };
+    let proposal = multisig_sdk::proposal_pda(&group, &args.proposal_seed).address;
+
+    let create_ix = multisig_sdk::create_config_proposal(args, group, proposer, None);
+    let system_position = create_ix
+        .accounts
+        .iter()
+        .position(|meta| meta.pubkey == system_program::ID)
+        .expect("system program");
+    assert!(create_ix.accounts[system_position + 1..]
+        .iter()
+        .all(|meta| meta.pubkey == multisig_sdk::PROGRAM_ID));
+
+    let token_bond = multisig_sdk::TokenBondAccounts {
+        mint: Pubkey::new_unique(),
+        token_account: Pubkey::new_unique(),
+        token_program: Pubkey::new_unique(),
+    };
+    let create_ix = multisig_sdk::with_proposal_bond(
+        multisig_sdk::with_rent_payer(create_ix, payer),
+        proposal,
+        Some(token_bond),
+    );
+
+    assert_eq!(create_ix.accounts[system_position - 1].pubkey, payer);
+    let bond_accounts: Vec<Pubkey> = create_ix.accounts[system_position + 1..]
+        .iter()
+        .map(|meta| meta.pubkey)
+        .collect();
+    assert_eq!(
+        bond_accounts,
+        [
+            multisig_sdk::proposal_bond_pda(&proposal).address,
+            token_bond.mint,
+            token_bond.token_account,
+            multisig_sdk::bond_escrow_pda(&proposal).address,
+            token_bond.token_program,
+        ]
+    );
+    assert!(create_ix.accounts[system_position + 1].is_writable);
+    assert!(create_ix.accounts[system_position + 4].is_writable);
+}
+
+#[test]
+fn schedule_instructions_use_the_schedule_and_asset_authority() {
+    let group = Pubkey::new_unique();
+    let asset_address = Pubkey::new_unique();
+    let schedule_seed = Pubkey::new_unique();
+    let rent_payer = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let schedule = multisig_sdk::schedule_pda(&group, &schedule_seed).address;
+    let asset_authority = multisig_sdk::asset_authority_pda(&group, &asset_address).address;
+
+    let create_ix = multisig_sdk::create_schedule(
+        CreateScheduleInstructionArgs {
+            schedule_seed,
+            transfer: ScheduledTransfer::Lamports {
+                destination,
+                amount: 1,
+            },
+            interval: 60,
+            total_runs: 2,
+            start_timestamp: 0,
+        },
+        group,
+        asset_address,
+        asset_authority,
+    );
+    // A proposal points its asset index at the asset address, the second account.
+    assert_eq!(create_ix.accounts[1].pubkey, asset_address);
+    assert_eq!(create_ix.accounts[3].pubkey, asset_authority);
+    assert!(create_ix.accounts[3].is_signer);
+    assert_eq!(create_ix.accounts[4].pubkey, schedule);
+    assert!(create_ix.accounts[4].is_writable);
+
+    let run_ix = multisig_sdk::run_schedule(
+        group,
+        schedule_seed,
+        asset_address,
+        destination,
+        rent_payer,
+        None,
+    );
+    assert_eq!(run_ix.accounts[1].pubkey, schedule);
+    assert_eq!(run_ix.accounts[2].pubkey, asset_authority);
+    assert!(run_ix.accounts[2].is_writable);
+    assert_eq!(run_ix.accounts[3].pubkey, destination);
+    assert!(run_ix.accounts[4..7]
+        .iter()
+        .all(|meta| meta.pubkey == multisig_sdk::PROGRAM_ID));
+
+    let token = multisig_sdk::ScheduleTokenAccounts {
+        mint: Pubkey::new_unique(),
+        token_program: Pubkey::new_unique(),
+    };
+    let run_ix = multisig_sdk::run_schedule(
+        group,
+        schedule_seed,
+        asset_address,
+        destination,
+        rent_payer,
+        Some(token),
+    );
+    assert_eq!(run_ix.accounts[4].pubkey, asset_address);
+    assert!(run_ix.accounts[4].is_writable);
+    assert_eq!(run_ix.accounts[5].pubkey, token.mint);
+    assert_eq!(run_ix.accounts[6].pubkey, token.token_program);
+}
+
+#[test]
+fn proposal_option_instructions_use_the_option_transaction() {
+    let group = Pubkey::new_unique();
+    let proposal_seed = Pubkey::new_unique();
+    let asset_address = Pubkey::new_unique();
+    let voter = Pubkey::new_unique();
+    let proposal = multisig_sdk::proposal_pda(&group, &proposal_seed).address;
+    let option_transaction = multisig_sdk::proposal_option_transaction_pda(&proposal, 1).address;
+
+    assert_ne!(
+        option_transaction,
+        multisig_sdk::proposal_transaction_pda(&proposal).address
+    );
+
+    let create_ix = multisig_sdk::create_proposal_option_transaction(
+        CreateProposalOptionTransactionInstructionArgs {
+            option_index: 1,
+            raw_instructions: vec![vec![1]],
+        },
+        group,
+        proposal_seed,
+        voter,
+        &[asset_address],
+    );
+    assert_eq!(create_ix.accounts[2].pubkey, option_transaction);
+    assert!(create_ix.accounts[2].is_writable);
+    assert_eq!(
+        create_ix.accounts[5].pubkey,
+        multisig_sdk::asset_pda(&group, &asset_address).address
+    );
+
+    let vote_ix = multisig_sdk::vote_on_proposal_option(
+        VoteOnProposalOptionInstructionArgs {
+            option_index: Some(1),
+        },
+        group,
+        proposal,
+        asset_address,
+        voter,
+    );
+    assert_eq!(vote_ix.accounts[2].pubkey, option_transaction);
+    assert_eq!(
+        vote_ix.accounts[6].pubkey,
+        multisig_sdk::normal_vote_record_pda(&group, &proposal, &voter, 0).address
+    );
+
+    // A vote against every option does not need an option transaction.
+    let against_ix = multisig_sdk::vote_on_proposal_option(
+        VoteOnProposalOptionInstructionArgs { option_index: None },
+        group,
+        proposal,
+        asset_address,
+        voter,
+    );
+    assert_eq!(against_ix.accounts[2].pubkey, multisig_sdk::PROGRAM_ID);
+}